@@ -4,7 +4,8 @@
 // Contains the implementation for the `DELETE /v1/w/{workspace_id}/change-sets/{change_set_id}` API endpoint call.
 
 // Design Choices:
-// - Uses the shared `get_api_config` function from the parent module.
+// - Uses the shared `get_api_config_for_write` function from the parent module, which refuses
+//   the call when the active profile is read-only.
 // - Sends a DELETE request.
 // - Handles response status and deserialization (expects `{"success": true}`).
 // - Logs request and response details.
@@ -14,7 +15,15 @@ use std::error::Error;
 // Use the shared config getter and ApiError type from the parent module
 use super::{
     ApiError,
-    get_api_config,
+    CallLog,
+    CancelToken,
+    LogEntry,
+    cache,
+    deserialize_error,
+    get_api_config_for_write,
+    read_body,
+    redact_text,
+    send,
 };
 // Import the specific response model needed for this function
 use crate::api_models::DeleteChangeSetV1Response;
@@ -26,17 +35,18 @@ use crate::api_models::DeleteChangeSetV1Response;
 /// # Arguments
 /// * `workspace_id` - The ID of the workspace containing the change set.
 /// * `change_set_id` - The ID of the change set to delete.
+/// * `cancel` - Cancels the request when cancelled (see `CancelToken`).
 ///
 /// # Returns
 /// A `Result` containing the `DeleteChangeSetV1Response` (which includes a `success` boolean) on success, or an error string on failure.
-/// Also returns a `Vec<String>` containing logs generated during the call.
+/// Also returns a `Vec<LogEntry>` containing logs generated during the call.
 ///
 /// # Intention
 /// Provides the functionality to abandon a change set via the API.
 ///
 /// # Design
 /// - Constructs the specific URL for the change set deletion endpoint.
-/// - Uses the shared `reqwest` client and configuration (via `get_api_config`).
+/// - Uses the shared `reqwest` client and configuration (via `get_api_config_for_write`).
 /// - Sends an HTTP DELETE request.
 /// - Handles success and error responses similarly to other API client functions.
 /// - Deserializes the success response into `DeleteChangeSetV1Response` (which contains `{ "success": true }`).
@@ -44,44 +54,50 @@ use crate::api_models::DeleteChangeSetV1Response;
 pub async fn abandon_change_set(
     workspace_id: &str,
     change_set_id: &str,
+    cancel: &CancelToken,
 ) -> Result<
-    (DeleteChangeSetV1Response, Vec<String>), // Return type already matches plan
+    (DeleteChangeSetV1Response, Vec<LogEntry>), // Return type already matches plan
     Box<dyn Error + Send + Sync>,
 > {
-    let mut logs = Vec::new();
+    let mut logs = CallLog::new("abandon_change_set");
     // Get the static ApiConfig reference
-    let config = get_api_config()?;
+    let config = get_api_config_for_write("abandon_change_set")?;
 
     let url = format!(
         "{}/v1/w/{}/change-sets/{}",
         config.base_url, workspace_id, change_set_id
     );
-    logs.push(format!("Calling API: DELETE {}", url));
+    logs.request("DELETE", &url);
 
-    let response = config.client.delete(&url).send().await?;
+    let response =
+        send(&config, &mut logs, cancel, |client| client.delete(&url)).await;
+    // Whatever the outcome, the change may have been made
+    cache::invalidate_cached_change_set(workspace_id, change_set_id);
+    let response = response?;
 
     let status = response.status();
-    logs.push(format!("API Response Status: {}", status));
+    logs.response(&response);
 
     if status.is_success() {
-        let response_text = response.text().await?;
-        logs.push(format!("API Success Body: {}", response_text));
+        let response_text = read_body(response, cancel).await?;
+        logs.response_body("API Success Body", &response_text);
         // Deserialize the response which should contain `{"success": true}`
         let abandon_response: DeleteChangeSetV1Response =
             serde_json::from_str(&response_text).map_err(|e| {
-                format!(
-                    "Failed to deserialize abandon change set response: {} - Body: {}", // Updated error message
-                    e, response_text
+                deserialize_error(
+                    "abandon change set response",
+                    e,
+                    &response_text,
                 )
             })?;
         // TODO: Consider checking abandon_response.success here? Or let caller handle it.
-        Ok((abandon_response, logs)) // Return the deserialized response
+        Ok((abandon_response, logs.into_entries())) // Return the deserialized response
     } else {
         let error_text = response
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error body".to_string());
-        logs.push(format!("API Error Body: {}", error_text));
+        logs.response_body("API Error Body", &error_text);
         let error_message = match serde_json::from_str::<ApiError>(&error_text)
         {
             Ok(api_error) => format!(
@@ -93,6 +109,6 @@ pub async fn abandon_change_set(
                 status, error_text
             ),
         };
-        Err(error_message.into())
+        Err(redact_text(&error_message).into())
     }
 }
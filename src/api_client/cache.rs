@@ -0,0 +1,334 @@
+// src/api_client/cache.rs
+
+// Intention:
+// Serve repeated reads of the same change set (schemas, components, a component, merge status)
+// from memory for a short while, so moving through the TUI does not refetch everything on
+// every key press.
+
+// Design Choices:
+// - Entries are keyed by workspace, change set and resource, and expire after the resource's
+//   TTL (`CacheTtls`, part of `ClientSettings`). A zero TTL disables caching of that resource.
+// - Mutations invalidate what they affect as soon as their response arrives, whatever its
+//   status: component changes drop the component, the component list and the merge status of
+//   their change set, abandoning drops the change set, applying drops the whole workspace
+//   (every change set is based on HEAD).
+// - Every invalidation bumps a generation and records it for what it dropped (a change set, a
+//   workspace or everything). A read stores its response only if nothing covering its change
+//   set was invalidated since it started, so a read racing a mutation cannot put stale data
+//   back, while reads of other change sets (e.g. during an auto-refresh) are still stored.
+// - The cache is cleared when other credentials are installed, and can be cleared on demand
+//   (`clear_cache`) for an explicit refresh.
+// - Reads that must see the server's current state (a watcher's polls) run inside `read_fresh`:
+//   they skip the lookup but still store their responses, so the cache is refreshed rather
+//   than emptied for the other callers. The flag is task-local, so concurrent reads elsewhere
+//   keep using the cache.
+
+use std::{
+    any::Any,
+    collections::HashMap,
+    future::Future,
+    sync::{
+        LazyLock,
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use super::{
+    ApiConfig,
+    CallLog,
+};
+
+/// What a cached response is for, within a change set.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CachedResource {
+    Schemas,
+    Components,        // The component list
+    Component(String), // One component, by ID
+    MergeStatus,
+}
+
+/// Where a cached response belongs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    pub workspace_id: String,
+    pub change_set_id: String,
+    pub resource: CachedResource,
+}
+
+impl CacheKey {
+    pub fn new(
+        workspace_id: &str,
+        change_set_id: &str,
+        resource: CachedResource,
+    ) -> Self {
+        Self {
+            workspace_id: workspace_id.to_string(),
+            change_set_id: change_set_id.to_string(),
+            resource,
+        }
+    }
+}
+
+/// How long responses are served from the cache; zero disables caching of that resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheTtls {
+    pub schemas: Duration,
+    pub components: Duration,
+    pub component: Duration,
+    pub merge_status: Duration,
+}
+
+impl CacheTtls {
+    pub const DEFAULT: CacheTtls = CacheTtls {
+        schemas: Duration::from_secs(300), // Changes only with installs
+        components: Duration::from_secs(30),
+        component: Duration::from_secs(30),
+        merge_status: Duration::from_secs(10), // Actions progress on their own
+    };
+
+    /// No caching at all.
+    pub const NONE: CacheTtls = CacheTtls {
+        schemas: Duration::ZERO,
+        components: Duration::ZERO,
+        component: Duration::ZERO,
+        merge_status: Duration::ZERO,
+    };
+
+    pub fn ttl(&self, resource: &CachedResource) -> Duration {
+        match resource {
+            CachedResource::Schemas => self.schemas,
+            CachedResource::Components => self.components,
+            CachedResource::Component(_) => self.component,
+            CachedResource::MergeStatus => self.merge_status,
+        }
+    }
+}
+
+impl Default for CacheTtls {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Responses by `CacheKey`, with the time they were stored.
+#[derive(Default)]
+pub struct ResponseCache {
+    entries: HashMap<CacheKey, (Instant, Box<dyn Any + Send>)>,
+    generation: u64,
+    // The generation of the last invalidation of each change set, workspace and of everything
+    change_sets_invalidated: HashMap<(String, String), u64>,
+    workspaces_invalidated: HashMap<String, u64>,
+    cleared: u64,
+}
+
+impl ResponseCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Bumped by every invalidation; see `insert`.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// The response stored for `key` and its age, if younger than `ttl` at `now`.
+    pub fn get<T: Clone + 'static>(
+        &self,
+        key: &CacheKey,
+        ttl: Duration,
+        now: Instant,
+    ) -> Option<(T, Duration)> {
+        let (stored, value) = self.entries.get(key)?;
+        let age = now.saturating_duration_since(*stored);
+        if age >= ttl {
+            return None;
+        }
+        value.downcast_ref::<T>().map(|value| (value.clone(), age))
+    }
+
+    /// Stores `value` for `key`, unless its change set was invalidated since `generation` was
+    /// read (when the request started). Returns whether it was stored.
+    pub fn insert<T: Send + 'static>(
+        &mut self,
+        key: CacheKey,
+        value: T,
+        now: Instant,
+        generation: u64,
+    ) -> bool {
+        if self.invalidated_at(&key) > generation {
+            return false;
+        }
+        self.entries.insert(key, (now, Box::new(value)));
+        true
+    }
+
+    /// Drops the component list and merge status of a change set, and `component_id` if given.
+    pub fn invalidate_components(
+        &mut self,
+        workspace_id: &str,
+        change_set_id: &str,
+        component_id: Option<&str>,
+    ) {
+        self.mark_change_set(workspace_id, change_set_id);
+        self.entries.retain(|key, _| {
+            !(key.workspace_id == workspace_id
+                && key.change_set_id == change_set_id
+                && match &key.resource {
+                    CachedResource::Components
+                    | CachedResource::MergeStatus => true,
+                    CachedResource::Component(id) => {
+                        component_id == Some(id.as_str())
+                    }
+                    CachedResource::Schemas => false,
+                })
+        });
+    }
+
+    /// Drops everything cached for a change set.
+    pub fn invalidate_change_set(
+        &mut self,
+        workspace_id: &str,
+        change_set_id: &str,
+    ) {
+        self.mark_change_set(workspace_id, change_set_id);
+        self.entries.retain(|key, _| {
+            !(key.workspace_id == workspace_id
+                && key.change_set_id == change_set_id)
+        });
+    }
+
+    /// Drops everything cached for a workspace.
+    pub fn invalidate_workspace(&mut self, workspace_id: &str) {
+        self.generation += 1;
+        self.workspaces_invalidated
+            .insert(workspace_id.to_string(), self.generation);
+        self.entries
+            .retain(|key, _| key.workspace_id != workspace_id);
+    }
+
+    pub fn clear(&mut self) {
+        self.generation += 1;
+        self.cleared = self.generation;
+        self.change_sets_invalidated.clear();
+        self.workspaces_invalidated.clear();
+        self.entries.clear();
+    }
+
+    fn mark_change_set(&mut self, workspace_id: &str, change_set_id: &str) {
+        self.generation += 1;
+        self.change_sets_invalidated.insert(
+            (workspace_id.to_string(), change_set_id.to_string()),
+            self.generation,
+        );
+    }
+
+    // The generation of the last invalidation covering `key`'s change set (0 if none).
+    fn invalidated_at(&self, key: &CacheKey) -> u64 {
+        let change_set = self
+            .change_sets_invalidated
+            .get(&(key.workspace_id.clone(), key.change_set_id.clone()));
+        let workspace = self.workspaces_invalidated.get(&key.workspace_id);
+        [change_set, workspace]
+            .into_iter()
+            .flatten()
+            .copied()
+            .fold(self.cleared, u64::max)
+    }
+}
+
+static CACHE: LazyLock<Mutex<ResponseCache>> =
+    LazyLock::new(|| Mutex::new(ResponseCache::new()));
+
+tokio::task_local! {
+    // Set while the current task's reads bypass cache lookups (see `read_fresh`)
+    static FRESH_READS: ();
+}
+
+fn with_cache<R>(f: impl FnOnce(&mut ResponseCache) -> R) -> R {
+    f(&mut CACHE.lock().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Drops every cached response, so the next reads go to the server.
+pub fn clear_cache() {
+    with_cache(ResponseCache::clear);
+}
+
+/// Runs `reads` without serving them from the cache: every read goes to the server, and its
+/// response replaces what was cached. Other cached responses are kept.
+pub async fn read_fresh<F: Future>(reads: F) -> F::Output {
+    FRESH_READS.scope((), reads).await
+}
+
+// Whether the current task is inside `read_fresh`.
+pub(super) fn reading_fresh() -> bool {
+    FRESH_READS.try_with(|_| ()).is_ok()
+}
+
+// The cached response for `key` if it is fresh under the configuration's TTLs, logged as such.
+pub(super) fn lookup<T: Clone + 'static>(
+    config: &ApiConfig,
+    key: &CacheKey,
+    logs: &mut CallLog,
+) -> Option<T> {
+    if reading_fresh() {
+        return None;
+    }
+    let ttl = config.cache.ttl(&key.resource);
+    let (value, age) =
+        with_cache(|cache| cache.get::<T>(key, ttl, Instant::now()))?;
+    logs.info(format!("Served from cache ({}s old)", age.as_secs()));
+    Some(value)
+}
+
+// The generation to pass to `store`, read before the request is sent.
+pub(super) fn generation() -> u64 {
+    with_cache(|cache| cache.generation())
+}
+
+// Stores a response read at `generation`, if its resource is cached at all.
+pub(super) fn store<T: Send + 'static>(
+    config: &ApiConfig,
+    key: CacheKey,
+    value: T,
+    generation: u64,
+) {
+    if !config.cache.ttl(&key.resource).is_zero() {
+        with_cache(|cache| {
+            cache.insert(key, value, Instant::now(), generation)
+        });
+    }
+}
+
+pub(super) fn invalidate_cached_components(
+    workspace_id: &str,
+    change_set_id: &str,
+    component_id: Option<&str>,
+) {
+    with_cache(|cache| {
+        cache.invalidate_components(workspace_id, change_set_id, component_id)
+    });
+}
+
+/// Drops everything cached for a change set, so its next reads go to the server (e.g. to watch
+/// it change).
+pub fn invalidate_cached_change_set(workspace_id: &str, change_set_id: &str) {
+    with_cache(|cache| {
+        cache.invalidate_change_set(workspace_id, change_set_id)
+    });
+}
+
+pub(super) fn invalidate_cached_workspace(workspace_id: &str) {
+    with_cache(|cache| cache.invalidate_workspace(workspace_id));
+}
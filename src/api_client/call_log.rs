@@ -0,0 +1,188 @@
+// src/api_client/call_log.rs
+
+// Intention:
+// Collects the log entries of a single API call, tagged with the calling function and a
+// request ID unique within the process, and records the call in the session HTTP history.
+
+// Design Choices:
+// - Each client function creates one `CallLog` and returns `into_entries()` alongside its result,
+//   replacing the `Vec<String>` the functions used to build by hand.
+// - Bodies go through `body`, which logs at `Trace` so they are dropped unless the verbosity
+//   setting asks for them.
+// - Every message is passed through the redactor before it is stored, so no body, header or
+//   error text reaches the log panel or log file unredacted.
+// - `request`, `response`, `request_body` and `response_body` both log and fill in an
+//   `HttpExchange`, which is recorded when the `CallLog` is dropped. Dropping covers every exit,
+//   including errors returned early with `?`.
+
+use std::{
+    sync::atomic::{
+        AtomicU64,
+        Ordering,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use chrono::Utc;
+use reqwest::Response;
+use serde::Serialize;
+
+use crate::{
+    http_history::{
+        HttpExchange,
+        HttpHistory,
+    },
+    logging::{
+        LogEntry,
+        LogLevel,
+    },
+    redact::Redactor,
+};
+
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+pub(crate) struct CallLog {
+    source: String,
+    request_id: String,
+    entries: Vec<LogEntry>,
+    started: Instant,
+    // Set by `request`; calls that never send a request are not recorded
+    exchange: Option<HttpExchange>,
+}
+
+impl CallLog {
+    /// Starts the log of a new call made by `function` (e.g. `"whoami"`).
+    pub(crate) fn new(function: &str) -> Self {
+        let id = NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+        Self {
+            source: format!("api::{}", function),
+            request_id: format!("req-{:04}", id),
+            entries: Vec::new(),
+            started: Instant::now(),
+            exchange: None,
+        }
+    }
+
+    fn push(&mut self, level: LogLevel, message: String) {
+        let message = Redactor::global().redact_text(&message);
+        self.entries.push(
+            LogEntry::new(level, self.source.clone(), message)
+                .with_request_id(self.request_id.clone()),
+        );
+    }
+
+    /// The name of the calling function, as given to `new`.
+    pub(crate) fn function(&self) -> &str {
+        self.source.trim_start_matches("api::")
+    }
+
+    pub(crate) fn info(&mut self, message: String) {
+        self.push(LogLevel::Info, message);
+    }
+
+    pub(crate) fn warn(&mut self, message: String) {
+        self.push(LogLevel::Warn, message);
+    }
+
+    pub(crate) fn error(&mut self, message: String) {
+        self.push(LogLevel::Error, message);
+    }
+
+    /// Logs a request or response body (or anything else as verbose).
+    pub(crate) fn body(&mut self, message: String) {
+        self.push(LogLevel::Trace, message);
+    }
+
+    /// Logs the request about to be sent and starts timing it.
+    pub(crate) fn request(&mut self, method: &str, url: &str) {
+        self.info(format!("Calling API: {} {}", method, url));
+        self.started = Instant::now();
+        self.exchange = Some(HttpExchange {
+            request_id: self.request_id.clone(),
+            source: self.source.clone(),
+            started_at: Utc::now(),
+            method: method.to_string(),
+            url: Redactor::global().redact_text(url),
+            status: None,
+            latency: Duration::ZERO,
+            response_size: None,
+            request_body: None,
+            response_body: None,
+        });
+    }
+
+    /// Forgets the request started with `request`, which ends up not being sent, so it is not
+    /// recorded in the HTTP history.
+    pub(crate) fn discard_request(&mut self) {
+        self.exchange = None;
+    }
+
+    /// Logs a request body serialized as JSON, so it is redacted field by field.
+    pub(crate) fn request_body(&mut self, body: &impl Serialize) {
+        let json = serde_json::to_string(body)
+            .unwrap_or_else(|e| format!("<unserializable: {}>", e));
+        let redacted = Redactor::global().redact_text(&json);
+        if let Some(exchange) = &mut self.exchange {
+            exchange.request_body = Some(redacted);
+        }
+        self.body(format!("Request Body: {}", json));
+    }
+
+    /// Logs the response status and headers (as `name: value` pairs, masking sensitive headers
+    /// by name).
+    pub(crate) fn response(&mut self, response: &Response) {
+        let status = response.status();
+        self.info(format!("API Response Status: {}", status));
+        let redactor = Redactor::global();
+        let lines: Vec<String> = response
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                let value = value.to_str().unwrap_or("<binary>");
+                format!(
+                    "{}: {}",
+                    name,
+                    redactor.redact_header(name.as_str(), value)
+                )
+            })
+            .collect();
+        self.body(format!("Response Headers: {}", lines.join("; ")));
+        let latency = self.started.elapsed();
+        if let Some(exchange) = &mut self.exchange {
+            exchange.status = Some(status.as_u16());
+            exchange.latency = latency;
+            exchange.response_size =
+                response.content_length().map(|len| len as usize);
+        }
+    }
+
+    /// Logs a response body as `<label>: <body>`.
+    pub(crate) fn response_body(&mut self, label: &str, text: &str) {
+        let latency = self.started.elapsed();
+        if let Some(exchange) = &mut self.exchange {
+            exchange.latency = latency;
+            exchange.response_size = Some(text.len());
+            exchange.response_body = Some(Redactor::global().redact_text(text));
+        }
+        self.body(format!("{}: {}", label, text));
+    }
+
+    pub(crate) fn into_entries(mut self) -> Vec<LogEntry> {
+        std::mem::take(&mut self.entries)
+    }
+}
+
+impl Drop for CallLog {
+    fn drop(&mut self) {
+        if let Some(mut exchange) = self.exchange.take() {
+            if exchange.status.is_none() {
+                // No response: the latency is how long the failed attempt took
+                exchange.latency = self.started.elapsed();
+            }
+            HttpHistory::global().record(exchange);
+        }
+    }
+}
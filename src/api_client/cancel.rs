@@ -0,0 +1,98 @@
+// src/api_client/cancel.rs
+
+// Intention:
+// Let the caller of a client function give up on it, e.g. when the user presses Esc or selects
+// another change set while the previous one is still loading.
+
+// Design Choices:
+// - Every client function takes a token: its request (and any retry delay) is dropped when the
+//   token is cancelled, which aborts it like a timeout would. `CancelToken::run` does the same
+//   for any other future, e.g. one made of several calls.
+// - A token is cheap to clone and every clone cancels the same calls, so one token can cover
+//   all the requests of a screen load, including ones spawned on other tasks.
+// - A cancelled call fails with `Cancelled`, checked with `is_cancelled`, so callers can tell
+//   it from a failure and skip logging it as one.
+
+use std::{
+    error::Error,
+    fmt,
+    sync::{
+        Arc,
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+    },
+};
+
+use tokio::sync::Notify;
+
+/// The error of a client call whose `CancelToken` was cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Cancelled")
+    }
+}
+
+impl Error for Cancelled {}
+
+/// Whether `error` comes from a cancelled call rather than a failed one.
+pub fn is_cancelled(error: &(dyn Error + Send + Sync + 'static)) -> bool {
+    error.downcast_ref::<Cancelled>().is_some()
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+/// Cancels the client calls run with it (and its clones).
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    inner: Arc<Inner>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels every call running with this token, and every call run with it from now on.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        self.inner.notify.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Completes once the token is cancelled.
+    pub async fn cancelled(&self) {
+        loop {
+            // Registered before checking the flag, so a `cancel` in between is not missed
+            let notified = self.inner.notify.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Runs `call` (a client function's future) until it completes or the token is cancelled,
+    /// in which case it is dropped and `Cancelled` is returned.
+    pub async fn run<T>(
+        &self,
+        call: impl Future<Output = Result<T, Box<dyn Error + Send + Sync>>>,
+    ) -> Result<T, Box<dyn Error + Send + Sync>> {
+        tokio::select! {
+            biased;
+            () = self.cancelled() => Err(Cancelled.into()),
+            result = call => result,
+        }
+    }
+}
@@ -4,7 +4,8 @@
 // Contains the implementation for the `POST /v1/w/{workspace_id}/change-sets` API endpoint call.
 
 // Design Choices:
-// - Uses the shared `get_api_config` function from the parent module.
+// - Uses the shared `get_api_config_for_write` function from the parent module, which refuses
+//   the call when the active profile is read-only.
 // - Serializes the request body and sends a POST request.
 // - Handles response status and deserialization.
 // - Logs request and response details.
@@ -14,7 +15,14 @@ use std::error::Error;
 // Use the shared config getter and ApiError type from the parent module
 use super::{
     ApiError,
-    get_api_config,
+    CallLog,
+    CancelToken,
+    LogEntry,
+    deserialize_error,
+    get_api_config_for_write,
+    read_body,
+    redact_text,
+    send,
 };
 // Import the specific request and response models needed for this function
 use crate::api_models::{
@@ -28,49 +36,50 @@ use crate::api_models::{
 ///         serializes the request body (`CreateChangeSetV1Request`), sends a POST request,
 ///         and deserializes the JSON response into `CreateChangeSetV1Response`.
 ///         Includes logging similar to other API functions.
-/// Returns: A tuple containing the `CreateChangeSetV1Response` on success and a `Vec<String>` of log messages.
+/// Returns: A tuple containing the `CreateChangeSetV1Response` on success and a `Vec<LogEntry>` of log messages.
 pub async fn create_change_set(
     workspace_id: &str,
     request_body: CreateChangeSetV1Request, // Use imported type directly
+    cancel: &CancelToken,
 ) -> Result<
-    (CreateChangeSetV1Response, Vec<String>), // Use imported type directly
+    (CreateChangeSetV1Response, Vec<LogEntry>), // Use imported type directly
     Box<dyn Error + Send + Sync>,
 > {
-    let mut logs = Vec::new();
+    let mut logs = CallLog::new("create_change_set");
     // Get the static ApiConfig reference
-    let config = get_api_config()?;
+    let config = get_api_config_for_write("create_change_set")?;
 
     let url = format!("{}/v1/w/{}/change-sets", config.base_url, workspace_id);
-    logs.push(format!("Calling API: POST {}", url));
-    logs.push(format!("Request Body: {:?}", request_body)); // Log the request body
+    logs.request("POST", &url);
+    logs.request_body(&request_body); // Log the request body
 
-    let response = config
-        .client
-        .post(&url)
-        .json(&request_body) // Serialize the request body struct to JSON
-        .send()
-        .await?;
+    let response = send(&config, &mut logs, cancel, |client| {
+        client.post(&url).json(&request_body) // Serialize the request body struct to JSON
+    })
+    .await?;
 
     let status = response.status();
-    logs.push(format!("API Response Status: {}", status));
+    logs.response(&response);
 
     if status.is_success() {
-        let response_text = response.text().await?;
-        logs.push(format!("API Success Body: {}", response_text));
-        let create_response: CreateChangeSetV1Response = serde_json::from_str(&response_text) // Use imported type directly
-            .map_err(|e| {
-                format!(
-                    "Failed to deserialize create change set response: {} - Body: {}",
-                    e, response_text
-                )
-            })?;
-        Ok((create_response, logs))
+        let response_text = read_body(response, cancel).await?;
+        logs.response_body("API Success Body", &response_text);
+        let create_response: CreateChangeSetV1Response =
+            serde_json::from_str(&response_text) // Use imported type directly
+                .map_err(|e| {
+                    deserialize_error(
+                        "create change set response",
+                        e,
+                        &response_text,
+                    )
+                })?;
+        Ok((create_response, logs.into_entries()))
     } else {
         let error_text = response
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error body".to_string());
-        logs.push(format!("API Error Body: {}", error_text));
+        logs.response_body("API Error Body", &error_text);
         let error_message = match serde_json::from_str::<ApiError>(&error_text)
         {
             Ok(api_error) => format!(
@@ -82,6 +91,6 @@ pub async fn create_change_set(
                 status, error_text
             ),
         };
-        Err(error_message.into())
+        Err(redact_text(&error_message).into())
     }
 }
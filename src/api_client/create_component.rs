@@ -4,7 +4,8 @@
 // Contains the implementation for the `POST /v1/w/{workspace_id}/change-sets/{change_set_id}/components` API endpoint call.
 
 // Design Choices:
-// - Uses the shared `get_api_config` function from the parent module.
+// - Uses the shared `get_api_config_for_write` function from the parent module, which refuses
+//   the call when the active profile is read-only.
 // - Serializes the request body and sends a POST request.
 // - Handles response status and deserialization.
 // - Logs request and response details.
@@ -14,7 +15,15 @@ use std::error::Error;
 // Use the shared config getter and ApiError type from the parent module
 use super::{
     ApiError,
-    get_api_config,
+    CallLog,
+    CancelToken,
+    LogEntry,
+    cache,
+    deserialize_error,
+    get_api_config_for_write,
+    read_body,
+    redact_text,
+    send,
 };
 // Import the specific request and response models needed for this function
 use crate::api_models::{
@@ -29,43 +38,51 @@ pub async fn create_component(
     workspace_id: &str,
     change_set_id: &str,
     request_body: CreateComponentV1Request,
+    cancel: &CancelToken,
 ) -> Result<
-    (CreateComponentV1Response, Vec<String>),
+    (CreateComponentV1Response, Vec<LogEntry>),
     Box<dyn Error + Send + Sync>,
 > {
-    let mut logs = Vec::new();
+    let mut logs = CallLog::new("create_component");
     // Get the static ApiConfig reference
-    let config = get_api_config()?;
+    let config = get_api_config_for_write("create_component")?;
 
     let url = format!(
         "{}/v1/w/{}/change-sets/{}/components",
         config.base_url, workspace_id, change_set_id
     );
-    logs.push(format!("Calling API: POST {}", url));
-    logs.push(format!("Request Body: {:?}", request_body));
+    logs.request("POST", &url);
+    logs.request_body(&request_body);
 
-    let response = config.client.post(&url).json(&request_body).send().await?;
+    let response = send(&config, &mut logs, cancel, |client| {
+        client.post(&url).json(&request_body)
+    })
+    .await;
+    // Whatever the outcome, the change may have been made
+    cache::invalidate_cached_components(workspace_id, change_set_id, None);
+    let response = response?;
 
     let status = response.status();
-    logs.push(format!("API Response Status: {}", status));
+    logs.response(&response);
 
     if status.is_success() {
-        let response_text = response.text().await?;
-        logs.push(format!("API Success Body: {}", response_text));
-        let create_response: CreateComponentV1Response = serde_json::from_str(&response_text)
-            .map_err(|e| {
-                format!(
-                    "Failed to deserialize create component response: {} - Body: {}",
-                    e, response_text
+        let response_text = read_body(response, cancel).await?;
+        logs.response_body("API Success Body", &response_text);
+        let create_response: CreateComponentV1Response =
+            serde_json::from_str(&response_text).map_err(|e| {
+                deserialize_error(
+                    "create component response",
+                    e,
+                    &response_text,
                 )
             })?;
-        Ok((create_response, logs))
+        Ok((create_response, logs.into_entries()))
     } else {
         let error_text = response
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error body".to_string());
-        logs.push(format!("API Error Body: {}", error_text));
+        logs.response_body("API Error Body", &error_text);
         let error_message = match serde_json::from_str::<ApiError>(&error_text)
         {
             Ok(api_error) => format!(
@@ -77,6 +94,6 @@ pub async fn create_component(
                 status, error_text
             ),
         };
-        Err(error_message.into())
+        Err(redact_text(&error_message).into())
     }
 }
@@ -4,7 +4,8 @@
 // Contains the implementation for the `DELETE /v1/w/{workspace_id}/change-sets/{change_set_id}/components/{component_id}` API endpoint call.
 
 // Design Choices:
-// - Uses the shared `get_api_config` function from the parent module.
+// - Uses the shared `get_api_config_for_write` function from the parent module, which refuses
+//   the call when the active profile is read-only.
 // - Sends a DELETE request.
 // - Handles response status and deserialization (expects `{"success": true}`).
 // - Logs request and response details.
@@ -14,7 +15,15 @@ use std::error::Error;
 // Use the shared config getter and ApiError type from the parent module
 use super::{
     ApiError,
-    get_api_config,
+    CallLog,
+    CancelToken,
+    LogEntry,
+    cache,
+    deserialize_error,
+    get_api_config_for_write,
+    read_body,
+    redact_text,
+    send,
 };
 // Import the specific response model needed for this function
 use crate::api_models::DeleteComponentV1Response;
@@ -26,42 +35,52 @@ pub async fn delete_component(
     workspace_id: &str,
     change_set_id: &str,
     component_id: &str,
+    cancel: &CancelToken,
 ) -> Result<
-    (DeleteComponentV1Response, Vec<String>),
+    (DeleteComponentV1Response, Vec<LogEntry>),
     Box<dyn Error + Send + Sync>,
 > {
-    let mut logs = Vec::new();
+    let mut logs = CallLog::new("delete_component");
     // Get the static ApiConfig reference
-    let config = get_api_config()?;
+    let config = get_api_config_for_write("delete_component")?;
 
     let url = format!(
         "{}/v1/w/{}/change-sets/{}/components/{}",
         config.base_url, workspace_id, change_set_id, component_id
     );
-    logs.push(format!("Calling API: DELETE {}", url));
+    logs.request("DELETE", &url);
 
-    let response = config.client.delete(&url).send().await?;
+    let response =
+        send(&config, &mut logs, cancel, |client| client.delete(&url)).await;
+    // Whatever the outcome, the change may have been made
+    cache::invalidate_cached_components(
+        workspace_id,
+        change_set_id,
+        Some(component_id),
+    );
+    let response = response?;
 
     let status = response.status();
-    logs.push(format!("API Response Status: {}", status));
+    logs.response(&response);
 
     if status.is_success() {
-        let response_text = response.text().await?;
-        logs.push(format!("API Success Body: {}", response_text));
-        let delete_response: DeleteComponentV1Response = serde_json::from_str(&response_text)
-            .map_err(|e| {
-                format!(
-                    "Failed to deserialize delete component response: {} - Body: {}",
-                    e, response_text
+        let response_text = read_body(response, cancel).await?;
+        logs.response_body("API Success Body", &response_text);
+        let delete_response: DeleteComponentV1Response =
+            serde_json::from_str(&response_text).map_err(|e| {
+                deserialize_error(
+                    "delete component response",
+                    e,
+                    &response_text,
                 )
             })?;
-        Ok((delete_response, logs))
+        Ok((delete_response, logs.into_entries()))
     } else {
         let error_text = response
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error body".to_string());
-        logs.push(format!("API Error Body: {}", error_text));
+        logs.response_body("API Error Body", &error_text);
         let error_message = match serde_json::from_str::<ApiError>(&error_text)
         {
             Ok(api_error) => format!(
@@ -73,6 +92,6 @@ pub async fn delete_component(
                 status, error_text
             ),
         };
-        Err(error_message.into())
+        Err(redact_text(&error_message).into())
     }
 }
@@ -4,7 +4,8 @@
 // Contains the implementation for the `POST /v1/w/{workspace_id}/change-sets/{change_set_id}/force_apply` API endpoint call.
 
 // Design Choices:
-// - Uses the shared `get_api_config` function from the parent module.
+// - Uses the shared `get_api_config_for_write` function from the parent module, which refuses
+//   the call when the active profile is read-only.
 // - Sends a POST request with no body.
 // - Handles response status (expects 200 OK with empty body).
 // - Logs request and response details.
@@ -14,7 +15,14 @@ use std::error::Error;
 // Use the shared config getter and ApiError type from the parent module
 use super::{
     ApiError,
-    get_api_config,
+    CallLog,
+    CancelToken,
+    LogEntry,
+    cache,
+    get_api_config_for_write,
+    read_body,
+    redact_text,
+    send,
 };
 
 /// Force applies a specific change set.
@@ -24,55 +32,58 @@ use super::{
 /// # Arguments
 /// * `workspace_id` - The ID of the workspace containing the change set.
 /// * `change_set_id` - The ID of the change set to force apply.
+/// * `cancel` - Cancels the request when cancelled (see `CancelToken`).
 ///
 /// # Returns
 /// A `Result` containing `()` on success (as the API returns no body), or an error string on failure.
-/// Also returns a `Vec<String>` containing logs generated during the call.
+/// Also returns a `Vec<LogEntry>` containing logs generated during the call.
 ///
 /// # Intention
 /// Provides the functionality to force apply a change set via the API.
 ///
 /// # Design
 /// - Constructs the specific URL for the force apply endpoint.
-/// - Uses the shared `reqwest` client and configuration (via `get_api_config`).
+/// - Uses the shared `reqwest` client and configuration (via `get_api_config_for_write`).
 /// - Sends an HTTP POST request (with no body).
 /// - Handles success (200 OK, empty body according to OpenAPI spec) and error responses similarly to other API client functions.
 /// - Logs relevant information about the request and response.
 pub async fn force_apply(
     workspace_id: &str,
     change_set_id: &str,
-) -> Result<((), Vec<String>), Box<dyn Error + Send + Sync>> {
+    cancel: &CancelToken,
+) -> Result<((), Vec<LogEntry>), Box<dyn Error + Send + Sync>> {
     // Return type is correct (unit tuple)
-    let mut logs = Vec::new();
+    let mut logs = CallLog::new("force_apply");
     // Get the static ApiConfig reference
-    let config = get_api_config()?;
+    let config = get_api_config_for_write("force_apply")?;
 
     let url = format!(
         "{}/v1/w/{}/change-sets/{}/force_apply", // Added /force_apply
         config.base_url, workspace_id, change_set_id
     );
-    logs.push(format!("Calling API: POST {}", url));
+    logs.request("POST", &url);
 
     // Send POST request with no body
-    let response = config.client.post(&url).send().await?;
+    let response =
+        send(&config, &mut logs, cancel, |client| client.post(&url)).await;
+    // Whatever the outcome, the change may have been made
+    cache::invalidate_cached_workspace(workspace_id);
+    let response = response?;
 
     let status = response.status();
-    logs.push(format!("API Response Status: {}", status));
+    logs.response(&response);
 
     if status.is_success() {
         // Success response has no body according to OpenAPI spec
-        let response_text = response.text().await?; // Read body anyway for logging
-        logs.push(format!(
-            "API Success Body (expected empty): {}",
-            response_text
-        ));
-        Ok(((), logs)) // Return unit tuple for success
+        let response_text = read_body(response, cancel).await?; // Read body anyway for logging
+        logs.response_body("API Success Body (expected empty)", &response_text);
+        Ok(((), logs.into_entries())) // Return unit tuple for success
     } else {
         let error_text = response
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error body".to_string());
-        logs.push(format!("API Error Body: {}", error_text));
+        logs.response_body("API Error Body", &error_text);
         let error_message = match serde_json::from_str::<ApiError>(&error_text)
         {
             Ok(api_error) => format!(
@@ -84,6 +95,6 @@ pub async fn force_apply(
                 status, error_text
             ),
         };
-        Err(error_message.into())
+        Err(redact_text(&error_message).into())
     }
 }
@@ -13,7 +13,14 @@ use std::error::Error;
 // Use the shared config getter and ApiError type from the parent module
 use super::{
     ApiError,
+    CallLog,
+    CancelToken,
+    LogEntry,
+    deserialize_error,
     get_api_config,
+    read_body,
+    redact_text,
+    send,
 };
 // Import the specific response model needed for this function
 use crate::api_models::GetChangeSetV1Response;
@@ -24,16 +31,18 @@ use crate::api_models::GetChangeSetV1Response;
 /// # Arguments
 /// * `workspace_id` - The ID of the workspace.
 /// * `change_set_id` - The ID of the change set to fetch.
+/// * `cancel` - Cancels the request when cancelled (see `CancelToken`).
 ///
 /// # Returns
 /// A `Result` containing the `GetChangeSetV1Response` on success, or an error string.
-/// Also returns a `Vec<String>` containing logs generated during the call.
+/// Also returns a `Vec<LogEntry>` containing logs generated during the call.
 pub async fn get_change_set(
     workspace_id: &str,
     change_set_id: &str,
-) -> Result<(GetChangeSetV1Response, Vec<String>), Box<dyn Error + Send + Sync>>
+    cancel: &CancelToken,
+) -> Result<(GetChangeSetV1Response, Vec<LogEntry>), Box<dyn Error + Send + Sync>>
 {
-    let mut logs = Vec::new();
+    let mut logs = CallLog::new("get_change_set");
     // Get the static ApiConfig reference
     let config = get_api_config()?;
 
@@ -41,32 +50,28 @@ pub async fn get_change_set(
         "{}/v1/w/{}/change-sets/{}",
         config.base_url, workspace_id, change_set_id
     );
-    logs.push(format!("Calling API: GET {}", url));
+    logs.request("GET", &url);
 
-    let response = config.client.get(&url).send().await?;
+    let response =
+        send(&config, &mut logs, cancel, |client| client.get(&url)).await?;
 
     let status = response.status();
-    logs.push(format!("API Response Status: {}", status));
+    logs.response(&response);
 
     if status.is_success() {
-        let response_text = response.text().await?;
-        logs.push(format!("API Success Body: {}", response_text));
-        let get_response: GetChangeSetV1Response = serde_json::from_str(
-            &response_text,
-        )
-        .map_err(|e| {
-            format!(
-                "Failed to deserialize get change set response: {} - Body: {}",
-                e, response_text
-            )
-        })?;
-        Ok((get_response, logs))
+        let response_text = read_body(response, cancel).await?;
+        logs.response_body("API Success Body", &response_text);
+        let get_response: GetChangeSetV1Response =
+            serde_json::from_str(&response_text).map_err(|e| {
+                deserialize_error("get change set response", e, &response_text)
+            })?;
+        Ok((get_response, logs.into_entries()))
     } else {
         let error_text = response
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error body".to_string());
-        logs.push(format!("API Error Body: {}", error_text));
+        logs.response_body("API Error Body", &error_text);
         let error_message = match serde_json::from_str::<ApiError>(&error_text)
         {
             Ok(api_error) => format!(
@@ -78,6 +83,6 @@ pub async fn get_change_set(
                 status, error_text
             ),
         };
-        Err(error_message.into())
+        Err(redact_text(&error_message).into())
     }
 }
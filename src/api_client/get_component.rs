@@ -7,17 +7,46 @@
 // - Uses the shared `get_api_config` function from the parent module.
 // - Handles response status and deserialization.
 // - Logs request and response details.
+// - `get_components` resolves many components at once (e.g. every component of a change set)
+//   with at most `ClientSettings::concurrency` requests in flight, so large change sets do not
+//   flood the backend.
 
-use std::error::Error;
+use std::{
+    error::Error,
+    sync::Arc,
+};
+
+use tokio::{
+    sync::Semaphore,
+    task::JoinSet,
+};
 
 // Use the shared config getter and ApiError type from the parent module
 use super::{
     ApiError,
+    CallLog,
+    CancelToken,
+    LogEntry,
+    cache::{
+        self,
+        CacheKey,
+        CachedResource,
+    },
+    client_settings,
+    deserialize_error,
     get_api_config,
+    read_body,
+    redact_text,
+    send,
 };
 // Import the specific response model needed for this function
 use crate::api_models::GetComponentV1Response;
 
+type GetComponentResult = Result<
+    (GetComponentV1Response, Vec<LogEntry>),
+    Box<dyn Error + Send + Sync>,
+>;
+
 /// Fetches details for a specific component within a change set.
 /// Corresponds to `GET /v1/w/{workspace_id}/change-sets/{change_set_id}/components/{component_id}`.
 /// Operation ID: `get_component`
@@ -25,42 +54,51 @@ pub async fn get_component(
     workspace_id: &str,
     change_set_id: &str,
     component_id: &str,
-) -> Result<(GetComponentV1Response, Vec<String>), Box<dyn Error + Send + Sync>>
+    cancel: &CancelToken,
+) -> Result<(GetComponentV1Response, Vec<LogEntry>), Box<dyn Error + Send + Sync>>
 {
-    let mut logs = Vec::new();
+    let mut logs = CallLog::new("get_component");
     // Get the static ApiConfig reference
     let config = get_api_config()?;
 
+    // Serve a fresh cached response without a request
+    let key = CacheKey::new(
+        workspace_id,
+        change_set_id,
+        CachedResource::Component(component_id.to_string()),
+    );
+    if let Some(cached) = cache::lookup(&config, &key, &mut logs) {
+        return Ok((cached, logs.into_entries()));
+    }
+    let generation = cache::generation();
+
     let url = format!(
         "{}/v1/w/{}/change-sets/{}/components/{}",
         config.base_url, workspace_id, change_set_id, component_id
     );
-    logs.push(format!("Calling API: GET {}", url));
+    logs.request("GET", &url);
 
-    let response = config.client.get(&url).send().await?;
+    let response =
+        send(&config, &mut logs, cancel, |client| client.get(&url)).await?;
 
     let status = response.status();
-    logs.push(format!("API Response Status: {}", status));
+    logs.response(&response);
 
     if status.is_success() {
-        let response_text = response.text().await?;
-        logs.push(format!("API Success Body: {}", response_text));
-        let get_response: GetComponentV1Response = serde_json::from_str(
-            &response_text,
-        )
-        .map_err(|e| {
-            format!(
-                "Failed to deserialize get component response: {} - Body: {}",
-                e, response_text
-            )
-        })?;
-        Ok((get_response, logs))
+        let response_text = read_body(response, cancel).await?;
+        logs.response_body("API Success Body", &response_text);
+        let get_response: GetComponentV1Response =
+            serde_json::from_str(&response_text).map_err(|e| {
+                deserialize_error("get component response", e, &response_text)
+            })?;
+        cache::store(&config, key, get_response.clone(), generation);
+        Ok((get_response, logs.into_entries()))
     } else {
         let error_text = response
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error body".to_string());
-        logs.push(format!("API Error Body: {}", error_text));
+        logs.response_body("API Error Body", &error_text);
         let error_message = match serde_json::from_str::<ApiError>(&error_text)
         {
             Ok(api_error) => format!(
@@ -72,6 +110,49 @@ pub async fn get_component(
                 status, error_text
             ),
         };
-        Err(error_message.into())
+        Err(redact_text(&error_message).into())
+    }
+}
+
+/// Fetches several components of a change set concurrently, with at most
+/// `ClientSettings::concurrency` requests in flight. Called inside `read_fresh`, the lookups
+/// bypass the cache too.
+/// Returns: Each component ID with its own result (and logs), in the order of `component_ids`.
+/// Fails only if a lookup task panicked.
+pub async fn get_components(
+    workspace_id: &str,
+    change_set_id: &str,
+    component_ids: &[String],
+    cancel: &CancelToken,
+) -> Result<Vec<(String, GetComponentResult)>, Box<dyn Error + Send + Sync>> {
+    let limit = Arc::new(Semaphore::new(client_settings().concurrency.max(1)));
+    // The lookups run in their own tasks, which do not inherit the caller's task-locals
+    let fresh = cache::reading_fresh();
+    // Dropping the set, e.g. when the caller gives up, aborts the lookups
+    let mut lookups = JoinSet::new();
+    for (position, id) in component_ids.iter().cloned().enumerate() {
+        let (ws_id, cs_id) =
+            (workspace_id.to_string(), change_set_id.to_string());
+        let (limit, cancel) = (Arc::clone(&limit), cancel.clone());
+        lookups.spawn(async move {
+            // Never closed, so acquiring only waits for a free permit
+            let _permit = limit.acquire_owned().await;
+            let lookup = get_component(&ws_id, &cs_id, &id, &cancel);
+            let result = if fresh {
+                cache::read_fresh(lookup).await
+            } else {
+                lookup.await
+            };
+            (position, id, result)
+        });
+    }
+    let mut results = Vec::with_capacity(component_ids.len());
+    while let Some(joined) = lookups.join_next().await {
+        results.push(joined?);
     }
+    results.sort_by_key(|(position, ..)| *position);
+    Ok(results
+        .into_iter()
+        .map(|(_, id, result)| (id, result))
+        .collect())
 }
@@ -13,7 +13,19 @@ use std::error::Error;
 // Use the shared config getter and ApiError type from the parent module
 use super::{
     ApiError,
+    CallLog,
+    CancelToken,
+    LogEntry,
+    cache::{
+        self,
+        CacheKey,
+        CachedResource,
+    },
+    deserialize_error,
     get_api_config,
+    read_body,
+    redact_text,
+    send,
 };
 // Import the specific response model needed for this function
 use crate::api_models::MergeStatusV1Response;
@@ -25,10 +37,11 @@ use crate::api_models::MergeStatusV1Response;
 /// # Arguments
 /// * `workspace_id` - The ID of the workspace containing the change set.
 /// * `change_set_id` - The ID of the change set to get the status for.
+/// * `cancel` - Cancels the request when cancelled (see `CancelToken`).
 ///
 /// # Returns
 /// A `Result` containing the `MergeStatusV1Response` on success, or an error string on failure.
-/// Also returns a `Vec<String>` containing logs generated during the call.
+/// Also returns a `Vec<LogEntry>` containing logs generated during the call.
 ///
 /// # Intention
 /// Provides the functionality to retrieve the merge status (including actions) for a change set.
@@ -43,40 +56,48 @@ use crate::api_models::MergeStatusV1Response;
 pub async fn get_merge_status(
     workspace_id: &str,
     change_set_id: &str,
-) -> Result<(MergeStatusV1Response, Vec<String>), Box<dyn Error + Send + Sync>>
+    cancel: &CancelToken,
+) -> Result<(MergeStatusV1Response, Vec<LogEntry>), Box<dyn Error + Send + Sync>>
 {
-    let mut logs = Vec::new();
+    let mut logs = CallLog::new("get_merge_status");
     // Get the static ApiConfig reference
     let config = get_api_config()?;
 
+    // Serve a fresh cached response without a request
+    let key =
+        CacheKey::new(workspace_id, change_set_id, CachedResource::MergeStatus);
+    if let Some(cached) = cache::lookup(&config, &key, &mut logs) {
+        return Ok((cached, logs.into_entries()));
+    }
+    let generation = cache::generation();
+
     let url = format!(
         "{}/v1/w/{}/change-sets/{}/merge_status", // Added /merge_status
         config.base_url, workspace_id, change_set_id
     );
-    logs.push(format!("Calling API: GET {}", url));
+    logs.request("GET", &url);
 
-    let response = config.client.get(&url).send().await?;
+    let response =
+        send(&config, &mut logs, cancel, |client| client.get(&url)).await?;
 
     let status = response.status();
-    logs.push(format!("API Response Status: {}", status));
+    logs.response(&response);
 
     if status.is_success() {
-        let response_text = response.text().await?;
-        logs.push(format!("API Success Body: {}", response_text));
-        let merge_status_response: MergeStatusV1Response = serde_json::from_str(&response_text)
-            .map_err(|e| {
-                format!(
-                    "Failed to deserialize merge status response: {} - Body: {}",
-                    e, response_text
-                )
+        let response_text = read_body(response, cancel).await?;
+        logs.response_body("API Success Body", &response_text);
+        let merge_status_response: MergeStatusV1Response =
+            serde_json::from_str(&response_text).map_err(|e| {
+                deserialize_error("merge status response", e, &response_text)
             })?;
-        Ok((merge_status_response, logs))
+        cache::store(&config, key, merge_status_response.clone(), generation);
+        Ok((merge_status_response, logs.into_entries()))
     } else {
         let error_text = response
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error body".to_string());
-        logs.push(format!("API Error Body: {}", error_text));
+        logs.response_body("API Error Body", &error_text);
         let error_message = match serde_json::from_str::<ApiError>(&error_text)
         {
             Ok(api_error) => format!(
@@ -88,6 +109,6 @@ pub async fn get_merge_status(
                 status, error_text
             ),
         };
-        Err(error_message.into())
+        Err(redact_text(&error_message).into())
     }
 }
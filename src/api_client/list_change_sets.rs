@@ -13,7 +13,14 @@ use std::error::Error;
 // Use the shared config getter and ApiError type from the parent module
 use super::{
     ApiError,
+    CallLog,
+    CancelToken,
+    LogEntry,
+    deserialize_error,
     get_api_config,
+    read_body,
+    redact_text,
+    send,
 };
 // Import the specific response model needed for this function
 use crate::api_models::ListChangeSetV1Response;
@@ -23,40 +30,45 @@ use crate::api_models::ListChangeSetV1Response;
 /// Design: Uses the initialized `reqwest::Client`, constructs the URL with the workspace ID,
 ///         sends a GET request, and deserializes the JSON response into `ListChangeSetV1Response`.
 ///         Includes logging similar to the `whoami` function.
-/// Returns: A tuple containing the `ListChangeSetV1Response` on success and a `Vec<String>` of log messages.
+/// Returns: A tuple containing the `ListChangeSetV1Response` on success and a `Vec<LogEntry>` of log messages.
 pub async fn list_change_sets(
     workspace_id: &str,
-) -> Result<(ListChangeSetV1Response, Vec<String>), Box<dyn Error + Send + Sync>>
-{
-    let mut logs = Vec::new();
+    cancel: &CancelToken,
+) -> Result<
+    (ListChangeSetV1Response, Vec<LogEntry>),
+    Box<dyn Error + Send + Sync>,
+> {
+    let mut logs = CallLog::new("list_change_sets");
     // Get the static ApiConfig reference
     let config = get_api_config()?;
 
     let url = format!("{}/v1/w/{}/change-sets", config.base_url, workspace_id);
-    logs.push(format!("Calling API: GET {}", url));
+    logs.request("GET", &url);
 
-    let response = config.client.get(&url).send().await?;
+    let response =
+        send(&config, &mut logs, cancel, |client| client.get(&url)).await?;
 
     let status = response.status();
-    logs.push(format!("API Response Status: {}", status));
+    logs.response(&response);
 
     if status.is_success() {
-        let response_text = response.text().await?;
-        logs.push(format!("API Success Body: {}", response_text));
+        let response_text = read_body(response, cancel).await?;
+        logs.response_body("API Success Body", &response_text);
         let list_response: ListChangeSetV1Response =
             serde_json::from_str(&response_text).map_err(|e| {
-                format!(
-                    "Failed to deserialize list change sets response: {} - Body: {}",
-                    e, response_text
+                deserialize_error(
+                    "list change sets response",
+                    e,
+                    &response_text,
                 )
             })?;
-        Ok((list_response, logs))
+        Ok((list_response, logs.into_entries()))
     } else {
         let error_text = response
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error body".to_string());
-        logs.push(format!("API Error Body: {}", error_text));
+        logs.response_body("API Error Body", &error_text);
         let error_message = match serde_json::from_str::<ApiError>(&error_text)
         {
             Ok(api_error) => format!(
@@ -68,6 +80,6 @@ pub async fn list_change_sets(
                 status, error_text
             ),
         };
-        Err(error_message.into())
+        Err(redact_text(&error_message).into())
     }
 }
@@ -16,7 +16,21 @@ use std::error::Error;
 use reqwest::Method; // Keep Method import for clarity, even if not used directly in this version
 
 // Use the shared config getter from the parent module
-use super::get_api_config;
+use super::{
+    CallLog,
+    CancelToken,
+    LogEntry,
+    cache::{
+        self,
+        CacheKey,
+        CachedResource,
+    },
+    deserialize_error,
+    get_api_config,
+    read_body,
+    redact_text,
+    send,
+};
 // Use models from the crate root
 use crate::api_models::{
     ApiError,
@@ -29,56 +43,75 @@ use crate::api_models::{
 ///
 /// * `workspace_id` - The ID of the workspace.
 /// * `change_set_id` - The ID of the change set.
+/// * `cancel` - Cancels the request when cancelled (see `CancelToken`).
 ///
 /// # Returns
 ///
 /// A `Result` containing:
-/// - Ok: A tuple with `ListComponentsV1Response` and a `Vec<String>` of logs.
+/// - Ok: A tuple with `ListComponentsV1Response` and a `Vec<LogEntry>` of logs.
 /// - Err: A `Box<dyn Error + Send + Sync>` indicating an error occurred.
 pub async fn list_components(
     workspace_id: &str,
     change_set_id: &str,
-) -> Result<(ListComponentsV1Response, Vec<String>), Box<dyn Error + Send + Sync>>
-{
-    let mut logs = Vec::new();
+    cancel: &CancelToken,
+) -> Result<
+    (ListComponentsV1Response, Vec<LogEntry>),
+    Box<dyn Error + Send + Sync>,
+> {
+    let mut logs = CallLog::new("list_components");
 
     // Get the static ApiConfig reference containing the client and base URL
     let config = get_api_config()?; // Propagate config error
 
+    // Serve a fresh cached response without a request
+    let key =
+        CacheKey::new(workspace_id, change_set_id, CachedResource::Components);
+    if let Some(cached) = cache::lookup(&config, &key, &mut logs) {
+        return Ok((cached, logs.into_entries()));
+    }
+    let generation = cache::generation();
+
     // Construct the URL
     let url = format!(
         "{}/v1/w/{}/change-sets/{}/components",
         config.base_url, workspace_id, change_set_id
     );
-    logs.push(format!("API Call: GET {}", url));
+    logs.request("GET", &url);
 
     // Make the GET request using the configured client
-    let response = config.client.get(&url).send().await?; // Propagate request error
+    let response =
+        send(&config, &mut logs, cancel, |client| client.get(&url)).await?; // Propagate request error
 
     let status = response.status();
-    logs.push(format!("Response Status: {}", status));
+    logs.response(&response);
 
     if status.is_success() {
         // Get the raw response text first for debugging
-        let response_text = response.text().await?;
-        logs.push(format!("Raw response: {}", response_text));
+        let response_text = read_body(response, cancel).await?;
+        logs.response_body("Raw response", &response_text);
 
         // Try to parse the response as JSON
         match serde_json::from_str::<ListComponentsV1Response>(&response_text) {
             Ok(response_body) => {
-                logs.push(
+                logs.info(
                     "Successfully deserialized ListComponentsV1Response."
                         .to_string(),
                 );
-                logs.push(format!(
+                logs.body(format!(
                     "Components: {:?}",
                     response_body.components
                 ));
-                Ok((response_body, logs))
+                cache::store(&config, key, response_body.clone(), generation);
+                Ok((response_body, logs.into_entries()))
             }
             Err(e) => {
-                logs.push(format!("Error deserializing response: {:?}", e));
-                Err(format!("Failed to deserialize response: {:?}", e).into())
+                logs.error(format!("Error deserializing response: {:?}", e));
+                Err(deserialize_error(
+                    "list components response",
+                    e,
+                    &response_text,
+                )
+                .into())
             }
         }
     } else {
@@ -87,7 +120,7 @@ pub async fn list_components(
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error body".to_string());
-        logs.push(format!("Error response body: {}", error_text));
+        logs.response_body("Error response body", &error_text);
 
         let error_message = match serde_json::from_str::<ApiError>(&error_text)
         {
@@ -100,7 +133,7 @@ pub async fn list_components(
                 status, error_text
             ),
         };
-        logs.push(error_message.clone());
-        Err(error_message.into()) // Return the formatted error message
+        logs.error(error_message.clone());
+        Err(redact_text(&error_message).into()) // Return the formatted error message
     }
 }
@@ -10,7 +10,20 @@ use std::error::Error;
 use reqwest::Method; // Method is not used directly anymore, but keep reqwest imports if needed
 
 // Use the shared config getter from the parent module
-use super::get_api_config;
+use super::{
+    CallLog,
+    CancelToken,
+    cache::{
+        self,
+        CacheKey,
+        CachedResource,
+    },
+    deserialize_error,
+    get_api_config,
+    read_body,
+    redact_text,
+    send,
+};
 use crate::ApiError;
 // Use models from the crate root
 // ApiError is brought into scope via `pub(crate) use` in src/api_client/mod.rs
@@ -22,34 +35,62 @@ use crate::api_models::ListSchemaV1Response; // Use crate:: for models within th
 ///
 /// * `workspace_id` - The ID of the workspace.
 /// * `change_set_id` - The ID of the change set.
+/// * `cancel` - Cancels the request when cancelled (see `CancelToken`).
 ///
 /// # Returns
 ///
 /// A `Result` containing either:
 /// - `Ok(ListSchemaV1Response)`: The successfully fetched schema list.
 /// - `Err(Box<dyn Error + Send + Sync>)`: An error if the request failed.
+///
 /// Design Choice: Follows pattern of list_change_sets.rs, handles response directly.
+/// Returns no logs, but the request is still recorded in the HTTP history through `CallLog`.
 pub async fn list_schemas(
     workspace_id: &str,
     change_set_id: &str,
+    cancel: &CancelToken,
 ) -> Result<ListSchemaV1Response, Box<dyn Error + Send + Sync>> {
+    let mut logs = CallLog::new("list_schemas");
     // Get the static ApiConfig reference containing the client and base URL
     let config = get_api_config()?;
 
+    // Serve a fresh cached response without a request
+    let key =
+        CacheKey::new(workspace_id, change_set_id, CachedResource::Schemas);
+    if let Some(cached) = cache::lookup(&config, &key, &mut logs) {
+        return Ok(cached);
+    }
+    let generation = cache::generation();
+
     // Construct the URL
     let url = format!(
         "{}/v1/w/{}/change-sets/{}/schema",
         config.base_url, workspace_id, change_set_id
     );
 
+    logs.request("GET", &url);
+
     // Make the GET request using the configured client
-    let response = config.client.get(&url).send().await?;
+    let response =
+        send(&config, &mut logs, cancel, |client| client.get(&url)).await?;
 
     let status = response.status();
+    logs.response(&response);
 
     if status.is_success() {
         // Deserialize the successful response
-        let response_body = response.json::<ListSchemaV1Response>().await?;
+        let response_text = read_body(response, cancel).await?;
+        logs.response_body("API Success Body", &response_text);
+        let response_body =
+            serde_json::from_str::<ListSchemaV1Response>(&response_text)
+                .map_err(|e| {
+                    deserialize_error(
+                        "list schemas response",
+                        e,
+                        &response_text,
+                    )
+                })?;
+        cache::store(&config, key, response_body.clone(), generation);
         Ok(response_body)
     } else {
         // Attempt to deserialize the error response as ApiError
@@ -57,6 +98,7 @@ pub async fn list_schemas(
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error body".to_string());
+        logs.response_body("API Error Body", &error_text);
         let error_message = match serde_json::from_str::<ApiError>(&error_text)
         {
             Ok(api_error) => format!(
@@ -68,6 +110,6 @@ pub async fn list_schemas(
                 status, error_text
             ),
         };
-        Err(error_message.into()) // Return the formatted error message
+        Err(redact_text(&error_message).into()) // Return the formatted error message
     }
 }
@@ -5,25 +5,79 @@
 // Declares modules for individual API endpoint functions.
 
 // Design Choices:
-// - Centralizes API configuration (URL, token, client) using OnceLock for lazy initialization.
+// - Centralizes API configuration (URL, token, client), initialized lazily from `.env` and
+//   replaceable with `use_credentials` when the TUI switches workspace.
+// - Client settings (timeouts, read-only) come from the selected profile (`use_settings`) and
+//   survive credential changes. Connect and request timeouts are on by default, so a hung
+//   backend fails the call instead of blocking its caller. Every call takes a `CancelToken`
+//   (see `cancel`) that stops its request, retries and body read. A read-only client refuses mutating calls before sending them.
+// - Requests go through `send`, which reads the token from its source again when the server
+//   answers 401 and resends the request once if the token changed (e.g. rotated in a file or
+//   vault). A token still rejected makes the client wait for a new one (see `reauth`). Failed
+//   reads are retried with backoff first (see `retry`).
+// - Reads of a change set's schemas, components and merge status are cached for a short TTL,
+//   and mutations invalidate what they change (see `cache`).
+// - The token's claims are decoded when a configuration is built, so its expiry can be shown
+//   without handing the token out.
 // - Provides a common `get_api_config` function for all endpoint modules.
 // - Re-exports functions from submodules to maintain a consistent external API.
 
 use std::{
     env,
     error::Error,
-    sync::OnceLock,
+    fmt,
+    sync::{
+        Arc,
+        RwLock,
+    },
+    time::Duration,
 };
 
+// Per-call log collector and the entry type every client function returns
+pub(crate) use call_log::CallLog;
 use dotenvy::dotenv;
-use reqwest::header::{
-    AUTHORIZATION,
-    HeaderMap,
-    HeaderValue,
+use reqwest::{
+    Request,
+    RequestBuilder,
+    Response,
+    StatusCode,
+    header::{
+        AUTHORIZATION,
+        HeaderMap,
+        HeaderValue,
+    },
 };
+use retry::send_with_retries;
 
 // Make ApiError accessible within this module and its children
 pub(crate) use crate::api_models::ApiError;
+use crate::jwt::{
+    JwtClaims,
+    decode_jwt_claims,
+};
+pub(crate) use crate::logging::LogEntry;
+// Applied to error messages, which carry response bodies out of the client
+pub(crate) use crate::redact::redact_text;
+
+// Intention: The error for a success body that does not match its model, redacted like every
+// other body the client logs, since it quotes the body (and serde quotes offending values).
+pub(crate) fn deserialize_error(
+    what: &str,
+    error: serde_json::Error,
+    body: &str,
+) -> String {
+    redact_text(&format!(
+        "Failed to deserialize {}: {} - Body: {}",
+        what, error, body
+    ))
+}
+
+mod cache;
+mod call_log;
+mod cancel;
+mod reauth;
+mod retry;
+mod token_source;
 
 // Declare modules for each API function
 pub mod abandon_change_set;
@@ -37,74 +91,394 @@ pub mod get_merge_status;
 pub mod list_change_sets;
 pub mod list_components; // Added module declaration
 pub mod list_schemas; // Added module declaration
+pub mod request_approval;
 pub mod update_component;
 pub mod whoami;
 
 // Re-export functions from submodules
 pub use abandon_change_set::abandon_change_set;
+pub use cache::{
+    CacheKey,
+    CacheTtls,
+    CachedResource,
+    ResponseCache,
+    clear_cache,
+    invalidate_cached_change_set,
+    read_fresh,
+};
+pub use cancel::{
+    CancelToken,
+    Cancelled,
+    is_cancelled,
+};
 pub use create_change_set::create_change_set;
 pub use create_component::create_component;
 pub use delete_component::delete_component;
 pub use force_apply::force_apply;
 pub use get_change_set::get_change_set;
-pub use get_component::get_component;
+pub use get_component::{
+    get_component,
+    get_components,
+};
 pub use get_merge_status::get_merge_status;
 pub use list_change_sets::list_change_sets;
 pub use list_components::list_components; // Added function re-export
 pub use list_schemas::list_schemas; // Added function re-export
+pub use reauth::{
+    Unauthorized,
+    awaiting_token,
+    dismiss_rejection,
+    is_unauthorized,
+    reauthenticate,
+};
+pub use request_approval::request_approval;
+pub use retry::{
+    RetryPolicy,
+    parse_retry_after,
+};
+pub use token_source::{
+    TOKEN_READ_TIMEOUT,
+    TokenSource,
+};
 pub use update_component::update_component;
 pub use whoami::whoami;
 
 // --- Shared Configuration Logic ---
 
-// Intention: Lazily initialize the reqwest client and load env vars once.
-// Design Choice: Use OnceLock for thread-safe, one-time initialization.
-// Stores the API base URL, JWT token, and the reqwest client.
-// Made fields pub(crate) so they are accessible within the api_client module.
+// Intention: The API base URL and JWT of one workspace.
+// Design Choice: `Debug` leaves out the token so credentials can be logged safely. `source`
+// is set when the token can change while the program runs, so it is read again after a 401.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Credentials {
+    pub api_url: String,
+    pub token: String,
+    pub source: Option<TokenSource>,
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("api_url", &self.api_url)
+            .field("token", &"[REDACTED]")
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+impl Credentials {
+    // Intention: The credentials in `SI_API` and `JWT_TOKEN`, loading `.env` first.
+    pub fn from_env() -> Result<Credentials, Box<dyn Error + Send + Sync>> {
+        dotenv().ok(); // Load .env file, ignore errors if it doesn't exist
+        let api_url =
+            env::var("SI_API").map_err(|e| format!("SI_API: {}", e))?;
+        let token =
+            env::var("JWT_TOKEN").map_err(|e| format!("JWT_TOKEN: {}", e))?;
+        Ok(Credentials {
+            api_url,
+            token,
+            source: None,
+        })
+    }
+}
+
+// Intention: How the client behaves, independently of the credentials it uses.
+// Design Choice: Timeouts of `None` leave reqwest's default (no timeout); the defaults bound
+// both, so that only a profile asking for it waits forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientSettings {
+    pub connect_timeout: Option<Duration>,
+    pub request_timeout: Option<Duration>, // Whole request, from connecting to the last byte
+    pub read_only: bool, // Refuse every call that changes something
+    pub retry: RetryPolicy, // Retries of failed requests
+    pub cache: CacheTtls, // How long reads are served from the cache
+    pub concurrency: usize, // Most requests in flight when resolving many components at once
+}
+
+impl ClientSettings {
+    pub const DEFAULT: ClientSettings = ClientSettings {
+        connect_timeout: Some(Duration::from_secs(10)),
+        request_timeout: Some(Duration::from_secs(60)),
+        read_only: false,
+        retry: RetryPolicy::DEFAULT,
+        cache: CacheTtls::DEFAULT,
+        concurrency: 8,
+    };
+}
+
+impl Default for ClientSettings {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+// Intention: Hold the reqwest client built for the current credentials.
+// Design Choice: Made fields pub(crate) so they are accessible within the api_client module.
 pub(crate) struct ApiConfig {
     client: reqwest::Client,
     base_url: String,
-    jwt_token: String, // Keep for potential future use/refresh
+    jwt_token: String, // Kept to rebuild the client when the settings change
+    token_source: Option<TokenSource>, // Read again when the server rejects `jwt_token`
+    claims: Option<JwtClaims>, // Decoded from `jwt_token`; `None` if it is not a JWT
+    read_only: bool,
+    retry: RetryPolicy,
+    cache: CacheTtls,
 }
 
-static API_CONFIG: OnceLock<Result<ApiConfig, Box<dyn Error + Send + Sync>>> =
-    OnceLock::new();
+impl ApiConfig {
+    fn credentials(&self) -> Credentials {
+        Credentials {
+            api_url: self.base_url.clone(),
+            token: self.jwt_token.clone(),
+            source: self.token_source.clone(),
+        }
+    }
+}
 
-// Helper function to create a config instance. Used by get_api_config.
-// Kept private to this module.
-fn create_new_api_config() -> Result<ApiConfig, Box<dyn Error + Send + Sync>> {
-    dotenv().ok(); // Load .env file, ignore errors if it doesn't exist
+// Intention: The configuration every client function uses, replaceable at runtime.
+// Design Choice: A lock around an `Arc`, so switching workspace swaps the whole configuration
+// while calls already in flight finish with the one they started with. Initialized lazily from
+// the environment when nothing was set with `use_credentials`.
+static API_CONFIG: RwLock<Option<Arc<ApiConfig>>> = RwLock::new(None);
 
-    let base_url = env::var("SI_API")
-        .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
-    let jwt_token = env::var("JWT_TOKEN")
-        .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+// Settings applied to every configuration built from now on
+static CLIENT_SETTINGS: RwLock<ClientSettings> =
+    RwLock::new(ClientSettings::DEFAULT);
 
+// Helper function to create a config instance for `credentials` with the current settings.
+// Kept private to this module.
+fn create_new_api_config(
+    credentials: &Credentials,
+) -> Result<ApiConfig, Box<dyn Error + Send + Sync>> {
+    let settings = client_settings();
     let mut headers = HeaderMap::new();
     let mut auth_value =
-        HeaderValue::from_str(&format!("Bearer {}", jwt_token))
+        HeaderValue::from_str(&format!("Bearer {}", credentials.token))
             .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
     auth_value.set_sensitive(true);
     headers.insert(AUTHORIZATION, auth_value);
 
-    let client = reqwest::Client::builder()
-        .default_headers(headers)
+    let mut builder = reqwest::Client::builder().default_headers(headers);
+    if let Some(timeout) = settings.connect_timeout {
+        builder = builder.connect_timeout(timeout);
+    }
+    if let Some(timeout) = settings.request_timeout {
+        builder = builder.timeout(timeout);
+    }
+    let client = builder
         .build()
         .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
 
     Ok(ApiConfig {
         client,
-        base_url,
-        jwt_token,
+        base_url: credentials.api_url.clone(),
+        jwt_token: credentials.token.clone(),
+        token_source: credentials.source.clone(),
+        claims: decode_jwt_claims(&credentials.token).ok(),
+        read_only: settings.read_only,
+        retry: settings.retry,
+        cache: settings.cache,
     })
 }
 
-// Provides access to the initialized ApiConfig.
+/// The settings the client is using.
+pub fn client_settings() -> ClientSettings {
+    CLIENT_SETTINGS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Makes every following API call use `settings`, keeping the current credentials.
+pub fn use_settings(
+    settings: ClientSettings,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    *CLIENT_SETTINGS.write().unwrap_or_else(|e| e.into_inner()) = settings;
+    let mut slot = API_CONFIG.write().unwrap_or_else(|e| e.into_inner());
+    if let Some(config) = slot.as_ref() {
+        *slot = Some(Arc::new(create_new_api_config(&config.credentials())?));
+    }
+    Ok(())
+}
+
+/// Makes every following API call use `credentials`, e.g. after switching workspace. Cached
+/// responses are dropped.
+pub fn use_credentials(
+    credentials: &Credentials,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let config = create_new_api_config(credentials)?;
+    *API_CONFIG.write().unwrap_or_else(|e| e.into_inner()) =
+        Some(Arc::new(config));
+    dismiss_rejection();
+    clear_cache();
+    Ok(())
+}
+
+/// The credentials in use, if any were set (or read from the environment) yet.
+pub fn current_credentials() -> Option<Credentials> {
+    API_CONFIG
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .map(|config| config.credentials())
+}
+
+/// The claims of the token in use, if one is set and is a JWT.
+// Design Choice: Does not create the configuration from the environment, so it can be called
+// on every frame without side effects.
+pub fn token_claims() -> Option<JwtClaims> {
+    API_CONFIG
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .and_then(|config| config.claims)
+}
+
+/// Whether `error` means the backend could not be reached (connection refused or dropped, DNS,
+/// timeout), as opposed to an answer the backend gave.
+pub fn is_unreachable(error: &(dyn Error + Send + Sync + 'static)) -> bool {
+    error
+        .downcast_ref::<reqwest::Error>()
+        .is_some_and(|e| e.is_connect() || e.is_timeout())
+}
+
+// Provides access to the current ApiConfig, created from the environment on first use.
 // Made pub(crate) for use by submodule functions.
 pub(crate) fn get_api_config()
--> Result<&'static ApiConfig, &'static (dyn Error + Send + Sync)> {
-    API_CONFIG
-        .get_or_init(create_new_api_config)
+-> Result<Arc<ApiConfig>, Box<dyn Error + Send + Sync>> {
+    if let Some(config) = API_CONFIG
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
         .as_ref()
-        .map_err(|e| &**e) // Convert Box<dyn Error> to &dyn Error
+    {
+        return Ok(Arc::clone(config));
+    }
+    let config = Arc::new(create_new_api_config(&Credentials::from_env()?)?);
+    let mut slot = API_CONFIG.write().unwrap_or_else(|e| e.into_inner());
+    Ok(Arc::clone(slot.get_or_insert(config)))
+}
+
+// Provides the ApiConfig for a call that changes something, refused when the client is
+// read-only. `operation` names the call in the error.
+pub(crate) fn get_api_config_for_write(
+    operation: &str,
+) -> Result<Arc<ApiConfig>, Box<dyn Error + Send + Sync>> {
+    let config = get_api_config()?;
+    if config.read_only {
+        return Err(format!(
+            "{} refused: the active profile is read-only",
+            operation
+        )
+        .into());
+    }
+    Ok(config)
+}
+
+// Intention: Read the token of `config` from its source again after the server rejected it.
+// Design Choice: Returns the new configuration only when the token changed, and installs it
+// only if `config` is still the current one, so a workspace switch made meanwhile is kept.
+async fn reread_token(
+    config: &Arc<ApiConfig>,
+    logs: &mut CallLog,
+) -> Option<Arc<ApiConfig>> {
+    let source = config.token_source.as_ref()?;
+    let token = match source.read_async(TOKEN_READ_TIMEOUT).await {
+        Ok(token) => token,
+        Err(e) => {
+            logs.warn(format!("Cannot read the token again after 401: {}", e));
+            return None;
+        }
+    };
+    if token == config.jwt_token {
+        return None;
+    }
+    let credentials = Credentials {
+        token,
+        ..config.credentials()
+    };
+    let new_config = Arc::new(create_new_api_config(&credentials).ok()?);
+    let mut slot = API_CONFIG.write().unwrap_or_else(|e| e.into_inner());
+    if slot
+        .as_ref()
+        .is_some_and(|current| Arc::ptr_eq(current, config))
+    {
+        *slot = Some(Arc::clone(&new_config));
+    }
+    Some(new_config)
+}
+
+// Intention: Send a request built with the configuration's client.
+// Design Choice: `build` is called again for every retry and for the resend after a 401, with
+// the client holding the re-read token. A request still rejected is returned as `Unauthorized`,
+// and while the client waits for a new token nothing is sent.
+pub(crate) async fn send(
+    config: &Arc<ApiConfig>,
+    logs: &mut CallLog,
+    cancel: &CancelToken,
+    build: impl Fn(&reqwest::Client) -> RequestBuilder,
+) -> Result<Response, Box<dyn Error + Send + Sync>> {
+    if awaiting_token() {
+        logs.discard_request();
+        return Err(reauth::waiting_for_token().into());
+    }
+    if cancel.is_cancelled() {
+        logs.discard_request();
+        return Err(Cancelled.into());
+    }
+    cancel.run(send_uncancelled(config, logs, build)).await
+}
+
+// Intention: The body of `send`, which `send` stops when its token is cancelled.
+async fn send_uncancelled(
+    config: &Arc<ApiConfig>,
+    logs: &mut CallLog,
+    build: impl Fn(&reqwest::Client) -> RequestBuilder,
+) -> Result<Response, Box<dyn Error + Send + Sync>> {
+    let response =
+        send_with_retries(&config.retry, logs, || build(&config.client))
+            .await?;
+    if response.status() != StatusCode::UNAUTHORIZED {
+        return Ok(response);
+    }
+    let response = match reread_token(config, logs).await {
+        Some(new_config) => {
+            logs.info(
+                "Token rejected (401); resending with the token read again"
+                    .to_string(),
+            );
+            send_with_retries(&new_config.retry, logs, || {
+                build(&new_config.client)
+            })
+            .await?
+        }
+        None => response,
+    };
+    if response.status() != StatusCode::UNAUTHORIZED {
+        return Ok(response);
+    }
+    let request = build(&config.client).build().ok();
+    Err(reauth::reject(logs, response, request).await.into())
+}
+
+// Intention: Read a response body, giving up when `cancel` is cancelled.
+pub(crate) async fn read_body(
+    response: Response,
+    cancel: &CancelToken,
+) -> Result<String, Box<dyn Error + Send + Sync>> {
+    cancel.run(async { Ok(response.text().await?) }).await
+}
+
+// Intention: Send a request that was built earlier (the one resent by `reauthenticate`).
+// Design Choice: Goes through `send`, so it gets the same 401 handling. Requests with a
+// streamed body cannot be copied and are sent without it; client requests never stream.
+pub(crate) async fn send_request(
+    config: &Arc<ApiConfig>,
+    logs: &mut CallLog,
+    cancel: &CancelToken,
+    request: Request,
+) -> Result<Response, Box<dyn Error + Send + Sync>> {
+    send(config, logs, cancel, |client| match request.try_clone() {
+        Some(copy) => RequestBuilder::from_parts(client.clone(), copy),
+        None => client.request(request.method().clone(), request.url().clone()),
+    })
+    .await
 }
@@ -0,0 +1,161 @@
+// src/api_client/reauth.rs
+
+// Intention:
+// Recover from a token the server rejects (401) by asking the user for a new one, instead of
+// failing every following call.
+
+// Design Choices:
+// - When a request is still rejected after the token source was read again, `send` returns an
+//   `Unauthorized` error and the client waits for a token: until `reauthenticate` is called or
+//   other credentials are installed, requests fail with `Unauthorized` without being sent. A
+//   screen of fetches so gives one prompt instead of a log full of 401s.
+// - The rejected request is kept when it changes something and resent by `reauthenticate`,
+//   so the user's change is not lost. Reads are not kept; the caller reloads what it shows.
+// - The kept request is dropped when credentials are replaced any other way (e.g. a workspace
+//   switch), so it is never sent to another workspace.
+
+use std::{
+    error::Error,
+    fmt,
+    sync::Mutex,
+};
+
+use reqwest::{
+    Method,
+    Request,
+    Response,
+};
+
+use super::{
+    CallLog,
+    CancelToken,
+    Credentials,
+    LogEntry,
+    current_credentials,
+    get_api_config,
+    redact_text,
+    send_request,
+    use_credentials,
+};
+
+/// The error of a call made with a token the server rejects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unauthorized {
+    message: String,
+}
+
+impl fmt::Display for Unauthorized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for Unauthorized {}
+
+/// Whether `error` comes from a rejected token, i.e. a new token is needed.
+pub fn is_unauthorized(error: &(dyn Error + Send + Sync + 'static)) -> bool {
+    error.downcast_ref::<Unauthorized>().is_some()
+}
+
+// The rejection the client is waiting on, with the request to resend if it changed something
+struct Rejection {
+    function: String,
+    request: Option<Request>,
+}
+
+static REJECTION: Mutex<Option<Rejection>> = Mutex::new(None);
+
+/// Whether a request was rejected with 401 and the client waits for a new token.
+pub fn awaiting_token() -> bool {
+    REJECTION
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .is_some()
+}
+
+/// Stops waiting for a token without giving one, dropping the rejected request. The next request
+/// is sent with the current token. Also done when other credentials are installed.
+pub fn dismiss_rejection() {
+    *REJECTION.lock().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+// The error of a call made while waiting for a token, which was not sent.
+pub(super) fn waiting_for_token() -> Unauthorized {
+    Unauthorized {
+        message: "Not sent: waiting for a new token after a 401".to_string(),
+    }
+}
+
+// Records `response` (a final 401) and starts waiting for a token. `request` is a copy of the
+// rejected request, kept unless it is a read.
+pub(super) async fn reject(
+    logs: &mut CallLog,
+    response: Response,
+    request: Option<Request>,
+) -> Unauthorized {
+    let status = response.status();
+    logs.response(&response);
+    let body = response
+        .text()
+        .await
+        .unwrap_or_else(|_| "Failed to read error body".to_string());
+    logs.response_body("API Error Body", &body);
+    *REJECTION.lock().unwrap_or_else(|e| e.into_inner()) = Some(Rejection {
+        function: logs.function().to_string(),
+        request: request.filter(|request| request.method() != Method::GET),
+    });
+    Unauthorized {
+        message: redact_text(&format!(
+            "API request failed with status {}: {}",
+            status, body
+        )),
+    }
+}
+
+/// Replaces the token of the current credentials (e.g. with one pasted after a 401) and resends
+/// the rejected request if it changed something.
+///
+/// Returns the logs of the resent request. A resent request that fails is returned as an error;
+/// the new token is in use either way (see `current_credentials`).
+pub async fn reauthenticate(
+    token: &str,
+    cancel: &CancelToken,
+) -> Result<Vec<LogEntry>, Box<dyn Error + Send + Sync>> {
+    let token = token.trim();
+    if token.is_empty() {
+        return Err("the token is empty".into());
+    }
+    let credentials = Credentials {
+        token: token.to_string(),
+        ..current_credentials().ok_or("no credentials are in use")?
+    };
+    let rejection = REJECTION.lock().unwrap_or_else(|e| e.into_inner()).take();
+    use_credentials(&credentials)?;
+
+    let Some(Rejection {
+        function,
+        request: Some(request),
+    }) = rejection
+    else {
+        return Ok(Vec::new());
+    };
+    let mut logs = CallLog::new(&function);
+    logs.info("Resending the request rejected with 401".to_string());
+    logs.request(request.method().as_str(), request.url().as_str());
+    let config = get_api_config()?;
+    let response = send_request(&config, &mut logs, cancel, request).await?;
+    let status = response.status();
+    logs.response(&response);
+    let body = response.text().await.unwrap_or_default();
+    if status.is_success() {
+        logs.response_body("API Success Body", &body);
+        Ok(logs.into_entries())
+    } else {
+        logs.response_body("API Error Body", &body);
+        Err(redact_text(&format!(
+            "Resent request failed with status {}: {}",
+            status, body
+        ))
+        .into())
+    }
+}
@@ -0,0 +1,95 @@
+// src/api_client/request_approval.rs
+
+// Intention:
+// Contains the implementation for the `POST /v1/w/{workspace_id}/change-sets/{change_set_id}/request_approval` API endpoint call.
+
+// Design Choices:
+// - Uses the shared `get_api_config_for_write` function from the parent module, which refuses
+//   the call when the active profile is read-only.
+// - Sends a POST request with no body.
+// - Handles response status (expects 200 OK with empty body).
+// - Logs request and response details.
+
+use std::error::Error;
+
+// Use the shared config getter and ApiError type from the parent module
+use super::{
+    ApiError,
+    CallLog,
+    CancelToken,
+    LogEntry,
+    get_api_config_for_write,
+    read_body,
+    redact_text,
+    send,
+};
+
+/// Requests approval to apply a specific change set.
+/// Corresponds to `POST /v1/w/{workspace_id}/change-sets/{change_set_id}/request_approval`.
+/// Operation ID: `request_approval`
+///
+/// # Arguments
+/// * `workspace_id` - The ID of the workspace containing the change set.
+/// * `change_set_id` - The ID of the change set to request approval for.
+/// * `cancel` - Cancels the request when cancelled (see `CancelToken`).
+///
+/// # Returns
+/// A `Result` containing `()` on success (as the API returns no body), or an error string on failure.
+/// Also returns a `Vec<LogEntry>` containing logs generated during the call.
+///
+/// # Intention
+/// Lets a change set that cannot be applied directly be put up for approval.
+///
+/// # Design
+/// - Constructs the specific URL for the request approval endpoint.
+/// - Uses the shared `reqwest` client and configuration (via `get_api_config_for_write`).
+/// - Sends an HTTP POST request (with no body).
+/// - Handles success (200 OK, empty body) and error responses the same way as `force_apply`.
+/// - Logs relevant information about the request and response.
+pub async fn request_approval(
+    workspace_id: &str,
+    change_set_id: &str,
+    cancel: &CancelToken,
+) -> Result<((), Vec<LogEntry>), Box<dyn Error + Send + Sync>> {
+    let mut logs = CallLog::new("request_approval");
+    // Get the static ApiConfig reference
+    let config = get_api_config_for_write("request_approval")?;
+
+    let url = format!(
+        "{}/v1/w/{}/change-sets/{}/request_approval",
+        config.base_url, workspace_id, change_set_id
+    );
+    logs.request("POST", &url);
+
+    // Send POST request with no body
+    let response =
+        send(&config, &mut logs, cancel, |client| client.post(&url)).await?;
+
+    let status = response.status();
+    logs.response(&response);
+
+    if status.is_success() {
+        // Success response has no body, same as force_apply
+        let response_text = read_body(response, cancel).await?; // Read body anyway for logging
+        logs.response_body("API Success Body (expected empty)", &response_text);
+        Ok(((), logs.into_entries())) // Return unit tuple for success
+    } else {
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Failed to read error body".to_string());
+        logs.response_body("API Error Body", &error_text);
+        let error_message = match serde_json::from_str::<ApiError>(&error_text)
+        {
+            Ok(api_error) => format!(
+                "API request failed with status {}: Code {:?}, Message: {}",
+                status, api_error.code, api_error.message
+            ),
+            Err(_) => format!(
+                "API request failed with status {}: {}",
+                status, error_text
+            ),
+        };
+        Err(redact_text(&error_message).into())
+    }
+}
@@ -0,0 +1,171 @@
+// src/api_client/retry.rs
+
+// Intention:
+// Retry requests that failed for a reason likely to pass (a dropped connection, a timeout, a
+// 502/503/504 from a proxy, or rate limiting), so one transient failure does not leave the TUI
+// with an empty list.
+
+// Design Choices:
+// - Only reads (`GET`) are retried by default. Calls that change something stay at-most-once,
+//   since a request that timed out may still have been carried out; `retry_mutations` opts in.
+// - The delay doubles from `base_delay` up to `max_delay`, with "equal jitter" (a random half
+//   on top of a fixed half) so clients that failed together do not retry together.
+// - A `Retry-After` header (seconds or an HTTP date) replaces the computed delay. One asking for
+//   longer than `max_retry_after` is not waited for; the response is returned as is.
+// - The policy is part of `ClientSettings`, so each profile can configure it.
+
+use std::time::Duration;
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use reqwest::{
+    Method,
+    Response,
+    StatusCode,
+    header::RETRY_AFTER,
+};
+use ring::rand::{
+    SecureRandom,
+    SystemRandom,
+};
+
+use super::CallLog;
+
+/// When and how often the client retries a failed request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32, // Retries after the first attempt; 0 disables retrying
+    pub base_delay: Duration, // Delay before the first retry, doubled for each one after
+    pub max_delay: Duration,  // Cap of the computed delay
+    pub max_retry_after: Duration, // Longest `Retry-After` the client waits for
+    pub retry_mutations: bool, // Also retry calls that change something
+}
+
+impl RetryPolicy {
+    pub const DEFAULT: RetryPolicy = RetryPolicy {
+        max_retries: 3,
+        base_delay: Duration::from_millis(250),
+        max_delay: Duration::from_secs(5),
+        max_retry_after: Duration::from_secs(30),
+        retry_mutations: false,
+    };
+
+    /// A policy that never retries.
+    pub const NEVER: RetryPolicy = RetryPolicy {
+        max_retries: 0,
+        ..RetryPolicy::DEFAULT
+    };
+
+    /// Whether a request with `method` may be sent more than once.
+    pub fn allows(&self, method: &Method) -> bool {
+        self.max_retries > 0 && (*method == Method::GET || self.retry_mutations)
+    }
+
+    /// Whether a response with `status` is worth retrying.
+    pub fn retries_status(status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::TOO_MANY_REQUESTS
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+
+    /// The delay before retry number `retry` (from 0), with `jitter` in `0.0..=1.0` choosing
+    /// where it falls between half and all of the exponential delay.
+    pub fn delay(&self, retry: u32, jitter: f64) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay);
+        let half = exponential / 2;
+        half + half.mul_f64(jitter.clamp(0.0, 1.0))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Parses a `Retry-After` value: whole seconds, or an HTTP date (a date in the past means now).
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = DateTime::parse_from_rfc2822(value)
+        .ok()?
+        .with_timezone(&Utc);
+    Some((at - now).to_std().unwrap_or(Duration::ZERO))
+}
+
+// A random number in `0.0..=1.0` for the jitter; no jitter if the system has no randomness.
+fn jitter() -> f64 {
+    let mut bytes = [0u8; 4];
+    match SystemRandom::new().fill(&mut bytes) {
+        Ok(()) => u32::from_le_bytes(bytes) as f64 / u32::MAX as f64,
+        Err(_) => 1.0,
+    }
+}
+
+// Intention: Send a request, retrying it as `policy` allows.
+// Design Choice: `build` makes a fresh request for every attempt. Each retry is logged with
+// its reason, and the last response or error is returned when the retries run out.
+pub(super) async fn send_with_retries(
+    policy: &RetryPolicy,
+    logs: &mut CallLog,
+    build: impl Fn() -> reqwest::RequestBuilder,
+) -> Result<Response, reqwest::Error> {
+    let mut retry = 0;
+    loop {
+        let request = build();
+        let method = request
+            .try_clone()
+            .and_then(|copy| copy.build().ok())
+            .map_or(Method::GET, |built| built.method().clone());
+        let result = request.send().await;
+        if retry >= policy.max_retries || !policy.allows(&method) {
+            return result;
+        }
+        let (reason, retry_after) = match &result {
+            Ok(response) if RetryPolicy::retries_status(response.status()) => (
+                format!("API responded {}", response.status()),
+                response
+                    .headers()
+                    .get(RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| parse_retry_after(value, Utc::now())),
+            ),
+            Err(e) if e.is_connect() || e.is_timeout() || e.is_request() => {
+                (format!("Request failed: {}", e), None)
+            }
+            _ => return result,
+        };
+        let delay = match retry_after {
+            Some(delay) if delay > policy.max_retry_after => {
+                logs.warn(format!(
+                    "{}; not retrying, Retry-After of {}s is too long",
+                    reason,
+                    delay.as_secs()
+                ));
+                return result;
+            }
+            Some(delay) => delay,
+            None => policy.delay(retry, jitter()),
+        };
+        retry += 1;
+        logs.warn(format!(
+            "{}; retry {}/{} in {} ms",
+            reason,
+            retry,
+            policy.max_retries,
+            delay.as_millis()
+        ));
+        tokio::time::sleep(delay).await;
+    }
+}
@@ -0,0 +1,221 @@
+// src/api_client/token_source.rs
+
+// Intention: Where a profile's or workspace's JWT comes from.
+// Design Choice: The source is kept rather than the token itself, and read when the client
+// configuration is built and again when the server rejects the token (401), so long sessions
+// pick up a rotated token. `Debug` hides literal tokens. The re-read after a 401 happens inside
+// a request, so it has an async variant that neither blocks the runtime (token commands, the
+// store's key derivation) nor waits forever (a command stuck on a prompt).
+
+use std::{
+    env,
+    fmt,
+    fs,
+    io,
+    path::PathBuf,
+    process::{
+        Command,
+        Output,
+    },
+    time::Duration,
+};
+
+use crate::token_store::{
+    TokenStore,
+    token_store_passphrase,
+    token_store_path,
+};
+
+/// How long `read_async` waits for a source (e.g. a token command) before giving up.
+pub const TOKEN_READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Clone, PartialEq, Eq)]
+pub enum TokenSource {
+    Value(String),   // The JWT itself (`token = ...`)
+    Env(String), // Name of the environment variable holding it (`token_env = ...`)
+    File(PathBuf), // File holding it (`token_file = ...`)
+    Command(String), // Shell command printing it, e.g. `pass show si/jwt` (`token_command = ...`)
+    Store(String),   // Entry of the encrypted token store (`token_store = ...`)
+}
+
+impl fmt::Debug for TokenSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenSource::Value(_) => f.write_str("Value([REDACTED])"),
+            TokenSource::Env(name) => f.debug_tuple("Env").field(name).finish(),
+            TokenSource::File(path) => {
+                f.debug_tuple("File").field(path).finish()
+            }
+            TokenSource::Command(command) => {
+                f.debug_tuple("Command").field(command).finish()
+            }
+            TokenSource::Store(name) => {
+                f.debug_tuple("Store").field(name).finish()
+            }
+        }
+    }
+}
+
+impl TokenSource {
+    /// The source a config file's `token*` key names (`token`, `token_env`, `token_file`,
+    /// `token_command`, `token_store`), or `None` for any other key.
+    pub fn from_key(key: &str, value: &str) -> Option<Self> {
+        match key {
+            "token" => Some(TokenSource::Value(value.to_string())),
+            "token_env" => Some(TokenSource::Env(value.to_string())),
+            "token_file" => Some(TokenSource::file(value)),
+            "token_command" => Some(TokenSource::Command(value.to_string())),
+            "token_store" => Some(TokenSource::Store(value.to_string())),
+            _ => None,
+        }
+    }
+
+    /// A `File` source, with a leading `~/` expanded to the home directory.
+    pub fn file(path: &str) -> Self {
+        let path = match (path.strip_prefix("~/"), env::var_os("HOME")) {
+            (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+            _ => PathBuf::from(path),
+        };
+        TokenSource::File(path)
+    }
+
+    /// Whether reading the source again can give a different token.
+    pub fn can_change(&self) -> bool {
+        !matches!(self, TokenSource::Value(_) | TokenSource::Env(_))
+    }
+
+    /// Reads the token, looking environment variables up in the process environment.
+    pub fn read(&self) -> Result<String, String> {
+        self.read_with(|name| env::var(name).ok())
+    }
+
+    /// Reads the token, looking environment variables up with `lookup_env`.
+    // Design Choice: Only the first line of a file or command output is used, since a JWT has
+    // no line breaks and tools such as `pass` print metadata after the secret.
+    pub fn read_with(
+        &self,
+        lookup_env: impl Fn(&str) -> Option<String>,
+    ) -> Result<String, String> {
+        self.first_line(self.read_raw(lookup_env)?)
+    }
+
+    /// Reads the token without blocking the async runtime, giving up after `timeout`.
+    // Design Choice: Commands run as tokio processes killed when the read is abandoned; the
+    // other sources (files, the store's key derivation) are read on the blocking pool.
+    pub async fn read_async(
+        &self,
+        timeout: Duration,
+    ) -> Result<String, String> {
+        let read = async {
+            match self {
+                TokenSource::Command(command) => {
+                    let output = if cfg!(windows) {
+                        tokio::process::Command::new("cmd")
+                            .args(["/C", command])
+                            .kill_on_drop(true)
+                            .output()
+                            .await
+                    } else {
+                        tokio::process::Command::new("sh")
+                            .args(["-c", command])
+                            .kill_on_drop(true)
+                            .output()
+                            .await
+                    };
+                    command_output(command, output)
+                }
+                source => {
+                    let source = source.clone();
+                    tokio::task::spawn_blocking(move || {
+                        source.read_raw(|name| env::var(name).ok())
+                    })
+                    .await
+                    .map_err(|e| format!("cannot read the token: {}", e))?
+                }
+            }
+        };
+        let raw =
+            tokio::time::timeout(timeout, read).await.map_err(|_| {
+                format!(
+                    "reading the token took longer than {}s",
+                    timeout.as_secs_f32()
+                )
+            })??;
+        self.first_line(raw)
+    }
+
+    // The source's raw content (file, command output, stored token).
+    fn read_raw(
+        &self,
+        lookup_env: impl Fn(&str) -> Option<String>,
+    ) -> Result<String, String> {
+        Ok(match self {
+            TokenSource::Value(token) => token.clone(),
+            TokenSource::Env(name) => lookup_env(name).unwrap_or_default(),
+            TokenSource::File(path) => {
+                fs::read_to_string(path).map_err(|e| {
+                    format!("cannot read token file {}: {}", path.display(), e)
+                })?
+            }
+            TokenSource::Command(command) => run_token_command(command)?,
+            TokenSource::Store(name) => {
+                let path = token_store_path();
+                let store =
+                    TokenStore::load(&path, &token_store_passphrase()?)?;
+                store.get(name).map(str::to_string).ok_or_else(|| {
+                    format!("no token named '{}' in {}", name, path.display())
+                })?
+            }
+        })
+    }
+
+    // The first line of the raw content, trimmed; an error naming the source if empty.
+    fn first_line(&self, token: String) -> Result<String, String> {
+        let token = token.lines().next().unwrap_or_default().trim();
+        if !token.is_empty() {
+            return Ok(token.to_string());
+        }
+        Err(match self {
+            TokenSource::Env(name) => {
+                format!("environment variable {} is not set", name)
+            }
+            TokenSource::File(path) => {
+                format!("token file {} is empty", path.display())
+            }
+            TokenSource::Command(command) => {
+                format!("token command `{}` printed nothing", command)
+            }
+            _ => "the token is empty".to_string(),
+        })
+    }
+}
+
+// Runs `command` with the platform shell and returns its standard output.
+fn run_token_command(command: &str) -> Result<String, String> {
+    let output = if cfg!(windows) {
+        Command::new("cmd").args(["/C", command]).output()
+    } else {
+        Command::new("sh").args(["-c", command]).output()
+    };
+    command_output(command, output)
+}
+
+// The standard output of a finished token command, or why it failed.
+fn command_output(
+    command: &str,
+    output: io::Result<Output>,
+) -> Result<String, String> {
+    let output = output.map_err(|e| {
+        format!("cannot run token command `{}`: {}", command, e)
+    })?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "token command `{}` failed ({}): {}",
+            command,
+            output.status,
+            stderr.lines().next().unwrap_or_default().trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
@@ -4,7 +4,8 @@
 // Contains the implementation for the `PUT /v1/w/{workspace_id}/change-sets/{change_set_id}/components/{component_id}` API endpoint call.
 
 // Design Choices:
-// - Uses the shared `get_api_config` function from the parent module.
+// - Uses the shared `get_api_config_for_write` function from the parent module, which refuses
+//   the call when the active profile is read-only.
 // - Serializes the request body and sends a PUT request.
 // - Handles response status and deserialization (expects empty `{}`).
 // - Logs request and response details.
@@ -14,7 +15,15 @@ use std::error::Error;
 // Use the shared config getter and ApiError type from the parent module
 use super::{
     ApiError,
-    get_api_config,
+    CallLog,
+    CancelToken,
+    LogEntry,
+    cache,
+    deserialize_error,
+    get_api_config_for_write,
+    read_body,
+    redact_text,
+    send,
 };
 // Import the specific request and response models needed for this function
 use crate::api_models::{
@@ -30,46 +39,56 @@ pub async fn update_component(
     change_set_id: &str,
     component_id: &str,
     request_body: UpdateComponentV1Request,
+    cancel: &CancelToken,
 ) -> Result<
-    (UpdateComponentV1Response, Vec<String>),
+    (UpdateComponentV1Response, Vec<LogEntry>),
     Box<dyn Error + Send + Sync>,
 > {
-    let mut logs = Vec::new();
+    let mut logs = CallLog::new("update_component");
     // Get the static ApiConfig reference
-    let config = get_api_config()?;
+    let config = get_api_config_for_write("update_component")?;
 
     let url = format!(
         "{}/v1/w/{}/change-sets/{}/components/{}",
         config.base_url, workspace_id, change_set_id, component_id
     );
-    logs.push(format!("Calling API: PUT {}", url));
-    logs.push(format!("Request Body: {:?}", request_body));
+    logs.request("PUT", &url);
+    logs.request_body(&request_body);
 
-    let response = config.client.put(&url).json(&request_body).send().await?;
+    let response = send(&config, &mut logs, cancel, |client| {
+        client.put(&url).json(&request_body)
+    })
+    .await;
+    // Whatever the outcome, the change may have been made
+    cache::invalidate_cached_components(
+        workspace_id,
+        change_set_id,
+        Some(component_id),
+    );
+    let response = response?;
 
     let status = response.status();
-    logs.push(format!("API Response Status: {}", status));
+    logs.response(&response);
 
     if status.is_success() {
-        let response_text = response.text().await?;
-        logs.push(format!("API Success Body: {}", response_text));
+        let response_text = read_body(response, cancel).await?;
+        logs.response_body("API Success Body", &response_text);
         // Response body is empty `{}`, so deserialize into the empty struct
-        let update_response: UpdateComponentV1Response = serde_json::from_str(
-            &response_text,
-        )
-        .map_err(|e| {
-            format!(
-                "Failed to deserialize update component response: {} - Body: {}",
-                e, response_text
-            )
-        })?;
-        Ok((update_response, logs))
+        let update_response: UpdateComponentV1Response =
+            serde_json::from_str(&response_text).map_err(|e| {
+                deserialize_error(
+                    "update component response",
+                    e,
+                    &response_text,
+                )
+            })?;
+        Ok((update_response, logs.into_entries()))
     } else {
         let error_text = response
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error body".to_string());
-        logs.push(format!("API Error Body: {}", error_text));
+        logs.response_body("API Error Body", &error_text);
         let error_message = match serde_json::from_str::<ApiError>(&error_text)
         {
             Ok(api_error) => format!(
@@ -81,6 +100,6 @@ pub async fn update_component(
                 status, error_text
             ),
         };
-        Err(error_message.into())
+        Err(redact_text(&error_message).into())
     }
 }
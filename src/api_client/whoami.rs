@@ -12,8 +12,15 @@ use std::error::Error;
 
 // Use the shared config getter and ApiError type from the parent module
 use super::{
-    get_api_config,
     ApiError,
+    CallLog,
+    CancelToken,
+    LogEntry,
+    deserialize_error,
+    get_api_config,
+    read_body,
+    redact_text,
+    send,
 };
 // Import the specific response model needed for this function
 use crate::api_models::WhoamiResponse;
@@ -27,32 +34,34 @@ use crate::api_models::WhoamiResponse;
 ///   - Confirmed Bearer token authentication matches expectations.
 ///   - Confirmed `WhoamiResponse` struct in `api_models.rs` matches the actual runtime response structure
 ///     (Note: `token` field is an object, differing from OpenAPI spec/service code which suggested string).
-/// Returns: A tuple containing the `WhoamiResponse` on success and a `Vec<String>` of log messages.
-pub async fn whoami() -> Result<(WhoamiResponse, Vec<String>), Box<dyn Error + Send + Sync>> {
-    let mut logs = Vec::new();
+///
+/// Returns: A tuple containing the `WhoamiResponse` on success and a `Vec<LogEntry>` of log messages.
+pub async fn whoami(
+    cancel: &CancelToken,
+) -> Result<(WhoamiResponse, Vec<LogEntry>), Box<dyn Error + Send + Sync>> {
+    let mut logs = CallLog::new("whoami");
     // Get the static ApiConfig reference using the unified function
     let config = get_api_config()?;
 
     let url = format!("{}/whoami", config.base_url);
-    logs.push(format!("Calling API: GET {}", url));
+    logs.request("GET", &url);
 
-    let response = config.client.get(&url).send().await?;
+    let response =
+        send(&config, &mut logs, cancel, |client| client.get(&url)).await?;
 
     let status = response.status();
-    logs.push(format!("API Response Status: {}", status));
+    logs.response(&response);
 
     if status.is_success() {
         // Attempt to deserialize the successful response
-        let response_text = response.text().await?; // Read body first for logging
-        logs.push(format!("API Success Body: {}", response_text));
-        let whoami_data: WhoamiResponse = serde_json::from_str(&response_text).map_err(|e| {
-            format!(
-                "Failed to deserialize success response: {} - Body: {}",
-                e, response_text
-            )
-        })?;
-        // logs.push(format!("API Response Data Parsed: {:?}", whoami_data)); // Maybe too verbose?
-        Ok((whoami_data, logs))
+        let response_text = read_body(response, cancel).await?; // Read body first for logging
+        logs.response_body("API Success Body", &response_text);
+        let whoami_data: WhoamiResponse = serde_json::from_str(&response_text)
+            .map_err(|e| {
+                deserialize_error("success response", e, &response_text)
+            })?;
+        // logs.info(format!("API Response Data Parsed: {:?}", whoami_data)); // Maybe too verbose?
+        Ok((whoami_data, logs.into_entries()))
     } else {
         // Verification (2025-04-21):
         // - OpenAPI spec lists 401/403 for /whoami but doesn't explicitly link ApiError schema.
@@ -64,15 +73,19 @@ pub async fn whoami() -> Result<(WhoamiResponse, Vec<String>), Box<dyn Error + S
             .text()
             .await
             .unwrap_or_else(|_| "Failed to read error body".to_string());
-        logs.push(format!("API Error Body: {}", error_text));
+        logs.response_body("API Error Body", &error_text);
         // Attempt to parse as ApiError for more structured logging, but fall back
-        let error_message = match serde_json::from_str::<ApiError>(&error_text) {
+        let error_message = match serde_json::from_str::<ApiError>(&error_text)
+        {
             Ok(api_error) => format!(
                 "API request failed with status {}: Code {:?}, Message: {}",
                 status, api_error.code, api_error.message
             ),
-            Err(_) => format!("API request failed with status {}: {}", status, error_text),
+            Err(_) => format!(
+                "API request failed with status {}: {}",
+                status, error_text
+            ),
         };
-        Err(error_message.into()) // Return the error message, logs are not returned on error path
+        Err(redact_text(&error_message).into()) // Return the error message, logs are not returned on error path
     }
 }
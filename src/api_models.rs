@@ -6,7 +6,8 @@
 // deserializing API responses.
 
 // Design Choices:
-// - Uses `serde::Deserialize` for easy conversion from JSON.
+// - Uses `serde::Deserialize` for easy conversion from JSON. Models kept in offline snapshots
+//   (see `snapshot`) also derive `Serialize`, with the same field names.
 // - Field names match the JSON properties defined in the OpenAPI schema.
 // - Uses `Option` for fields that are not explicitly marked as required or might be nullable
 //   (like `code` in `ApiError`).
@@ -19,7 +20,7 @@ use serde_json; // Added import for serde_json::Value
 
 /// Represents the nested token details within the WhoamiResponse.
 /// This structure reflects the actual runtime response from the API.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(serde::Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")] // Assuming camelCase based on parent, adjust if needed
 pub struct TokenDetails {
     /// Issued at timestamp.
@@ -37,7 +38,7 @@ pub struct TokenDetails {
 /// Represents the response from the `/whoami` endpoint.
 /// Contains information about the authenticated user and their workspace.
 /// Verification (2025-04-21): Updated based on runtime error. The `token` field is an object, not a string.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(serde::Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct WhoamiResponse {
     /// The unique identifier for the user. Matches OpenAPI `userId`.
@@ -66,7 +67,7 @@ pub struct ApiError {
 /// Represents a summary of a change set, typically used in lists.
 /// Based on the example in openapi.json for ListChangeSetV1Response.
 /// Fields assumed based on the example: {"id":"...", "name":"...", "status":"..."}
-#[derive(Debug, Deserialize, Clone)]
+#[derive(serde::Serialize, Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ChangeSetSummary {
     /// The unique identifier for the change set.
@@ -80,7 +81,7 @@ pub struct ChangeSetSummary {
 
 /// Represents the detailed structure of a change set.
 /// Based on ChangeSetSummary and common fields expected in detailed views.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(serde::Serialize, Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ChangeSet {
     /// The unique identifier for the change set.
@@ -144,7 +145,7 @@ pub struct DeleteChangeSetV1Response {
 
 /// Represents component details within a merge status action.
 /// Based on `MergeStatusV1ResponseActionComponent` in openapi.json.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(serde::Serialize, Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MergeStatusV1ResponseActionComponent {
     /// The unique identifier for the component.
@@ -155,7 +156,7 @@ pub struct MergeStatusV1ResponseActionComponent {
 
 /// Represents a single action within the merge status response.
 /// Based on `MergeStatusV1ResponseAction` in openapi.json.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(serde::Serialize, Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MergeStatusV1ResponseAction {
     /// The unique identifier for the action.
@@ -172,7 +173,7 @@ pub struct MergeStatusV1ResponseAction {
 
 /// Represents the response from the `GET /v1/w/{workspace_id}/change-sets/{change_set_id}/merge_status` endpoint.
 /// Based on the schema `MergeStatusV1Response` in openapi.json.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(serde::Serialize, Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct MergeStatusV1Response {
     /// Contains details of the change set itself.
@@ -182,6 +183,36 @@ pub struct MergeStatusV1Response {
     pub actions: Vec<MergeStatusV1ResponseAction>,
 }
 
+/// Display order for action kinds when actions are grouped.
+/// Kinds not listed here (e.g. "Manual") are placed after these, by kind name.
+pub const ACTION_KIND_ORDER: [&str; 4] =
+    ["Create", "Update", "Delete", "Refresh"];
+
+impl MergeStatusV1Response {
+    /// Returns the actions ordered by kind group (Create, Update, Delete, Refresh, then others).
+    /// Intention: Give the TUI a single ordering to render and to map a selected row back to an action.
+    /// Design: Stable sort, so actions keep their API order within each kind; other kinds are
+    /// ordered by name so each of them forms a single group.
+    pub fn actions_grouped_by_kind(&self) -> Vec<&MergeStatusV1ResponseAction> {
+        let mut actions: Vec<&MergeStatusV1ResponseAction> =
+            self.actions.iter().collect();
+        actions.sort_by(|a, b| kind_rank(a).cmp(&kind_rank(b)));
+        actions
+    }
+}
+
+/// Sort key of an action's kind group: its position in `ACTION_KIND_ORDER`, or after those and
+/// by name for other kinds.
+fn kind_rank(action: &MergeStatusV1ResponseAction) -> (usize, &str) {
+    match ACTION_KIND_ORDER
+        .iter()
+        .position(|kind| kind.eq_ignore_ascii_case(&action.kind))
+    {
+        Some(position) => (position, ""),
+        None => (ACTION_KIND_ORDER.len(), action.kind.as_str()),
+    }
+}
+
 //=============================================================================
 // Component API Models (Added based on openapi.json)
 //=============================================================================
@@ -279,13 +310,25 @@ pub struct GetComponentV1Response {
     /// The component's data (arbitrary JSON object).
     pub component: serde_json::Value,
     /// The component's domain properties (arbitrary JSON object).
+    /// Not listed as required in openapi.json, so defaults to `null` when absent.
+    #[serde(default)]
     pub domain: serde_json::Value,
     /// List of available management functions for the component.
     pub management_functions: Vec<GetComponentV1ResponseManagementFunction>,
     /// List of view-related data for the component.
+    /// Not listed as required in openapi.json, so defaults to empty when absent.
+    #[serde(default)]
     pub view_data: Vec<GeometryAndViewAndName>,
 }
 
+impl GetComponentV1Response {
+    /// Returns a string field of the untyped `component` object (e.g. `"schemaId"`, `"name"`).
+    /// Intention: Let callers read the few fields they need without a typed component model.
+    pub fn component_str(&self, field: &str) -> Option<&str> {
+        self.component.get(field).and_then(|value| value.as_str())
+    }
+}
+
 // --- Update Component ---
 
 /// Request body for `PUT /v1/w/{workspace_id}/change-sets/{change_set_id}/components/{component_id}`.
@@ -455,7 +498,7 @@ pub struct ListComponentsV1Response {
 
 /// Represents a summary of a schema as returned by the list_schemas endpoint.
 /// Based on the example in `ListSchemaV1Response` in openapi.json.
-#[derive(Deserialize, Debug, Clone)]
+#[derive(serde::Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct SchemaSummary {
     /// The unique identifier for the schema.
@@ -1,21 +1,86 @@
 // Intention: Define application state and related enums/methods for the TUI.
 // Design Choice: Central struct `App` holds all state, moved from main.rs.
 // Enums `InputMode` and `DropdownFocus` define specific UI states.
+// Command palette entries and ranking live in the `palette` submodule, schema tree
+// navigation in `schema_list`, log filtering and scrolling in `log_panel`, the HTTP request
+// inspector in `inspector`, mouse hit-testing in `mouse`, pane resizing and zoom in `layout`, the
+// `?` help overlay in `help`, the workspace switcher in `workspaces`, token expiry and the token
+// prompt in `token`, snapshots and the offline mode in `offline`, background refresh results in
+// `auto_refresh`, change set comparisons in `compare`, the pre-apply review in `review`, edits
+// overlapping across open change sets in `conflicts`.
 // Methods previously in `impl App` are kept here.
 
-use std::cmp::min;
-use std::collections::HashMap; // Added for potential future use with schemas
+mod auto_refresh;
+mod compare;
+mod conflicts;
+mod help;
+mod inspector;
+mod layout;
+mod log_panel;
+mod mouse;
+mod offline;
+mod palette;
+mod review;
+mod schema_list;
+mod token;
+mod workspaces;
 
-use ratatui::widgets::ListState;
-use situation::api_models::SchemaSummary;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+        VecDeque,
+    },
+    time::Instant,
+};
+
+use crossterm::event::Event;
+pub use help::text_entry_keys;
+pub use mouse::{
+    ClickTarget,
+    ScreenAreas,
+};
+pub use palette::{
+    PaletteCommand,
+    PaletteEntry,
+    PaletteTarget,
+};
+use ratatui::widgets::{
+    ListState,
+    TableState,
+};
 use situation::api_models::{
     ChangeSet,
     ChangeSetSummary,
     ComponentViewV1, // Added import for component details
+    GetComponentV1Response,
     MergeStatusV1Response,
+    MergeStatusV1ResponseAction,
     // SchemaSummary, // Removed from group
     WhoamiResponse,
 }; // Ensure correct import name: MergeStatusV1Response // Import separately
+use situation::{
+    ApplyMonitor,
+    ApplyReview,
+    AutoRefresh,
+    CancelToken,
+    ChangeSetComparison,
+    ConflictReport,
+    JwtClaims,
+    KeyContext,
+    Keymap,
+    LogEntry,
+    LogFile,
+    LogLevel,
+    PaneLayout,
+    Profile,
+    Theme,
+    WorkspaceEntry,
+    WorkspaceSnapshot,
+    api_models::SchemaSummary,
+    layout::DEFAULT_LOG_HEIGHT,
+};
+pub use token::TokenUrgency;
 
 // Intention: Define different input modes for the application.
 // Design Choice: Enum to represent distinct input states.
@@ -23,6 +88,10 @@ use situation::api_models::{
 pub enum InputMode {
     Normal,
     ChangeSetName,
+    CommandPalette, // Ctrl-P palette overlay is open
+    SchemaSearch,   // Typing an incremental `/` search in the schema list
+    LogSearch,      // Typing a `/` search in the log panel
+    Token,          // Pasting a new token after a 401 or from the palette
 }
 
 // Intention: Define the possible areas of the UI that can have focus.
@@ -34,6 +103,7 @@ pub enum AppFocus {
     ContentArea, // Placeholder for future content interaction
     LogPanel,
     ChangeSetDropdown, // Focus specifically when the dropdown is active
+    WorkspaceDropdown, // Focus while the workspace dropdown is open
     Input,             // Focus when in input mode
 }
 
@@ -57,22 +127,65 @@ pub struct App {
     pub selected_change_set_details: Option<ChangeSet>, // Details of the selected change set
     pub selected_change_set_merge_status: Option<MergeStatusV1Response>, // Merge status of the selected change set
     pub selected_change_set_components: Option<Vec<ComponentViewV1>>, // Components in the selected change set, parsed from JSON string
-    pub current_action: Option<String>, // Feedback for ongoing actions
-    pub input_mode: InputMode,          // Current input mode
-    pub input_buffer: String,           // Buffer for text input
-    pub logs: Vec<String>,
-    pub log_scroll: usize,
+    pub action_table_state: TableState, // Selection within the merge status actions table (grouped order)
+    pub selected_component_details: Option<GetComponentV1Response>, // Component opened from the actions table
+    pub apply_monitor: Option<ApplyMonitor>, // Progress of the last force apply, kept after completion for the summary
+    pub current_action: Option<String>,      // Feedback for ongoing actions
+    pub input_mode: InputMode,               // Current input mode
+    pub input_buffer: String,                // Buffer for text input
+    pub logs: Vec<LogEntry>,
+    pub log_scroll: usize, // First visible line among the entries passing the filters
+    pub log_verbosity: LogLevel, // Entries below this level are dropped when logged
+    pub log_level_filter: LogLevel, // Entries below this level are hidden in the panel
+    pub log_query: String,          // `/` search applied to the log panel
+    pub log_file: Option<LogFile>,  // Persistent log receiving every kept entry
+    pub log_view_height: usize, // Text lines the log panel showed when last drawn
+    pub inspector_open: bool, // HTTP request inspector shown in place of the content area
+    pub inspector_table_state: TableState, // Selected request (index into the HTTP history)
+    pub inspector_detail_scroll: u16, // Scroll of the selected request's detail view
     pub dropdown_focus: DropdownFocus, // Which dropdown trigger is focused (within TopBar)
     pub changeset_dropdown_active: bool, // Is the changeset dropdown list visible?
+    pub workspaces: Vec<WorkspaceEntry>, // Workspaces to switch between (`.env`, then the workspaces file)
+    pub active_workspace: Option<usize>, // Index into `workspaces` of the one in use
+    pub workspace_list_state: ListState, // Selection within the workspace dropdown
+    pub workspace_dropdown_active: bool, // Is the workspace dropdown list visible?
+    pub profile: Option<Profile>, // Profile selected with `--profile`/`SI_PROFILE`, if any
+    pub token_claims: Option<JwtClaims>, // Claims of the token in use, for its expiry
+    pub token_expiry_warned: bool, // The expiry warning was logged for the current token
+    pub palette_query: String,     // Query typed into the command palette
+    pub palette_list_state: ListState, // Selection within the filtered palette entries
+    pub help_open: bool, // `?` help overlay shown above the current mode
+    pub help_scroll: u16, // First visible line of the help overlay
+    pub load_token: CancelToken, // Cancels the fetches started by the current input event
+    pub pending_events: VecDeque<Event>, // Input read during a fetch, handled after it
+    pub offline: bool, // Backend unreachable: the snapshot is shown, read-only
+    pub snapshot: Option<WorkspaceSnapshot>, // Last state saved for the workspace in use
+    pub auto_refresh: AutoRefresh, // When the next background refresh is due
+    pub changed_change_set_ids: HashSet<String>, // Change sets changed by the last refresh
+    pub changed_action_ids: HashSet<String>, // Actions changed by the last refresh
+    pub comparison: Option<ChangeSetComparison>, // Shown in place of the change set view
+    pub comparison_scroll: u16, // First visible row of the comparison
+    pub palette_compare_to: Option<String>, // Palette picks a change set to compare this one with
+    pub review: Option<ApplyReview>, // Shown before a force apply, which it then confirms
+    pub review_scroll: u16,          // First visible row of the review
+    pub conflicts: Option<ConflictReport>, // Last scan of the open change sets' overlaps
 
     // Schema List State
     // Intention: Store detailed schema information for display and interaction.
     // Design Choice: Use the SchemaSummary struct from api_models to hold category, installed status, etc.
     pub schemas: Vec<SchemaSummary>, // Changed from Vec<String>
-    pub schema_list_state: ListState, // State for the schema list selection
+    pub schema_list_state: ListState, // Selected row of the schema tree (see `schema_tree_rows`)
+    pub schema_query: String, // Incremental `/` search applied to the schema tree
+    pub schema_installed_only: bool, // Hide schemas that are not installed
+    pub collapsed_categories: HashSet<String>, // Schema categories collapsed in the tree
+    pub schema_component_counts: HashMap<String, usize>, // Components per schema ID in the selected change set
 
     // Overall Focus
     pub current_focus: AppFocus, // Tracks which major UI pane has focus
+    pub keymap: Keymap, // Key bindings per focus, from the keybindings file or the defaults
+    pub theme: Theme, // Colours of every UI role, from `SI_THEME`/`NO_COLOR` or the default
+    pub screen_areas: ScreenAreas, // Pane rectangles of the last frame, for mouse hit-testing
+    pub layout: PaneLayout, // Pane sizes, collapsed panes and zoom, saved in the layout file
 }
 
 impl App {
@@ -86,46 +199,101 @@ impl App {
             selected_change_set_details: None,
             selected_change_set_merge_status: None,
             selected_change_set_components: None, // Initialize the new field
+            action_table_state: TableState::default(),
+            selected_component_details: None,
+            apply_monitor: None,
             current_action: None,
             input_mode: InputMode::Normal,
             input_buffer: String::new(),
             logs: Vec::new(),
             log_scroll: 0,
+            log_verbosity: LogLevel::verbosity_from_env(),
+            log_level_filter: LogLevel::Trace,
+            log_query: String::new(),
+            // A missing log file is not fatal; the panel still works
+            log_file: LogFile::open(LogFile::default_path()).ok(),
+            // The default panel's height inside its borders, until the first draw
+            log_view_height: DEFAULT_LOG_HEIGHT as usize - 2,
+            inspector_open: false,
+            inspector_table_state: TableState::default(),
+            inspector_detail_scroll: 0,
             dropdown_focus: DropdownFocus::Workspace, // Start focus on workspace trigger in top bar
             changeset_dropdown_active: false,         // Dropdown starts closed
+            workspaces: Vec::new(), // Filled in by `run_app` at startup
+            active_workspace: None,
+            workspace_list_state: ListState::default(),
+            workspace_dropdown_active: false,
+            profile: None, // Set by `run_app` from the profile `main` selected
+            token_claims: None, // Updated from the API client on every loop iteration
+            token_expiry_warned: false,
+            palette_query: String::new(),
+            palette_list_state: ListState::default(),
+            help_open: false,
+            help_scroll: 0,
+            load_token: CancelToken::new(), // Replaced for every input event
+            pending_events: VecDeque::new(),
+            offline: false,
+            snapshot: None, // Loaded from disk only when going offline
+            auto_refresh: AutoRefresh::new(None, Instant::now()), // Set by `run_app`
+            changed_change_set_ids: HashSet::new(),
+            changed_action_ids: HashSet::new(),
+            comparison: None,
+            comparison_scroll: 0,
+            palette_compare_to: None,
+            review: None,
+            review_scroll: 0,
+            conflicts: None,
 
             // Initialize schema list
             schemas: Vec::new(),
             schema_list_state: ListState::default(),
+            schema_query: String::new(),
+            schema_installed_only: false,
+            collapsed_categories: HashSet::new(),
+            schema_component_counts: HashMap::new(),
 
             // Initialize focus
             current_focus: AppFocus::TopBar, // Start focus on the top bar
+            keymap: Keymap::default(), // Replaced by the keybindings file at startup
+            theme: Theme::default(), // Replaced by the configured theme at startup
+            screen_areas: ScreenAreas::default(), // Filled in by the first draw
+            layout: PaneLayout::default(), // Replaced by the saved layout at startup
         }
     }
 
-    // Intention: Add a log message and automatically scroll to the bottom if needed.
-    // Design Choice: Calculates the maximum scroll position based on log count and view height,
-    // then sets the current scroll position to the maximum, ensuring the latest log is visible.
-    // This method is intended to be used whenever a new log entry is generated by the application.
-    // The `view_height` parameter should match the height constraint used for the log Paragraph in the UI.
-    pub fn add_log_auto_scroll(&mut self, message: String, view_height: usize) {
-        self.logs.push(message);
-        // Calculate max scroll based on the *new* number of logs and window height
-        let max_scroll = self.logs.len().saturating_sub(view_height);
-        self.log_scroll = max_scroll; // Always scroll to the bottom
-    }
-
-    // Intention: Scroll the log view up by one line.
-    pub fn scroll_logs_up(&mut self) {
-        self.log_scroll = self.log_scroll.saturating_sub(1);
+    // Intention: Map the current focus to the keymap context its bindings live in.
+    // Design Choice: The open request inspector takes over the content area's keys.
+    pub fn key_context(&self) -> KeyContext {
+        match self.current_focus {
+            AppFocus::TopBar | AppFocus::Input => KeyContext::TopBar,
+            AppFocus::SchemaList => KeyContext::SchemaList,
+            AppFocus::ContentArea if self.inspector_open => {
+                KeyContext::Inspector
+            }
+            AppFocus::ContentArea => KeyContext::ContentArea,
+            AppFocus::LogPanel => KeyContext::LogPanel,
+            AppFocus::ChangeSetDropdown => KeyContext::ChangeSetDropdown,
+            AppFocus::WorkspaceDropdown => KeyContext::WorkspaceDropdown,
+        }
     }
 
-    // Intention: Scroll the log view down by one line.
-    // Design Choice: Prevent scrolling beyond the available log lines.
-    pub fn scroll_logs_down(&mut self, view_height: usize) {
-        // Calculate max scroll based on number of logs and window height
-        let max_scroll = self.logs.len().saturating_sub(view_height);
-        self.log_scroll = min(self.log_scroll.saturating_add(1), max_scroll);
+    // Intention: Switch to the next available theme (built-ins, then user theme files).
+    // Design Choice: A theme file that fails to load is logged and skipped over.
+    pub fn cycle_theme(&mut self) {
+        let names = Theme::available();
+        let current = names.iter().position(|name| *name == self.theme.name);
+        let start = current.map_or(0, |i| i + 1);
+        for offset in 0..names.len() {
+            let name = &names[(start + offset) % names.len()];
+            match Theme::load(name) {
+                Ok(theme) => {
+                    self.theme = theme;
+                    self.add_log_auto_scroll(format!("Theme: {}", name));
+                    return;
+                }
+                Err(e) => self.add_log_auto_scroll(LogEntry::error("theme", e)),
+            }
+        }
     }
 
     // Intention: Move selection down in the change set list (dropdown).
@@ -149,6 +317,8 @@ impl App {
             self.selected_change_set_details = None;
             self.selected_change_set_merge_status = None;
             self.selected_change_set_components = None; // Clear components too
+            self.action_table_state.select(None);
+            self.selected_component_details = None;
         }
     }
 
@@ -173,6 +343,8 @@ impl App {
             self.selected_change_set_details = None;
             self.selected_change_set_merge_status = None;
             self.selected_change_set_components = None; // Clear components too
+            self.action_table_state.select(None);
+            self.selected_component_details = None;
         }
     }
 
@@ -181,19 +353,19 @@ impl App {
     // and updates the list state. If the ID is not found or the list is empty/None,
     // the selection remains unchanged. Also clears details/components.
     pub fn select_change_set_by_id(&mut self, change_set_id: &str) {
-        if let Some(change_sets) = &self.change_sets {
-            if let Some(index) =
+        // If change_sets is None or the ID is not found, do nothing, keep current selection
+        if let Some(change_sets) = &self.change_sets
+            && let Some(index) =
                 change_sets.iter().position(|cs| cs.id == change_set_id)
-            {
-                self.change_set_list_state.select(Some(index));
-                // Clear details when selection changes programmatically too
-                self.selected_change_set_details = None;
-                self.selected_change_set_merge_status = None;
-                self.selected_change_set_components = None; // Clear components too
-            }
-            // If ID not found, do nothing, keep current selection
+        {
+            self.change_set_list_state.select(Some(index));
+            // Clear details when selection changes programmatically too
+            self.selected_change_set_details = None;
+            self.selected_change_set_merge_status = None;
+            self.selected_change_set_components = None; // Clear components too
+            self.action_table_state.select(None);
+            self.selected_component_details = None;
         }
-        // If change_sets is None, do nothing
     }
 
     // Intention: Get the summary of the currently selected change set.
@@ -204,75 +376,48 @@ impl App {
         })
     }
 
-    // Intention: Move selection down in the schema list.
-    // Design Choice: Handles wrapping and empty list case.
-    // When a schema is selected, the content area will filter components to show only those
-    // that match the selected schema's ID.
-    pub fn schema_next(&mut self) {
-        if self.schemas.is_empty() {
+    // Intention: Move selection down in the merge status actions table.
+    // Design Choice: Indexes into `actions_grouped_by_kind()` so the selection matches the rendered order.
+    pub fn action_next(&mut self) {
+        let count = self
+            .selected_change_set_merge_status
+            .as_ref()
+            .map_or(0, |status| status.actions.len());
+        if count == 0 {
             return;
         }
-        let i = match self.schema_list_state.selected() {
-            Some(i) => {
-                if i >= self.schemas.len() - 1 {
-                    0 // Wrap around
-                } else {
-                    i + 1
-                }
-            }
-            None => 0, // Select first if nothing selected
+        let i = match self.action_table_state.selected() {
+            Some(i) if i + 1 < count => i + 1,
+            Some(_) => 0, // Wrap around
+            None => 0,    // Select first if nothing selected
         };
-        self.schema_list_state.select(Some(i));
-        // Note: Component filtering based on selected schema is handled in render_content_area.rs
-
-        // Debug: Log the selected schema
-        if let Some(selected_idx) = self.schema_list_state.selected() {
-            if !self.schemas.is_empty() {
-                let selected_schema = &self.schemas[selected_idx];
-                self.add_log_auto_scroll(
-                    format!(
-                        "DEBUG: Selected schema: {} (id: {})",
-                        selected_schema.schema_name, selected_schema.schema_id
-                    ),
-                    10, // LOG_HEIGHT
-                );
-            }
-        }
+        self.action_table_state.select(Some(i));
     }
 
-    // Intention: Move selection up in the schema list.
-    // Design Choice: Handles wrapping and empty list case.
-    // When a schema is selected, the content area will filter components to show only those
-    // that match the selected schema's ID.
-    pub fn schema_previous(&mut self) {
-        if self.schemas.is_empty() {
+    // Intention: Move selection up in the merge status actions table.
+    pub fn action_previous(&mut self) {
+        let count = self
+            .selected_change_set_merge_status
+            .as_ref()
+            .map_or(0, |status| status.actions.len());
+        if count == 0 {
             return;
         }
-        let i = match self.schema_list_state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    self.schemas.len() - 1 // Wrap around
-                } else {
-                    i - 1
-                }
-            }
-            None => self.schemas.len() - 1, // Select last if nothing selected
+        let i = match self.action_table_state.selected() {
+            Some(0) | None => count - 1, // Wrap around / select last
+            Some(i) => i - 1,
         };
-        self.schema_list_state.select(Some(i));
-        // Note: Component filtering based on selected schema is handled in render_content_area.rs
+        self.action_table_state.select(Some(i));
+    }
 
-        // Debug: Log the selected schema
-        if let Some(selected_idx) = self.schema_list_state.selected() {
-            if !self.schemas.is_empty() {
-                let selected_schema = &self.schemas[selected_idx];
-                self.add_log_auto_scroll(
-                    format!(
-                        "DEBUG: Selected schema: {} (id: {})",
-                        selected_schema.schema_name, selected_schema.schema_id
-                    ),
-                    10, // LOG_HEIGHT
-                );
-            }
-        }
+    // Intention: Get the action currently selected in the actions table.
+    // Design Choice: Resolves the table index against the grouped order used for rendering.
+    pub fn get_selected_action(&self) -> Option<&MergeStatusV1ResponseAction> {
+        let idx = self.action_table_state.selected()?;
+        self.selected_change_set_merge_status
+            .as_ref()
+            .and_then(|status| {
+                status.actions_grouped_by_kind().get(idx).copied()
+            })
     }
 }
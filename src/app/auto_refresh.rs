@@ -0,0 +1,98 @@
+// src/app/auto_refresh.rs
+
+// Intention: Apply the results of a background refresh to the app state without disturbing
+// what the user is looking at, and remember which rows changed so they can be highlighted.
+// Design Choice: Selections are kept by ID rather than by index, since rows can be added or
+// removed anywhere in the lists. The highlighted rows are those changed by the last refresh
+// only; a refresh that changes nothing clears them.
+
+use std::time::Instant;
+
+use situation::{
+    LogEntry,
+    api_models::{
+        ChangeSet,
+        ChangeSetSummary,
+        MergeStatusV1Response,
+    },
+    changed_actions,
+    changed_change_sets,
+};
+
+use super::App;
+
+impl App {
+    // Intention: Replace the change set list, keeping the selected change set selected.
+    // Returns false if the selected change set is no longer listed; the selection then moves to
+    // the row at the same position (or the last one).
+    pub fn apply_refreshed_change_sets(
+        &mut self,
+        change_sets: Vec<ChangeSetSummary>,
+    ) -> bool {
+        let selected_id = self
+            .get_selected_changeset_summary()
+            .map(|cs| cs.id.clone());
+        self.changed_change_set_ids = match &self.change_sets {
+            Some(previous) => changed_change_sets(previous, &change_sets),
+            None => Default::default(), // Nothing to compare with yet
+        };
+        let position = selected_id
+            .as_ref()
+            .and_then(|id| change_sets.iter().position(|cs| &cs.id == id));
+        let kept = position.is_some() || selected_id.is_none();
+        let index = position.or_else(|| {
+            let last = change_sets.len().checked_sub(1)?;
+            Some(self.change_set_list_state.selected().unwrap_or(0).min(last))
+        });
+        self.change_sets = Some(change_sets);
+        self.change_set_list_state.select(index);
+        kept
+    }
+
+    // Intention: Replace the selected change set's details and merge status, keeping the
+    // selected action selected.
+    pub fn apply_refreshed_status(
+        &mut self,
+        details: ChangeSet,
+        status: MergeStatusV1Response,
+    ) {
+        let selected_action = self.get_selected_action().map(|a| a.id.clone());
+        self.changed_action_ids = match &self.selected_change_set_merge_status {
+            Some(previous) => {
+                changed_actions(&previous.actions, &status.actions)
+            }
+            None => Default::default(),
+        };
+        let actions = status.actions_grouped_by_kind();
+        let index = selected_action
+            .and_then(|id| actions.iter().position(|a| a.id == id))
+            .or((!actions.is_empty()).then_some(0));
+        self.action_table_state.select(index);
+        self.selected_change_set_details = Some(details);
+        self.selected_change_set_merge_status = Some(status);
+    }
+
+    // Intention: Forget the highlighted rows, e.g. when another change set is loaded.
+    pub fn clear_refresh_highlights(&mut self) {
+        self.changed_change_set_ids.clear();
+        self.changed_action_ids.clear();
+    }
+
+    // Intention: Pause or resume the background refresh from the command palette.
+    pub fn toggle_auto_refresh(&mut self, now: Instant) {
+        let Some(interval) = self.auto_refresh.interval() else {
+            self.add_log_auto_scroll(LogEntry::warn(
+                "refresh",
+                "Auto-refresh is disabled (SI_REFRESH_INTERVAL).",
+            ));
+            return;
+        };
+        let message = if self.auto_refresh.toggle_paused(now) {
+            format!("Auto-refresh resumed (every {}s).", interval.as_secs())
+        } else {
+            self.clear_refresh_highlights();
+            "Auto-refresh paused.".to_string()
+        };
+        self.add_log_auto_scroll(LogEntry::info("refresh", message));
+    }
+}
@@ -0,0 +1,59 @@
+// src/app/compare.rs
+
+// Intention: State of the change set comparison shown in the content area, and the palette used
+// to pick the change set to compare with.
+// Design Choice: The selected change set is always the right side, so a comparison reads as
+// "what this change set changes" whether the other side is HEAD or a picked change set. The
+// picker reuses the command palette, restricted to the other change sets.
+
+use situation::{
+    ChangeSetComparison,
+    LogEntry,
+};
+
+use super::{
+    App,
+    AppFocus,
+};
+
+impl App {
+    // Intention: Open the palette listing the change sets to compare the selected one with.
+    pub fn open_compare_palette(&mut self) {
+        let Some(cs_id) = self
+            .get_selected_changeset_summary()
+            .map(|cs| cs.id.clone())
+        else {
+            self.add_log_auto_scroll(LogEntry::warn(
+                "compare",
+                "Cannot compare: No change set selected.",
+            ));
+            return;
+        };
+        self.open_palette();
+        self.palette_compare_to = Some(cs_id);
+    }
+
+    // Intention: Show a comparison in the content area, scrolled to the top, and focus it so
+    // the scroll keys work straight away.
+    pub fn show_comparison(&mut self, comparison: ChangeSetComparison) {
+        self.comparison = Some(comparison);
+        self.comparison_scroll = 0;
+        self.selected_component_details = None;
+        self.close_review();
+        self.current_focus = AppFocus::ContentArea;
+    }
+
+    pub fn close_comparison(&mut self) {
+        self.comparison = None;
+        self.comparison_scroll = 0;
+    }
+
+    pub fn scroll_comparison_up(&mut self, lines: u16) {
+        self.comparison_scroll = self.comparison_scroll.saturating_sub(lines);
+    }
+
+    // Design Choice: Bounded by the renderer, which clamps the scroll to the rows it has.
+    pub fn scroll_comparison_down(&mut self, lines: u16) {
+        self.comparison_scroll = self.comparison_scroll.saturating_add(lines);
+    }
+}
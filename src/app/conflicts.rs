@@ -0,0 +1,67 @@
+// src/app/conflicts.rs
+
+// Intention: Keep the last scan for components modified in more than one open change set, for
+// the warning badges in the change set dropdown and on the review.
+// Design Choice: The scan loads every open change set, so it runs on demand (palette, review)
+// rather than with the background refresh; its result stays until the next scan or workspace
+// switch.
+
+use situation::{
+    ConflictReport,
+    LogEntry,
+    LogLevel,
+};
+
+use super::App;
+
+impl App {
+    // Intention: Store a scan and log what it found, one warning per overlapping component.
+    pub fn set_conflicts(&mut self, report: ConflictReport) {
+        let level = if report.is_empty() {
+            LogLevel::Info
+        } else {
+            LogLevel::Warn
+        };
+        self.add_log_auto_scroll(LogEntry::new(
+            level,
+            "conflicts",
+            format!("Conflict scan: {}.", report.summary()),
+        ));
+        for overlap in &report.components {
+            let names: Vec<&str> = overlap
+                .change_sets
+                .iter()
+                .map(|cs| cs.name.as_str())
+                .collect();
+            let paths: Vec<&str> =
+                overlap.props.iter().map(|p| p.path.as_str()).collect();
+            self.add_log_auto_scroll(LogEntry::warn(
+                "conflicts",
+                if paths.is_empty() {
+                    format!(
+                        "{} is modified in {}.",
+                        overlap.name,
+                        names.join(", ")
+                    )
+                } else {
+                    format!(
+                        "{} is modified in {}, with different values for {}.",
+                        overlap.name,
+                        names.join(", "),
+                        paths.join(", ")
+                    )
+                },
+            ));
+        }
+        self.conflicts = Some(report);
+    }
+
+    // Intention: Number of components a change set shares with other open change sets, for its
+    // badge; `None` when there is nothing to warn about (or no scan yet).
+    pub fn conflict_count(&self, change_set_id: &str) -> Option<usize> {
+        self.conflicts
+            .as_ref()
+            .map(|report| report.count_for(change_set_id))
+            .filter(|count| *count > 0)
+    }
+}
@@ -0,0 +1,122 @@
+// src/app/help.rs
+
+// Intention: The `?` help overlay: the keys that work right now, with short descriptions.
+// Design Choice: In Normal mode the sections come from the keymap (the focused pane's bindings,
+// then the global ones), so remapped keys are shown. The text-entry modes are not remappable, so
+// their keys are listed here; global keys do not apply while typing and are left out. The
+// overlay sits above the current mode, which is unchanged when it closes.
+
+use situation::KeyContext;
+
+use super::{
+    App,
+    InputMode,
+};
+
+// Intention: The fixed keys of a text-entry mode, as `(keys, description)`.
+pub fn text_entry_keys(
+    mode: &InputMode,
+) -> &'static [(&'static str, &'static str)] {
+    match mode {
+        InputMode::Normal => &[],
+        InputMode::ChangeSetName => &[
+            ("Enter", "Submit Name & Create"),
+            ("Esc", "Cancel Input"),
+            ("Backspace", "Delete Character"),
+            ("(any char)", "Append Character"),
+        ],
+        InputMode::CommandPalette => &[
+            ("Up/Ctrl-p", "Select Previous Entry"),
+            ("Down/Ctrl-n", "Select Next Entry"),
+            ("Enter", "Run Selected Entry"),
+            ("Esc", "Close Palette"),
+            ("Backspace", "Delete Character"),
+            ("(any char)", "Filter Entries"),
+        ],
+        InputMode::SchemaSearch => &[
+            ("Up/Down", "Select Previous/Next Row"),
+            ("Enter", "Keep the Filter"),
+            ("Esc", "Clear the Filter"),
+            ("Backspace", "Delete Character"),
+            ("(any char)", "Search Schemas"),
+        ],
+        InputMode::LogSearch => &[
+            ("Up/Down", "Scroll Logs"),
+            ("Enter", "Keep the Filter"),
+            ("Esc", "Clear the Filter"),
+            ("Backspace", "Delete Character"),
+            ("(any char)", "Search Logs"),
+        ],
+        InputMode::Token => &[
+            ("Enter", "Use the Token & Retry"),
+            ("Esc", "Keep the Current Token"),
+            ("Backspace", "Delete Character"),
+            ("(any char)", "Append Character (or paste)"),
+        ],
+    }
+}
+
+fn text_entry_title(mode: &InputMode) -> &'static str {
+    match mode {
+        InputMode::Normal => "",
+        InputMode::ChangeSetName => "Input Mode (Create Change Set)",
+        InputMode::CommandPalette => "Command Palette",
+        InputMode::SchemaSearch => "Schema Search",
+        InputMode::LogSearch => "Log Search",
+        InputMode::Token => "New Token",
+    }
+}
+
+impl App {
+    pub fn open_help(&mut self) {
+        self.help_open = true;
+        self.help_scroll = 0;
+    }
+
+    pub fn close_help(&mut self) {
+        self.help_open = false;
+    }
+
+    pub fn help_scroll_up(&mut self, lines: u16) {
+        self.help_scroll = self.help_scroll.saturating_sub(lines);
+    }
+
+    // The renderer clamps the scroll to the help's length
+    pub fn help_scroll_down(&mut self, lines: u16) {
+        self.help_scroll = self.help_scroll.saturating_add(lines);
+    }
+
+    // Intention: The help sections for the current focus and input mode, as
+    // `(title, [(keys, description)])`.
+    pub fn help_sections(&self) -> Vec<(String, Vec<(String, String)>)> {
+        if self.input_mode != InputMode::Normal {
+            let keys = text_entry_keys(&self.input_mode)
+                .iter()
+                .map(|(keys, description)| {
+                    (keys.to_string(), description.to_string())
+                })
+                .collect();
+            return vec![(
+                text_entry_title(&self.input_mode).to_string(),
+                keys,
+            )];
+        }
+        let context = self.key_context();
+        self.keymap
+            .help_for(context)
+            .into_iter()
+            .map(|(c, entries)| {
+                let title = if c == KeyContext::Global && context != c {
+                    "Global (everywhere)".to_string()
+                } else {
+                    c.title().to_string()
+                };
+                let entries = entries
+                    .into_iter()
+                    .map(|(keys, description)| (keys, description.to_string()))
+                    .collect();
+                (title, entries)
+            })
+            .collect()
+    }
+}
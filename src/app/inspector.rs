@@ -0,0 +1,70 @@
+// src/app/inspector.rs
+
+// Intention: Navigation of the HTTP request inspector, which replaces the content area with the
+// session's request history (`HttpHistory::global`) when open.
+// Design Choice: The history lives in the library and only grows, so the selection is a plain
+// index into its snapshot. Opening the inspector selects the latest request; the detail view
+// scrolls independently of the table.
+
+use situation::HttpHistory;
+
+use super::{
+    App,
+    AppFocus,
+};
+
+impl App {
+    // Intention: Show or hide the inspector. Opening it focuses the content area it occupies.
+    pub fn toggle_inspector(&mut self) {
+        self.inspector_open = !self.inspector_open;
+        if self.inspector_open {
+            self.current_focus = AppFocus::ContentArea;
+            self.inspector_select_latest();
+        }
+    }
+
+    // Intention: Select the most recent request, e.g. after opening the inspector.
+    pub fn inspector_select_latest(&mut self) {
+        let len = HttpHistory::global().len();
+        self.inspector_table_state.select(len.checked_sub(1));
+        self.inspector_detail_scroll = 0;
+    }
+
+    // Intention: Move selection down in the request table.
+    // Design Choice: Does not wrap, so holding Down stops at the latest request.
+    pub fn inspector_next(&mut self) {
+        let len = HttpHistory::global().len();
+        if len == 0 {
+            return;
+        }
+        let i = self
+            .inspector_table_state
+            .selected()
+            .map_or(0, |i| (i + 1).min(len - 1));
+        self.inspector_table_state.select(Some(i));
+        self.inspector_detail_scroll = 0;
+    }
+
+    // Intention: Move selection up in the request table.
+    pub fn inspector_previous(&mut self) {
+        if HttpHistory::global().is_empty() {
+            return;
+        }
+        let i = self
+            .inspector_table_state
+            .selected()
+            .map_or(0, |i| i.saturating_sub(1));
+        self.inspector_table_state.select(Some(i));
+        self.inspector_detail_scroll = 0;
+    }
+
+    pub fn inspector_scroll_down(&mut self, lines: u16) {
+        self.inspector_detail_scroll =
+            self.inspector_detail_scroll.saturating_add(lines);
+    }
+
+    pub fn inspector_scroll_up(&mut self, lines: u16) {
+        self.inspector_detail_scroll =
+            self.inspector_detail_scroll.saturating_sub(lines);
+    }
+}
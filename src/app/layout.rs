@@ -0,0 +1,89 @@
+// src/app/layout.rs
+
+// Intention: Pane layout commands: resize, collapse, zoom and reset, saved after every change.
+// Design Choice: Sizes are bounded by the area the panes were last drawn in
+// (`ScreenAreas::panes`). A collapsed pane gives its focus to the details pane; focusing a
+// collapsed pane shows it again. While zoomed, the zoom follows the focused pane (see `ui`).
+
+use situation::{
+    LogEntry,
+    Pane,
+    PaneLayout,
+};
+
+use super::{
+    App,
+    AppFocus,
+};
+
+// Columns added or removed by one schema list resize
+const SCHEMA_WIDTH_STEP: i16 = 2;
+
+impl App {
+    // Intention: The pane holding the focus, if the focus is in one.
+    pub fn focused_pane(&self) -> Option<Pane> {
+        match self.current_focus {
+            AppFocus::SchemaList => Some(Pane::SchemaList),
+            AppFocus::ContentArea => Some(Pane::Content),
+            AppFocus::LogPanel => Some(Pane::LogPanel),
+            _ => None,
+        }
+    }
+
+    pub fn resize_log_panel(&mut self, grow: bool) {
+        let delta = if grow { 1 } else { -1 };
+        self.layout.resize_log(delta, self.screen_areas.panes);
+        self.save_layout();
+    }
+
+    pub fn resize_schema_list(&mut self, widen: bool) {
+        let delta = if widen {
+            SCHEMA_WIDTH_STEP
+        } else {
+            -SCHEMA_WIDTH_STEP
+        };
+        self.layout.resize_schema(delta, self.screen_areas.panes);
+        self.save_layout();
+    }
+
+    // Intention: Collapse or restore the schema list or log panel.
+    pub fn toggle_pane_collapsed(&mut self, pane: Pane) {
+        self.layout.toggle_collapsed(pane);
+        if self.layout.is_collapsed(pane) && self.focused_pane() == Some(pane) {
+            self.current_focus = AppFocus::ContentArea;
+        }
+        self.save_layout();
+    }
+
+    // Intention: Show a collapsed pane again, used when it is given the focus.
+    pub fn reveal_pane(&mut self, pane: Pane) {
+        if self.layout.is_collapsed(pane) {
+            self.toggle_pane_collapsed(pane);
+        }
+    }
+
+    // Intention: Zoom the focused pane to full screen, or restore the layout.
+    // Design Choice: With the focus in the top bar, the details pane is zoomed.
+    pub fn toggle_zoom(&mut self) {
+        let pane = self.focused_pane().unwrap_or(Pane::Content);
+        self.layout.toggle_zoom(pane);
+    }
+
+    // Intention: Go back to the default sizes with every pane shown.
+    pub fn reset_layout(&mut self) {
+        self.layout = PaneLayout::default();
+        self.save_layout();
+    }
+
+    // Intention: Write the layout file so the layout is restored next session.
+    // Design Choice: A failed write is logged; the layout still applies to this session.
+    fn save_layout(&mut self) {
+        let path = PaneLayout::default_path();
+        if let Err(e) = self.layout.save(&path) {
+            self.add_log_auto_scroll(LogEntry::warn(
+                "layout",
+                format!("Could not save {}: {}", path.display(), e),
+            ));
+        }
+    }
+}
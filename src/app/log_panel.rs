@@ -0,0 +1,119 @@
+// src/app/log_panel.rs
+
+// Intention: Logging into the app state and navigation of the log panel.
+// Design Choice: `logs` keeps every entry at or above the verbosity; the panel shows the subset
+// passing `log_level_filter` and `log_query`, and `log_scroll` indexes that subset. Every kept
+// entry is also appended to the persistent log file, whatever the panel filters. Scroll limits
+// use `log_view_height`, the number of lines the panel showed when it was last drawn.
+
+use std::cmp::min;
+
+use situation::LogEntry;
+
+use super::{
+    App,
+    InputMode,
+};
+
+impl App {
+    // Intention: Add a log entry and automatically scroll to the bottom.
+    // Design Choice: Accepts anything convertible into a `LogEntry`, so plain strings still log
+    // as application `Info` entries. Entries below the verbosity are dropped entirely.
+    pub fn add_log_auto_scroll(&mut self, entry: impl Into<LogEntry>) {
+        let entry = entry.into();
+        if entry.level < self.log_verbosity {
+            return;
+        }
+        if let Some(file) = &self.log_file {
+            // Failing to write the file must not interrupt the UI
+            let _ = file.append(&entry);
+        }
+        self.logs.push(entry);
+        self.scroll_logs_to_bottom();
+    }
+
+    // Intention: The entries shown in the log panel, in order.
+    pub fn visible_logs(&self) -> Vec<&LogEntry> {
+        self.logs
+            .iter()
+            .filter(|entry| {
+                entry.matches(self.log_level_filter, &self.log_query)
+            })
+            .collect()
+    }
+
+    // Intention: Scroll the log view up by one line.
+    pub fn scroll_logs_up(&mut self) {
+        self.log_scroll = self.log_scroll.saturating_sub(1);
+    }
+
+    // Intention: Scroll the log view down by one line.
+    // Design Choice: Prevent scrolling beyond the visible log lines.
+    pub fn scroll_logs_down(&mut self) {
+        self.log_scroll =
+            min(self.log_scroll.saturating_add(1), self.max_log_scroll());
+    }
+
+    fn max_log_scroll(&self) -> usize {
+        self.visible_logs()
+            .len()
+            .saturating_sub(self.log_view_height)
+    }
+
+    fn scroll_logs_to_bottom(&mut self) {
+        self.log_scroll = self.max_log_scroll();
+    }
+
+    // Intention: Record the log panel's rendered text height.
+    // Design Choice: Called while drawing. A view scrolled to the bottom stays there when the
+    // panel is resized; a hidden panel (height 0) keeps the last height.
+    pub fn set_log_view_height(&mut self, height: usize) {
+        if height == 0 || height == self.log_view_height {
+            return;
+        }
+        let at_bottom = self.log_scroll >= self.max_log_scroll();
+        self.log_view_height = height;
+        if at_bottom {
+            self.scroll_logs_to_bottom();
+        } else {
+            self.log_scroll = min(self.log_scroll, self.max_log_scroll());
+        }
+    }
+
+    // Intention: Cycle the minimum level shown in the panel (TRACE → … → ERROR → TRACE).
+    pub fn cycle_log_level_filter(&mut self) {
+        self.log_level_filter = self.log_level_filter.next();
+        self.scroll_logs_to_bottom();
+    }
+
+    // Intention: Cycle the verbosity, i.e. which new entries are kept at all.
+    // Design Choice: Lowering it to `Trace` turns on request/response body logging.
+    pub fn cycle_log_verbosity(&mut self) {
+        self.log_verbosity = self.log_verbosity.next();
+    }
+
+    // Intention: Start typing a `/` search in the log panel.
+    pub fn start_log_search(&mut self) {
+        self.input_mode = InputMode::LogSearch;
+    }
+
+    pub fn log_search_push_char(&mut self, c: char) {
+        self.log_query.push(c);
+        self.scroll_logs_to_bottom();
+    }
+
+    pub fn log_search_pop_char(&mut self) {
+        self.log_query.pop();
+        self.scroll_logs_to_bottom();
+    }
+
+    // Intention: Leave search input. `keep_filter` keeps the query applied (Enter); otherwise
+    // the search is cleared (Esc).
+    pub fn finish_log_search(&mut self, keep_filter: bool) {
+        self.input_mode = InputMode::Normal;
+        if !keep_filter {
+            self.log_query.clear();
+            self.scroll_logs_to_bottom();
+        }
+    }
+}
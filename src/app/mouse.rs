@@ -0,0 +1,105 @@
+// src/app/mouse.rs
+
+// Intention: Hit-testing for mouse events against the panes drawn in the last frame.
+// Design Choice: `ui` records the rectangle of every clickable pane in `App::screen_areas` while
+// rendering, so clicks are resolved against exactly what is on screen. List rows are found from
+// the list state's scroll offset, which ratatui updates when the list is rendered.
+
+use ratatui::layout::Rect;
+
+use super::App;
+
+// Intention: Screen rectangles of the clickable parts of the last rendered frame.
+// Design Choice: Defaults to empty rectangles, which contain no position, until the first draw.
+// Collapsed panes, and the panes hidden by a zoom, also have empty rectangles.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScreenAreas {
+    pub panes: Rect, // Everything below the top bar split by the pane layout
+    pub workspace_trigger: Rect,
+    pub change_set_trigger: Rect,
+    pub schema_list: Rect,
+    pub content: Rect,
+    pub log_panel: Rect,
+    pub dropdown: Option<Rect>, // Change set dropdown overlay, when open
+    pub workspace_dropdown: Option<Rect>, // Workspace dropdown overlay, when open
+}
+
+// Intention: What a mouse position points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClickTarget {
+    WorkspaceTrigger,
+    ChangeSetTrigger,
+    Dropdown(Option<usize>), // Change set index of the clicked row, None on the border
+    WorkspaceDropdown(Option<usize>), // Workspace index of the clicked row, None on the border
+    SchemaList(Option<usize>),        // Schema tree row, None outside the rows
+    Content,
+    LogPanel,
+}
+
+// Intention: Whether the cell at (column, row) lies inside `area`.
+fn contains(area: Rect, column: u16, row: u16) -> bool {
+    column >= area.x
+        && column < area.x + area.width
+        && row >= area.y
+        && row < area.y + area.height
+}
+
+// Intention: Index of the list item drawn at screen `row` of a bordered list.
+// Design Choice: Returns None on the borders and below the last item.
+fn list_row(area: Rect, offset: usize, len: usize, row: u16) -> Option<usize> {
+    let inner_top = area.y + 1;
+    let inner_bottom = (area.y + area.height).saturating_sub(1);
+    if row < inner_top || row >= inner_bottom {
+        return None;
+    }
+    let index = offset + (row - inner_top) as usize;
+    (index < len).then_some(index)
+}
+
+impl App {
+    // Intention: Resolve a mouse position to the element drawn there.
+    // Design Choice: The dropdown overlays are checked first since they are drawn above the panes.
+    pub fn click_target(&self, column: u16, row: u16) -> Option<ClickTarget> {
+        let areas = &self.screen_areas;
+        if let Some(dropdown) = areas.dropdown
+            && contains(dropdown, column, row)
+        {
+            let len = self.change_sets.as_ref().map_or(0, |cs| cs.len());
+            let offset = self.change_set_list_state.offset();
+            return Some(ClickTarget::Dropdown(list_row(
+                dropdown, offset, len, row,
+            )));
+        }
+        if let Some(dropdown) = areas.workspace_dropdown
+            && contains(dropdown, column, row)
+        {
+            let offset = self.workspace_list_state.offset();
+            return Some(ClickTarget::WorkspaceDropdown(list_row(
+                dropdown,
+                offset,
+                self.workspaces.len(),
+                row,
+            )));
+        }
+        if contains(areas.workspace_trigger, column, row) {
+            Some(ClickTarget::WorkspaceTrigger)
+        } else if contains(areas.change_set_trigger, column, row) {
+            Some(ClickTarget::ChangeSetTrigger)
+        } else if contains(areas.schema_list, column, row) {
+            let len = self.schema_tree_rows().len();
+            let offset = self.schema_list_state.offset();
+            Some(ClickTarget::SchemaList(list_row(
+                areas.schema_list,
+                offset,
+                len,
+                row,
+            )))
+        } else if contains(areas.content, column, row) {
+            Some(ClickTarget::Content)
+        } else if contains(areas.log_panel, column, row) {
+            Some(ClickTarget::LogPanel)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,207 @@
+// src/app/offline.rs
+
+// Intention: Keep a snapshot of what was loaded from the workspace, and show it in a read-only
+// offline mode when the backend cannot be reached.
+// Design Choice: The snapshot is kept in memory and written after every load made online; only
+// what the load actually got is recorded, so a failed or cancelled fetch does not erase the
+// last good data. Going offline replaces what is shown with the snapshot, keeping the selected
+// change set if the snapshot lists it, and lasts until a load reaches the backend again.
+
+use std::path::PathBuf;
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use situation::{
+    LogEntry,
+    SnapshotComponent,
+    WorkspaceSnapshot,
+    count_components_by_schema,
+};
+
+use super::App;
+
+impl App {
+    // Intention: The snapshot file of the workspace in use.
+    pub fn snapshot_path(&self) -> Option<PathBuf> {
+        self.active_workspace_name().map(|name| {
+            WorkspaceSnapshot::path_in(&WorkspaceSnapshot::default_dir(), name)
+        })
+    }
+
+    // Intention: Record what is loaded in the snapshot and write it to disk.
+    // Design Choice: Nothing is recorded offline (it would only save the snapshot back) or
+    // after a cancelled load, which leaves its views empty.
+    pub fn save_snapshot(&mut self, now: DateTime<Utc>) {
+        if self.offline || self.load_token.is_cancelled() {
+            return;
+        }
+        let (Some(whoami), Some(change_sets), Some(path)) = (
+            self.whoami_data.clone(),
+            self.change_sets.clone(),
+            self.snapshot_path(),
+        ) else {
+            return;
+        };
+        let mut snapshot = match self.snapshot.take() {
+            Some(snapshot)
+                if snapshot.whoami.workspace_id == whoami.workspace_id =>
+            {
+                snapshot
+            }
+            _ => WorkspaceSnapshot::new(whoami.clone(), Vec::new(), now),
+        };
+        snapshot.whoami = whoami;
+        snapshot.saved_at = now;
+        snapshot.set_change_sets(change_sets);
+        if let Some(id) = self
+            .get_selected_changeset_summary()
+            .map(|cs| cs.id.clone())
+        {
+            let loaded = snapshot.loaded.entry(id.clone()).or_default();
+            if let Some(details) = &self.selected_change_set_details {
+                loaded.details = Some(details.clone());
+            }
+            if let Some(status) = &self.selected_change_set_merge_status {
+                loaded.merge_status = Some(status.clone());
+            }
+            if !self.schemas.is_empty() {
+                loaded.schemas = self.schemas.clone();
+            }
+            if let Some(components) = &self.selected_change_set_components {
+                loaded.components = Some(
+                    components
+                        .iter()
+                        .map(|comp| SnapshotComponent {
+                            id: comp.id.clone(),
+                            name: comp.name.clone(),
+                            schema_id: comp.schema_id.clone(),
+                        })
+                        .collect(),
+                );
+            }
+            snapshot.selected_change_set = Some(id);
+        }
+        if let Err(e) = snapshot.save(&path) {
+            self.add_log_auto_scroll(LogEntry::warn(
+                "offline",
+                format!("Cannot save the snapshot {}: {}", path.display(), e),
+            ));
+        }
+        self.snapshot = Some(snapshot);
+    }
+
+    // Intention: Switch to the snapshot after the backend could not be reached. Returns whether
+    // a snapshot is shown.
+    // Design Choice: The load in flight is cancelled, since its other requests would wait for
+    // the same unreachable backend.
+    pub fn go_offline(&mut self, now: DateTime<Utc>) -> bool {
+        if self.offline {
+            return true;
+        }
+        let snapshot = match self.snapshot.clone() {
+            Some(snapshot) => Ok(snapshot),
+            None => self
+                .snapshot_path()
+                .ok_or_else(|| "no workspace in use".to_string())
+                .and_then(|path| WorkspaceSnapshot::load(&path)),
+        };
+        let snapshot = match snapshot {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                self.add_log_auto_scroll(LogEntry::warn(
+                    "offline",
+                    format!(
+                        "Backend unreachable and no snapshot to show ({}).",
+                        e
+                    ),
+                ));
+                return false;
+            }
+        };
+        self.load_token.cancel();
+        let current = self
+            .get_selected_changeset_summary()
+            .map(|cs| cs.id.clone());
+        let selected = current
+            .filter(|id| snapshot.change_sets.iter().any(|cs| &cs.id == id))
+            .or_else(|| snapshot.selected_change_set.clone());
+        let index = selected
+            .and_then(|id| {
+                snapshot.change_sets.iter().position(|cs| cs.id == id)
+            })
+            .or((!snapshot.change_sets.is_empty()).then_some(0));
+        self.whoami_data = Some(snapshot.whoami.clone());
+        self.change_sets = Some(snapshot.change_sets.clone());
+        self.change_set_list_state.select(index);
+        self.add_log_auto_scroll(LogEntry::warn(
+            "offline",
+            format!(
+                "Backend unreachable: showing the snapshot saved {} ago, read-only. Refresh to reconnect.",
+                snapshot.age_label(now)
+            ),
+        ));
+        self.snapshot = Some(snapshot);
+        self.offline = true;
+        self.show_snapshot_change_set();
+        true
+    }
+
+    // Intention: Leave the offline mode once a load reached the backend.
+    pub fn go_online(&mut self) {
+        if self.offline {
+            self.offline = false;
+            self.add_log_auto_scroll(LogEntry::info(
+                "offline",
+                "Backend reachable again, back online.",
+            ));
+        }
+    }
+
+    // Intention: Show the selected change set as the snapshot has it (empty if never loaded).
+    pub fn show_snapshot_change_set(&mut self) {
+        let loaded = self
+            .get_selected_changeset_summary()
+            .zip(self.snapshot.as_ref())
+            .and_then(|(cs, snapshot)| snapshot.loaded.get(&cs.id).cloned())
+            .unwrap_or_default();
+        self.selected_change_set_details = loaded.details;
+        self.action_table_state.select(
+            loaded
+                .merge_status
+                .as_ref()
+                .is_some_and(|status| !status.actions.is_empty())
+                .then_some(0),
+        );
+        self.selected_change_set_merge_status = loaded.merge_status;
+        self.selected_component_details = None;
+        self.schemas = loaded.schemas;
+        self.reset_schema_selection();
+        let components: Option<Vec<_>> = loaded
+            .components
+            .map(|list| list.iter().map(SnapshotComponent::to_view).collect());
+        self.schema_component_counts = count_components_by_schema(
+            components
+                .iter()
+                .flatten()
+                .map(|comp| comp.schema_id.as_str())
+                .filter(|schema_id| *schema_id != "unknown"),
+        );
+        self.selected_change_set_components = components;
+    }
+
+    // Intention: Refuse something that needs the backend while offline, logging why.
+    pub fn refuse_if_offline(&mut self, what: &str) -> bool {
+        if self.offline {
+            self.add_log_auto_scroll(LogEntry::warn(
+                "offline",
+                format!(
+                    "Cannot {}: offline, showing a snapshot. Refresh to reconnect.",
+                    what
+                ),
+            ));
+        }
+        self.offline
+    }
+}
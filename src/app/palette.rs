@@ -0,0 +1,259 @@
+// src/app/palette.rs
+
+// Intention: Define the command palette entries and how they are built and ranked from app state.
+// Design Choice: Entries are rebuilt from the current change sets, schemas and components each time
+// they are needed instead of being cached, so the palette never shows stale data. Ranking uses the
+// library's `fuzzy_match`; with an empty query the natural order (commands first) is kept.
+
+use std::cmp::Reverse;
+
+use situation::fuzzy_match;
+
+use super::{
+    App,
+    AppFocus,
+    InputMode,
+};
+
+// Intention: Commands that can be run from the palette.
+// Design Choice: Each variant maps to one function in `run_app::commands`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteCommand {
+    CreateChangeSet,
+    AbandonChangeSet,
+    ApplyChangeSet,
+    RequestApproval,
+    Refresh,
+    SwitchWorkspace,
+    ToggleAutoRefresh,
+    ToggleInspector,
+    CycleTheme,
+    EnterToken,
+    CompareWithHead,
+    CompareChangeSets,
+    CheckConflicts,
+}
+
+impl PaletteCommand {
+    pub const ALL: [PaletteCommand; 13] = [
+        PaletteCommand::CreateChangeSet,
+        PaletteCommand::AbandonChangeSet,
+        PaletteCommand::ApplyChangeSet,
+        PaletteCommand::RequestApproval,
+        PaletteCommand::Refresh,
+        PaletteCommand::SwitchWorkspace,
+        PaletteCommand::ToggleAutoRefresh,
+        PaletteCommand::ToggleInspector,
+        PaletteCommand::CycleTheme,
+        PaletteCommand::EnterToken,
+        PaletteCommand::CompareWithHead,
+        PaletteCommand::CompareChangeSets,
+        PaletteCommand::CheckConflicts,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PaletteCommand::CreateChangeSet => "Create change set",
+            PaletteCommand::AbandonChangeSet => "Abandon change set",
+            PaletteCommand::ApplyChangeSet => "Apply change set",
+            PaletteCommand::RequestApproval => "Request approval",
+            PaletteCommand::Refresh => "Refresh",
+            PaletteCommand::SwitchWorkspace => "Switch workspace / profile",
+            PaletteCommand::ToggleAutoRefresh => "Toggle auto-refresh",
+            PaletteCommand::ToggleInspector => "Toggle request inspector",
+            PaletteCommand::CycleTheme => "Switch theme",
+            PaletteCommand::EnterToken => "Enter new token",
+            PaletteCommand::CompareWithHead => "Compare with HEAD",
+            PaletteCommand::CompareChangeSets => "Compare with change set",
+            PaletteCommand::CheckConflicts => {
+                "Check conflicts across change sets"
+            }
+        }
+    }
+}
+
+// Intention: What selecting a palette entry acts on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaletteTarget {
+    Command(PaletteCommand),
+    ChangeSet(String),   // Change set ID
+    Schema(usize),       // Index into `App::schemas`
+    Component(String),   // Component ID
+    CompareWith(String), // Change set ID, compared with the selected one
+}
+
+impl PaletteTarget {
+    // Intention: Short tag shown in front of each entry so mixed results stay readable.
+    pub fn kind_label(&self) -> &'static str {
+        match self {
+            PaletteTarget::Command(_) => "cmd",
+            PaletteTarget::ChangeSet(_) => "change set",
+            PaletteTarget::Schema(_) => "schema",
+            PaletteTarget::Component(_) => "component",
+            PaletteTarget::CompareWith(_) => "compare",
+        }
+    }
+}
+
+// Intention: A palette entry that matched the current query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteEntry {
+    pub target: PaletteTarget,
+    pub label: String,
+    pub detail: String, // Secondary text (status, category, ID), not matched
+    pub positions: Vec<usize>, // Matched character positions in `label`
+}
+
+impl App {
+    // Intention: Open the palette with an empty query and the first entry selected.
+    pub fn open_palette(&mut self) {
+        self.input_mode = InputMode::CommandPalette;
+        self.palette_query.clear();
+        self.palette_list_state.select(Some(0));
+        self.changeset_dropdown_active = false;
+        self.workspace_dropdown_active = false;
+        if matches!(
+            self.current_focus,
+            AppFocus::ChangeSetDropdown | AppFocus::WorkspaceDropdown
+        ) {
+            self.current_focus = AppFocus::TopBar;
+        }
+    }
+
+    // Intention: Close the palette without running anything.
+    pub fn close_palette(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.palette_compare_to = None;
+        self.palette_query.clear();
+        self.palette_list_state.select(None);
+    }
+
+    // Intention: Update the query and reset the selection to the best match.
+    pub fn palette_push_char(&mut self, c: char) {
+        self.palette_query.push(c);
+        self.palette_list_state.select(Some(0));
+    }
+
+    pub fn palette_pop_char(&mut self) {
+        self.palette_query.pop();
+        self.palette_list_state.select(Some(0));
+    }
+
+    // Intention: Build every entry that matches the current query, best match first.
+    // Design Choice: Stable sort by score so equally scored entries keep the natural order.
+    // While picking a change set to compare with, only the other change sets are listed.
+    pub fn palette_entries(&self) -> Vec<PaletteEntry> {
+        if let Some(compare_to) = &self.palette_compare_to {
+            let candidates = self
+                .change_sets
+                .iter()
+                .flatten()
+                .filter(|cs| &cs.id != compare_to)
+                .map(|cs| {
+                    (
+                        PaletteTarget::CompareWith(cs.id.clone()),
+                        cs.name.clone(),
+                        cs.status.clone(),
+                    )
+                })
+                .collect();
+            return self.rank_palette_candidates(candidates);
+        }
+        let mut candidates: Vec<(PaletteTarget, String, String)> =
+            PaletteCommand::ALL
+                .iter()
+                .map(|cmd| {
+                    (
+                        PaletteTarget::Command(*cmd),
+                        cmd.label().to_string(),
+                        String::new(),
+                    )
+                })
+                .collect();
+        if let Some(change_sets) = &self.change_sets {
+            candidates.extend(change_sets.iter().map(|cs| {
+                (
+                    PaletteTarget::ChangeSet(cs.id.clone()),
+                    cs.name.clone(),
+                    cs.status.clone(),
+                )
+            }));
+        }
+        candidates.extend(self.schemas.iter().enumerate().map(
+            |(i, schema)| {
+                (
+                    PaletteTarget::Schema(i),
+                    schema.schema_name.clone(),
+                    schema.category.clone(),
+                )
+            },
+        ));
+        if let Some(components) = &self.selected_change_set_components {
+            candidates.extend(components.iter().map(|comp| {
+                (
+                    PaletteTarget::Component(comp.id.clone()),
+                    comp.name.clone(),
+                    String::new(),
+                )
+            }));
+        }
+        self.rank_palette_candidates(candidates)
+    }
+
+    // Intention: Keep the candidates matching the query, best match first.
+    fn rank_palette_candidates(
+        &self,
+        candidates: Vec<(PaletteTarget, String, String)>,
+    ) -> Vec<PaletteEntry> {
+        let mut scored: Vec<(i64, PaletteEntry)> = candidates
+            .into_iter()
+            .filter_map(|(target, label, detail)| {
+                let matched = fuzzy_match(&self.palette_query, &label)?;
+                Some((
+                    matched.score,
+                    PaletteEntry {
+                        target,
+                        label,
+                        detail,
+                        positions: matched.positions,
+                    },
+                ))
+            })
+            .collect();
+        scored.sort_by_key(|(score, _)| Reverse(*score));
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    // Intention: Move the palette selection, wrapping around like the other lists.
+    pub fn palette_next(&mut self) {
+        let len = self.palette_entries().len();
+        if len == 0 {
+            self.palette_list_state.select(None);
+            return;
+        }
+        let i = match self.palette_list_state.selected() {
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0,
+        };
+        self.palette_list_state.select(Some(i));
+    }
+
+    pub fn palette_previous(&mut self) {
+        let len = self.palette_entries().len();
+        if len == 0 {
+            self.palette_list_state.select(None);
+            return;
+        }
+        let i = match self.palette_list_state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => (i - 1).min(len - 1),
+        };
+        self.palette_list_state.select(Some(i));
+    }
+
+    // Intention: Return the entry under the selection, if any.
+    pub fn get_selected_palette_entry(&self) -> Option<PaletteEntry> {
+        let index = self.palette_list_state.selected()?;
+        self.palette_entries().into_iter().nth(index)
+    }
+}
@@ -0,0 +1,68 @@
+// src/app/review.rs
+
+// Intention: State of the review shown before a force apply, and its Markdown export.
+// Design Choice: The review replaces the change set view until it is confirmed (which applies
+// the change set it was made for) or closed, like a comparison.
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use situation::{
+    ApplyReview,
+    LogEntry,
+};
+
+use super::{
+    App,
+    AppFocus,
+};
+
+impl App {
+    // Intention: Show a review in the content area, scrolled to the top, and focus it so its
+    // keys (apply, export, scroll) work straight away.
+    pub fn show_review(&mut self, review: ApplyReview) {
+        self.review = Some(review);
+        self.review_scroll = 0;
+        self.selected_component_details = None;
+        self.close_comparison();
+        self.current_focus = AppFocus::ContentArea;
+    }
+
+    pub fn close_review(&mut self) {
+        self.review = None;
+        self.review_scroll = 0;
+    }
+
+    pub fn scroll_review_up(&mut self, lines: u16) {
+        self.review_scroll = self.review_scroll.saturating_sub(lines);
+    }
+
+    // Design Choice: Bounded by the renderer, which clamps the scroll to the rows it has.
+    pub fn scroll_review_down(&mut self, lines: u16) {
+        self.review_scroll = self.review_scroll.saturating_add(lines);
+    }
+
+    // Intention: Write the review shown as Markdown to the export directory and log where.
+    pub fn export_review(&mut self, now: DateTime<Utc>) {
+        let Some(review) = &self.review else {
+            self.add_log_auto_scroll(LogEntry::warn(
+                "review",
+                "No review to export; press f to review the change set first.",
+            ));
+            return;
+        };
+        let path = review.export_path_in(&ApplyReview::export_dir(), now);
+        let entry = match std::fs::write(&path, review.to_markdown(now)) {
+            Ok(()) => LogEntry::info(
+                "review",
+                format!("Review exported to {}", path.display()),
+            ),
+            Err(e) => LogEntry::error(
+                "review",
+                format!("Cannot write {}: {}", path.display(), e),
+            ),
+        };
+        self.add_log_auto_scroll(entry);
+    }
+}
@@ -0,0 +1,186 @@
+// src/app/schema_list.rs
+
+// Intention: Navigation and filtering of the schema pane, which shows schemas as a tree grouped
+// by category.
+// Design Choice: `schema_list_state` indexes the visible rows returned by `schema_tree_rows`, so
+// category headers are selectable (to collapse/expand them). Rows are rebuilt from state when
+// needed; callers that change the filters go through the methods here so the selection is kept
+// on a valid row.
+
+use situation::{
+    SchemaTreeOptions,
+    SchemaTreeRow,
+    api_models::SchemaSummary,
+    build_schema_tree,
+};
+
+use super::{
+    App,
+    InputMode,
+};
+
+impl App {
+    // Intention: The rows currently visible in the schema pane.
+    pub fn schema_tree_rows(&self) -> Vec<SchemaTreeRow> {
+        build_schema_tree(
+            &self.schemas,
+            &self.schema_component_counts,
+            SchemaTreeOptions {
+                query: &self.schema_query,
+                installed_only: self.schema_installed_only,
+                collapsed: &self.collapsed_categories,
+            },
+        )
+    }
+
+    // Intention: Get the schema under the selection, if a schema (not a category) is selected.
+    pub fn get_selected_schema(&self) -> Option<&SchemaSummary> {
+        let selected = self.schema_list_state.selected()?;
+        match self.schema_tree_rows().get(selected)? {
+            SchemaTreeRow::Schema { index, .. } => self.schemas.get(*index),
+            SchemaTreeRow::Category { .. } => None,
+        }
+    }
+
+    // Intention: Select the first schema row (or the first row if every category is collapsed).
+    // Design Choice: Called after the schemas or filters change, when the old row index is meaningless.
+    pub fn reset_schema_selection(&mut self) {
+        let rows = self.schema_tree_rows();
+        let first = rows
+            .iter()
+            .position(|row| matches!(row, SchemaTreeRow::Schema { .. }))
+            .or((!rows.is_empty()).then_some(0));
+        self.schema_list_state.select(first);
+    }
+
+    // Intention: Move selection down in the schema tree.
+    // Design Choice: Handles wrapping and empty list case, like the other lists.
+    pub fn schema_next(&mut self) {
+        let len = self.schema_tree_rows().len();
+        if len == 0 {
+            self.schema_list_state.select(None);
+            return;
+        }
+        let i = match self.schema_list_state.selected() {
+            Some(i) if i + 1 < len => i + 1,
+            _ => 0, // Wrap around / select first
+        };
+        self.schema_list_state.select(Some(i));
+    }
+
+    // Intention: Move selection up in the schema tree.
+    pub fn schema_previous(&mut self) {
+        let len = self.schema_tree_rows().len();
+        if len == 0 {
+            self.schema_list_state.select(None);
+            return;
+        }
+        let i = match self.schema_list_state.selected() {
+            Some(0) | None => len - 1, // Wrap around / select last
+            Some(i) => (i - 1).min(len - 1),
+        };
+        self.schema_list_state.select(Some(i));
+    }
+
+    // Intention: Collapse or expand the category of the selected row.
+    // Design Choice: On a schema row the selection moves to its category header when collapsing,
+    // since the schema row disappears. `expand: None` toggles.
+    pub fn set_selected_category_collapsed(&mut self, expand: Option<bool>) {
+        let Some(selected) = self.schema_list_state.selected() else {
+            return;
+        };
+        let rows = self.schema_tree_rows();
+        let category = match rows.get(selected) {
+            Some(SchemaTreeRow::Category { name, .. }) => name.clone(),
+            Some(SchemaTreeRow::Schema { index, .. }) => {
+                self.schemas[*index].category.clone()
+            }
+            None => return,
+        };
+        let collapse = match expand {
+            Some(expand) => !expand,
+            None => !self.collapsed_categories.contains(&category),
+        };
+        if collapse {
+            self.collapsed_categories.insert(category.clone());
+        } else {
+            self.collapsed_categories.remove(&category);
+        }
+        self.select_category_row(&category);
+    }
+
+    // Intention: Switch between showing all schemas and installed schemas only.
+    pub fn toggle_schema_installed_only(&mut self) {
+        let previous = self.get_selected_schema().map(|s| s.schema_id.clone());
+        self.schema_installed_only = !self.schema_installed_only;
+        self.reselect_schema(previous.as_deref());
+    }
+
+    // Intention: Start typing an incremental search in the schema pane.
+    pub fn start_schema_search(&mut self) {
+        self.input_mode = InputMode::SchemaSearch;
+    }
+
+    // Intention: Update the search as the user types, keeping the selection on the first match.
+    pub fn schema_search_push_char(&mut self, c: char) {
+        self.schema_query.push(c);
+        self.reset_schema_selection();
+    }
+
+    pub fn schema_search_pop_char(&mut self) {
+        self.schema_query.pop();
+        self.reset_schema_selection();
+    }
+
+    // Intention: Leave search input. `keep_filter` keeps the query applied (Enter); otherwise
+    // the search is cleared (Esc) and the selected schema stays selected if possible.
+    pub fn finish_schema_search(&mut self, keep_filter: bool) {
+        self.input_mode = InputMode::Normal;
+        if !keep_filter {
+            let previous =
+                self.get_selected_schema().map(|s| s.schema_id.clone());
+            self.schema_query.clear();
+            self.reselect_schema(previous.as_deref());
+        }
+    }
+
+    // Intention: Select a schema by its index in `schemas`, making it visible first.
+    // Design Choice: Used by the command palette. Clears the search, turns off the installed
+    // filter and expands the category as needed so the row exists.
+    pub fn select_schema_index(&mut self, index: usize) {
+        let Some(schema) = self.schemas.get(index) else {
+            return;
+        };
+        if self.schema_installed_only && !schema.installed {
+            self.schema_installed_only = false;
+        }
+        let category = schema.category.clone();
+        self.collapsed_categories.remove(&category);
+        self.schema_query.clear();
+        let row = self.schema_tree_rows().iter().position(|row| {
+            matches!(row, SchemaTreeRow::Schema { index: i, .. } if *i == index)
+        });
+        self.schema_list_state.select(row);
+    }
+
+    // Intention: Keep the same schema selected after the visible rows change, if it is still visible.
+    fn reselect_schema(&mut self, schema_id: Option<&str>) {
+        let row = schema_id.and_then(|id| {
+            self.schema_tree_rows().iter().position(|row| {
+                matches!(row, SchemaTreeRow::Schema { index, .. }
+                    if self.schemas[*index].schema_id == id)
+            })
+        });
+        match row {
+            Some(row) => self.schema_list_state.select(Some(row)),
+            None => self.reset_schema_selection(),
+        }
+    }
+
+    fn select_category_row(&mut self, category: &str) {
+        let row = self.schema_tree_rows().iter().position(|row| {
+            matches!(row, SchemaTreeRow::Category { name, .. } if name == category)
+        });
+        self.schema_list_state.select(row);
+    }
+}
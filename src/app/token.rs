@@ -0,0 +1,124 @@
+// src/app/token.rs
+
+// Intention: Track the expiry of the token in use and the prompt for a new one after a 401.
+// Design Choice: The claims come from the API client (`api_client::token_claims`) on every loop
+// iteration, so a token re-read from its source or pasted into the prompt is picked up without
+// the app holding the token. The expiry warning is logged once per token.
+
+use std::error::Error;
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use situation::{
+    LogEntry,
+    api_client,
+    format_time_left,
+    jwt::EXPIRY_WARNING,
+};
+
+use super::{
+    App,
+    AppFocus,
+    InputMode,
+};
+
+// Intention: How close the token is to its expiry, for the top bar's style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenUrgency {
+    Valid,
+    ExpiresSoon, // Within `EXPIRY_WARNING`
+    Expired,
+}
+
+impl App {
+    // Intention: Refresh the token's claims from the client and warn once when it nears expiry.
+    pub fn update_token_status(&mut self, now: DateTime<Utc>) {
+        let claims = api_client::token_claims();
+        if claims != self.token_claims {
+            self.token_claims = claims;
+            self.token_expiry_warned = false;
+        }
+        let Some((left, urgency)) = self.token_time_left(now) else {
+            return;
+        };
+        if urgency == TokenUrgency::Valid || self.token_expiry_warned {
+            return;
+        }
+        self.token_expiry_warned = true;
+        let message = if urgency == TokenUrgency::Expired {
+            "The token has expired.".to_string()
+        } else {
+            format!("The token expires in {}.", left)
+        };
+        self.add_log_auto_scroll(LogEntry::warn(
+            "token",
+            format!(
+                "{} Use \"Enter new token\" in the command palette to replace it.",
+                message
+            ),
+        ));
+    }
+
+    // Intention: Time left before the token expires, as shown in the top bar, if it has `exp`.
+    pub fn token_time_left(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Option<(String, TokenUrgency)> {
+        let expires_at = self.token_claims?.expires_at()?;
+        let urgency = if expires_at <= now {
+            TokenUrgency::Expired
+        } else if expires_at - now <= EXPIRY_WARNING {
+            TokenUrgency::ExpiresSoon
+        } else {
+            TokenUrgency::Valid
+        };
+        Some((format_time_left(expires_at, now), urgency))
+    }
+
+    // Intention: Ask for a new token, e.g. after the server rejected the current one.
+    // Design Choice: Takes over from any other input mode or overlay, since every request waits
+    // on the answer.
+    pub fn open_token_prompt(&mut self, rejected: bool) {
+        if rejected {
+            self.add_log_auto_scroll(LogEntry::warn(
+                "token",
+                "The server rejected the token (401). Paste a new token, or press Esc to keep the current one.",
+            ));
+        }
+        self.help_open = false;
+        self.changeset_dropdown_active = false;
+        self.workspace_dropdown_active = false;
+        self.input_mode = InputMode::Token;
+        self.current_focus = AppFocus::Input;
+        self.input_buffer.clear();
+    }
+
+    // Intention: Leave the token prompt, forgetting what was typed.
+    pub fn close_token_prompt(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.current_focus = AppFocus::TopBar;
+        self.input_buffer.clear();
+    }
+
+    // Intention: Log a failed API call, unless it failed because the token was rejected or the
+    // call was cancelled, and go offline if the backend could not be reached.
+    // Design Choice: A rejected token opens the token prompt (see `run_app`), which says so
+    // once, instead of every request of a screen logging the same 401. A cancelled load is
+    // logged once when it is cancelled.
+    pub fn log_api_error(
+        &mut self,
+        entry: impl Into<LogEntry>,
+        error: &(dyn Error + Send + Sync + 'static),
+    ) {
+        if !api_client::is_unauthorized(error)
+            && !api_client::is_cancelled(error)
+        {
+            self.add_log_auto_scroll(entry);
+        }
+        if api_client::is_unreachable(error) && !self.offline {
+            self.go_offline(Utc::now());
+        }
+    }
+}
@@ -0,0 +1,73 @@
+// src/app/workspaces.rs
+
+// Intention: State of the workspace switcher: the dropdown selection and clearing what was
+// loaded from the previous workspace.
+// Design Choice: `workspaces` is filled at startup (`.env` credentials first, then the
+// workspaces file); `active_workspace` only changes once the API client uses the new
+// credentials (see `switch_workspace` in `run_app/commands.rs`).
+
+use ratatui::widgets::{
+    ListState,
+    TableState,
+};
+
+use super::App;
+
+impl App {
+    // Intention: The name of the workspace in use, if one is configured.
+    pub fn active_workspace_name(&self) -> Option<&str> {
+        self.active_workspace
+            .and_then(|i| self.workspaces.get(i))
+            .map(|w| w.name.as_str())
+    }
+
+    // Intention: Move selection down in the workspace dropdown, wrapping around.
+    pub fn workspace_next(&mut self) {
+        let len = self.workspaces.len();
+        if len == 0 {
+            return;
+        }
+        let i = self
+            .workspace_list_state
+            .selected()
+            .map_or(0, |i| (i + 1) % len);
+        self.workspace_list_state.select(Some(i));
+    }
+
+    // Intention: Move selection up in the workspace dropdown, wrapping around.
+    pub fn workspace_previous(&mut self) {
+        let len = self.workspaces.len();
+        if len == 0 {
+            return;
+        }
+        let i = match self.workspace_list_state.selected() {
+            Some(0) | None => len - 1,
+            Some(i) => i - 1,
+        };
+        self.workspace_list_state.select(Some(i));
+    }
+
+    // Intention: Forget everything loaded from the previous workspace before loading another.
+    // Design Choice: Logs, the request history, theme, keymap and layout are kept; they belong
+    // to the session, not the workspace.
+    pub fn reset_workspace_data(&mut self) {
+        self.whoami_data = None;
+        self.change_sets = None;
+        self.change_set_list_state = ListState::default();
+        self.selected_change_set_details = None;
+        self.selected_change_set_merge_status = None;
+        self.selected_change_set_components = None;
+        self.action_table_state = TableState::default();
+        self.selected_component_details = None;
+        self.apply_monitor = None;
+        self.schemas.clear();
+        self.schema_list_state = ListState::default();
+        self.schema_component_counts.clear();
+        self.offline = false;
+        self.snapshot = None;
+        self.clear_refresh_highlights();
+        self.close_comparison();
+        self.close_review();
+        self.conflicts = None;
+    }
+}
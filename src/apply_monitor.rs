@@ -0,0 +1,436 @@
+// src/apply_monitor.rs
+
+// Intention:
+// Tracks the progress of a change set after `force_apply` by polling the API until the
+// apply has finished, and summarises which actions succeeded and which failed.
+// Shared by the TUI (which polls from its main loop) and the CLI `wait` command
+// (which runs the monitor to completion).
+
+// Design Choices:
+// - `ApplyMonitor` is a step-driven state machine: callers ask `is_due()` and then `poll()`,
+//   so the TUI can keep handling input between polls instead of blocking until completion.
+//   `run_to_completion` wraps the same loop for callers that just want to wait.
+// - Each poll calls `get_change_set` and `get_merge_status`, bypassing the client cache. The
+//   pure bookkeeping lives in `record_observation`, which is public so it can be unit tested
+//   without a backend.
+// - Actions are tracked by ID. An action that disappears from the merge status has been
+//   dispatched and completed, so it counts as succeeded. An action reported as "Failed" counts
+//   as failed. Everything else is still pending.
+// - Polling backs off exponentially while nothing changes and resets to the initial interval
+//   as soon as an observation differs from the previous one.
+// - Transient API errors are tolerated up to `max_consecutive_errors` before giving up.
+
+use std::{
+    error::Error,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use crate::{
+    api_client::{
+        self,
+        CancelToken,
+    },
+    api_models::MergeStatusV1ResponseAction,
+    logging::LogEntry,
+};
+
+/// Change set statuses after which an apply can no longer make progress.
+const TERMINAL_CHANGE_SET_STATUSES: [&str; 4] =
+    ["Applied", "Failed", "Abandoned", "Rejected"];
+
+/// Action states in which an action will not run without user intervention.
+const SETTLED_ACTION_STATES: [&str; 2] = ["Failed", "OnHold"];
+
+/// Tuning knobs for `ApplyMonitor`.
+#[derive(Debug, Clone)]
+pub struct ApplyMonitorOptions {
+    /// Delay before the second poll, and after any poll that observed a change.
+    pub initial_interval: Duration,
+    /// Upper bound for the backoff delay.
+    pub max_interval: Duration,
+    /// Multiplier applied to the delay after a poll that observed no change.
+    pub backoff_factor: u32,
+    /// Give up after this long. `None` waits indefinitely.
+    pub timeout: Option<Duration>,
+    /// Number of failed polls in a row tolerated before the monitor gives up.
+    pub max_consecutive_errors: u32,
+}
+
+impl Default for ApplyMonitorOptions {
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(15),
+            backoff_factor: 2,
+            timeout: Some(Duration::from_secs(600)),
+            max_consecutive_errors: 3,
+        }
+    }
+}
+
+/// Where a tracked action currently stands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActionOutcome {
+    /// Still listed in the merge status. Holds the last reported state (e.g. "Queued", "Running").
+    Pending(String),
+    /// No longer listed in the merge status: the action ran to completion.
+    Succeeded,
+    /// Reported with the "Failed" state.
+    Failed,
+}
+
+/// Progress of a single action, as shown by the TUI and the CLI.
+#[derive(Debug, Clone)]
+pub struct ActionProgress {
+    /// The action as it was last reported by the API.
+    pub action: MergeStatusV1ResponseAction,
+    /// Current outcome for the action.
+    pub outcome: ActionOutcome,
+}
+
+/// How the monitor finished.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApplyOutcome {
+    /// The change set reached a terminal status and no action is still running.
+    Completed,
+    /// `ApplyMonitorOptions::timeout` elapsed first.
+    TimedOut,
+    /// Too many consecutive polls failed. Holds the last error message.
+    Errored(String),
+}
+
+/// Final report produced by `ApplyMonitor::summary`.
+#[derive(Debug, Clone)]
+pub struct ApplySummary {
+    pub change_set_id: String,
+    /// Last change set status seen, if any poll succeeded.
+    pub change_set_status: Option<String>,
+    /// `None` while the monitor is still running.
+    pub outcome: Option<ApplyOutcome>,
+    pub succeeded: Vec<ActionProgress>,
+    pub failed: Vec<ActionProgress>,
+    /// Actions that were still queued, running or on hold when the monitor stopped.
+    pub pending: Vec<ActionProgress>,
+    pub elapsed: Duration,
+}
+
+impl ApplySummary {
+    /// True if the apply completed and no action failed.
+    pub fn is_success(&self) -> bool {
+        self.outcome == Some(ApplyOutcome::Completed)
+            && self.failed.is_empty()
+            && self.change_set_status.as_deref() != Some("Failed")
+    }
+}
+
+/// Polls a change set after `force_apply` until the apply has finished.
+#[derive(Debug, Clone)]
+pub struct ApplyMonitor {
+    workspace_id: String,
+    change_set_id: String,
+    options: ApplyMonitorOptions,
+    started_at: Instant,
+    next_poll_at: Instant,
+    current_interval: Duration,
+    change_set_status: Option<String>,
+    actions: Vec<ActionProgress>,
+    poll_count: u32,
+    consecutive_errors: u32,
+    outcome: Option<ApplyOutcome>,
+    finished_at: Option<Instant>,
+}
+
+impl ApplyMonitor {
+    /// Creates a monitor for `change_set_id`. The first poll is due immediately.
+    pub fn new(
+        workspace_id: &str,
+        change_set_id: &str,
+        options: ApplyMonitorOptions,
+    ) -> Self {
+        let now = Instant::now();
+        Self {
+            workspace_id: workspace_id.to_string(),
+            change_set_id: change_set_id.to_string(),
+            current_interval: options.initial_interval,
+            options,
+            started_at: now,
+            next_poll_at: now,
+            change_set_status: None,
+            actions: Vec::new(),
+            poll_count: 0,
+            consecutive_errors: 0,
+            outcome: None,
+            finished_at: None,
+        }
+    }
+
+    /// Seeds the monitor with the actions queued before the apply was triggered.
+    /// Intention: Actions that finish before the first poll would otherwise never be seen,
+    /// so they could not be reported as succeeded.
+    pub fn with_actions(
+        mut self,
+        actions: &[MergeStatusV1ResponseAction],
+    ) -> Self {
+        self.actions = actions
+            .iter()
+            .map(|action| ActionProgress {
+                action: action.clone(),
+                outcome: ActionOutcome::Pending(action.state.clone()),
+            })
+            .collect();
+        self
+    }
+
+    pub fn change_set_id(&self) -> &str {
+        &self.change_set_id
+    }
+
+    pub fn change_set_status(&self) -> Option<&str> {
+        self.change_set_status.as_deref()
+    }
+
+    /// Tracked actions in the order they were first seen.
+    pub fn actions(&self) -> &[ActionProgress] {
+        &self.actions
+    }
+
+    pub fn poll_count(&self) -> u32 {
+        self.poll_count
+    }
+
+    /// Time since the monitor was created, frozen once it has finished.
+    pub fn elapsed(&self) -> Duration {
+        self.finished_at
+            .unwrap_or_else(Instant::now)
+            .duration_since(self.started_at)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    /// True if the monitor is still running and the next poll is due.
+    pub fn is_due(&self) -> bool {
+        !self.is_finished() && Instant::now() >= self.next_poll_at
+    }
+
+    /// Time left until the next poll is due (zero if due or finished).
+    pub fn time_until_next_poll(&self) -> Duration {
+        self.next_poll_at.saturating_duration_since(Instant::now())
+    }
+
+    /// Fetches the change set and its merge status once and records the result.
+    /// Returns the API logs of this poll. An `Err` is only returned for a failed poll;
+    /// it finishes the monitor once `max_consecutive_errors` is exceeded.
+    pub async fn poll(
+        &mut self,
+        cancel: &CancelToken,
+    ) -> Result<Vec<LogEntry>, Box<dyn Error + Send + Sync>> {
+        let mut logs = Vec::new();
+        if self.is_finished() {
+            return Ok(logs);
+        }
+        if self.check_timeout() {
+            logs.push(LogEntry::warn(
+                "apply_monitor",
+                format!(
+                    "Apply monitor for {} timed out after {}s.",
+                    self.change_set_id,
+                    self.elapsed().as_secs()
+                ),
+            ));
+            return Ok(logs);
+        }
+        self.poll_count += 1;
+
+        // Every poll must observe the server, not a cached merge status
+        api_client::invalidate_cached_change_set(
+            &self.workspace_id,
+            &self.change_set_id,
+        );
+        let fetched = async {
+            let (change_set_response, cs_logs) = api_client::get_change_set(
+                &self.workspace_id,
+                &self.change_set_id,
+                cancel,
+            )
+            .await?;
+            let (merge_status, ms_logs) = api_client::get_merge_status(
+                &self.workspace_id,
+                &self.change_set_id,
+                cancel,
+            )
+            .await?;
+            Ok::<_, Box<dyn Error + Send + Sync>>((
+                change_set_response.change_set.status,
+                merge_status.actions,
+                cs_logs,
+                ms_logs,
+            ))
+        }
+        .await;
+
+        match fetched {
+            Ok((status, actions, cs_logs, ms_logs)) => {
+                logs.extend(cs_logs);
+                logs.extend(ms_logs);
+                self.consecutive_errors = 0;
+                let changed = self.record_observation(&status, &actions);
+                self.schedule_next_poll(changed);
+                Ok(logs)
+            }
+            Err(e) => {
+                self.consecutive_errors += 1;
+                if self.consecutive_errors > self.options.max_consecutive_errors
+                {
+                    self.finish(ApplyOutcome::Errored(e.to_string()));
+                } else {
+                    self.schedule_next_poll(false);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Records one observation of the change set status and its queued actions.
+    /// Returns true if anything changed compared to the previous observation.
+    /// Marks the monitor as completed once the change set status is terminal and
+    /// no tracked action can still make progress.
+    pub fn record_observation(
+        &mut self,
+        change_set_status: &str,
+        actions: &[MergeStatusV1ResponseAction],
+    ) -> bool {
+        let mut changed =
+            self.change_set_status.as_deref() != Some(change_set_status);
+        self.change_set_status = Some(change_set_status.to_string());
+
+        // Update actions we already track
+        for tracked in &mut self.actions {
+            let new_outcome =
+                match actions.iter().find(|a| a.id == tracked.action.id) {
+                    Some(current) => {
+                        tracked.action = current.clone();
+                        if current.state.eq_ignore_ascii_case("Failed") {
+                            ActionOutcome::Failed
+                        } else {
+                            ActionOutcome::Pending(current.state.clone())
+                        }
+                    }
+                    None => ActionOutcome::Succeeded,
+                };
+            if new_outcome != tracked.outcome {
+                tracked.outcome = new_outcome;
+                changed = true;
+            }
+        }
+
+        // Start tracking actions we have not seen before
+        for action in actions {
+            if !self.actions.iter().any(|t| t.action.id == action.id) {
+                let outcome = if action.state.eq_ignore_ascii_case("Failed") {
+                    ActionOutcome::Failed
+                } else {
+                    ActionOutcome::Pending(action.state.clone())
+                };
+                self.actions.push(ActionProgress {
+                    action: action.clone(),
+                    outcome,
+                });
+                changed = true;
+            }
+        }
+
+        let status_is_terminal = TERMINAL_CHANGE_SET_STATUSES
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(change_set_status));
+        let actions_settled =
+            self.actions.iter().all(|tracked| match &tracked.outcome {
+                ActionOutcome::Pending(state) => SETTLED_ACTION_STATES
+                    .iter()
+                    .any(|s| s.eq_ignore_ascii_case(state)),
+                ActionOutcome::Succeeded | ActionOutcome::Failed => true,
+            });
+        if status_is_terminal && actions_settled {
+            self.finish(ApplyOutcome::Completed);
+        }
+
+        changed
+    }
+
+    /// Builds the current summary. Can be called at any time; `outcome` is `None`
+    /// while the monitor is still running.
+    pub fn summary(&self) -> ApplySummary {
+        let by_outcome = |wanted: fn(&ActionOutcome) -> bool| {
+            self.actions
+                .iter()
+                .filter(|tracked| wanted(&tracked.outcome))
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        ApplySummary {
+            change_set_id: self.change_set_id.clone(),
+            change_set_status: self.change_set_status.clone(),
+            outcome: self.outcome.clone(),
+            succeeded: by_outcome(|o| *o == ActionOutcome::Succeeded),
+            failed: by_outcome(|o| *o == ActionOutcome::Failed),
+            pending: by_outcome(|o| matches!(o, ActionOutcome::Pending(_))),
+            elapsed: self.elapsed(),
+        }
+    }
+
+    /// Polls until the monitor finishes, sleeping between polls.
+    /// `on_poll` is called after every poll with the monitor and that poll's result,
+    /// so callers can print progress. Stops early, unfinished, once `cancel` is cancelled.
+    pub async fn run_to_completion<F>(
+        mut self,
+        cancel: &CancelToken,
+        mut on_poll: F,
+    ) -> ApplySummary
+    where
+        F: FnMut(&ApplyMonitor, &Result<Vec<LogEntry>, String>),
+    {
+        while !self.is_finished() && !cancel.is_cancelled() {
+            tokio::time::sleep(self.time_until_next_poll()).await;
+            let result = self.poll(cancel).await.map_err(|e| e.to_string());
+            on_poll(&self, &result);
+        }
+        self.summary()
+    }
+
+    // Records the outcome and freezes the elapsed time.
+    fn finish(&mut self, outcome: ApplyOutcome) {
+        self.outcome = Some(outcome);
+        self.finished_at = Some(Instant::now());
+    }
+
+    // Marks the monitor as timed out if the configured timeout has elapsed.
+    fn check_timeout(&mut self) -> bool {
+        if let Some(timeout) = self.options.timeout
+            && self.elapsed() >= timeout
+        {
+            self.finish(ApplyOutcome::TimedOut);
+            return true;
+        }
+        false
+    }
+
+    // Resets the interval after a change, otherwise backs off up to `max_interval`.
+    fn schedule_next_poll(&mut self, changed: bool) {
+        self.current_interval = if changed {
+            self.options.initial_interval
+        } else {
+            (self.current_interval * self.options.backoff_factor)
+                .min(self.options.max_interval)
+        };
+        self.next_poll_at = Instant::now() + self.current_interval;
+        // Never sleep past the timeout, so it is reported on time
+        if let Some(timeout) = self.options.timeout {
+            self.next_poll_at =
+                self.next_poll_at.min(self.started_at + timeout);
+        }
+    }
+}
@@ -0,0 +1,139 @@
+// src/auto_refresh.rs
+
+// Intention:
+// Refresh the change set list and the selected change set's status and actions in the
+// background, so changes made elsewhere (e.g. by teammates in the web UI) show up without a
+// restart, and tell which rows changed since the previous refresh.
+
+// Design Choices:
+// - `AutoRefresh` only schedules, like `ApplyMonitor`: the TUI asks `is_due` from its main loop
+//   and does the fetching itself, so input keeps being handled between refreshes.
+// - The interval comes from `SI_REFRESH_INTERVAL` (whole seconds, default 30); `0` or `off`
+//   disables it. It can be paused and resumed at run time.
+// - Changes are computed by ID: a change set is changed if it is new or its name or status
+//   differs, an action if it is new or its state differs. Removed rows cannot be highlighted,
+//   so they are not reported.
+
+use std::{
+    collections::HashSet,
+    env,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use crate::api_models::{
+    ChangeSetSummary,
+    MergeStatusV1ResponseAction,
+};
+
+/// Environment variable setting the refresh interval in seconds (`0` or `off` disables).
+pub const REFRESH_INTERVAL_ENV: &str = "SI_REFRESH_INTERVAL";
+
+/// Interval used when `SI_REFRESH_INTERVAL` is not set.
+pub const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// When the next background refresh is due.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutoRefresh {
+    interval: Option<Duration>, // `None` when disabled
+    paused: bool,
+    next_due: Option<Instant>,
+}
+
+impl AutoRefresh {
+    /// A schedule refreshing every `interval` from now, or never if `None` or zero.
+    pub fn new(interval: Option<Duration>, now: Instant) -> Self {
+        let interval = interval.filter(|interval| !interval.is_zero());
+        Self {
+            interval,
+            paused: false,
+            next_due: interval.map(|interval| now + interval),
+        }
+    }
+
+    /// Parses an interval in whole seconds; `0` or `off` disables refreshing.
+    pub fn parse_interval(value: &str) -> Result<Option<Duration>, String> {
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("off") {
+            return Ok(None);
+        }
+        let secs = value.parse::<u64>().map_err(|_| {
+            format!("expected whole seconds or `off`, got '{}'", value)
+        })?;
+        Ok((secs > 0).then(|| Duration::from_secs(secs)))
+    }
+
+    /// The interval from `SI_REFRESH_INTERVAL`, or the default when unset.
+    pub fn interval_from_env() -> Result<Option<Duration>, String> {
+        match env::var(REFRESH_INTERVAL_ENV) {
+            Ok(value) if !value.trim().is_empty() => {
+                Self::parse_interval(&value)
+                    .map_err(|e| format!("{}: {}", REFRESH_INTERVAL_ENV, e))
+            }
+            _ => Ok(Some(DEFAULT_REFRESH_INTERVAL)),
+        }
+    }
+
+    pub fn interval(&self) -> Option<Duration> {
+        self.interval
+    }
+
+    /// Whether refreshes happen at all (configured and not paused).
+    pub fn is_active(&self) -> bool {
+        self.interval.is_some() && !self.paused
+    }
+
+    /// Whether a refresh should run at `now`.
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.is_active() && self.next_due.is_some_and(|due| now >= due)
+    }
+
+    /// Schedules the next refresh one interval after `now` (after a refresh, automatic or not).
+    pub fn schedule(&mut self, now: Instant) {
+        self.next_due = self.interval.map(|interval| now + interval);
+    }
+
+    /// Pauses or resumes refreshing; resuming schedules the next refresh from `now`. Returns
+    /// whether refreshing is now active.
+    pub fn toggle_paused(&mut self, now: Instant) -> bool {
+        self.paused = !self.paused;
+        if !self.paused {
+            self.schedule(now);
+        }
+        self.is_active()
+    }
+}
+
+/// IDs of the change sets in `new` that are not in `old` or whose name or status changed.
+pub fn changed_change_sets(
+    old: &[ChangeSetSummary],
+    new: &[ChangeSetSummary],
+) -> HashSet<String> {
+    new.iter()
+        .filter(|cs| {
+            !old.iter().any(|previous| {
+                previous.id == cs.id
+                    && previous.name == cs.name
+                    && previous.status == cs.status
+            })
+        })
+        .map(|cs| cs.id.clone())
+        .collect()
+}
+
+/// IDs of the actions in `new` that are not in `old` or whose state changed.
+pub fn changed_actions(
+    old: &[MergeStatusV1ResponseAction],
+    new: &[MergeStatusV1ResponseAction],
+) -> HashSet<String> {
+    new.iter()
+        .filter(|action| {
+            !old.iter().any(|previous| {
+                previous.id == action.id && previous.state == action.state
+            })
+        })
+        .map(|action| action.id.clone())
+        .collect()
+}
@@ -0,0 +1,188 @@
+// src/cli.rs
+
+// Intention: Parse command line arguments and run the non-interactive subcommands.
+// Design Choice: Running without a subcommand starts the TUI as before. Subcommands live in
+// `src/cli/` (one per file) and return a process exit code instead of printing into the TUI.
+// Arguments are parsed by hand to avoid pulling in a CLI framework for a handful of flags.
+
+mod diff;
+mod token;
+mod wait;
+mod watch;
+
+use diff::{
+    DiffArgs,
+    parse_diff_args,
+    run_diff,
+};
+use token::{
+    TokenArgs,
+    parse_token_args,
+    run_token,
+};
+use wait::{
+    WaitArgs,
+    parse_wait_args,
+    run_wait,
+};
+use watch::{
+    WatchArgs,
+    parse_watch_args,
+    run_watch,
+};
+
+// --- Exit codes shared by all subcommands ---
+pub const EXIT_SUCCESS: i32 = 0;
+/// The command ran, but what it observed failed (e.g. an action failed during apply), or
+/// differs (`diff`).
+pub const EXIT_FAILURE: i32 = 1;
+/// Invalid command line.
+pub const EXIT_USAGE: i32 = 2;
+/// The command could not run (configuration or API error).
+pub const EXIT_ERROR: i32 = 3;
+/// The command gave up waiting (same code as coreutils `timeout`).
+pub const EXIT_TIMEOUT: i32 = 124;
+
+pub const USAGE: &str = "\
+Usage: situation [--profile <name>] [COMMAND]
+
+Without a command, starts the interactive TUI.
+
+Options:
+  --profile <name>
+      Use a profile from the profiles file (default: $SI_PROFILE, else the
+      SI_API and JWT_TOKEN variables).
+
+Commands:
+  wait <change-set-id> [--timeout <secs>] [--interval <secs>]
+      Wait for an apply of the change set to finish and print a summary.
+      --timeout   Give up after this many seconds (default 600, 0 = never).
+      --interval  Initial poll interval in seconds (default 1).
+  watch [<change-set-id>] [--interval <secs>] [--no-components] [--json]
+      Print what changes in the workspace, or one change set, until
+      interrupted: change sets created, changing status or abandoned,
+      components added, removed or modified, actions queued or completed.
+      --interval       Seconds between polls (default 10).
+      --no-components  Skip component events (one request per component).
+      --json           One JSON object per event.
+  diff <change-set> [<other-change-set>] [--json]
+      Compare two change sets (by ID or name) component by component, or
+      one with HEAD: components added, removed or modified, with the domain
+      props and connections that differ. Exits with 1 if they differ.
+      --json  Print the comparison as JSON.
+  token set <name> | token remove <name> | token list
+      Manage the encrypted token store used by `token_store` profiles.
+      `set` reads the token from standard input. The passphrase is taken
+      from $SI_TOKEN_STORE_PASSPHRASE.
+  help
+      Show this message.
+
+Exit codes:
+  0 success, 1 actions failed (wait) or change sets differ (diff),
+  2 usage error, 3 API or configuration error, 124 timed out.";
+
+// Intention: Represent what the binary was asked to do.
+#[derive(Debug)]
+pub enum CliCommand {
+    Tui,
+    Help,
+    Wait(WaitArgs),
+    Watch(WatchArgs),
+    Diff(DiffArgs),
+    Token(TokenArgs),
+}
+
+impl CliCommand {
+    // Intention: Whether the command talks to the API, and so needs the selected profile.
+    // Design Choice: `token` must work before the profile's token exists in the store.
+    pub fn uses_api(&self) -> bool {
+        matches!(
+            self,
+            CliCommand::Tui
+                | CliCommand::Wait(_)
+                | CliCommand::Watch(_)
+                | CliCommand::Diff(_)
+        )
+    }
+}
+
+// Intention: The command plus the options that apply to every command.
+#[derive(Debug)]
+pub struct Invocation {
+    pub profile: Option<String>,
+    pub command: CliCommand,
+}
+
+// Intention: Turn the raw arguments (without the program name) into an invocation.
+// Design Choice: `--profile` is only accepted before the command, so it never clashes with a
+// subcommand's own options. Returns a message suitable for printing next to `USAGE` on error.
+pub fn parse_args(args: &[String]) -> Result<Invocation, String> {
+    let mut profile = None;
+    let mut rest = args;
+    while let Some((arg, tail)) = rest.split_first() {
+        if arg == "--profile" {
+            let mut iter = tail.iter();
+            profile = Some(flag_value(arg, &mut iter)?.clone());
+            rest = iter.as_slice();
+        } else if let Some(name) = arg.strip_prefix("--profile=") {
+            profile = Some(name.to_string());
+            rest = tail;
+        } else {
+            break;
+        }
+    }
+    if profile.as_ref().is_some_and(|name| name.trim().is_empty()) {
+        return Err("--profile requires a profile name".to_string());
+    }
+    Ok(Invocation {
+        profile,
+        command: parse_command(rest)?,
+    })
+}
+
+// Intention: Turn the arguments after the global options into a command.
+fn parse_command(args: &[String]) -> Result<CliCommand, String> {
+    match args.split_first() {
+        None => Ok(CliCommand::Tui),
+        Some((command, rest)) => match command.as_str() {
+            "help" | "--help" | "-h" => Ok(CliCommand::Help),
+            "wait" => parse_wait_args(rest).map(CliCommand::Wait),
+            "watch" => parse_watch_args(rest).map(CliCommand::Watch),
+            "diff" => parse_diff_args(rest).map(CliCommand::Diff),
+            "token" => parse_token_args(rest).map(CliCommand::Token),
+            other => Err(format!("Unknown command: {}", other)),
+        },
+    }
+}
+
+// Intention: Run a non-TUI command and return the process exit code.
+pub async fn run_command(command: CliCommand) -> i32 {
+    match command {
+        CliCommand::Tui => EXIT_USAGE, // The TUI is started by main, not here
+        CliCommand::Help => {
+            println!("{}", USAGE);
+            EXIT_SUCCESS
+        }
+        CliCommand::Wait(args) => run_wait(args).await,
+        CliCommand::Watch(args) => run_watch(args).await,
+        CliCommand::Diff(args) => run_diff(args).await,
+        CliCommand::Token(args) => run_token(args),
+    }
+}
+
+// Intention: Read the value following a flag, e.g. `--timeout 30`.
+// Design Choice: Shared by the subcommand parsers so error messages stay consistent.
+fn flag_value<'a>(
+    flag: &str,
+    args: &mut impl Iterator<Item = &'a String>,
+) -> Result<&'a String, String> {
+    args.next()
+        .ok_or_else(|| format!("Missing value for {}", flag))
+}
+
+// Intention: Parse a whole number of seconds for duration flags.
+fn parse_seconds(flag: &str, value: &str) -> Result<u64, String> {
+    value.parse::<u64>().map_err(|_| {
+        format!("Invalid number of seconds for {}: {}", flag, value)
+    })
+}
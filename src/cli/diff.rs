@@ -0,0 +1,169 @@
+// src/cli/diff.rs
+
+// Intention: Implement `situation diff <change-set> [<other>]`, which prints how two change sets
+// differ component by component, or how one differs from HEAD.
+// Design Choice: Change sets are given by ID or name. Each differing component is printed with
+// its rows side by side (what differs, left value, right value), the same rows the TUI shows.
+// Like diff(1), the exit code tells whether anything differs.
+
+use situation::{
+    ChangeSetComparison,
+    api_client::{
+        self,
+        CancelToken,
+    },
+    api_models::ChangeSetSummary,
+    compare::{
+        HEAD_CHANGE_SET_NAME,
+        find_change_set,
+        find_head,
+    },
+    compare_change_sets,
+};
+
+use super::{
+    EXIT_ERROR,
+    EXIT_FAILURE,
+    EXIT_SUCCESS,
+};
+
+// Intention: Arguments accepted by the `diff` command.
+#[derive(Debug)]
+pub struct DiffArgs {
+    pub left: Option<String>, // None: HEAD
+    pub right: String,
+    pub json: bool,
+}
+
+// Intention: Parse `<change-set> [<other-change-set>] [--json]`.
+// Design Choice: With one change set it is compared against HEAD, so HEAD is the left side.
+pub fn parse_diff_args(args: &[String]) -> Result<DiffArgs, String> {
+    let mut change_sets = Vec::new();
+    let mut json = false;
+    for arg in args {
+        match arg.as_str() {
+            "--json" => json = true,
+            flag if flag.starts_with("--") => {
+                return Err(format!("Unknown option for diff: {}", flag));
+            }
+            change_set => change_sets.push(change_set.to_string()),
+        }
+    }
+    let mut change_sets = change_sets.into_iter();
+    match (change_sets.next(), change_sets.next(), change_sets.next()) {
+        (Some(right), None, _) => Ok(DiffArgs {
+            left: None,
+            right,
+            json,
+        }),
+        (Some(left), Some(right), None) => Ok(DiffArgs {
+            left: Some(left),
+            right,
+            json,
+        }),
+        (None, ..) => Err("diff requires a change set ID or name".to_string()),
+        (.., Some(extra)) => Err(format!("Unexpected argument: {}", extra)),
+    }
+}
+
+// Intention: Print one comparison as text: a summary line, then each differing component
+// followed by its rows in aligned columns.
+fn print_comparison(comparison: &ChangeSetComparison) {
+    println!(
+        "{} ({}) -> {} ({}): {}",
+        comparison.left.name,
+        comparison.left.id,
+        comparison.right.name,
+        comparison.right.id,
+        comparison.summary()
+    );
+    for diff in &comparison.components {
+        println!();
+        println!("{} {} ({})", diff.kind.symbol(), diff.name(), diff.id());
+        let rows = diff.rows();
+        let label_width =
+            rows.iter().map(|row| row.label.len()).max().unwrap_or(0);
+        let left_width = rows
+            .iter()
+            .map(|row| row.left.chars().count())
+            .max()
+            .unwrap_or(0);
+        for row in rows {
+            println!(
+                "    {:label_width$}  {:left_width$}  | {}",
+                row.label, row.left, row.right
+            );
+        }
+    }
+}
+
+// Intention: Resolve both change sets, compare them and print the result.
+// Returns 0 when they do not differ, 1 when they do, 3 on errors.
+pub async fn run_diff(args: DiffArgs) -> i32 {
+    let cancel = CancelToken::new();
+    let workspace_id = match api_client::whoami(&cancel).await {
+        Ok((whoami_data, _logs)) => whoami_data.workspace_id,
+        Err(e) => {
+            eprintln!("Error fetching workspace via /whoami: {}", e);
+            return EXIT_ERROR;
+        }
+    };
+    let change_sets =
+        match api_client::list_change_sets(&workspace_id, &cancel).await {
+            Ok((response, _logs)) => response.change_sets,
+            Err(e) => {
+                eprintln!("Error listing change sets: {}", e);
+                return EXIT_ERROR;
+            }
+        };
+    let resolve =
+        |id_or_name: Option<&str>| -> Result<ChangeSetSummary, String> {
+            match id_or_name {
+                Some(id_or_name) => find_change_set(&change_sets, id_or_name)
+                    .ok_or_else(|| format!("No change set {}.", id_or_name)),
+                None => find_head(&change_sets).ok_or_else(|| {
+                    format!("No {} change set listed.", HEAD_CHANGE_SET_NAME)
+                }),
+            }
+            .cloned()
+        };
+    let (left, right) =
+        match (resolve(args.left.as_deref()), resolve(Some(&args.right))) {
+            (Ok(left), Ok(right)) => (left, right),
+            (Err(e), _) | (_, Err(e)) => {
+                eprintln!("{}", e);
+                return EXIT_ERROR;
+            }
+        };
+
+    let comparison = match compare_change_sets(
+        &workspace_id,
+        &left,
+        &right,
+        &cancel,
+    )
+    .await
+    {
+        Ok((comparison, _logs)) => comparison,
+        Err(e) => {
+            eprintln!("Error comparing change sets: {}", e);
+            return EXIT_ERROR;
+        }
+    };
+    if args.json {
+        match serde_json::to_string_pretty(&comparison.redacted()) {
+            Ok(text) => println!("{}", text),
+            Err(e) => {
+                eprintln!("Cannot encode the comparison: {}", e);
+                return EXIT_ERROR;
+            }
+        }
+    } else {
+        print_comparison(&comparison);
+    }
+    if comparison.components.is_empty() {
+        EXIT_SUCCESS
+    } else {
+        EXIT_FAILURE
+    }
+}
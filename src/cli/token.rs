@@ -0,0 +1,91 @@
+// src/cli/token.rs
+
+// Intention: Implement `situation token set|remove|list`, which manage the encrypted token store.
+// Design Choice: `set` reads the token from standard input (first line), so it never appears in
+// the shell history or the process list: `pass show si/jwt | situation token set prod`. The
+// passphrase comes from `SI_TOKEN_STORE_PASSPHRASE`, as when profiles read the store.
+
+use std::io::{
+    self,
+    BufRead,
+};
+
+use situation::{
+    TokenStore,
+    token_store_passphrase,
+    token_store_path,
+};
+
+use super::{
+    EXIT_ERROR,
+    EXIT_SUCCESS,
+};
+
+// Intention: What the `token` command was asked to do.
+#[derive(Debug)]
+pub enum TokenArgs {
+    Set(String),
+    Remove(String),
+    List,
+}
+
+// Intention: Parse `set <name>`, `remove <name>` or `list`.
+pub fn parse_token_args(args: &[String]) -> Result<TokenArgs, String> {
+    let name = |rest: &[String]| match rest {
+        [name] if !name.trim().is_empty() => Ok(name.trim().to_string()),
+        [] => Err("token set/remove requires a name".to_string()),
+        _ => Err(format!("Unexpected argument: {}", rest[rest.len() - 1])),
+    };
+    match args.split_first() {
+        Some((action, rest)) => match action.as_str() {
+            "set" => name(rest).map(TokenArgs::Set),
+            "remove" => name(rest).map(TokenArgs::Remove),
+            "list" if rest.is_empty() => Ok(TokenArgs::List),
+            "list" => Err(format!("Unexpected argument: {}", rest[0])),
+            other => Err(format!("Unknown token action: {}", other)),
+        },
+        None => Err("token requires set, remove or list".to_string()),
+    }
+}
+
+// Intention: Change or list the token store and map the result to an exit code.
+pub fn run_token(args: TokenArgs) -> i32 {
+    match update_store(args) {
+        Ok(()) => EXIT_SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            EXIT_ERROR
+        }
+    }
+}
+
+fn update_store(args: TokenArgs) -> Result<(), String> {
+    let path = token_store_path();
+    let passphrase = token_store_passphrase()?;
+    let mut store = TokenStore::load(&path, &passphrase)?;
+    match args {
+        TokenArgs::List => {
+            store.names().for_each(|name| println!("{}", name));
+            return Ok(());
+        }
+        TokenArgs::Set(name) => {
+            let mut token = String::new();
+            io::stdin()
+                .lock()
+                .read_line(&mut token)
+                .map_err(|e| format!("cannot read the token: {}", e))?;
+            if token.trim().is_empty() {
+                return Err("no token on standard input".to_string());
+            }
+            store.set(&name, token.trim());
+            eprintln!("Stored token '{}' in {}", name, path.display());
+        }
+        TokenArgs::Remove(name) => {
+            if !store.remove(&name) {
+                return Err(format!("no token named '{}'", name));
+            }
+            eprintln!("Removed token '{}' from {}", name, path.display());
+        }
+    }
+    store.save(&path, &passphrase)
+}
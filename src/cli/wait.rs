@@ -0,0 +1,157 @@
+// src/cli/wait.rs
+
+// Intention: Implement `situation wait <change-set-id>`, which waits for an apply to finish.
+// Design Choice: Reuses the library `ApplyMonitor`, the same one the TUI polls after `f`.
+// Progress is printed to stderr whenever it changes, the summary to stdout, so scripts can
+// capture the summary and rely on the exit code.
+
+use std::time::Duration;
+
+use situation::{
+    ApplyMonitor,
+    ApplyMonitorOptions,
+    ApplyOutcome,
+    api_client::{
+        self,
+        CancelToken,
+    },
+};
+
+use super::{
+    EXIT_ERROR,
+    EXIT_FAILURE,
+    EXIT_SUCCESS,
+    EXIT_TIMEOUT,
+    flag_value,
+    parse_seconds,
+};
+
+// Intention: Arguments accepted by the `wait` command.
+#[derive(Debug)]
+pub struct WaitArgs {
+    pub change_set_id: String,
+    pub timeout: Option<Duration>,
+    pub interval: Option<Duration>,
+}
+
+// Intention: Parse `<change-set-id> [--timeout <secs>] [--interval <secs>]`.
+// Design Choice: `--timeout 0` disables the timeout; omitted flags keep the monitor defaults.
+pub fn parse_wait_args(args: &[String]) -> Result<WaitArgs, String> {
+    let mut change_set_id = None;
+    let mut timeout = ApplyMonitorOptions::default().timeout;
+    let mut interval = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--timeout" => {
+                let secs = parse_seconds(arg, flag_value(arg, &mut iter)?)?;
+                timeout = (secs > 0).then(|| Duration::from_secs(secs));
+            }
+            "--interval" => {
+                let secs = parse_seconds(arg, flag_value(arg, &mut iter)?)?;
+                interval = Some(Duration::from_secs(secs.max(1)));
+            }
+            flag if flag.starts_with("--") => {
+                return Err(format!("Unknown option for wait: {}", flag));
+            }
+            id if change_set_id.is_none() => {
+                change_set_id = Some(id.to_string());
+            }
+            extra => return Err(format!("Unexpected argument: {}", extra)),
+        }
+    }
+
+    Ok(WaitArgs {
+        change_set_id: change_set_id
+            .ok_or_else(|| "wait requires a change set ID".to_string())?,
+        timeout,
+        interval,
+    })
+}
+
+// Intention: Wait for the apply of the given change set and map the result to an exit code.
+// Design Choice: The workspace is resolved with `/whoami`, like the TUI does on startup.
+pub async fn run_wait(args: WaitArgs) -> i32 {
+    let cancel = CancelToken::new();
+    let workspace_id = match api_client::whoami(&cancel).await {
+        Ok((whoami_data, _logs)) => whoami_data.workspace_id,
+        Err(e) => {
+            eprintln!("Error fetching workspace via /whoami: {}", e);
+            return EXIT_ERROR;
+        }
+    };
+
+    let mut options = ApplyMonitorOptions {
+        timeout: args.timeout,
+        ..ApplyMonitorOptions::default()
+    };
+    if let Some(interval) = args.interval {
+        options.initial_interval = interval;
+        options.max_interval = options.max_interval.max(interval);
+    }
+
+    eprintln!("Waiting for apply of change set {}...", args.change_set_id);
+    let monitor =
+        ApplyMonitor::new(&workspace_id, &args.change_set_id, options);
+    let mut last_line = String::new();
+    let summary = monitor
+        .run_to_completion(&cancel, |monitor, result| {
+            if let Err(e) = result {
+                eprintln!(
+                    "[{:>4}s] poll failed: {}",
+                    monitor.elapsed().as_secs(),
+                    e
+                );
+                return;
+            }
+            let progress = monitor.summary();
+            let line = format!(
+                "status={} succeeded={} failed={} pending={}",
+                monitor.change_set_status().unwrap_or("unknown"),
+                progress.succeeded.len(),
+                progress.failed.len(),
+                progress.pending.len()
+            );
+            // Only print when something changed, to keep long waits readable
+            if line != last_line {
+                eprintln!("[{:>4}s] {}", monitor.elapsed().as_secs(), line);
+                last_line = line;
+            }
+        })
+        .await;
+
+    println!(
+        "Change set {}: status {}, {}s elapsed",
+        summary.change_set_id,
+        summary.change_set_status.as_deref().unwrap_or("unknown"),
+        summary.elapsed.as_secs()
+    );
+    for (label, actions) in [
+        ("Succeeded", &summary.succeeded),
+        ("Failed", &summary.failed),
+        ("Pending", &summary.pending),
+    ] {
+        println!("{} ({}):", label, actions.len());
+        for tracked in actions {
+            println!(
+                "  [{}] {} ({})",
+                tracked.action.kind, tracked.action.name, tracked.action.id
+            );
+        }
+    }
+
+    match summary.outcome {
+        Some(ApplyOutcome::Completed) if summary.is_success() => EXIT_SUCCESS,
+        Some(ApplyOutcome::Completed) => EXIT_FAILURE,
+        Some(ApplyOutcome::TimedOut) => {
+            eprintln!("Timed out waiting for apply.");
+            EXIT_TIMEOUT
+        }
+        Some(ApplyOutcome::Errored(e)) => {
+            eprintln!("Gave up after repeated errors: {}", e);
+            EXIT_ERROR
+        }
+        None => EXIT_ERROR, // run_to_completion only returns once finished
+    }
+}
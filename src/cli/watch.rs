@@ -0,0 +1,129 @@
+// src/cli/watch.rs
+
+// Intention: Implement `situation watch [<change-set-id>]`, which prints what changes in the
+// workspace (or one change set) as it happens, until interrupted.
+// Design Choice: Reuses the library `Watcher`. Events go to stdout, one per line (plain text,
+// or JSON with `--json` for scripts and bots); progress and errors go to stderr. Failed polls
+// are retried on the next interval, and the command gives up after a few in a row.
+
+use std::time::Duration;
+
+use situation::{
+    WatchOptions,
+    WatchScope,
+    Watcher,
+    api_client::{
+        self,
+        CancelToken,
+    },
+};
+
+use super::{
+    EXIT_ERROR,
+    flag_value,
+    parse_seconds,
+};
+
+// Failed polls in a row after which the command gives up
+const MAX_CONSECUTIVE_ERRORS: u32 = 3;
+
+// Intention: Arguments accepted by the `watch` command.
+#[derive(Debug)]
+pub struct WatchArgs {
+    pub change_set_id: Option<String>,
+    pub interval: Option<Duration>,
+    pub components: bool,
+    pub json: bool,
+}
+
+// Intention: Parse `[<change-set-id>] [--interval <secs>] [--no-components] [--json]`.
+pub fn parse_watch_args(args: &[String]) -> Result<WatchArgs, String> {
+    let mut parsed = WatchArgs {
+        change_set_id: None,
+        interval: None,
+        components: true,
+        json: false,
+    };
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--interval" => {
+                let secs = parse_seconds(arg, flag_value(arg, &mut iter)?)?;
+                parsed.interval = Some(Duration::from_secs(secs.max(1)));
+            }
+            "--no-components" => parsed.components = false,
+            "--json" => parsed.json = true,
+            flag if flag.starts_with("--") => {
+                return Err(format!("Unknown option for watch: {}", flag));
+            }
+            id if parsed.change_set_id.is_none() => {
+                parsed.change_set_id = Some(id.to_string());
+            }
+            extra => return Err(format!("Unexpected argument: {}", extra)),
+        }
+    }
+    Ok(parsed)
+}
+
+// Intention: Print events until interrupted; returns an exit code only when giving up.
+// Design Choice: The workspace is resolved with `/whoami`, like `wait` does.
+pub async fn run_watch(args: WatchArgs) -> i32 {
+    let cancel = CancelToken::new();
+    let workspace_id = match api_client::whoami(&cancel).await {
+        Ok((whoami_data, _logs)) => whoami_data.workspace_id,
+        Err(e) => {
+            eprintln!("Error fetching workspace via /whoami: {}", e);
+            return EXIT_ERROR;
+        }
+    };
+
+    let mut options = WatchOptions {
+        components: args.components,
+        ..WatchOptions::default()
+    };
+    if let Some(interval) = args.interval {
+        options.interval = interval;
+    }
+    let scope = match &args.change_set_id {
+        Some(id) => WatchScope::ChangeSet(id.clone()),
+        None => WatchScope::Workspace,
+    };
+    match &scope {
+        WatchScope::Workspace => {
+            eprintln!("Watching workspace {}...", workspace_id)
+        }
+        WatchScope::ChangeSet(id) => eprintln!("Watching change set {}...", id),
+    }
+
+    let mut watcher = Watcher::new(&workspace_id, scope, options);
+    let mut consecutive_errors = 0;
+    loop {
+        match watcher.poll(&cancel).await {
+            Ok((events, _logs)) => {
+                consecutive_errors = 0;
+                for event in events {
+                    if args.json {
+                        match serde_json::to_string(&event) {
+                            Ok(line) => println!("{}", line),
+                            Err(e) => eprintln!("Cannot encode event: {}", e),
+                        }
+                    } else {
+                        println!("{}", event);
+                    }
+                }
+            }
+            Err(e) => {
+                consecutive_errors += 1;
+                eprintln!("Poll failed: {}", e);
+                if consecutive_errors >= MAX_CONSECUTIVE_ERRORS {
+                    eprintln!(
+                        "Gave up after {} failed polls in a row.",
+                        consecutive_errors
+                    );
+                    return EXIT_ERROR;
+                }
+            }
+        }
+        tokio::time::sleep(watcher.time_until_next_poll()).await;
+    }
+}
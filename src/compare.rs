@@ -0,0 +1,130 @@
+// src/compare.rs
+
+// Intention:
+// Compare two change sets (or one and HEAD) component by component, for the CLI `diff` command
+// and the TUI's side-by-side comparison.
+
+// Design Choices:
+// - Both sides are loaded with `watch::observe_components` (the component list, then every
+//   component), concurrently, and compared by the pure functions in `diff`.
+// - The API has no flag for HEAD; it is the change set named `HEAD`.
+// - Change sets are given by ID or name, as they are typed on the command line.
+
+mod diff;
+
+use std::error::Error;
+
+pub use diff::{
+    ABSENT,
+    ComponentDiff,
+    ComponentDiffKind,
+    ComponentSide,
+    DOMAIN_PATH_ROOT,
+    DiffRow,
+    PropDiff,
+    compare_components,
+    connection_label,
+    diff_props,
+    flatten_domain,
+    is_secret_prop,
+    prop_value_label,
+    value_label,
+};
+use serde::Serialize;
+
+use crate::{
+    api_client::CancelToken,
+    api_models::ChangeSetSummary,
+    logging::LogEntry,
+    watch::observe_components,
+};
+
+/// Name of the change set every other one is based on.
+pub const HEAD_CHANGE_SET_NAME: &str = "HEAD";
+
+/// Whether this is the HEAD change set.
+pub fn is_head(change_set: &ChangeSetSummary) -> bool {
+    change_set.name == HEAD_CHANGE_SET_NAME
+}
+
+/// The HEAD change set of a list, if listed.
+pub fn find_head(
+    change_sets: &[ChangeSetSummary],
+) -> Option<&ChangeSetSummary> {
+    change_sets.iter().find(|cs| is_head(cs))
+}
+
+/// The change set with this ID or, failing that, this name.
+pub fn find_change_set<'a>(
+    change_sets: &'a [ChangeSetSummary],
+    id_or_name: &str,
+) -> Option<&'a ChangeSetSummary> {
+    change_sets
+        .iter()
+        .find(|cs| cs.id == id_or_name)
+        .or_else(|| change_sets.iter().find(|cs| cs.name == id_or_name))
+}
+
+/// The differences between two change sets, from `left` to `right`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChangeSetComparison {
+    pub left: ChangeSetSummary,
+    pub right: ChangeSetSummary,
+    pub components: Vec<ComponentDiff>,
+}
+
+impl ChangeSetComparison {
+    /// Number of components that differ in this way.
+    pub fn count(&self, kind: ComponentDiffKind) -> usize {
+        self.components
+            .iter()
+            .filter(|diff| diff.kind == kind)
+            .count()
+    }
+
+    /// The same comparison with secret prop values replaced by `REDACTED`, to print or export.
+    pub fn redacted(&self) -> ChangeSetComparison {
+        ChangeSetComparison {
+            left: self.left.clone(),
+            right: self.right.clone(),
+            components: self
+                .components
+                .iter()
+                .map(ComponentDiff::redacted)
+                .collect(),
+        }
+    }
+
+    /// `N added, N removed, N modified`.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} added, {} removed, {} modified",
+            self.count(ComponentDiffKind::Added),
+            self.count(ComponentDiffKind::Removed),
+            self.count(ComponentDiffKind::Modified)
+        )
+    }
+}
+
+/// Loads the components of both change sets and compares them.
+pub async fn compare_change_sets(
+    workspace_id: &str,
+    left: &ChangeSetSummary,
+    right: &ChangeSetSummary,
+    cancel: &CancelToken,
+) -> Result<(ChangeSetComparison, Vec<LogEntry>), Box<dyn Error + Send + Sync>>
+{
+    let ((left_components, mut logs), (right_components, right_logs)) = tokio::try_join!(
+        observe_components(workspace_id, &left.id, cancel),
+        observe_components(workspace_id, &right.id, cancel),
+    )?;
+    logs.extend(right_logs);
+    Ok((
+        ChangeSetComparison {
+            left: left.clone(),
+            right: right.clone(),
+            components: compare_components(&left_components, &right_components),
+        },
+        logs,
+    ))
+}
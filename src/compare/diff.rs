@@ -0,0 +1,398 @@
+// src/compare/diff.rs
+
+// Intention: Compare the components of two change sets: which were added, removed or modified,
+// and for the modified ones which domain prop paths and connections differ.
+// Design Choice: Pure functions over what `observe_components` returns, so the comparison can
+// be tested without a backend and reused by the pre-apply review and conflict detection.
+// Components are matched by ID first; the rest by name, since a component created separately
+// in two change sets gets a different ID in each. Prop values are kept as they are, so changed
+// secrets are still found, and masked wherever they are shown (`prop_value_label`, `redacted`).
+
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    redact::{
+        REDACTED,
+        Redactor,
+        looks_like_secret,
+    },
+    watch::ObservedComponent,
+};
+
+/// Root of the prop paths reported for domain properties.
+pub const DOMAIN_PATH_ROOT: &str = "/domain";
+
+/// How a component differs between the two sides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentDiffKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+impl ComponentDiffKind {
+    /// Marker used in text output (`+`, `-`, `~`).
+    pub fn symbol(&self) -> char {
+        match self {
+            ComponentDiffKind::Added => '+',
+            ComponentDiffKind::Removed => '-',
+            ComponentDiffKind::Modified => '~',
+        }
+    }
+}
+
+/// A component as found on one side.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ComponentSide {
+    pub id: String,
+    pub name: String,
+    pub to_delete: bool,
+}
+
+/// A domain property whose value differs; `None` where the path does not exist.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PropDiff {
+    pub path: String,
+    pub left: Option<Value>,
+    pub right: Option<Value>,
+}
+
+impl PropDiff {
+    /// The same diff with secret values (`is_secret_prop`) replaced by `REDACTED`.
+    pub fn redacted(&self) -> PropDiff {
+        let mask = |value: &Option<Value>| {
+            value.as_ref().map(|value| {
+                if is_secret_prop(&self.path, value) {
+                    Value::String(REDACTED.to_string())
+                } else {
+                    value.clone()
+                }
+            })
+        };
+        PropDiff {
+            path: self.path.clone(),
+            left: mask(&self.left),
+            right: mask(&self.right),
+        }
+    }
+}
+
+/// One row of the side-by-side view: what differs, then its value on each side (`-` where
+/// absent).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffRow {
+    pub label: String,
+    pub left: String,
+    pub right: String,
+}
+
+/// Placeholder for a value missing on one side.
+pub const ABSENT: &str = "-";
+
+/// One component that differs between the two sides.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComponentDiff {
+    pub kind: ComponentDiffKind,
+    pub left: Option<ComponentSide>,
+    pub right: Option<ComponentSide>,
+    pub props: Vec<PropDiff>,
+    /// Connections only on the left, as `connection_label`s.
+    pub connections_removed: Vec<String>,
+    /// Connections only on the right.
+    pub connections_added: Vec<String>,
+}
+
+impl ComponentDiff {
+    /// The component's name on the right, or on the left if removed.
+    pub fn name(&self) -> &str {
+        self.right
+            .as_ref()
+            .or(self.left.as_ref())
+            .map_or("", |side| side.name.as_str())
+    }
+
+    /// The component's ID on the right, or on the left if removed.
+    pub fn id(&self) -> &str {
+        self.right
+            .as_ref()
+            .or(self.left.as_ref())
+            .map_or("", |side| side.id.as_str())
+    }
+
+    /// The rows of the side-by-side view: name and deletion flag if they changed, then the
+    /// domain props, then the connections.
+    pub fn rows(&self) -> Vec<DiffRow> {
+        let row = |label: &str, left: Option<String>, right: Option<String>| {
+            DiffRow {
+                label: label.to_string(),
+                left: left.unwrap_or_else(|| ABSENT.to_string()),
+                right: right.unwrap_or_else(|| ABSENT.to_string()),
+            }
+        };
+        let mut rows = Vec::new();
+        if let (Some(left), Some(right)) = (&self.left, &self.right) {
+            if left.name != right.name {
+                rows.push(row(
+                    "name",
+                    Some(left.name.clone()),
+                    Some(right.name.clone()),
+                ));
+            }
+            if left.to_delete != right.to_delete {
+                rows.push(row(
+                    "to delete",
+                    Some(left.to_delete.to_string()),
+                    Some(right.to_delete.to_string()),
+                ));
+            }
+        }
+        rows.extend(self.props.iter().map(|prop| {
+            row(
+                &prop.path,
+                prop.left
+                    .as_ref()
+                    .map(|value| prop_value_label(&prop.path, value)),
+                prop.right
+                    .as_ref()
+                    .map(|value| prop_value_label(&prop.path, value)),
+            )
+        }));
+        rows.extend(
+            self.connections_removed
+                .iter()
+                .map(|label| row("connection", Some(label.clone()), None)),
+        );
+        rows.extend(
+            self.connections_added
+                .iter()
+                .map(|label| row("connection", None, Some(label.clone()))),
+        );
+        rows
+    }
+
+    /// The same diff with secret prop values replaced by `REDACTED`, e.g. to export it.
+    pub fn redacted(&self) -> ComponentDiff {
+        ComponentDiff {
+            props: self.props.iter().map(PropDiff::redacted).collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Whether the right side marks the component for deletion and the left does not.
+    pub fn marked_for_deletion(&self) -> bool {
+        self.right.as_ref().is_some_and(|side| side.to_delete)
+            && !self.left.as_ref().is_some_and(|side| side.to_delete)
+    }
+}
+
+/// Flattens a domain object into its leaf values by prop path (`/domain/a/b`, array items by
+/// index). Empty objects and arrays are leaves, so adding one is reported.
+pub fn flatten_domain(domain: &Value) -> BTreeMap<String, Value> {
+    let mut leaves = BTreeMap::new();
+    if !domain.is_null() {
+        flatten_into(DOMAIN_PATH_ROOT.to_string(), domain, &mut leaves);
+    }
+    leaves
+}
+
+fn flatten_into(
+    path: String,
+    value: &Value,
+    leaves: &mut BTreeMap<String, Value>,
+) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                flatten_into(format!("{}/{}", path, key), child, leaves);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (index, child) in items.iter().enumerate() {
+                flatten_into(format!("{}/{}", path, index), child, leaves);
+            }
+        }
+        leaf => {
+            leaves.insert(path, leaf.clone());
+        }
+    }
+}
+
+/// A value as shown in a diff: strings without quotes, anything else as JSON.
+pub fn value_label(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Whether a prop value is masked when shown: a segment of its path names a sensitive key
+/// (`Redactor::is_sensitive_key`), or the value looks like a secret (`looks_like_secret`).
+pub fn is_secret_prop(path: &str, value: &Value) -> bool {
+    let redactor = Redactor::global();
+    path.split('/')
+        .any(|segment| redactor.is_sensitive_key(segment))
+        || value.as_str().is_some_and(looks_like_secret)
+}
+
+/// A prop value as shown in a diff: its `value_label`, or `REDACTED` if it is a secret.
+pub fn prop_value_label(path: &str, value: &Value) -> String {
+    if is_secret_prop(path, value) {
+        REDACTED.to_string()
+    } else {
+        value_label(value)
+    }
+}
+
+/// One line describing a connection of the component (`connections` of `ComponentViewV1`).
+/// Uses component names, not IDs, so connections compare across change sets.
+pub fn connection_label(connection: &Value) -> String {
+    let text = |value: &Value, field: &str| {
+        value
+            .get(field)
+            .and_then(Value::as_str)
+            .unwrap_or("?")
+            .to_string()
+    };
+    if let Some(incoming) = connection.get("incoming") {
+        format!(
+            "{}.{} -> {}",
+            text(incoming, "fromComponentName"),
+            text(incoming, "from"),
+            text(incoming, "to")
+        )
+    } else if let Some(outgoing) = connection.get("outgoing") {
+        let target = match outgoing.get("to").and_then(Value::as_str) {
+            Some(socket) => {
+                format!("{}.{}", text(outgoing, "toComponentName"), socket)
+            }
+            None => text(outgoing, "toComponentName"),
+        };
+        format!("{} -> {}", text(outgoing, "from"), target)
+    } else if let Some(managing) = connection.get("managing") {
+        format!("manages {}", text(managing, "componentName"))
+    } else if let Some(managed_by) = connection.get("managedBy") {
+        format!("managed by {}", text(managed_by, "componentName"))
+    } else {
+        connection.to_string()
+    }
+}
+
+fn connection_labels(component: &ObservedComponent) -> BTreeSet<String> {
+    component
+        .component
+        .get("connections")
+        .and_then(Value::as_array)
+        .map(|connections| connections.iter().map(connection_label).collect())
+        .unwrap_or_default()
+}
+
+fn side(id: &str, component: &ObservedComponent) -> ComponentSide {
+    ComponentSide {
+        id: id.to_string(),
+        name: component.name.clone(),
+        to_delete: component
+            .component
+            .get("toDelete")
+            .and_then(Value::as_bool)
+            .unwrap_or(false),
+    }
+}
+
+/// The domain props that differ between two components (either may be missing).
+pub fn diff_props(
+    left: Option<&ObservedComponent>,
+    right: Option<&ObservedComponent>,
+) -> Vec<PropDiff> {
+    let flatten = |component: Option<&ObservedComponent>| {
+        component
+            .map(|c| flatten_domain(&c.domain))
+            .unwrap_or_default()
+    };
+    let (left, right) = (flatten(left), flatten(right));
+    let paths: BTreeSet<&String> = left.keys().chain(right.keys()).collect();
+    paths
+        .into_iter()
+        .filter(|path| left.get(*path) != right.get(*path))
+        .map(|path| PropDiff {
+            path: path.clone(),
+            left: left.get(path).cloned(),
+            right: right.get(path).cloned(),
+        })
+        .collect()
+}
+
+// Diff of one pair of components; `None` when they do not differ.
+fn diff_pair(
+    left: Option<(&String, &ObservedComponent)>,
+    right: Option<(&String, &ObservedComponent)>,
+) -> Option<ComponentDiff> {
+    let kind = match (left, right) {
+        (None, Some(_)) => ComponentDiffKind::Added,
+        (Some(_), None) => ComponentDiffKind::Removed,
+        (Some(_), Some(_)) => ComponentDiffKind::Modified,
+        (None, None) => return None,
+    };
+    let left_connections =
+        left.map(|(_, c)| connection_labels(c)).unwrap_or_default();
+    let right_connections =
+        right.map(|(_, c)| connection_labels(c)).unwrap_or_default();
+    let diff = ComponentDiff {
+        kind,
+        left: left.map(|(id, c)| side(id, c)),
+        right: right.map(|(id, c)| side(id, c)),
+        props: diff_props(left.map(|(_, c)| c), right.map(|(_, c)| c)),
+        connections_removed: left_connections
+            .difference(&right_connections)
+            .cloned()
+            .collect(),
+        connections_added: right_connections
+            .difference(&left_connections)
+            .cloned()
+            .collect(),
+    };
+    let unchanged = kind == ComponentDiffKind::Modified
+        && diff.props.is_empty()
+        && diff.connections_removed.is_empty()
+        && diff.connections_added.is_empty()
+        && diff.left.as_ref().map(|s| (&s.name, s.to_delete))
+            == diff.right.as_ref().map(|s| (&s.name, s.to_delete));
+    (!unchanged).then_some(diff)
+}
+
+/// The components that differ between two change sets (by component ID), sorted by name.
+pub fn compare_components(
+    left: &BTreeMap<String, ObservedComponent>,
+    right: &BTreeMap<String, ObservedComponent>,
+) -> Vec<ComponentDiff> {
+    let mut unmatched_left: BTreeMap<&String, &ObservedComponent> = left
+        .iter()
+        .filter(|(id, _)| !right.contains_key(*id))
+        .collect();
+    let mut diffs = Vec::new();
+    for (id, component) in right {
+        let counterpart = match left.get_key_value(id) {
+            Some(pair) => Some(pair),
+            None => {
+                let by_name = unmatched_left
+                    .iter()
+                    .find(|(_, other)| other.name == component.name)
+                    .map(|(other_id, _)| *other_id);
+                by_name
+                    .and_then(|other_id| unmatched_left.remove_entry(other_id))
+            }
+        };
+        diffs.extend(diff_pair(counterpart, Some((id, component))));
+    }
+    for removed in unmatched_left {
+        diffs.extend(diff_pair(Some(removed), None));
+    }
+    diffs.sort_by(|a, b| a.name().cmp(b.name()).then(a.id().cmp(b.id())));
+    diffs
+}
@@ -0,0 +1,153 @@
+// src/config_file.rs
+
+// Intention:
+// What the config files share: the directory they live in, and their INI-like format of
+// `key = value` lines, in `[name]` sections or not.
+
+// Design Choices:
+// - Config files live in `$XDG_CONFIG_HOME/situation`, else `~/.config/situation`, else the
+//   current directory. Most of them can also be named directly by an environment variable.
+// - One splitter (`split_sections`) for every file: blank lines and `#` comments are skipped and
+//   lines without `=` reported. `parse_entries` (no sections) and `parse_sections` (only
+//   sections, uniquely named) add the checks of each shape. What the keys mean, and which are
+//   missing, is left to each file's parser.
+// - Problems are `(line, message)` pairs, returned sorted by line as `line N: message`
+//   (`line_errors`), so a file with several mistakes is fixed in one go.
+
+use std::{
+    env,
+    path::PathBuf,
+};
+
+/// `$XDG_CONFIG_HOME/situation`, else `~/.config/situation`; `None` without either variable.
+pub fn config_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| {
+            env::var_os("HOME").map(|home| PathBuf::from(home).join(".config"))
+        })
+        .map(|dir| dir.join("situation"))
+}
+
+/// The path in `env_var` if set, else `file_name` in `config_dir`, else in the current
+/// directory.
+pub fn config_path(env_var: &str, file_name: &str) -> PathBuf {
+    if let Ok(path) = env::var(env_var)
+        && !path.trim().is_empty()
+    {
+        return PathBuf::from(path);
+    }
+    match config_dir() {
+        Some(dir) => dir.join(file_name),
+        None => PathBuf::from(file_name),
+    }
+}
+
+/// A `key = value` line, trimmed, with its line number (1-based).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry<'a> {
+    pub line: usize,
+    pub key: &'a str,
+    pub value: &'a str,
+}
+
+/// A `[name]` section, with the line of its header and its entries in file order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub name: &'a str,
+    pub line: usize,
+    pub entries: Vec<Entry<'a>>,
+}
+
+/// Splits a file without sections into its `key = value` lines (layout, theme).
+pub fn parse_entries<'a>(
+    text: &'a str,
+    errors: &mut Vec<(usize, String)>,
+) -> Vec<Entry<'a>> {
+    let (entries, sections) = split_sections(text, errors);
+    for section in sections {
+        errors.push((section.line, "expected `key = value`".to_string()));
+    }
+    entries
+}
+
+/// Splits a file into the entries before its first `[name]` header and its sections, in file
+/// order. Names are not checked, and a name may come back several times (keymap).
+pub fn split_sections<'a>(
+    text: &'a str,
+    errors: &mut Vec<(usize, String)>,
+) -> (Vec<Entry<'a>>, Vec<Section<'a>>) {
+    let mut leading = Vec::new();
+    let mut sections: Vec<Section> = Vec::new();
+    for (index, raw) in text.lines().enumerate() {
+        let line_no = index + 1;
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(name) =
+            line.strip_prefix('[').and_then(|l| l.strip_suffix(']'))
+        {
+            sections.push(Section {
+                name: name.trim(),
+                line: line_no,
+                entries: Vec::new(),
+            });
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            errors.push((line_no, "expected `key = value`".to_string()));
+            continue;
+        };
+        let entry = Entry {
+            line: line_no,
+            key: key.trim(),
+            value: value.trim(),
+        };
+        match sections.last_mut() {
+            Some(section) => section.entries.push(entry),
+            None => leading.push(entry),
+        }
+    }
+    (leading, sections)
+}
+
+/// Splits a file made only of named sections (profiles, workspaces). `kind` names a section in
+/// messages, e.g. `profile`. Sections with an empty or repeated name are still returned, after
+/// reporting them.
+pub fn parse_sections<'a>(
+    text: &'a str,
+    kind: &str,
+    errors: &mut Vec<(usize, String)>,
+) -> Vec<Section<'a>> {
+    let (leading, sections) = split_sections(text, errors);
+    for entry in leading {
+        errors.push((entry.line, "expected a `[name]` section".to_string()));
+    }
+    for (index, section) in sections.iter().enumerate() {
+        if section.name.is_empty() {
+            errors.push((section.line, format!("empty {} name", kind)));
+        } else if sections[..index].iter().any(|s| s.name == section.name) {
+            errors.push((
+                section.line,
+                format!("duplicate {} '{}'", kind, section.name),
+            ));
+        }
+    }
+    sections
+}
+
+/// `Ok(value)` without problems, else every problem as `line N: message`, in line order.
+pub fn line_errors<T>(
+    value: T,
+    mut errors: Vec<(usize, String)>,
+) -> Result<T, Vec<String>> {
+    if errors.is_empty() {
+        return Ok(value);
+    }
+    errors.sort_by_key(|(line, _)| *line);
+    Err(errors
+        .into_iter()
+        .map(|(line, message)| format!("line {}: {}", line, message))
+        .collect())
+}
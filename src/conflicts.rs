@@ -0,0 +1,293 @@
+// src/conflicts.rs
+
+// Intention:
+// Find components that more than one open change set modifies, and the props they set to
+// different values, so edits are not silently overwritten when one of them is applied.
+
+// Design Choices:
+// - Each open change set (status not `Applied` or `Abandoned`, HEAD excluded) is compared with
+//   HEAD by `compare::compare_components`; HEAD's components are loaded once, then the change
+//   sets one after another with `watch::observe_components`, so a scan never has more lookups
+//   in flight than the client's concurrency limit, however many change sets are open.
+// - Components are matched across change sets by their ID in HEAD, so components a change set
+//   adds never overlap. Deleting a component another change set modifies is reported as a
+//   conflict on its `to delete` flag.
+// - A prop path changed to the same value everywhere is an overlap but not a conflict.
+
+use std::{
+    collections::BTreeMap,
+    error::Error,
+};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    api_client::CancelToken,
+    api_models::ChangeSetSummary,
+    compare::{
+        ABSENT,
+        ChangeSetComparison,
+        ComponentDiff,
+        ComponentDiffKind,
+        compare_components,
+        find_head,
+        is_head,
+        prop_value_label,
+    },
+    logging::LogEntry,
+    watch::{
+        is_open_status,
+        observe_components,
+    },
+};
+
+/// Path reported when change sets disagree on deleting a component.
+pub const DELETION_PATH: &str = "to delete";
+
+/// What one change set sets a conflicting prop to (`None` if it removes the prop).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConflictingValue {
+    pub change_set: ChangeSetSummary,
+    pub value: Option<Value>,
+}
+
+/// A prop path that change sets set to different values.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PropConflict {
+    pub path: String,
+    pub values: Vec<ConflictingValue>,
+}
+
+impl PropConflict {
+    /// The value set by this change set, as shown in tables (`-` if removed or not set, masked
+    /// if it is a secret, see `compare::prop_value_label`).
+    pub fn value_label(&self, change_set_id: &str) -> String {
+        self.values
+            .iter()
+            .find(|v| v.change_set.id == change_set_id)
+            .and_then(|v| v.value.as_ref())
+            .map_or_else(
+                || ABSENT.to_string(),
+                |value| prop_value_label(&self.path, value),
+            )
+    }
+}
+
+/// A HEAD component modified in more than one open change set.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComponentOverlap {
+    /// The component's ID in HEAD.
+    pub id: String,
+    pub name: String,
+    /// The change sets modifying it, in list order.
+    pub change_sets: Vec<ChangeSetSummary>,
+    /// The prop paths set to different values, by path.
+    pub props: Vec<PropConflict>,
+}
+
+impl ComponentOverlap {
+    /// Whether the change sets disagree on any value, not just touch the same component.
+    pub fn has_conflicting_values(&self) -> bool {
+        !self.props.is_empty()
+    }
+
+    /// Whether this change set is one of those modifying the component.
+    pub fn involves(&self, change_set_id: &str) -> bool {
+        self.change_sets.iter().any(|cs| cs.id == change_set_id)
+    }
+
+    /// Names of the change sets modifying the component, other than this one.
+    pub fn others(&self, change_set_id: &str) -> Vec<&str> {
+        self.change_sets
+            .iter()
+            .filter(|cs| cs.id != change_set_id)
+            .map(|cs| cs.name.as_str())
+            .collect()
+    }
+}
+
+/// The components modified in more than one open change set.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ConflictReport {
+    /// The open change sets scanned.
+    pub change_sets: Vec<ChangeSetSummary>,
+    /// Sorted by component name.
+    pub components: Vec<ComponentOverlap>,
+}
+
+impl ConflictReport {
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// The overlaps this change set is part of.
+    pub fn involving(&self, change_set_id: &str) -> Vec<&ComponentOverlap> {
+        self.components
+            .iter()
+            .filter(|overlap| overlap.involves(change_set_id))
+            .collect()
+    }
+
+    /// Number of components this change set shares with other open change sets.
+    pub fn count_for(&self, change_set_id: &str) -> usize {
+        self.involving(change_set_id).len()
+    }
+
+    /// `N component(s) modified in more than one of N open change set(s), N with conflicting
+    /// values`.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} component(s) modified in more than one of {} open change set(s), {} with \
+             conflicting values",
+            self.components.len(),
+            self.change_sets.len(),
+            self.components
+                .iter()
+                .filter(|overlap| overlap.has_conflicting_values())
+                .count()
+        )
+    }
+}
+
+// What one change set does to a HEAD component: its prop values, and whether it deletes it.
+struct Edit<'a> {
+    change_set: &'a ChangeSetSummary,
+    diff: &'a ComponentDiff,
+}
+
+impl Edit<'_> {
+    fn deletes(&self) -> bool {
+        self.diff.kind == ComponentDiffKind::Removed
+            || self.diff.marked_for_deletion()
+    }
+}
+
+/// Finds the overlaps between comparisons of open change sets (right) with HEAD (left).
+pub fn find_conflicts(comparisons: &[ChangeSetComparison]) -> ConflictReport {
+    let mut edits: BTreeMap<&str, Vec<Edit>> = BTreeMap::new();
+    for comparison in comparisons {
+        for diff in &comparison.components {
+            if let Some(head_side) = &diff.left {
+                edits.entry(head_side.id.as_str()).or_default().push(Edit {
+                    change_set: &comparison.right,
+                    diff,
+                });
+            }
+        }
+    }
+
+    let mut components: Vec<ComponentOverlap> = edits
+        .into_iter()
+        .filter(|(_, edits)| edits.len() > 1)
+        .map(|(id, edits)| {
+            let mut paths: BTreeMap<&str, Vec<ConflictingValue>> =
+                BTreeMap::new();
+            for edit in &edits {
+                for prop in &edit.diff.props {
+                    paths.entry(prop.path.as_str()).or_default().push(
+                        ConflictingValue {
+                            change_set: edit.change_set.clone(),
+                            value: prop.right.clone(),
+                        },
+                    );
+                }
+            }
+            let mut props: Vec<PropConflict> = paths
+                .into_iter()
+                .filter(|(_, values)| {
+                    values.len() > 1
+                        && values.iter().any(|v| v.value != values[0].value)
+                })
+                .map(|(path, values)| PropConflict {
+                    path: path.to_string(),
+                    values,
+                })
+                .collect();
+            if edits.iter().any(Edit::deletes)
+                && !edits.iter().all(Edit::deletes)
+            {
+                props.insert(
+                    0,
+                    PropConflict {
+                        path: DELETION_PATH.to_string(),
+                        values: edits
+                            .iter()
+                            .map(|edit| ConflictingValue {
+                                change_set: edit.change_set.clone(),
+                                value: Some(Value::Bool(edit.deletes())),
+                            })
+                            .collect(),
+                    },
+                );
+            }
+            ComponentOverlap {
+                id: id.to_string(),
+                name: edits[0].diff.name().to_string(),
+                change_sets: edits
+                    .iter()
+                    .map(|edit| edit.change_set.clone())
+                    .collect(),
+                props,
+            }
+        })
+        .collect();
+    components.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+
+    ConflictReport {
+        change_sets: comparisons.iter().map(|c| c.right.clone()).collect(),
+        components,
+    }
+}
+
+/// The open change sets of the list (status not `Applied` or `Abandoned`), HEAD excluded.
+pub fn open_change_sets(
+    change_sets: &[ChangeSetSummary],
+) -> Vec<ChangeSetSummary> {
+    change_sets
+        .iter()
+        .filter(|cs| is_open_status(&cs.status) && !is_head(cs))
+        .cloned()
+        .collect()
+}
+
+/// Loads HEAD's components, then those of each change set in turn, and compares each change set
+/// with HEAD. One change set is loaded at a time, so at most `ClientSettings::concurrency`
+/// component lookups are in flight.
+pub async fn compare_with_head(
+    workspace_id: &str,
+    head: &ChangeSetSummary,
+    change_sets: &[ChangeSetSummary],
+    cancel: &CancelToken,
+) -> Result<
+    (Vec<ChangeSetComparison>, Vec<LogEntry>),
+    Box<dyn Error + Send + Sync>,
+> {
+    let (head_components, mut logs) =
+        observe_components(workspace_id, &head.id, cancel).await?;
+    let mut comparisons = Vec::with_capacity(change_sets.len());
+    for change_set in change_sets {
+        let (components, component_logs) =
+            observe_components(workspace_id, &change_set.id, cancel).await?;
+        logs.extend(component_logs);
+        comparisons.push(ChangeSetComparison {
+            components: compare_components(&head_components, &components),
+            left: head.clone(),
+            right: change_set.clone(),
+        });
+    }
+    Ok((comparisons, logs))
+}
+
+/// Loads HEAD and every open change set of the list and finds their overlaps.
+pub async fn scan_conflicts(
+    workspace_id: &str,
+    change_sets: &[ChangeSetSummary],
+    cancel: &CancelToken,
+) -> Result<(ConflictReport, Vec<LogEntry>), Box<dyn Error + Send + Sync>> {
+    let head = find_head(change_sets).ok_or("HEAD is not listed")?;
+    let open = open_change_sets(change_sets);
+    let (comparisons, logs) =
+        compare_with_head(workspace_id, head, &open, cancel).await?;
+    Ok((find_conflicts(&comparisons), logs))
+}
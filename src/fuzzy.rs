@@ -0,0 +1,125 @@
+// src/fuzzy.rs
+
+// Intention:
+// Provides the fuzzy matcher used to filter the command palette (change sets, schemas,
+// components and commands share one ranked list).
+
+// Design Choices:
+// - Plain subsequence matching, case-insensitive: every query character must appear in the
+//   candidate in order. No external crate needed for lists of this size.
+// - Scoring favours matches at word starts and consecutive runs, so "fa" ranks
+//   "Force Apply" above "Refresh all".
+// - Lives in the library so it can be unit tested from `tests/unit/`.
+
+// Score awarded for every matched character.
+const MATCH_SCORE: i64 = 16;
+// Bonus when a matched character directly follows the previous match.
+const CONSECUTIVE_BONUS: i64 = 12;
+// Bonus when a matched character starts a word (start, after a separator, or camelCase hump).
+const WORD_START_BONUS: i64 = 10;
+// Penalty per unmatched character between the first and last match.
+const GAP_PENALTY: i64 = 1;
+// Penalty per unmatched character before the first match, capped at `MAX_LEADING_PENALTY`.
+const LEADING_PENALTY: i64 = 2;
+const MAX_LEADING_PENALTY: i64 = 12;
+
+/// A successful fuzzy match of a query against a candidate string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Higher is better. Only comparable between matches of the same query.
+    pub score: i64,
+    /// Character (not byte) indices into the candidate that matched the query, ascending.
+    pub positions: Vec<usize>,
+}
+
+/// Matches `query` against `candidate`, ignoring case and whitespace in the query.
+///
+/// Returns `None` if the query characters do not all appear in order in the candidate.
+/// An empty query matches everything with a score of 0.
+///
+/// Every occurrence of the first query character is tried as a starting point and the
+/// best-scoring alignment is kept, so a later word-start match beats an earlier mid-word one.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+
+    let original: Vec<char> = candidate.chars().collect();
+    let lowered: Vec<char> = original
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    let mut best: Option<FuzzyMatch> = None;
+    for start in (0..lowered.len()).filter(|&i| lowered[i] == query[0]) {
+        let Some(positions) = align_from(&query, &lowered, start) else {
+            // No later start can succeed if this one ran out of characters
+            break;
+        };
+        let score = score_positions(&positions, &original);
+        if best.as_ref().is_none_or(|b| score > b.score) {
+            best = Some(FuzzyMatch { score, positions });
+        }
+    }
+    best
+}
+
+// Intention: Greedily align the query starting with the first character at `start`.
+// Design Choice: Takes the earliest hit for each remaining character. Trying every start
+// in `fuzzy_match` covers the common case where a later word start scores better.
+fn align_from(
+    query: &[char],
+    lowered: &[char],
+    start: usize,
+) -> Option<Vec<usize>> {
+    let mut positions = Vec::with_capacity(query.len());
+    positions.push(start);
+    let mut next = start + 1;
+    for &qc in &query[1..] {
+        let found = (next..lowered.len()).find(|&i| lowered[i] == qc)?;
+        positions.push(found);
+        next = found + 1;
+    }
+    Some(positions)
+}
+
+// Intention: Score an alignment using the bonuses and penalties defined above.
+fn score_positions(positions: &[usize], original: &[char]) -> i64 {
+    let mut score = 0;
+    let first = positions[0] as i64;
+    score -= (first * LEADING_PENALTY).min(MAX_LEADING_PENALTY);
+
+    let mut previous: Option<usize> = None;
+    for &pos in positions {
+        score += MATCH_SCORE;
+        if is_word_start(original, pos) {
+            score += WORD_START_BONUS;
+        }
+        if let Some(prev) = previous {
+            if pos == prev + 1 {
+                score += CONSECUTIVE_BONUS;
+            } else {
+                score -= (pos - prev - 1) as i64 * GAP_PENALTY;
+            }
+        }
+        previous = Some(pos);
+    }
+    score
+}
+
+fn is_word_start(chars: &[char], pos: usize) -> bool {
+    if pos == 0 {
+        return true;
+    }
+    let prev = chars[pos - 1];
+    let current = chars[pos];
+    !prev.is_alphanumeric() || (prev.is_lowercase() && current.is_uppercase())
+}
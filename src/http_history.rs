@@ -0,0 +1,128 @@
+// src/http_history.rs
+
+// Intention:
+// Keeps a session history of every HTTP request the API client made, for the TUI's request
+// inspector: method, URL, status, latency, size and the (redacted) request and response bodies.
+
+// Design Choices:
+// - Exchanges are recorded by the client's per-call `CallLog` when it is dropped, so requests
+//   that fail before a response (connection errors returned with `?`) are still listed.
+// - The history is process-wide (like the API configuration), bounded to `MAX_EXCHANGES`, and
+//   read by the TUI as a snapshot on each draw. No client function signature changes.
+// - Bodies are stored already redacted; `pretty_body` formats JSON for display.
+
+use std::{
+    collections::VecDeque,
+    sync::Mutex,
+    time::Duration,
+};
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+
+/// Maximum number of exchanges kept; the oldest are dropped first.
+pub const MAX_EXCHANGES: usize = 500;
+
+/// One request made by the API client and its response, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpExchange {
+    /// Request ID shared with the log entries of the call.
+    pub request_id: String,
+    /// The client function that made the request, e.g. `api::whoami`.
+    pub source: String,
+    pub started_at: DateTime<Utc>,
+    pub method: String,
+    pub url: String,
+    /// `None` when no response was received.
+    pub status: Option<u16>,
+    /// Time until the response body was read (or the call ended).
+    pub latency: Duration,
+    /// Response body size in bytes, from the body or `Content-Length`.
+    pub response_size: Option<usize>,
+    pub request_body: Option<String>,
+    pub response_body: Option<String>,
+}
+
+impl HttpExchange {
+    /// Whether the request got a 2xx response.
+    pub fn is_success(&self) -> bool {
+        self.status
+            .is_some_and(|status| (200..300).contains(&status))
+    }
+}
+
+/// A bounded, thread-safe list of exchanges, oldest first.
+#[derive(Debug)]
+pub struct HttpHistory {
+    capacity: usize,
+    exchanges: Mutex<VecDeque<HttpExchange>>,
+}
+
+impl HttpHistory {
+    pub const fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            exchanges: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// The history the API client records into.
+    pub fn global() -> &'static HttpHistory {
+        static GLOBAL: HttpHistory = HttpHistory::new(MAX_EXCHANGES);
+        &GLOBAL
+    }
+
+    pub fn record(&self, exchange: HttpExchange) {
+        if let Ok(mut exchanges) = self.exchanges.lock() {
+            exchanges.push_back(exchange);
+            while exchanges.len() > self.capacity {
+                exchanges.pop_front();
+            }
+        }
+    }
+
+    /// A copy of the recorded exchanges, oldest first.
+    pub fn snapshot(&self) -> Vec<HttpExchange> {
+        self.exchanges
+            .lock()
+            .map(|exchanges| exchanges.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.exchanges.lock().map_or(0, |exchanges| exchanges.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut exchanges) = self.exchanges.lock() {
+            exchanges.clear();
+        }
+    }
+}
+
+/// Pretty-prints a body if it is JSON, otherwise returns it unchanged.
+pub fn pretty_body(body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| body.to_string())
+}
+
+/// Formats a byte count for display (`512 B`, `1.5 KB`, `2.0 MB`).
+pub fn format_size(bytes: usize) -> String {
+    const KB: f64 = 1024.0;
+    let bytes_f = bytes as f64;
+    if bytes_f < KB {
+        format!("{} B", bytes)
+    } else if bytes_f < KB * KB {
+        format!("{:.1} KB", bytes_f / KB)
+    } else {
+        format!("{:.1} MB", bytes_f / (KB * KB))
+    }
+}
@@ -0,0 +1,100 @@
+// src/jwt.rs
+
+// Intention:
+// Read the claims of the session JWT locally, so the TUI can show how long the token is valid
+// and warn before it expires.
+
+// Design Choices:
+// - The signature is not verified: the claims are only used for display, and the server stays
+//   the authority on whether a token is accepted.
+// - Only the registered `exp` and `iat` claims are read; both are optional, as tokens without an
+//   expiry are valid JWTs.
+// - base64url is decoded here, as hex is in the token store, rather than adding a crate for it.
+
+use chrono::{
+    DateTime,
+    TimeDelta,
+    Utc,
+};
+use serde::Deserialize;
+
+/// How long before expiry the TUI starts warning.
+pub const EXPIRY_WARNING: TimeDelta = TimeDelta::minutes(10);
+
+/// The registered time claims of a JWT, as Unix timestamps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub struct JwtClaims {
+    pub exp: Option<i64>, // Expiry
+    pub iat: Option<i64>, // Issued at
+}
+
+impl JwtClaims {
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.exp.and_then(|exp| DateTime::from_timestamp(exp, 0))
+    }
+
+    pub fn issued_at(&self) -> Option<DateTime<Utc>> {
+        self.iat.and_then(|iat| DateTime::from_timestamp(iat, 0))
+    }
+}
+
+fn base64url_value(c: u8) -> Option<u32> {
+    match c {
+        b'A'..=b'Z' => Some((c - b'A') as u32),
+        b'a'..=b'z' => Some((c - b'a') as u32 + 26),
+        b'0'..=b'9' => Some((c - b'0') as u32 + 52),
+        b'-' => Some(62),
+        b'_' => Some(63),
+        _ => None,
+    }
+}
+
+// Decodes unpadded (or padded) base64url text
+fn from_base64url(text: &str) -> Option<Vec<u8>> {
+    let text = text.trim_end_matches('=');
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    for c in text.bytes() {
+        buffer = (buffer << 6) | base64url_value(c)?;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    // Left-over bits must be padding zeros; a single character left over is not valid
+    (bits < 6 && buffer == 0).then_some(bytes)
+}
+
+/// Decodes the time claims of `token` without verifying its signature.
+pub fn decode_jwt_claims(token: &str) -> Result<JwtClaims, String> {
+    let mut parts = token.trim().split('.');
+    let (Some(_header), Some(payload), Some(_signature), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(
+            "not a JWT (expected three dot-separated parts)".to_string()
+        );
+    };
+    let payload = from_base64url(payload)
+        .ok_or_else(|| "the JWT payload is not base64url".to_string())?;
+    serde_json::from_slice(&payload)
+        .map_err(|e| format!("the JWT payload is not valid claims: {}", e))
+}
+
+/// Time left before `expires_at` as a short label (`2d 4h`, `3h 05m`, `12m`, `45s`), or
+/// `expired`.
+pub fn format_time_left(
+    expires_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> String {
+    let secs = (expires_at - now).num_seconds();
+    match secs {
+        ..=0 => "expired".to_string(),
+        1..60 => format!("{}s", secs),
+        60..3_600 => format!("{}m", secs / 60),
+        3_600..86_400 => format!("{}h {:02}m", secs / 3_600, secs % 3_600 / 60),
+        _ => format!("{}d {}h", secs / 86_400, secs % 86_400 / 3_600),
+    }
+}
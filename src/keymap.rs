@@ -0,0 +1,358 @@
+// src/keymap.rs
+
+// Intention:
+// Maps keys to actions per focus context for the TUI, loaded from a keybindings file with the
+// built-in bindings as defaults. The on-screen help is generated from the same map.
+
+// Design Choices:
+// - The file is a small INI-like format (no extra dependency): a `[context]` section per focus
+//   and `action = key, key` lines. An action listed in the file replaces its default keys in that
+//   context; everything else keeps its default.
+//   ```text
+//   [global]
+//   quit = q, Ctrl-c
+//   [top_bar]
+//   abandon_change_set = D
+//   ```
+// - Lookups check the focused context first, then `[global]`.
+// - `conflicts` reports a key bound to two actions in one context, and a context key that hides a
+//   global binding for a different action. The TUI refuses a file with parse errors or conflicts
+//   at startup, logs why, and uses the defaults.
+
+mod action;
+mod chord;
+
+use std::{
+    env,
+    fmt,
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+pub use action::{
+    Action,
+    DEFAULT_BINDINGS,
+    KeyContext,
+};
+pub use chord::{
+    Key,
+    KeyChord,
+};
+
+use crate::config_file::{
+    Entry,
+    config_path,
+    split_sections,
+};
+
+/// Environment variable overriding the keybindings file path.
+pub const KEYMAP_FILE_ENV: &str = "SI_KEYMAP_FILE";
+
+/// The keys bound to one action in one context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Binding {
+    pub context: KeyContext,
+    pub action: Action,
+    pub keys: Vec<KeyChord>,
+}
+
+/// Why a keybindings file was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeymapError {
+    /// The file exists but could not be read.
+    Io(String),
+    /// A line (1-based) could not be understood.
+    Parse { line: usize, message: String },
+    /// `key` triggers both `first` and `second` in `context`. When `second` is bound in
+    /// `[global]`, the context binding hides it.
+    Conflict {
+        context: KeyContext,
+        key: KeyChord,
+        first: Action,
+        second: Action,
+        shadows_global: bool,
+    },
+}
+
+impl fmt::Display for KeymapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeymapError::Io(message) => write!(f, "{}", message),
+            KeymapError::Parse { line, message } => {
+                write!(f, "line {}: {}", line, message)
+            }
+            KeymapError::Conflict {
+                context,
+                key,
+                first,
+                second,
+                shadows_global,
+            } => {
+                if *shadows_global {
+                    write!(
+                        f,
+                        "[{}] '{}' is bound to {} and hides global {}",
+                        context.name(),
+                        key,
+                        first.name(),
+                        second.name()
+                    )
+                } else {
+                    write!(
+                        f,
+                        "[{}] '{}' is bound to both {} and {}",
+                        context.name(),
+                        key,
+                        first.name(),
+                        second.name()
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Key bindings for every context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: Vec<Binding>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = DEFAULT_BINDINGS
+            .iter()
+            .map(|(context, action, keys)| Binding {
+                context: *context,
+                action: *action,
+                keys: keys
+                    .iter()
+                    .map(|key| {
+                        KeyChord::parse(key).expect("default key binding")
+                    })
+                    .collect(),
+            })
+            .collect();
+        Self { bindings }
+    }
+}
+
+impl Keymap {
+    /// Applies a keybindings file to the defaults. Returns every parse error and conflict found.
+    pub fn parse(text: &str) -> Result<Keymap, Vec<KeymapError>> {
+        let mut keymap = Keymap::default();
+        let mut errors = Vec::new();
+        let (global, sections) = split_sections(text, &mut errors);
+        // Lines before the first section are global bindings
+        let mut contexts = vec![(Some(KeyContext::Global), global)];
+        for section in sections {
+            let context = KeyContext::from_name(section.name);
+            if context.is_none() {
+                errors.push((
+                    section.line,
+                    format!("unknown section [{}]", section.name),
+                ));
+            }
+            contexts.push((context, section.entries));
+        }
+        for (context, entries) in contexts {
+            for Entry { line, key, value } in entries {
+                let Some(action) = Action::from_name(key) else {
+                    errors.push((line, format!("unknown action '{}'", key)));
+                    continue;
+                };
+                let mut chords = Vec::new();
+                for key in value.split(',').filter(|k| !k.trim().is_empty()) {
+                    match KeyChord::parse(key) {
+                        Ok(chord) if !chords.contains(&chord) => {
+                            chords.push(chord)
+                        }
+                        Ok(_) => {}
+                        Err(message) => errors.push((line, message)),
+                    }
+                }
+                // Bindings under an unknown section are only checked
+                if let Some(context) = context {
+                    keymap.set(context, action, chords);
+                }
+            }
+        }
+        errors.sort_by_key(|(line, _)| *line);
+        let mut errors: Vec<KeymapError> = errors
+            .into_iter()
+            .map(|(line, message)| KeymapError::Parse { line, message })
+            .collect();
+        errors.extend(keymap.conflicts());
+        if errors.is_empty() {
+            Ok(keymap)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Loads a keybindings file. A missing file means the defaults.
+    pub fn load(path: &Path) -> Result<Keymap, Vec<KeymapError>> {
+        match fs::read_to_string(path) {
+            Ok(text) => Keymap::parse(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                Ok(Keymap::default())
+            }
+            Err(e) => Err(vec![KeymapError::Io(format!(
+                "failed to read {}: {}",
+                path.display(),
+                e
+            ))]),
+        }
+    }
+
+    /// `SI_KEYMAP_FILE`, else `$XDG_CONFIG_HOME/situation/keybindings.conf`, else
+    /// `~/.config/situation/keybindings.conf`.
+    pub fn default_path() -> PathBuf {
+        config_path(KEYMAP_FILE_ENV, "keybindings.conf")
+    }
+
+    /// Replaces the keys of `action` in `context` (an empty list unbinds it).
+    pub fn set(
+        &mut self,
+        context: KeyContext,
+        action: Action,
+        keys: Vec<KeyChord>,
+    ) {
+        match self
+            .bindings
+            .iter_mut()
+            .find(|b| b.context == context && b.action == action)
+        {
+            Some(binding) => binding.keys = keys,
+            None => self.bindings.push(Binding {
+                context,
+                action,
+                keys,
+            }),
+        }
+    }
+
+    pub fn bindings(&self) -> &[Binding] {
+        &self.bindings
+    }
+
+    fn action_in(
+        &self,
+        context: KeyContext,
+        chord: &KeyChord,
+    ) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|b| b.context == context && b.keys.contains(chord))
+            .map(|b| b.action)
+    }
+
+    /// The action `chord` triggers in `context`, falling back to the global bindings.
+    pub fn action_for(
+        &self,
+        context: KeyContext,
+        chord: &KeyChord,
+    ) -> Option<Action> {
+        self.action_in(context, chord)
+            .or_else(|| self.action_in(KeyContext::Global, chord))
+    }
+
+    /// The keys bound to `action` in `context`.
+    pub fn keys_for(&self, context: KeyContext, action: Action) -> &[KeyChord] {
+        self.bindings
+            .iter()
+            .find(|b| b.context == context && b.action == action)
+            .map_or(&[], |b| b.keys.as_slice())
+    }
+
+    /// The keys of `action` in `context` for display, e.g. `PageUp/K`; `-` when unbound.
+    pub fn describe(&self, context: KeyContext, action: Action) -> String {
+        let keys = self.keys_for(context, action);
+        if keys.is_empty() {
+            "-".to_string()
+        } else {
+            keys.iter()
+                .map(|k| k.to_string())
+                .collect::<Vec<_>>()
+                .join("/")
+        }
+    }
+
+    /// Keys bound to more than one action in a context, or hiding a global binding.
+    pub fn conflicts(&self) -> Vec<KeymapError> {
+        let mut conflicts = Vec::new();
+        for context in KeyContext::ALL {
+            let mut seen: Vec<(KeyChord, Action)> = Vec::new();
+            for binding in self.bindings.iter().filter(|b| b.context == context)
+            {
+                for key in &binding.keys {
+                    if let Some((_, other)) = seen
+                        .iter()
+                        .find(|(k, a)| k == key && *a != binding.action)
+                    {
+                        conflicts.push(KeymapError::Conflict {
+                            context,
+                            key: *key,
+                            first: *other,
+                            second: binding.action,
+                            shadows_global: false,
+                        });
+                    }
+                    seen.push((*key, binding.action));
+                    if context != KeyContext::Global
+                        && let Some(global) =
+                            self.action_in(KeyContext::Global, key)
+                        && global != binding.action
+                    {
+                        conflicts.push(KeymapError::Conflict {
+                            context,
+                            key: *key,
+                            first: binding.action,
+                            second: global,
+                            shadows_global: true,
+                        });
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// The help listing: for each context, `(keys, description)` of every bound action.
+    pub fn help(&self) -> Vec<(KeyContext, Vec<(String, &'static str)>)> {
+        KeyContext::ALL
+            .into_iter()
+            .map(|context| {
+                let entries = self
+                    .bindings
+                    .iter()
+                    .filter(|b| b.context == context && !b.keys.is_empty())
+                    .map(|b| {
+                        (
+                            self.describe(context, b.action),
+                            b.action.description(),
+                        )
+                    })
+                    .collect();
+                (context, entries)
+            })
+            .filter(|(_, entries): &(_, Vec<_>)| !entries.is_empty())
+            .collect()
+    }
+
+    /// The help for one context: its own bindings, then the global ones that also apply there.
+    pub fn help_for(
+        &self,
+        context: KeyContext,
+    ) -> Vec<(KeyContext, Vec<(String, &'static str)>)> {
+        let mut sections = self.help();
+        sections.retain(|(c, _)| *c == context || *c == KeyContext::Global);
+        // The focused context comes first; it is what the user is looking at
+        sections.sort_by_key(|(c, _)| *c == KeyContext::Global);
+        sections
+    }
+}
@@ -0,0 +1,423 @@
+// src/keymap/action.rs
+
+// Intention:
+// Lists the contexts keys are bound in and the actions they can be bound to, with the default
+// bindings.
+
+// Design Choices:
+// - `KeyContext` mirrors the TUI's `AppFocus` (plus the request inspector, which takes over the
+//   content area) so this module does not depend on the binary. `Global` bindings apply in every
+//   context unless the context binds the same key itself.
+// - Names are the snake_case identifiers used in the keybindings file; descriptions are shown in
+//   the generated help.
+// - Text entry (change set name, palette, searches) is not configurable: every printable key is
+//   input there.
+
+/// Where a binding applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyContext {
+    Global,
+    TopBar,
+    SchemaList,
+    ContentArea,
+    /// The content area while the request inspector is open.
+    Inspector,
+    LogPanel,
+    ChangeSetDropdown,
+    WorkspaceDropdown,
+}
+
+impl KeyContext {
+    /// All contexts, in help order.
+    pub const ALL: [KeyContext; 8] = [
+        KeyContext::Global,
+        KeyContext::TopBar,
+        KeyContext::WorkspaceDropdown,
+        KeyContext::ChangeSetDropdown,
+        KeyContext::SchemaList,
+        KeyContext::ContentArea,
+        KeyContext::Inspector,
+        KeyContext::LogPanel,
+    ];
+
+    /// Section name in the keybindings file.
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyContext::Global => "global",
+            KeyContext::TopBar => "top_bar",
+            KeyContext::SchemaList => "schema_list",
+            KeyContext::ContentArea => "content_area",
+            KeyContext::Inspector => "inspector",
+            KeyContext::LogPanel => "log_panel",
+            KeyContext::ChangeSetDropdown => "change_set_dropdown",
+            KeyContext::WorkspaceDropdown => "workspace_dropdown",
+        }
+    }
+
+    /// Heading in the generated help.
+    pub fn title(&self) -> &'static str {
+        match self {
+            KeyContext::Global => "Global",
+            KeyContext::TopBar => "Top Bar",
+            KeyContext::SchemaList => "Schema List",
+            KeyContext::ContentArea => "Details (Actions Table)",
+            KeyContext::Inspector => "Request Inspector",
+            KeyContext::LogPanel => "Log Panel",
+            KeyContext::ChangeSetDropdown => "Change Set Dropdown",
+            KeyContext::WorkspaceDropdown => "Workspace Dropdown",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<KeyContext> {
+        KeyContext::ALL.into_iter().find(|c| c.name() == name)
+    }
+}
+
+/// Something a key can do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    ShowHelp,
+    OpenPalette,
+    ToggleInspector,
+    CycleFocus,
+    FocusWorkspace,
+    FocusChangeSet,
+    FocusSchemas,
+    FocusLogs,
+    ScrollLogsUp,
+    ScrollLogsDown,
+    GrowLogPanel,
+    ShrinkLogPanel,
+    WidenSchemaList,
+    NarrowSchemaList,
+    ToggleSchemaList,
+    ToggleLogPanel,
+    ZoomPane,
+    ResetLayout,
+    SwitchTrigger,
+    ActivateTrigger,
+    CreateChangeSet,
+    AbandonChangeSet,
+    ForceApply,
+    DropdownPrevious,
+    DropdownNext,
+    DropdownConfirm,
+    DropdownClose,
+    SchemaPrevious,
+    SchemaNext,
+    CollapseCategory,
+    ExpandCategory,
+    ToggleCategory,
+    OpenSchema,
+    SearchSchemas,
+    ToggleInstalledOnly,
+    ActionPrevious,
+    ActionNext,
+    OpenComponent,
+    Back,
+    ConfirmApply,
+    ExportReview,
+    InspectorPrevious,
+    InspectorNext,
+    InspectorLatest,
+    InspectorScrollUp,
+    InspectorScrollDown,
+    CloseInspector,
+    CycleLogFilter,
+    CycleLogVerbosity,
+    SearchLogs,
+    ClearLogSearch,
+}
+
+impl Action {
+    pub const ALL: [Action; 52] = [
+        Action::Quit,
+        Action::ShowHelp,
+        Action::OpenPalette,
+        Action::ToggleInspector,
+        Action::CycleFocus,
+        Action::FocusWorkspace,
+        Action::FocusChangeSet,
+        Action::FocusSchemas,
+        Action::FocusLogs,
+        Action::ScrollLogsUp,
+        Action::ScrollLogsDown,
+        Action::GrowLogPanel,
+        Action::ShrinkLogPanel,
+        Action::WidenSchemaList,
+        Action::NarrowSchemaList,
+        Action::ToggleSchemaList,
+        Action::ToggleLogPanel,
+        Action::ZoomPane,
+        Action::ResetLayout,
+        Action::SwitchTrigger,
+        Action::ActivateTrigger,
+        Action::CreateChangeSet,
+        Action::AbandonChangeSet,
+        Action::ForceApply,
+        Action::DropdownPrevious,
+        Action::DropdownNext,
+        Action::DropdownConfirm,
+        Action::DropdownClose,
+        Action::SchemaPrevious,
+        Action::SchemaNext,
+        Action::CollapseCategory,
+        Action::ExpandCategory,
+        Action::ToggleCategory,
+        Action::OpenSchema,
+        Action::SearchSchemas,
+        Action::ToggleInstalledOnly,
+        Action::ActionPrevious,
+        Action::ActionNext,
+        Action::OpenComponent,
+        Action::Back,
+        Action::ConfirmApply,
+        Action::ExportReview,
+        Action::InspectorPrevious,
+        Action::InspectorNext,
+        Action::InspectorLatest,
+        Action::InspectorScrollUp,
+        Action::InspectorScrollDown,
+        Action::CloseInspector,
+        Action::CycleLogFilter,
+        Action::CycleLogVerbosity,
+        Action::SearchLogs,
+        Action::ClearLogSearch,
+    ];
+
+    /// Identifier in the keybindings file.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::ShowHelp => "show_help",
+            Action::OpenPalette => "open_palette",
+            Action::ToggleInspector => "toggle_inspector",
+            Action::CycleFocus => "cycle_focus",
+            Action::FocusWorkspace => "focus_workspace",
+            Action::FocusChangeSet => "focus_change_set",
+            Action::FocusSchemas => "focus_schemas",
+            Action::FocusLogs => "focus_logs",
+            Action::ScrollLogsUp => "scroll_logs_up",
+            Action::ScrollLogsDown => "scroll_logs_down",
+            Action::GrowLogPanel => "grow_log_panel",
+            Action::ShrinkLogPanel => "shrink_log_panel",
+            Action::WidenSchemaList => "widen_schema_list",
+            Action::NarrowSchemaList => "narrow_schema_list",
+            Action::ToggleSchemaList => "toggle_schema_list",
+            Action::ToggleLogPanel => "toggle_log_panel",
+            Action::ZoomPane => "zoom_pane",
+            Action::ResetLayout => "reset_layout",
+            Action::SwitchTrigger => "switch_trigger",
+            Action::ActivateTrigger => "activate_trigger",
+            Action::CreateChangeSet => "create_change_set",
+            Action::AbandonChangeSet => "abandon_change_set",
+            Action::ForceApply => "force_apply",
+            Action::DropdownPrevious => "dropdown_previous",
+            Action::DropdownNext => "dropdown_next",
+            Action::DropdownConfirm => "dropdown_confirm",
+            Action::DropdownClose => "dropdown_close",
+            Action::SchemaPrevious => "schema_previous",
+            Action::SchemaNext => "schema_next",
+            Action::CollapseCategory => "collapse_category",
+            Action::ExpandCategory => "expand_category",
+            Action::ToggleCategory => "toggle_category",
+            Action::OpenSchema => "open_schema",
+            Action::SearchSchemas => "search_schemas",
+            Action::ToggleInstalledOnly => "toggle_installed_only",
+            Action::ActionPrevious => "action_previous",
+            Action::ActionNext => "action_next",
+            Action::OpenComponent => "open_component",
+            Action::Back => "back",
+            Action::ConfirmApply => "confirm_apply",
+            Action::ExportReview => "export_review",
+            Action::InspectorPrevious => "inspector_previous",
+            Action::InspectorNext => "inspector_next",
+            Action::InspectorLatest => "inspector_latest",
+            Action::InspectorScrollUp => "inspector_scroll_up",
+            Action::InspectorScrollDown => "inspector_scroll_down",
+            Action::CloseInspector => "close_inspector",
+            Action::CycleLogFilter => "cycle_log_filter",
+            Action::CycleLogVerbosity => "cycle_log_verbosity",
+            Action::SearchLogs => "search_logs",
+            Action::ClearLogSearch => "clear_log_search",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Action::Quit => "Quit",
+            Action::ShowHelp => "Show Keys for the Focused Pane",
+            Action::OpenPalette => "Open Command Palette",
+            Action::ToggleInspector => "Open/Close Request Inspector",
+            Action::CycleFocus => {
+                "Cycle Focus (Top Bar -> Schemas -> Details -> Logs)"
+            }
+            Action::FocusWorkspace => "Focus Workspace Trigger",
+            Action::FocusChangeSet => "Focus Change Set Trigger",
+            Action::FocusSchemas => "Focus Schema List",
+            Action::FocusLogs => "Focus Log Panel",
+            Action::ScrollLogsUp => "Scroll Logs Up",
+            Action::ScrollLogsDown => "Scroll Logs Down",
+            Action::GrowLogPanel => "Grow Log Panel",
+            Action::ShrinkLogPanel => "Shrink Log Panel",
+            Action::WidenSchemaList => "Widen Schema List",
+            Action::NarrowSchemaList => "Narrow Schema List",
+            Action::ToggleSchemaList => "Collapse/Restore Schema List",
+            Action::ToggleLogPanel => "Collapse/Restore Log Panel",
+            Action::ZoomPane => "Zoom Focused Pane / Restore Layout",
+            Action::ResetLayout => "Reset Pane Layout",
+            Action::SwitchTrigger => "Switch Between Triggers",
+            Action::ActivateTrigger => {
+                "Activate Focused Trigger (Open Dropdown)"
+            }
+            Action::CreateChangeSet => "Create Change Set (Enter Input Mode)",
+            Action::AbandonChangeSet => "Delete Selected Change Set",
+            Action::ForceApply => "Force Apply Selected Change Set",
+            Action::DropdownPrevious => "Select Previous Item",
+            Action::DropdownNext => "Select Next Item",
+            Action::DropdownConfirm => "Confirm Selection & Close Dropdown",
+            Action::DropdownClose => "Close Dropdown",
+            Action::SchemaPrevious => "Select Previous Row",
+            Action::SchemaNext => "Select Next Row",
+            Action::CollapseCategory => "Collapse Category",
+            Action::ExpandCategory => "Expand Category",
+            Action::ToggleCategory => "Toggle Category",
+            Action::OpenSchema => "Toggle Category / Load Components",
+            Action::SearchSchemas => "Search Schemas",
+            Action::ToggleInstalledOnly => "Toggle Installed Only",
+            Action::ActionPrevious => "Select Previous Action",
+            Action::ActionNext => "Select Next Action",
+            Action::OpenComponent => "Open Action's Component",
+            Action::Back => {
+                "Back from Component, Comparison or Review / Dismiss Apply Summary"
+            }
+            Action::ConfirmApply => "Review Before Applying / Apply Reviewed",
+            Action::ExportReview => "Export Review as Markdown",
+            Action::InspectorPrevious => "Select Previous Request",
+            Action::InspectorNext => "Select Next Request",
+            Action::InspectorLatest => "Select Latest Request",
+            Action::InspectorScrollUp => "Scroll Request Details Up",
+            Action::InspectorScrollDown => "Scroll Request Details Down",
+            Action::CloseInspector => "Close Request Inspector",
+            Action::CycleLogFilter => "Cycle Level Filter",
+            Action::CycleLogVerbosity => "Cycle Verbosity",
+            Action::SearchLogs => "Search Logs",
+            Action::ClearLogSearch => "Clear Log Search",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Action> {
+        Action::ALL.into_iter().find(|a| a.name() == name)
+    }
+}
+
+/// The built-in bindings, in help order.
+pub const DEFAULT_BINDINGS: &[(KeyContext, Action, &[&str])] = &[
+    (KeyContext::Global, Action::Quit, &["q"]),
+    (KeyContext::Global, Action::ShowHelp, &["?", "F1"]),
+    (KeyContext::Global, Action::OpenPalette, &["Ctrl-p"]),
+    (KeyContext::Global, Action::ToggleInspector, &["F12"]),
+    (KeyContext::Global, Action::CycleFocus, &["Tab"]),
+    (KeyContext::Global, Action::FocusWorkspace, &["Alt-w"]),
+    (KeyContext::Global, Action::FocusChangeSet, &["Alt-c"]),
+    (KeyContext::Global, Action::FocusSchemas, &["Alt-s"]),
+    (KeyContext::Global, Action::FocusLogs, &["Alt-l"]),
+    (KeyContext::Global, Action::ScrollLogsUp, &["k"]),
+    (KeyContext::Global, Action::ScrollLogsDown, &["j"]),
+    (KeyContext::Global, Action::GrowLogPanel, &["Alt-Up"]),
+    (KeyContext::Global, Action::ShrinkLogPanel, &["Alt-Down"]),
+    (KeyContext::Global, Action::WidenSchemaList, &["Alt-Right"]),
+    (KeyContext::Global, Action::NarrowSchemaList, &["Alt-Left"]),
+    (KeyContext::Global, Action::ToggleSchemaList, &["Alt-1"]),
+    (KeyContext::Global, Action::ToggleLogPanel, &["Alt-2"]),
+    (KeyContext::Global, Action::ZoomPane, &["Alt-z"]),
+    (KeyContext::Global, Action::ResetLayout, &["Alt-0"]),
+    (
+        KeyContext::TopBar,
+        Action::SwitchTrigger,
+        &["Left", "Right"],
+    ),
+    (
+        KeyContext::TopBar,
+        Action::ActivateTrigger,
+        &["Enter", "Space"],
+    ),
+    (KeyContext::TopBar, Action::CreateChangeSet, &["c"]),
+    (KeyContext::TopBar, Action::AbandonChangeSet, &["d"]),
+    (KeyContext::TopBar, Action::ForceApply, &["f"]),
+    (
+        KeyContext::WorkspaceDropdown,
+        Action::DropdownPrevious,
+        &["Up"],
+    ),
+    (
+        KeyContext::WorkspaceDropdown,
+        Action::DropdownNext,
+        &["Down"],
+    ),
+    (
+        KeyContext::WorkspaceDropdown,
+        Action::DropdownConfirm,
+        &["Enter"],
+    ),
+    (
+        KeyContext::WorkspaceDropdown,
+        Action::DropdownClose,
+        &["Esc"],
+    ),
+    (
+        KeyContext::ChangeSetDropdown,
+        Action::DropdownPrevious,
+        &["Up"],
+    ),
+    (
+        KeyContext::ChangeSetDropdown,
+        Action::DropdownNext,
+        &["Down"],
+    ),
+    (
+        KeyContext::ChangeSetDropdown,
+        Action::DropdownConfirm,
+        &["Enter"],
+    ),
+    (
+        KeyContext::ChangeSetDropdown,
+        Action::DropdownClose,
+        &["Esc"],
+    ),
+    (KeyContext::SchemaList, Action::SchemaPrevious, &["Up"]),
+    (KeyContext::SchemaList, Action::SchemaNext, &["Down"]),
+    (KeyContext::SchemaList, Action::CollapseCategory, &["Left"]),
+    (KeyContext::SchemaList, Action::ExpandCategory, &["Right"]),
+    (KeyContext::SchemaList, Action::ToggleCategory, &["Space"]),
+    (KeyContext::SchemaList, Action::OpenSchema, &["Enter"]),
+    (KeyContext::SchemaList, Action::SearchSchemas, &["/"]),
+    (KeyContext::SchemaList, Action::ToggleInstalledOnly, &["i"]),
+    (KeyContext::ContentArea, Action::ActionPrevious, &["Up"]),
+    (KeyContext::ContentArea, Action::ActionNext, &["Down"]),
+    (KeyContext::ContentArea, Action::OpenComponent, &["Enter"]),
+    (KeyContext::ContentArea, Action::Back, &["Esc"]),
+    (KeyContext::ContentArea, Action::ConfirmApply, &["f"]),
+    (KeyContext::ContentArea, Action::ExportReview, &["m"]),
+    (KeyContext::Inspector, Action::InspectorPrevious, &["Up"]),
+    (KeyContext::Inspector, Action::InspectorNext, &["Down"]),
+    (KeyContext::Inspector, Action::InspectorLatest, &["End"]),
+    (
+        KeyContext::Inspector,
+        Action::InspectorScrollUp,
+        &["PageUp", "K"],
+    ),
+    (
+        KeyContext::Inspector,
+        Action::InspectorScrollDown,
+        &["PageDown", "J"],
+    ),
+    (KeyContext::Inspector, Action::CloseInspector, &["Esc"]),
+    (KeyContext::LogPanel, Action::ScrollLogsUp, &["Up"]),
+    (KeyContext::LogPanel, Action::ScrollLogsDown, &["Down"]),
+    (KeyContext::LogPanel, Action::CycleLogFilter, &["f"]),
+    (KeyContext::LogPanel, Action::CycleLogVerbosity, &["v"]),
+    (KeyContext::LogPanel, Action::SearchLogs, &["/"]),
+    (KeyContext::LogPanel, Action::ClearLogSearch, &["Esc"]),
+];
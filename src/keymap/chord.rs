@@ -0,0 +1,177 @@
+// src/keymap/chord.rs
+
+// Intention:
+// A key plus modifiers, as written in the keybindings file and as received from the terminal.
+
+// Design Choices:
+// - Only Ctrl and Alt are modifiers. Shift is carried by the character itself (`K` vs `k`),
+//   which is how terminals report it.
+// - Letters combined with Ctrl/Alt are stored lower case, so `Ctrl-P` and `Ctrl-p` are the same
+//   chord and match what the terminal sends.
+// - Names are case-insensitive (`pgup`, `PageUp`); `Display` writes the canonical form, which
+//   parses back to the same chord.
+
+use std::fmt;
+
+use crossterm::event::{
+    KeyCode,
+    KeyEvent,
+    KeyModifiers,
+};
+
+/// A key without modifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    Char(char),
+    Enter,
+    Esc,
+    Tab,
+    Backspace,
+    Delete,
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Home,
+    End,
+    F(u8),
+}
+
+/// A key with the Ctrl/Alt modifiers held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub key: Key,
+    pub ctrl: bool,
+    pub alt: bool,
+}
+
+impl KeyChord {
+    pub fn new(key: Key, ctrl: bool, alt: bool) -> Self {
+        let key = match key {
+            Key::Char(c) if ctrl || alt => Key::Char(c.to_ascii_lowercase()),
+            key => key,
+        };
+        Self { key, ctrl, alt }
+    }
+
+    /// Parses `q`, `Ctrl-p`, `Alt+w`, `Space`, `PageUp`, `F12`, ...
+    pub fn parse(text: &str) -> Result<KeyChord, String> {
+        let mut rest = text.trim();
+        let (mut ctrl, mut alt) = (false, false);
+        loop {
+            let lower = rest.to_ascii_lowercase();
+            // A lone "-" or "+" is the key itself, not a separator
+            if rest.len() > 5
+                && (lower.starts_with("ctrl-") || lower.starts_with("ctrl+"))
+            {
+                ctrl = true;
+                rest = &rest[5..];
+            } else if rest.len() > 4
+                && (lower.starts_with("alt-") || lower.starts_with("alt+"))
+            {
+                alt = true;
+                rest = &rest[4..];
+            } else {
+                break;
+            }
+        }
+        let key = parse_key(rest)
+            .ok_or_else(|| format!("unknown key '{}'", text.trim()))?;
+        Ok(KeyChord::new(key, ctrl, alt))
+    }
+
+    /// The chord of a terminal key event, ignoring Shift.
+    pub fn from_event(event: &KeyEvent) -> Option<KeyChord> {
+        let key = match event.code {
+            KeyCode::Char(c) => Key::Char(c),
+            KeyCode::Enter => Key::Enter,
+            KeyCode::Esc => Key::Esc,
+            KeyCode::Tab => Key::Tab,
+            KeyCode::Backspace => Key::Backspace,
+            KeyCode::Delete => Key::Delete,
+            KeyCode::Up => Key::Up,
+            KeyCode::Down => Key::Down,
+            KeyCode::Left => Key::Left,
+            KeyCode::Right => Key::Right,
+            KeyCode::PageUp => Key::PageUp,
+            KeyCode::PageDown => Key::PageDown,
+            KeyCode::Home => Key::Home,
+            KeyCode::End => Key::End,
+            KeyCode::F(n) => Key::F(n),
+            _ => return None,
+        };
+        Some(KeyChord::new(
+            key,
+            event.modifiers.contains(KeyModifiers::CONTROL),
+            event.modifiers.contains(KeyModifiers::ALT),
+        ))
+    }
+}
+
+fn parse_key(name: &str) -> Option<Key> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(Key::Char(c));
+    }
+    let lower = name.to_ascii_lowercase();
+    let key = match lower.as_str() {
+        "space" => Key::Char(' '),
+        "comma" => Key::Char(','),
+        "enter" | "return" => Key::Enter,
+        "esc" | "escape" => Key::Esc,
+        "tab" => Key::Tab,
+        "backspace" => Key::Backspace,
+        "delete" | "del" => Key::Delete,
+        "up" => Key::Up,
+        "down" => Key::Down,
+        "left" => Key::Left,
+        "right" => Key::Right,
+        "pageup" | "pgup" => Key::PageUp,
+        "pagedown" | "pgdn" => Key::PageDown,
+        "home" => Key::Home,
+        "end" => Key::End,
+        _ => {
+            let n: u8 = lower.strip_prefix('f')?.parse().ok()?;
+            return (1..=24).contains(&n).then_some(Key::F(n));
+        }
+    };
+    Some(key)
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Key::Char(' ') => f.write_str("Space"),
+            Key::Char(',') => f.write_str("Comma"),
+            Key::Char(c) => write!(f, "{}", c),
+            Key::Enter => f.write_str("Enter"),
+            Key::Esc => f.write_str("Esc"),
+            Key::Tab => f.write_str("Tab"),
+            Key::Backspace => f.write_str("Backspace"),
+            Key::Delete => f.write_str("Delete"),
+            Key::Up => f.write_str("Up"),
+            Key::Down => f.write_str("Down"),
+            Key::Left => f.write_str("Left"),
+            Key::Right => f.write_str("Right"),
+            Key::PageUp => f.write_str("PageUp"),
+            Key::PageDown => f.write_str("PageDown"),
+            Key::Home => f.write_str("Home"),
+            Key::End => f.write_str("End"),
+            Key::F(n) => write!(f, "F{}", n),
+        }
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            f.write_str("Ctrl-")?;
+        }
+        if self.alt {
+            f.write_str("Alt-")?;
+        }
+        write!(f, "{}", self.key)
+    }
+}
@@ -0,0 +1,284 @@
+// src/layout.rs
+
+// Intention:
+// Sizes and visibility of the TUI's panes (schema list, details, log panel): resizable and
+// collapsible with keys, one pane can be zoomed to fill the screen, and the layout is saved
+// between sessions.
+
+// Design Choices:
+// - `PaneLayout` is plain state plus `split`, which turns the area below the top bar into pane
+//   rectangles, so the maths is testable without a terminal. The stored sizes are clamped to the
+//   available space when splitting; resizing clamps them to the area last rendered, so a key
+//   press always has a visible effect.
+// - The layout file uses `key = value` lines like the other config files (no extra dependency).
+//   Problems are reported by line and the defaults are used. Zoom is not saved: a new session
+//   starts with every pane visible.
+
+use std::{
+    env,
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use ratatui::layout::Rect;
+
+use crate::config_file::{
+    Entry,
+    config_path,
+    line_errors,
+    parse_entries,
+};
+
+/// Environment variable overriding the layout file path.
+pub const LAYOUT_FILE_ENV: &str = "SI_LAYOUT_FILE";
+
+/// Height of the log panel, borders included, before it is resized.
+pub const DEFAULT_LOG_HEIGHT: u16 = 10;
+/// Width of the schema list, borders included, before it is resized.
+pub const DEFAULT_SCHEMA_WIDTH: u16 = 30;
+
+// Smallest sizes a pane can be resized to: its borders plus a line (or a few columns)
+const MIN_LOG_HEIGHT: u16 = 3;
+const MIN_SCHEMA_WIDTH: u16 = 12;
+// Space always left to the details pane when resizing its neighbours
+const MIN_CONTENT_HEIGHT: u16 = 5;
+const MIN_CONTENT_WIDTH: u16 = 20;
+
+/// A resizable region of the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pane {
+    SchemaList,
+    Content,
+    LogPanel,
+}
+
+/// Where each pane is drawn. A hidden pane gets an empty `Rect`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PaneAreas {
+    pub schema_list: Rect,
+    pub content: Rect,
+    pub log_panel: Rect,
+}
+
+/// The user's pane layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaneLayout {
+    pub log_height: u16,
+    pub schema_width: u16,
+    pub log_collapsed: bool,
+    pub schema_collapsed: bool,
+    /// The pane filling the whole area, if any.
+    pub zoomed: Option<Pane>,
+}
+
+impl Default for PaneLayout {
+    fn default() -> Self {
+        PaneLayout {
+            log_height: DEFAULT_LOG_HEIGHT,
+            schema_width: DEFAULT_SCHEMA_WIDTH,
+            log_collapsed: false,
+            schema_collapsed: false,
+            zoomed: None,
+        }
+    }
+}
+
+// Intention: Clamp `size` to `min..=total - reserved`, never above `total`.
+fn clamp_size(size: u16, min: u16, total: u16, reserved: u16) -> u16 {
+    size.min(total.saturating_sub(reserved)).max(min).min(total)
+}
+
+impl PaneLayout {
+    /// Splits `area` (everything between the top bar and the input line) into the panes.
+    pub fn split(&self, area: Rect) -> PaneAreas {
+        if let Some(pane) = self.zoomed {
+            let mut areas = PaneAreas::default();
+            match pane {
+                Pane::SchemaList => areas.schema_list = area,
+                Pane::Content => areas.content = area,
+                Pane::LogPanel => areas.log_panel = area,
+            }
+            return areas;
+        }
+
+        let log_height = self.log_height_in(area);
+        let middle = Rect {
+            height: area.height - log_height,
+            ..area
+        };
+        let log_panel = if log_height == 0 {
+            Rect::default()
+        } else {
+            Rect {
+                y: area.y + middle.height,
+                height: log_height,
+                ..area
+            }
+        };
+
+        let schema_width = self.schema_width_in(middle);
+        let schema_list = if schema_width == 0 {
+            Rect::default()
+        } else {
+            Rect {
+                width: schema_width,
+                ..middle
+            }
+        };
+        let content = Rect {
+            x: middle.x + schema_width,
+            width: middle.width - schema_width,
+            ..middle
+        };
+        PaneAreas {
+            schema_list,
+            content,
+            log_panel,
+        }
+    }
+
+    fn log_height_in(&self, area: Rect) -> u16 {
+        if self.log_collapsed {
+            return 0;
+        }
+        clamp_size(
+            self.log_height,
+            MIN_LOG_HEIGHT,
+            area.height,
+            MIN_CONTENT_HEIGHT,
+        )
+    }
+
+    fn schema_width_in(&self, middle: Rect) -> u16 {
+        if self.schema_collapsed {
+            return 0;
+        }
+        clamp_size(
+            self.schema_width,
+            MIN_SCHEMA_WIDTH,
+            middle.width,
+            MIN_CONTENT_WIDTH,
+        )
+    }
+
+    /// Whether `pane` is collapsed. The details pane never is.
+    pub fn is_collapsed(&self, pane: Pane) -> bool {
+        match pane {
+            Pane::SchemaList => self.schema_collapsed,
+            Pane::LogPanel => self.log_collapsed,
+            Pane::Content => false,
+        }
+    }
+
+    /// Grows (positive `delta`) or shrinks the log panel, showing it if it was collapsed.
+    /// `area` is the area last split, which bounds the new height.
+    pub fn resize_log(&mut self, delta: i16, area: Rect) {
+        self.log_collapsed = false;
+        let current = self.log_height_in(area);
+        let target = current.saturating_add_signed(delta);
+        self.log_height =
+            clamp_size(target, MIN_LOG_HEIGHT, area.height, MIN_CONTENT_HEIGHT);
+    }
+
+    /// Widens (positive `delta`) or narrows the schema list, showing it if it was collapsed.
+    pub fn resize_schema(&mut self, delta: i16, area: Rect) {
+        self.schema_collapsed = false;
+        // The schema list shares the full width with the details pane
+        let target = self.schema_width_in(area).saturating_add_signed(delta);
+        self.schema_width =
+            clamp_size(target, MIN_SCHEMA_WIDTH, area.width, MIN_CONTENT_WIDTH);
+    }
+
+    /// Collapses or restores the schema list or the log panel. The details pane always stays.
+    pub fn toggle_collapsed(&mut self, pane: Pane) {
+        match pane {
+            Pane::SchemaList => self.schema_collapsed = !self.schema_collapsed,
+            Pane::LogPanel => self.log_collapsed = !self.log_collapsed,
+            Pane::Content => {}
+        }
+    }
+
+    /// Zooms `pane` to fill the area, or restores the layout if it is already zoomed.
+    pub fn toggle_zoom(&mut self, pane: Pane) {
+        self.zoomed = if self.zoomed == Some(pane) {
+            None
+        } else {
+            Some(pane)
+        };
+    }
+
+    /// Parses a layout file. Keys not listed keep their default.
+    pub fn parse(text: &str) -> Result<PaneLayout, Vec<String>> {
+        let mut layout = PaneLayout::default();
+        let mut errors = Vec::new();
+        for Entry { line, key, value } in parse_entries(text, &mut errors) {
+            let result = match key {
+                "log_height" => value
+                    .parse()
+                    .map(|v| layout.log_height = v)
+                    .map_err(|_| format!("invalid height '{}'", value)),
+                "schema_width" => value
+                    .parse()
+                    .map(|v| layout.schema_width = v)
+                    .map_err(|_| format!("invalid width '{}'", value)),
+                "log_collapsed" => {
+                    value.parse().map(|v| layout.log_collapsed = v).map_err(
+                        |_| format!("expected true or false, got '{}'", value),
+                    )
+                }
+                "schema_collapsed" => {
+                    value.parse().map(|v| layout.schema_collapsed = v).map_err(
+                        |_| format!("expected true or false, got '{}'", value),
+                    )
+                }
+                _ => Err(format!("unknown key '{}'", key)),
+            };
+            if let Err(message) = result {
+                errors.push((line, message));
+            }
+        }
+        line_errors(layout, errors)
+    }
+
+    /// The layout file's contents for this layout (without the zoom).
+    pub fn to_file_string(&self) -> String {
+        format!(
+            "log_height = {}\nschema_width = {}\nlog_collapsed = {}\nschema_collapsed = {}\n",
+            self.log_height,
+            self.schema_width,
+            self.log_collapsed,
+            self.schema_collapsed,
+        )
+    }
+
+    /// Loads the layout file at `path`; a missing file gives the default layout.
+    pub fn load(path: &Path) -> Result<PaneLayout, Vec<String>> {
+        match fs::read_to_string(path) {
+            Ok(text) => PaneLayout::parse(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                Ok(PaneLayout::default())
+            }
+            Err(e) => Err(vec![e.to_string()]),
+        }
+    }
+
+    /// Writes the layout file, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent()
+            && !dir.as_os_str().is_empty()
+        {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_file_string())
+    }
+
+    /// `SI_LAYOUT_FILE`, else `$XDG_CONFIG_HOME/situation/layout.conf`, else
+    /// `~/.config/situation/layout.conf`.
+    pub fn default_path() -> PathBuf {
+        config_path(LAYOUT_FILE_ENV, "layout.conf")
+    }
+}
@@ -8,7 +8,125 @@
 // Declare and make modules public so they can be used by main.rs and tests.
 pub mod api_client;
 pub mod api_models;
+pub mod apply_monitor;
+pub mod auto_refresh;
+pub mod compare;
+pub mod config_file;
+pub mod conflicts;
+pub mod fuzzy;
+pub mod http_history;
+pub mod jwt;
+pub mod keymap;
+pub mod layout;
+pub mod logging;
+pub mod profiles;
+pub mod redact;
+pub mod review;
+pub mod schema_tree;
+pub mod snapshot;
+pub mod theme;
+pub mod token_store;
+pub mod watch;
+pub mod workspaces;
 
 // Re-export key items for easier use (optional but good practice)
 pub use api_client::*;
 pub use api_models::*;
+pub use apply_monitor::{
+    ApplyMonitor,
+    ApplyMonitorOptions,
+    ApplyOutcome,
+    ApplySummary,
+};
+pub use auto_refresh::{
+    AutoRefresh,
+    changed_actions,
+    changed_change_sets,
+};
+pub use compare::{
+    ChangeSetComparison,
+    ComponentDiff,
+    ComponentDiffKind,
+    compare_change_sets,
+};
+pub use conflicts::{
+    ConflictReport,
+    scan_conflicts,
+};
+pub use fuzzy::{
+    FuzzyMatch,
+    fuzzy_match,
+};
+pub use http_history::{
+    HttpExchange,
+    HttpHistory,
+    format_size,
+    pretty_body,
+};
+pub use jwt::{
+    JwtClaims,
+    decode_jwt_claims,
+    format_time_left,
+};
+pub use keymap::{
+    Action,
+    KeyChord,
+    KeyContext,
+    Keymap,
+    KeymapError,
+};
+pub use layout::{
+    Pane,
+    PaneAreas,
+    PaneLayout,
+};
+pub use logging::{
+    LogEntry,
+    LogFile,
+    LogLevel,
+};
+pub use profiles::{
+    Profile,
+    load_profiles,
+    parse_profiles,
+    profiles_path,
+    select_profile,
+};
+pub use redact::{
+    Redactor,
+    looks_like_secret,
+    redact_text,
+};
+pub use review::{
+    ApplyReview,
+    load_review,
+};
+pub use schema_tree::{
+    SchemaTreeOptions,
+    SchemaTreeRow,
+    build_schema_tree,
+    count_components_by_schema,
+};
+pub use snapshot::{
+    ChangeSetSnapshot,
+    SnapshotComponent,
+    WorkspaceSnapshot,
+};
+pub use theme::Theme;
+pub use token_store::{
+    TokenStore,
+    token_store_passphrase,
+    token_store_path,
+};
+pub use watch::{
+    WatchEvent,
+    WatchOptions,
+    WatchScope,
+    Watcher,
+};
+pub use workspaces::{
+    WorkspaceEntry,
+    load_workspaces,
+    parse_workspaces,
+    workspaces_path,
+};
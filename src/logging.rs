@@ -0,0 +1,280 @@
+// src/logging.rs
+
+// Intention:
+// Defines the structured log entry shared by the API client, the apply monitor, the TUI log
+// panel and the persistent log file.
+
+// Design Choices:
+// - Every entry carries a level, a UTC timestamp, a source (which part of the program logged it)
+//   and, for API calls, the request ID the entry belongs to. The TUI filters on these fields
+//   instead of parsing prefixes such as "DEBUG:" out of strings.
+// - Request and response bodies are logged at `Trace`, so the verbosity setting decides whether
+//   they are kept at all. The default verbosity (`Info`) drops them.
+// - `LogFile` appends one line per entry and is shared behind `Arc<Mutex<_>>` so the state that
+//   owns it can stay `Clone`.
+
+use std::{
+    env,
+    fmt,
+    fs::{
+        self,
+        File,
+        OpenOptions,
+    },
+    io::{
+        self,
+        Write,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use chrono::{
+    DateTime,
+    SecondsFormat,
+    Utc,
+};
+
+/// Environment variable selecting the verbosity (`trace`, `debug`, `info`, `warn`, `error`).
+pub const LOG_LEVEL_ENV: &str = "SI_LOG_LEVEL";
+/// Environment variable overriding the log file path.
+pub const LOG_FILE_ENV: &str = "SI_LOG_FILE";
+
+/// Severity of a log entry, ordered from most to least verbose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// Request and response bodies.
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 5] = [
+        LogLevel::Trace,
+        LogLevel::Debug,
+        LogLevel::Info,
+        LogLevel::Warn,
+        LogLevel::Error,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "TRACE",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+
+    /// Parses a level name, ignoring case. Accepts `warning` for `Warn`.
+    pub fn parse(value: &str) -> Option<LogLevel> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "trace" => Some(LogLevel::Trace),
+            "debug" => Some(LogLevel::Debug),
+            "info" => Some(LogLevel::Info),
+            "warn" | "warning" => Some(LogLevel::Warn),
+            "error" => Some(LogLevel::Error),
+            _ => None,
+        }
+    }
+
+    /// The next level in `ALL`, wrapping around. Used to cycle filters from a single key.
+    pub fn next(self) -> LogLevel {
+        let i = LogLevel::ALL.iter().position(|l| *l == self).unwrap_or(0);
+        LogLevel::ALL[(i + 1) % LogLevel::ALL.len()]
+    }
+
+    /// Reads the verbosity from `SI_LOG_LEVEL`, defaulting to `Info` when unset or invalid.
+    pub fn verbosity_from_env() -> LogLevel {
+        env::var(LOG_LEVEL_ENV)
+            .ok()
+            .and_then(|value| LogLevel::parse(&value))
+            .unwrap_or(LogLevel::Info)
+    }
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single structured log entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    pub timestamp: DateTime<Utc>,
+    pub level: LogLevel,
+    /// Where the entry came from, e.g. `api::whoami` or `app`.
+    pub source: String,
+    pub message: String,
+    /// Set on entries logged while making an API request, to group them per request.
+    pub request_id: Option<String>,
+}
+
+impl LogEntry {
+    /// Creates an entry timestamped now.
+    pub fn new(
+        level: LogLevel,
+        source: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            level,
+            source: source.into(),
+            message: message.into(),
+            request_id: None,
+        }
+    }
+
+    pub fn trace(
+        source: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::new(LogLevel::Trace, source, message)
+    }
+
+    pub fn debug(
+        source: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::new(LogLevel::Debug, source, message)
+    }
+
+    pub fn info(source: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(LogLevel::Info, source, message)
+    }
+
+    pub fn warn(source: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(LogLevel::Warn, source, message)
+    }
+
+    pub fn error(
+        source: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::new(LogLevel::Error, source, message)
+    }
+
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// Whether the entry passes a minimum level and a case-insensitive text search.
+    /// The search matches the message, the source and the request ID.
+    pub fn matches(&self, min_level: LogLevel, query: &str) -> bool {
+        if self.level < min_level {
+            return false;
+        }
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self.message.to_lowercase().contains(&query)
+            || self.source.to_lowercase().contains(&query)
+            || self
+                .request_id
+                .as_ref()
+                .is_some_and(|id| id.to_lowercase().contains(&query))
+    }
+
+    /// Formats the entry as a single line for the log file.
+    /// Format: `<RFC 3339 timestamp> <LEVEL> [<source>] (<request id>) <message>`,
+    /// with the request ID part omitted when absent. Newlines in the message are escaped.
+    pub fn to_line(&self) -> String {
+        let request = self
+            .request_id
+            .as_ref()
+            .map(|id| format!(" ({})", id))
+            .unwrap_or_default();
+        format!(
+            "{} {:<5} [{}]{} {}",
+            self.timestamp.to_rfc3339_opts(SecondsFormat::Millis, true),
+            self.level.as_str(),
+            self.source,
+            request,
+            self.message.replace('\n', "\\n")
+        )
+    }
+}
+
+/// Plain messages are application `Info` entries, so existing `String` call sites keep working.
+impl From<String> for LogEntry {
+    fn from(message: String) -> Self {
+        LogEntry::info("app", message)
+    }
+}
+
+impl From<&str> for LogEntry {
+    fn from(message: &str) -> Self {
+        LogEntry::info("app", message)
+    }
+}
+
+/// An append-only log file receiving one line per entry.
+#[derive(Debug, Clone)]
+pub struct LogFile {
+    path: PathBuf,
+    file: Arc<Mutex<File>>,
+}
+
+impl LogFile {
+    /// Opens (creating parent directories and the file if needed) `path` for appending.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        Ok(Self {
+            path,
+            file: Arc::new(Mutex::new(file)),
+        })
+    }
+
+    /// The default log file location.
+    /// Order: `SI_LOG_FILE`, `$XDG_STATE_HOME/situation/situation.log`,
+    /// `$HOME/.local/state/situation/situation.log`, then `situation.log` in the working directory.
+    pub fn default_path() -> PathBuf {
+        if let Ok(path) = env::var(LOG_FILE_ENV)
+            && !path.trim().is_empty()
+        {
+            return PathBuf::from(path);
+        }
+        let state_dir = env::var_os("XDG_STATE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| {
+                env::var_os("HOME")
+                    .map(|home| PathBuf::from(home).join(".local/state"))
+            });
+        match state_dir {
+            Some(dir) => dir.join("situation").join("situation.log"),
+            None => PathBuf::from("situation.log"),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Appends one entry and flushes, so the file is complete even if the program crashes.
+    pub fn append(&self, entry: &LogEntry) -> io::Result<()> {
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| io::Error::other("log file lock poisoned"))?;
+        writeln!(file, "{}", entry.to_line())?;
+        file.flush()
+    }
+}
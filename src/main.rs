@@ -1,19 +1,23 @@
 // Intention: Define the application entry point and terminal setup/teardown.
 // Design Choice: This file now only contains the `main` function.
-// It declares the other modules (`app`, `refresh_change_sets`, `run_app`, `ui`)
-// and calls `run_app::run_app` to start the TUI.
+// It declares the other modules (`app`, `cli`, `refresh_change_sets`, `run_app`, `ui`)
+// and either runs a CLI subcommand or calls `run_app::run_app` to start the TUI.
 
 // Declare modules created from splitting the original main.rs
 mod app;
+mod cli;
 mod refresh_change_sets;
 mod run_app;
 mod ui;
 
 use std::{
+    env,
     error::Error,
     io,
+    process,
 };
 
+use cli::CliCommand;
 use crossterm::{
     event::{
         DisableMouseCapture,
@@ -33,12 +37,52 @@ use ratatui::{
 };
 // Use the run_app function from the newly created module
 use run_app::run_app;
-use tokio;
+use situation::select_profile;
 
 // Intention: Entry point for the TUI application.
 // Design Choice: Using tokio::main for the async `run_app` function.
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    // Intention: Run a CLI subcommand instead of the TUI when one is given.
+    // Design Choice: Subcommands exit with their own status code and never touch the terminal mode.
+    let args: Vec<String> = env::args().skip(1).collect();
+    let invocation = match cli::parse_args(&args) {
+        Ok(invocation) => invocation,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, cli::USAGE);
+            process::exit(cli::EXIT_USAGE);
+        }
+    };
+
+    // Intention: Apply the selected profile before any API call, for the TUI and subcommands.
+    // Design Choice: A profile that cannot be used is fatal; falling back to `.env` could point
+    // at a different backend than the one asked for. Commands without API calls skip it.
+    let selected = if invocation.command.uses_api() {
+        select_profile(invocation.profile.as_deref())
+    } else {
+        Ok(None)
+    };
+    let profile = match selected {
+        Ok(profile) => profile,
+        Err(errors) => {
+            for error in errors {
+                eprintln!("{}", error);
+            }
+            process::exit(cli::EXIT_ERROR);
+        }
+    };
+    if let Some(profile) = &profile
+        && let Err(e) = profile.activate()
+    {
+        eprintln!("Cannot use profile '{}': {}", profile.name, e);
+        process::exit(cli::EXIT_ERROR);
+    }
+
+    match invocation.command {
+        CliCommand::Tui => {}
+        command => process::exit(cli::run_command(command).await),
+    }
+
     // Intention: Set up the terminal for TUI rendering.
     // Design Choice: Enable raw mode and enter alternate screen for a clean TUI experience.
     // Ensure terminal is restored even on panic.
@@ -50,7 +94,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     // Intention: Run the main application loop by calling the function from the run_app module.
     // Design Choice: Pass the terminal instance to the run_app function.
-    let res = run_app(&mut terminal).await;
+    let res = run_app(&mut terminal, profile).await;
 
     // Intention: Restore the terminal to its original state after the application exits.
     // Design Choice: Disable raw mode, leave alternate screen, and disable mouse capture.
@@ -0,0 +1,241 @@
+// src/profiles.rs
+
+// Intention:
+// Named configuration profiles (e.g. dev, staging, prod), each with its own API URL, token
+// source, timeouts, retry policy and read-only flag, selected with `--profile` or `SI_PROFILE`.
+
+// Design Choices:
+// - The profiles file is INI-like, as the workspaces file: a `[name]` section per profile, split
+//   by `config_file::parse_sections`. Problems are reported by line (or by section, for missing
+//   keys).
+//   ```text
+//   [prod]
+//   api = https://prod.example.com/api
+//   token_command = pass show si/prod-jwt
+//   connect_timeout = 5
+//   timeout = 30
+//   retries = 5
+//   read_only = true
+//   production = true
+//   ```
+// - The token comes from exactly one of `token`, `token_env`, `token_file`, `token_command` or
+//   `token_store` (see `TokenSource`); a second one in the same profile is an error.
+// - Timeouts are whole seconds (default 10 to connect, 60 for the whole request); `0` means no
+//   timeout. `retries` is how often a failed read is retried (default 3, `0` = never);
+//   `retry_mutations = true` retries changes too. `read_only`, `retry_mutations` and
+//   `production` default to `false`. `production` only changes how the TUI looks; `read_only`
+//   is enforced by the client. `concurrency` caps the component lookups in flight at once
+//   (default 8).
+// - Without a selected profile the `.env` credentials and default client settings are used, as
+//   before profiles existed.
+
+use std::{
+    env,
+    error::Error,
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::Duration,
+};
+
+use dotenvy::dotenv;
+
+use crate::{
+    api_client::{
+        self,
+        ClientSettings,
+        Credentials,
+        TokenSource,
+    },
+    config_file::{
+        Entry,
+        Section,
+        config_path,
+        line_errors,
+        parse_sections,
+    },
+};
+
+/// Environment variable overriding the profiles file path.
+pub const PROFILES_FILE_ENV: &str = "SI_PROFILES_FILE";
+
+/// Environment variable selecting a profile when `--profile` is not given.
+pub const PROFILE_ENV: &str = "SI_PROFILE";
+
+/// A named set of connection settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub name: String,
+    pub api_url: String,
+    pub token: TokenSource,
+    pub settings: ClientSettings,
+    pub production: bool, // Shown with a warning banner in the TUI
+}
+
+impl Profile {
+    /// The profile's API URL with its token read from the token source.
+    pub fn credentials(&self) -> Result<Credentials, String> {
+        Ok(Credentials {
+            api_url: self.api_url.clone(),
+            token: self.token.read()?,
+            source: self.token.can_change().then(|| self.token.clone()),
+        })
+    }
+
+    /// Makes every following API call use this profile's settings and credentials.
+    pub fn activate(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let credentials = self.credentials()?;
+        api_client::use_settings(self.settings.clone())?;
+        api_client::use_credentials(&credentials)
+    }
+}
+
+fn parse_bool(value: &str) -> Result<bool, String> {
+    match value {
+        "true" | "yes" | "on" => Ok(true),
+        "false" | "no" | "off" => Ok(false),
+        _ => Err(format!("expected true or false, got '{}'", value)),
+    }
+}
+
+fn parse_timeout(value: &str) -> Result<Option<Duration>, String> {
+    let secs = value
+        .parse::<u64>()
+        .map_err(|_| format!("expected whole seconds, got '{}'", value))?;
+    Ok((secs > 0).then(|| Duration::from_secs(secs)))
+}
+
+/// Parses a profiles file.
+pub fn parse_profiles(text: &str) -> Result<Vec<Profile>, Vec<String>> {
+    let mut errors: Vec<(usize, String)> = Vec::new();
+    let sections = parse_sections(text, "profile", &mut errors);
+    let profiles = sections
+        .iter()
+        .filter_map(|section| parse_profile(section, &mut errors))
+        .collect();
+    line_errors(profiles, errors)
+}
+
+// The profile of one section, or `None` after reporting why there is none.
+fn parse_profile(
+    section: &Section,
+    errors: &mut Vec<(usize, String)>,
+) -> Option<Profile> {
+    let (mut api, mut token) = (None, None);
+    let mut settings = ClientSettings::default();
+    let mut production = false;
+    for &Entry { line, key, value } in &section.entries {
+        if let Some(source) = TokenSource::from_key(key, value) {
+            if token.is_some() {
+                errors.push((
+                    line,
+                    format!(
+                        "`{}`: profile '{}' already has a token source",
+                        key, section.name
+                    ),
+                ));
+            } else {
+                token = Some(source);
+            }
+            continue;
+        }
+        let result = match key {
+            "api" => {
+                api = Some(value.to_string());
+                Ok(())
+            }
+            "connect_timeout" => parse_timeout(value)
+                .map(|timeout| settings.connect_timeout = timeout),
+            "timeout" => parse_timeout(value)
+                .map(|timeout| settings.request_timeout = timeout),
+            "retries" => value
+                .parse::<u32>()
+                .map(|retries| settings.retry.max_retries = retries)
+                .map_err(|_| {
+                    format!("expected a whole number, got '{}'", value)
+                }),
+            "concurrency" => match value.parse::<usize>() {
+                Ok(limit) if limit > 0 => {
+                    settings.concurrency = limit;
+                    Ok(())
+                }
+                _ => {
+                    Err(format!("expected a positive number, got '{}'", value))
+                }
+            },
+            "retry_mutations" => parse_bool(value)
+                .map(|flag| settings.retry.retry_mutations = flag),
+            "read_only" => {
+                parse_bool(value).map(|flag| settings.read_only = flag)
+            }
+            "production" => parse_bool(value).map(|flag| production = flag),
+            _ => Err(format!("unknown key '{}'", key)),
+        };
+        if let Err(message) = result {
+            errors.push((line, message));
+        }
+    }
+    let missing =
+        |key: &str| format!("profile '{}' has no `{}`", section.name, key);
+    if api.is_none() {
+        errors.push((section.line, missing("api")));
+    }
+    if token.is_none() {
+        errors.push((section.line, missing("token")));
+    }
+    Some(Profile {
+        name: section.name.to_string(),
+        api_url: api?,
+        token: token?,
+        settings,
+        production,
+    })
+}
+
+/// Loads the profiles file at `path`; a missing file means no profiles.
+pub fn load_profiles(path: &Path) -> Result<Vec<Profile>, Vec<String>> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse_profiles(&text),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(vec![e.to_string()]),
+    }
+}
+
+/// `SI_PROFILES_FILE`, else `$XDG_CONFIG_HOME/situation/profiles.conf`, else
+/// `~/.config/situation/profiles.conf`.
+pub fn profiles_path() -> PathBuf {
+    config_path(PROFILES_FILE_ENV, "profiles.conf")
+}
+
+/// Finds the profile named on the command line, else in `SI_PROFILE` (which may be set in
+/// `.env`). Returns `Ok(None)` when no profile is selected.
+pub fn select_profile(
+    cli_name: Option<&str>,
+) -> Result<Option<Profile>, Vec<String>> {
+    dotenv().ok(); // Load .env file, ignore errors if it doesn't exist
+    let name = match cli_name {
+        Some(name) => name.to_string(),
+        None => match env::var(PROFILE_ENV) {
+            Ok(name) if !name.trim().is_empty() => name.trim().to_string(),
+            _ => return Ok(None),
+        },
+    };
+    let path = profiles_path();
+    let profiles = load_profiles(&path).map_err(|errors| {
+        errors
+            .into_iter()
+            .map(|e| format!("{}: {}", path.display(), e))
+            .collect::<Vec<_>>()
+    })?;
+    match profiles.into_iter().find(|p| p.name == name) {
+        Some(profile) => Ok(Some(profile)),
+        None => Err(vec![format!(
+            "profile '{}' not found in {}",
+            name,
+            path.display()
+        )]),
+    }
+}
@@ -0,0 +1,399 @@
+// src/redact.rs
+
+// Intention:
+// Masks credentials in everything the API client logs (bodies, headers, error messages), so
+// screen-sharing the TUI or sharing the log file does not leak component secrets.
+
+// Design Choices:
+// - Two complementary rules: keys whose name looks sensitive (`password`, `token`, ...) have
+//   their values masked, and values that look like secrets on their own (JWTs, PEM private keys,
+//   AWS access keys, known token prefixes, long mixed-case random strings) are masked wherever
+//   they appear.
+// - Component properties come as `{ "path": ".../password", "value": ... }`, so an object whose
+//   `path`/`name`/`key` field names a sensitive key has its `value` masked too.
+// - Text containing JSON (e.g. `API Success Body: {...}`) is parsed and redacted structurally;
+//   anything else is scanned for `key: value` / `key=value` pairs and secret-looking tokens.
+// - Extra key patterns come from `SI_REDACT_KEYS` (comma-separated) on top of the defaults.
+//   Over-masking is preferred to leaking, so key patterns match as substrings.
+
+use std::{
+    env,
+    sync::OnceLock,
+};
+
+use serde_json::Value;
+
+/// Environment variable with extra comma-separated key patterns to redact.
+pub const REDACT_KEYS_ENV: &str = "SI_REDACT_KEYS";
+/// Replacement text for redacted values.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Key name fragments treated as sensitive by default. Matched against the key lowercased with
+/// `_`, `-` and `.` removed, so `api_key`, `API-Key` and `apiKey` all match `apikey`.
+pub const DEFAULT_KEY_PATTERNS: &[&str] = &[
+    "password",
+    "passwd",
+    "passphrase",
+    "secret",
+    "token",
+    "apikey",
+    "accesskey",
+    "privatekey",
+    "credential",
+    "authorization",
+    "cookie",
+    "sessionid",
+    "signature",
+];
+
+/// Fields naming the property an object's `value` belongs to.
+const NAME_FIELDS: &[&str] = &["path", "name", "key", "propPath"];
+
+/// Well-known prefixes of provider tokens.
+const TOKEN_PREFIXES: &[&str] = &[
+    "ghp_",
+    "gho_",
+    "ghs_",
+    "github_pat_",
+    "glpat-",
+    "xoxb-",
+    "xoxp-",
+    "sk_live_",
+    "rk_live_",
+    "sk-",
+    "AIza",
+];
+
+/// Masks secrets in log text, JSON values and headers.
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    key_patterns: Vec<String>,
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self::new(std::iter::empty::<&str>())
+    }
+}
+
+impl Redactor {
+    /// Creates a redactor using the default key patterns plus `extra_patterns`.
+    pub fn new<I, S>(extra_patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut key_patterns: Vec<String> =
+            DEFAULT_KEY_PATTERNS.iter().map(|p| p.to_string()).collect();
+        for pattern in extra_patterns {
+            let pattern = normalize_key(pattern.as_ref());
+            if !pattern.is_empty() && !key_patterns.contains(&pattern) {
+                key_patterns.push(pattern);
+            }
+        }
+        Self { key_patterns }
+    }
+
+    /// Creates a redactor with the extra patterns listed in `SI_REDACT_KEYS`.
+    pub fn from_env() -> Self {
+        let extra = env::var(REDACT_KEYS_ENV).unwrap_or_default();
+        Self::new(extra.split(','))
+    }
+
+    /// The process-wide redactor used by the API client, read from the environment once.
+    pub fn global() -> &'static Redactor {
+        static GLOBAL: OnceLock<Redactor> = OnceLock::new();
+        GLOBAL.get_or_init(Redactor::from_env)
+    }
+
+    /// Whether a key (JSON field, header name, property path) names a sensitive value.
+    /// For paths such as `root/domain/password` only the last segment is considered.
+    pub fn is_sensitive_key(&self, key: &str) -> bool {
+        let last = key.rsplit(['/', '.']).next().unwrap_or(key);
+        let key = normalize_key(last);
+        !key.is_empty()
+            && self.key_patterns.iter().any(|p| key.contains(p.as_str()))
+    }
+
+    /// Masks secrets in a JSON value in place.
+    pub fn redact_json(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                let names_secret = NAME_FIELDS.iter().any(|field| {
+                    map.get(*field)
+                        .and_then(Value::as_str)
+                        .is_some_and(|name| self.is_sensitive_key(name))
+                });
+                for (key, child) in map.iter_mut() {
+                    if self.is_sensitive_key(key)
+                        || (names_secret && key == "value")
+                    {
+                        mask_all(child);
+                    } else {
+                        self.redact_json(child);
+                    }
+                }
+            }
+            Value::Array(items) => {
+                items.iter_mut().for_each(|item| self.redact_json(item))
+            }
+            Value::String(s) if looks_like_secret(s) => {
+                *s = REDACTED.to_string();
+            }
+            _ => {}
+        }
+    }
+
+    /// Masks secrets in a line of log text.
+    /// If the text is (or ends with) a JSON document, it is redacted structurally and
+    /// re-serialized; otherwise `key: value` pairs and secret-looking tokens are masked.
+    pub fn redact_text(&self, text: &str) -> String {
+        if let Some(start) = text.find(['{', '['])
+            && let Ok(mut value) = serde_json::from_str::<Value>(&text[start..])
+        {
+            self.redact_json(&mut value);
+            return format!("{}{}", &text[..start], value);
+        }
+        mask_secret_tokens(&self.redact_pairs(&mask_pem_blocks(text)))
+    }
+
+    /// Masks a header value if the header name is sensitive, otherwise scans the value.
+    pub fn redact_header(&self, name: &str, value: &str) -> String {
+        if self.is_sensitive_key(name) {
+            REDACTED.to_string()
+        } else {
+            self.redact_text(value)
+        }
+    }
+
+    // Masks the value after every `key:` / `key=` whose key is sensitive. Quoted keys and
+    // values are handled, as are `Some("...")`-style wrappers from `Debug` output.
+    fn redact_pairs(&self, text: &str) -> String {
+        let chars: Vec<char> = text.chars().collect();
+        let mut out = String::with_capacity(text.len());
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            out.push(c);
+            i += 1;
+            if (c != ':' && c != '=') || !self.key_before(&chars[..i - 1]) {
+                continue;
+            }
+            // Copy whitespace and `Wrapper(` prefixes, then mask the value itself
+            while i < chars.len() && chars[i].is_whitespace() {
+                out.push(chars[i]);
+                i += 1;
+            }
+            loop {
+                let word_end = (i..chars.len())
+                    .find(|&j| !chars[j].is_alphanumeric())
+                    .unwrap_or(chars.len());
+                if word_end > i && chars.get(word_end) == Some(&'(') {
+                    out.extend(&chars[i..=word_end]);
+                    i = word_end + 1;
+                } else {
+                    break;
+                }
+            }
+            let end = skip_value(&chars, i);
+            if end > i {
+                out.push_str(REDACTED);
+            }
+            i = end;
+        }
+        out
+    }
+
+    // Whether `before` ends with a sensitive key, optionally quoted and followed by spaces.
+    fn key_before(&self, before: &[char]) -> bool {
+        let mut end = before.len();
+        while end > 0 && before[end - 1].is_whitespace() {
+            end -= 1;
+        }
+        if end > 0 && matches!(before[end - 1], '"' | '\'') {
+            end -= 1;
+        }
+        let start = (0..end)
+            .rev()
+            .find(|&j| !is_key_char(before[j]))
+            .map_or(0, |j| j + 1);
+        let key: String = before[start..end].iter().collect();
+        self.is_sensitive_key(&key)
+    }
+}
+
+/// Masks secrets in `text` using the process-wide redactor.
+pub fn redact_text(text: &str) -> String {
+    Redactor::global().redact_text(text)
+}
+
+/// Whether a value looks like a credential regardless of its key.
+pub fn looks_like_secret(value: &str) -> bool {
+    let value = value.trim();
+    let lower = value.to_ascii_lowercase();
+    if let Some(credentials) = lower
+        .strip_prefix("bearer ")
+        .or_else(|| lower.strip_prefix("basic "))
+    {
+        return !credentials.trim().is_empty();
+    }
+    if value.contains("-----BEGIN") && value.contains("PRIVATE KEY") {
+        return true;
+    }
+    if value.chars().any(char::is_whitespace) {
+        return false;
+    }
+    is_jwt(value)
+        || is_aws_access_key_id(value)
+        || (value.len() >= 20
+            && TOKEN_PREFIXES
+                .iter()
+                .any(|prefix| value.starts_with(prefix)))
+        || is_random_looking(value)
+}
+
+fn normalize_key(key: &str) -> String {
+    key.chars()
+        .filter(|c| !matches!(c, '_' | '-' | '.' | ' '))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn is_key_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '/')
+}
+
+// Characters that can make up a token in free text (base64url, JWT dots). `/` is left out so
+// URL paths full of IDs are not mistaken for one long random string.
+fn is_token_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '+' | '_' | '-' | '.')
+}
+
+// Three dot-separated base64url segments, the first being a JSON header (`eyJ` = `{"`).
+fn is_jwt(value: &str) -> bool {
+    let segments: Vec<&str> = value.split('.').collect();
+    value.starts_with("eyJ")
+        && value.len() >= 20
+        && segments.len() == 3
+        && segments.iter().take(2).all(|s| !s.is_empty())
+        && segments.iter().all(|s| {
+            s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        })
+}
+
+fn is_aws_access_key_id(value: &str) -> bool {
+    value.len() == 20
+        && (value.starts_with("AKIA") || value.starts_with("ASIA"))
+        && value
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+// Long strings mixing upper case, lower case and digits. IDs (ULIDs are upper case and digits)
+// and hex hashes (lower case and digits) do not qualify.
+fn is_random_looking(value: &str) -> bool {
+    value.len() >= 32
+        && value
+            .chars()
+            .all(|c| is_token_char(c) || matches!(c, '/' | '='))
+        && value.chars().any(|c| c.is_ascii_uppercase())
+        && value.chars().any(|c| c.is_ascii_lowercase())
+        && value.chars().any(|c| c.is_ascii_digit())
+}
+
+// Replaces every value below a sensitive key, keeping the structure (and nulls) visible.
+fn mask_all(value: &mut Value) {
+    match value {
+        Value::Object(map) => map.values_mut().for_each(mask_all),
+        Value::Array(items) => items.iter_mut().for_each(mask_all),
+        Value::Null => {}
+        _ => *value = Value::String(REDACTED.to_string()),
+    }
+}
+
+// Returns the index just past the value starting at `i`: a quoted string, or a run up to
+// whitespace or a delimiter. `Bearer`/`Basic` schemes take the following word too.
+fn skip_value(chars: &[char], mut i: usize) -> usize {
+    if let Some(&quote @ ('"' | '\'')) = chars.get(i) {
+        i += 1;
+        while i < chars.len() && chars[i] != quote {
+            i += if chars[i] == '\\' { 2 } else { 1 };
+        }
+        return (i + 1).min(chars.len());
+    }
+    let run_end = |from: usize| {
+        (from..chars.len())
+            .find(|&j| {
+                chars[j].is_whitespace()
+                    || matches!(chars[j], ',' | ';' | '&' | ')' | '}' | ']')
+            })
+            .unwrap_or(chars.len())
+    };
+    let end = run_end(i);
+    let word: String = chars[i..end].iter().collect();
+    if matches!(word.to_ascii_lowercase().as_str(), "bearer" | "basic")
+        && chars.get(end) == Some(&' ')
+    {
+        return run_end(end + 1);
+    }
+    end
+}
+
+// Masks every run of token characters (plus `=` padding) that looks like a secret.
+fn mask_secret_tokens(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut token = String::new();
+    let flush = |token: &mut String, out: &mut String| {
+        if looks_like_secret(token) {
+            out.push_str(REDACTED);
+        } else {
+            out.push_str(token);
+        }
+        token.clear();
+    };
+    for c in text.chars() {
+        if is_token_char(c) || (c == '=' && !token.is_empty()) {
+            if c != '=' && token.ends_with('=') {
+                // `=` only continues a token as trailing padding
+                flush(&mut token, &mut out);
+            }
+            token.push(c);
+        } else {
+            flush(&mut token, &mut out);
+            out.push(c);
+        }
+    }
+    flush(&mut token, &mut out);
+    out
+}
+
+// Replaces PEM private key blocks, which span several whitespace-separated lines.
+fn mask_pem_blocks(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("-----BEGIN") {
+        let header_end =
+            rest[start + 10..].find("-----").map(|i| start + 10 + i + 5);
+        let Some(header_end) = header_end else {
+            break;
+        };
+        if !rest[start..header_end].contains("PRIVATE KEY") {
+            out.push_str(&rest[..header_end]);
+            rest = &rest[header_end..];
+            continue;
+        }
+        let block_end = rest[header_end..]
+            .find("-----END")
+            .and_then(|i| {
+                let footer = header_end + i + 8;
+                rest[footer..].find("-----").map(|j| footer + j + 5)
+            })
+            .unwrap_or(rest.len());
+        out.push_str(&rest[..start]);
+        out.push_str(REDACTED);
+        rest = &rest[block_end..];
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,308 @@
+// src/review.rs
+
+// Intention:
+// Summarise what applying a change set will change relative to HEAD, for the review screen
+// shown before a force apply and for change-review tickets (Markdown).
+
+// Design Choices:
+// - Built from a `ChangeSetComparison` with HEAD on the left and the merge status of the change
+//   set: components it adds are created, components gone from it or marked `toDelete` in it
+//   are deleted, the rest that differ are modified.
+// - Components the change set shares with other open change sets are added from a
+//   `ConflictReport` after it is built, since finding them needs every open change set.
+//   `load_review` scans them with the same comparisons, so HEAD and the change set are not
+//   loaded twice.
+// - The Markdown takes the time it was generated as a parameter, so it is reproducible in tests.
+
+use std::{
+    error::Error,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+
+use crate::{
+    api_client::{
+        self,
+        CancelToken,
+    },
+    api_models::{
+        ChangeSetSummary,
+        MergeStatusV1ResponseAction,
+    },
+    compare::{
+        ChangeSetComparison,
+        ComponentDiff,
+        ComponentDiffKind,
+        find_head,
+    },
+    conflicts::{
+        ComponentOverlap,
+        ConflictReport,
+        compare_with_head,
+        find_conflicts,
+        open_change_sets,
+    },
+    logging::LogEntry,
+};
+
+/// Environment variable naming the directory review exports are written to.
+pub const REVIEW_DIR_ENV: &str = "SI_REVIEW_DIR";
+
+/// What applying a change set will change relative to HEAD.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApplyReview {
+    pub head: ChangeSetSummary,
+    pub change_set: ChangeSetSummary,
+    pub created: Vec<ComponentDiff>,
+    pub modified: Vec<ComponentDiff>,
+    pub deleted: Vec<ComponentDiff>,
+    /// Queued actions, grouped by kind.
+    pub actions: Vec<MergeStatusV1ResponseAction>,
+    /// Components also modified in other open change sets.
+    pub conflicts: Vec<ComponentOverlap>,
+}
+
+impl ApplyReview {
+    /// Sorts the components of a comparison with HEAD (left) into created, modified and deleted.
+    pub fn new(
+        comparison: ChangeSetComparison,
+        actions: Vec<MergeStatusV1ResponseAction>,
+    ) -> Self {
+        let (mut created, mut modified, mut deleted) =
+            (Vec::new(), Vec::new(), Vec::new());
+        for diff in comparison.components {
+            match diff.kind {
+                ComponentDiffKind::Added => created.push(diff),
+                ComponentDiffKind::Removed => deleted.push(diff),
+                ComponentDiffKind::Modified if diff.marked_for_deletion() => {
+                    deleted.push(diff)
+                }
+                ComponentDiffKind::Modified => modified.push(diff),
+            }
+        }
+        Self {
+            head: comparison.left,
+            change_set: comparison.right,
+            created,
+            modified,
+            deleted,
+            actions,
+            conflicts: Vec::new(),
+        }
+    }
+
+    /// Keeps the overlaps of a conflict scan that this change set is part of.
+    pub fn with_conflicts(mut self, report: &ConflictReport) -> Self {
+        self.conflicts = report
+            .involving(&self.change_set.id)
+            .into_iter()
+            .cloned()
+            .collect();
+        self
+    }
+
+    /// Whether applying would change nothing and run nothing.
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty()
+            && self.modified.is_empty()
+            && self.deleted.is_empty()
+            && self.actions.is_empty()
+    }
+
+    /// `N created, N modified, N deleted, N queued action(s)`.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} created, {} modified, {} deleted, {} queued action(s)",
+            self.created.len(),
+            self.modified.len(),
+            self.deleted.len(),
+            self.actions.len()
+        )
+    }
+
+    /// The review as a Markdown document: the summary, the components shared with other open
+    /// change sets (if any) and the values they conflict on, a table of differing values per created
+    /// and modified component, the deleted components and the queued actions.
+    pub fn to_markdown(&self, generated_at: DateTime<Utc>) -> String {
+        let mut out = format!(
+            "# Apply review: {} (`{}`)\n\nCompared with {} (`{}`) at {}.\n\n{}.\n",
+            self.change_set.name,
+            self.change_set.id,
+            self.head.name,
+            self.head.id,
+            generated_at.format("%Y-%m-%d %H:%M:%S UTC"),
+            self.summary()
+        );
+        if !self.conflicts.is_empty() {
+            out.push_str(&format!(
+                "\n## Conflicts with other open change sets ({})\n",
+                self.conflicts.len()
+            ));
+            for overlap in &self.conflicts {
+                out.push_str(&format!(
+                    "\n### {} (`{}`)\n\nAlso modified in: {}.\n\n",
+                    overlap.name,
+                    overlap.id,
+                    overlap.others(&self.change_set.id).join(", ")
+                ));
+                if !overlap.has_conflicting_values() {
+                    out.push_str("No conflicting values.\n");
+                    continue;
+                }
+                out.push_str("| Prop | Change set | Value |\n|---|---|---|\n");
+                for prop in &overlap.props {
+                    for value in &prop.values {
+                        out.push_str(&format!(
+                            "| `{}` | {} | {} |\n",
+                            markdown_cell(&prop.path),
+                            markdown_cell(&value.change_set.name),
+                            markdown_cell(
+                                &prop.value_label(&value.change_set.id)
+                            )
+                        ));
+                    }
+                }
+            }
+        }
+        for (title, diffs) in
+            [("Created", &self.created), ("Modified", &self.modified)]
+        {
+            out.push_str(&format!("\n## {} ({})\n", title, diffs.len()));
+            for diff in diffs {
+                out.push_str(&format!(
+                    "\n### {} (`{}`)\n\n",
+                    diff.name(),
+                    diff.id()
+                ));
+                let rows = diff.rows();
+                if rows.is_empty() {
+                    out.push_str("No property or connection changes.\n");
+                    continue;
+                }
+                out.push_str(&format!(
+                    "| Prop / connection | {} | {} |\n|---|---|---|\n",
+                    markdown_cell(&self.head.name),
+                    markdown_cell(&self.change_set.name)
+                ));
+                for row in rows {
+                    out.push_str(&format!(
+                        "| `{}` | {} | {} |\n",
+                        markdown_cell(&row.label),
+                        markdown_cell(&row.left),
+                        markdown_cell(&row.right)
+                    ));
+                }
+            }
+        }
+        out.push_str(&format!("\n## Deleted ({})\n\n", self.deleted.len()));
+        for diff in &self.deleted {
+            out.push_str(&format!("- {} (`{}`)\n", diff.name(), diff.id()));
+        }
+        out.push_str(&format!(
+            "\n## Queued actions ({})\n",
+            self.actions.len()
+        ));
+        if !self.actions.is_empty() {
+            out.push_str(
+                "\n| Kind | Action | Component | State |\n|---|---|---|---|\n",
+            );
+            for action in &self.actions {
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    markdown_cell(&action.kind),
+                    markdown_cell(&action.name),
+                    markdown_cell(
+                        action
+                            .component
+                            .as_ref()
+                            .map_or("-", |c| c.name.as_str())
+                    ),
+                    markdown_cell(&action.state)
+                ));
+            }
+        }
+        out
+    }
+
+    /// File name of an export: the change set name (characters other than letters, digits,
+    /// `-` and `_` replaced) and the time.
+    pub fn export_path_in(
+        &self,
+        dir: &Path,
+        generated_at: DateTime<Utc>,
+    ) -> PathBuf {
+        let name: String = self
+            .change_set
+            .name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        dir.join(format!(
+            "review-{}-{}.md",
+            name,
+            generated_at.format("%Y%m%d-%H%M%S")
+        ))
+    }
+
+    /// Directory exports are written to: `SI_REVIEW_DIR`, else the current directory.
+    pub fn export_dir() -> PathBuf {
+        std::env::var(REVIEW_DIR_ENV)
+            .ok()
+            .filter(|dir| !dir.is_empty())
+            .map_or_else(|| PathBuf::from("."), PathBuf::from)
+    }
+}
+
+// Table cells cannot contain pipes or line breaks.
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+/// Loads the review of `change_set` against HEAD with its conflicts, and the conflict report of
+/// the open change sets of `change_sets`. HEAD and each change set are loaded once
+/// (`compare_with_head`), alongside the change set's merge status.
+pub async fn load_review(
+    workspace_id: &str,
+    change_sets: &[ChangeSetSummary],
+    change_set: &ChangeSetSummary,
+    cancel: &CancelToken,
+) -> Result<
+    (ApplyReview, ConflictReport, Vec<LogEntry>),
+    Box<dyn Error + Send + Sync>,
+> {
+    let head = find_head(change_sets).ok_or("HEAD is not listed")?;
+    let mut compared = open_change_sets(change_sets);
+    if !compared.iter().any(|cs| cs.id == change_set.id) {
+        compared.push(change_set.clone()); // Reviewed even if not open
+    }
+    let ((comparisons, mut logs), (status, status_logs)) = tokio::try_join!(
+        compare_with_head(workspace_id, head, &compared, cancel),
+        api_client::get_merge_status(workspace_id, &change_set.id, cancel),
+    )?;
+    logs.extend(status_logs);
+    let report = find_conflicts(&comparisons);
+    let comparison = comparisons
+        .into_iter()
+        .find(|comparison| comparison.right.id == change_set.id)
+        .ok_or("The change set was not compared with HEAD")?;
+    let actions = status
+        .actions_grouped_by_kind()
+        .into_iter()
+        .cloned()
+        .collect();
+    let review = ApplyReview::new(comparison, actions).with_conflicts(&report);
+    Ok((review, report, logs))
+}
@@ -1,14 +1,26 @@
 // Intention: Define the main application loop, handling events and state updates.
 // Design Choice: Contains the core TUI logic, including the main loop and initial data fetching.
-// Event handling is delegated to the `event_handler` submodule.
+// Key handling is delegated to the `event_handler` submodule, mouse handling to `mouse_handler`.
 
+mod auto_refresh;
+mod commands;
 mod event_handler; // Declare the submodule file
+mod fetch_helpers;
+mod key_actions;
+mod loading;
+mod mouse_handler;
+mod poll_apply_monitor;
 
 use std::{
     io,
-    time::Duration,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
+use auto_refresh::auto_refresh;
+use chrono::Utc;
 use crossterm::event::{
     self,
     Event,
@@ -16,206 +28,213 @@ use crossterm::event::{
     KeyEvent, // Needed for the event::read pattern
 };
 use event_handler::handle_key_event; // Import from the declared submodule
+use fetch_helpers::load_workspace;
+use mouse_handler::handle_mouse_event;
+use poll_apply_monitor::poll_apply_monitor;
 use ratatui::{
     Terminal,
     backend::Backend,
 };
-use situation::api_client; // Use api_client from the library crate
+use situation::api_client::{
+    self,
+    CancelToken,
+    Credentials,
+}; // Use api_client from the library crate
 use situation::api_models::CreateChangeSetV1Request; // Use specific model
+use situation::{
+    AutoRefresh,
+    Keymap,
+    LogEntry,
+    PaneLayout,
+    Profile,
+    Theme,
+    WorkspaceEntry,
+    load_workspaces,
+    workspaces::ENV_WORKSPACE_NAME,
+    workspaces_path,
+};
 
-use crate::app::App; // Use App from local app module
-use crate::refresh_change_sets::refresh_change_sets; // Use refresh function from local module
+use crate::app::{
+    App,
+    InputMode,
+}; // Use App from local app module
 use crate::ui::ui; // Use ui function from local module // Import the new handler function
 
 // Intention: Main application loop for initializing, fetching data, rendering UI, and dispatching events.
 // Design Choice: A loop that initializes state, fetches data, draws UI, and handles input asynchronously.
-pub async fn run_app<B: Backend>(terminal: &mut Terminal<B>) -> io::Result<()> {
+pub async fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    profile: Option<Profile>,
+) -> io::Result<()> {
     // Intention: Initialize application state using the new constructor.
     let mut app = App::new();
-    // Define log height consistent with UI definition here as well
-    const LOG_HEIGHT: usize = 10;
-
-    // Intention: Perform initial data fetch (whoami and change sets) and log the process.
-    // Design Choice: Call whoami first, then list_change_sets if whoami succeeds.
-    app.add_log_auto_scroll(
-        "Fetching initial /whoami data...".to_string(),
-        LOG_HEIGHT,
-    );
-    match api_client::whoami().await {
-        Ok((whoami_data, whoami_logs)) => {
-            let _workspace_id = whoami_data.workspace_id.clone(); // Prefix with _ as it's not directly used here
-            app.whoami_data = Some(whoami_data);
-            // Add logs individually to ensure auto-scroll
-            for log in whoami_logs {
-                app.add_log_auto_scroll(log, LOG_HEIGHT);
+
+    // Intention: Apply the theme selected with `SI_THEME` (or `NO_COLOR`) before the first draw.
+    // Design Choice: An unknown or invalid theme is logged and the default theme is kept.
+    match Theme::from_env() {
+        Ok(theme) => app.theme = theme,
+        Err(e) => {
+            app.add_log_auto_scroll(LogEntry::error("theme", e));
+            app.add_log_auto_scroll(LogEntry::warn(
+                "theme",
+                "Using the default theme.",
+            ));
+        }
+    }
+
+    // Intention: Apply the user's keybindings file before the first draw.
+    // Design Choice: A file with parse errors or conflicting keys is rejected as a whole;
+    // every problem is logged and the default bindings stay in place.
+    let keymap_path = Keymap::default_path();
+    match Keymap::load(&keymap_path) {
+        Ok(keymap) => app.keymap = keymap,
+        Err(errors) => {
+            for error in errors {
+                app.add_log_auto_scroll(LogEntry::error(
+                    "keymap",
+                    format!("{}: {}", keymap_path.display(), error),
+                ));
             }
-            app.add_log_auto_scroll(
-                "/whoami call successful.".to_string(),
-                LOG_HEIGHT,
-            );
-            // Initial fetch of change sets
-            refresh_change_sets(&mut app).await; // This populates app.change_sets and might select one
-
-            // After fetching change sets, try to fetch schemas for the selected one
-            if let Some(selected_cs) = app.get_selected_changeset_summary() {
-                let cs_id = selected_cs.id.clone();
-                let workspace_id =
-                    app.whoami_data.as_ref().unwrap().workspace_id.clone(); // Safe unwrap due to check above
-                app.add_log_auto_scroll(
-                    format!("Fetching schemas for change set {}...", cs_id),
-                    LOG_HEIGHT,
-                );
-                match api_client::list_schemas(&workspace_id, &cs_id).await {
-                    Ok(schema_response) => {
-                        // Removed 'mut'
-                        // Store the full SchemaSummary vector directly
-                        app.schemas = schema_response.schemas;
-                        // Sort by category, then by schema name
-                        app.schemas.sort_unstable_by(|a, b| {
-                            a.category
-                                .cmp(&b.category)
-                                .then_with(|| a.schema_name.cmp(&b.schema_name))
-                        });
-                        // Select the first schema by default if list is not empty
-                        if !app.schemas.is_empty() {
-                            app.schema_list_state.select(Some(0));
-                        }
-                        app.add_log_auto_scroll(
-                            "Successfully fetched schemas.".to_string(),
-                            LOG_HEIGHT,
-                        );
-
-                        // Fetch components for the selected change set
-                        app.add_log_auto_scroll(
-                            format!(
-                                "Fetching components for change set {}...",
-                                cs_id
-                            ),
-                            LOG_HEIGHT,
-                        );
-                        match api_client::list_components(&workspace_id, &cs_id)
-                            .await
-                        {
-                            Ok((components_response, mut api_logs)) => {
-                                // Add API client logs first
-                                api_logs.drain(..).for_each(|log| {
-                                    app.add_log_auto_scroll(log, LOG_HEIGHT)
-                                });
-
-                                // Log the component IDs
-                                let num_components =
-                                    components_response.components.len();
-                                app.add_log_auto_scroll(
-                                    format!(
-                                        "DEBUG: Received {} component IDs from API.",
-                                        num_components
-                                    ),
-                                    LOG_HEIGHT,
-                                );
-
-                                // Log the component IDs for debugging
-                                for (i, component_id) in components_response
-                                    .components
-                                    .iter()
-                                    .enumerate()
-                                {
-                                    app.add_log_auto_scroll(
-                                        format!(
-                                            "DEBUG: Component ID {}: {}",
-                                            i, component_id
-                                        ),
-                                        LOG_HEIGHT,
-                                    );
-                                }
-
-                                // For now, create dummy ComponentViewV1 objects with the IDs
-                                // In a real implementation, you would fetch the component details for each ID
-                                let components = components_response
-                                    .components
-                                    .iter()
-                                    .map(|id| {
-                                        situation::api_models::ComponentViewV1 {
-                                            id: id.clone(),
-                                            schema_id: "unknown".to_string(), // We don't need to filter by schema ID
-                                            schema_variant_id: "unknown"
-                                                .to_string(),
-                                            sockets: Vec::new(),
-                                            domain_props: Vec::new(),
-                                            resource_props: Vec::new(),
-                                            name: id.clone(), // Use the ID as the name for now
-                                            resource_id: "unknown".to_string(),
-                                            to_delete: false,
-                                            can_be_upgraded: false,
-                                            connections: Vec::new(),
-                                            views: Vec::new(),
-                                        }
-                                    })
-                                    .collect::<Vec<_>>();
-
-                                app.selected_change_set_components =
-                                    Some(components);
-                                app.add_log_auto_scroll(
-                                    format!(
-                                        "Successfully processed {} component IDs.",
-                                        num_components
-                                    ),
-                                    LOG_HEIGHT,
-                                );
-                            }
-                            Err(e) => {
-                                app.add_log_auto_scroll(
-                                    format!(
-                                        "Error fetching components: {:?}",
-                                        e
-                                    ),
-                                    LOG_HEIGHT,
-                                );
-                                app.selected_change_set_components = None;
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        app.add_log_auto_scroll(
-                            format!("Error fetching schemas: {}", e),
-                            LOG_HEIGHT,
-                        );
-                    }
+            app.add_log_auto_scroll(LogEntry::warn(
+                "keymap",
+                "Using the default keybindings.",
+            ));
+        }
+    }
+
+    // Intention: Restore the pane layout saved by the previous session.
+    // Design Choice: An unreadable or invalid layout file is logged and the default layout kept.
+    let layout_path = PaneLayout::default_path();
+    match PaneLayout::load(&layout_path) {
+        Ok(layout) => app.layout = layout,
+        Err(errors) => {
+            for error in errors {
+                app.add_log_auto_scroll(LogEntry::error(
+                    "layout",
+                    format!("{}: {}", layout_path.display(), error),
+                ));
+            }
+            app.add_log_auto_scroll(LogEntry::warn(
+                "layout",
+                "Using the default layout.",
+            ));
+        }
+    }
+
+    // Intention: Schedule the background refresh configured with `SI_REFRESH_INTERVAL`.
+    // Design Choice: An invalid interval is logged and the default interval used.
+    let interval = AutoRefresh::interval_from_env().unwrap_or_else(|e| {
+        app.add_log_auto_scroll(LogEntry::error("refresh", e));
+        Some(situation::auto_refresh::DEFAULT_REFRESH_INTERVAL)
+    });
+    app.auto_refresh = AutoRefresh::new(interval, Instant::now());
+
+    // Intention: List the workspaces to switch between and connect to the first one.
+    // Design Choice: The selected profile's credentials (else `.env`) come first, then the
+    // workspaces file. An invalid file is logged and ignored, leaving only the first entry.
+    // `main` has already applied the profile's client settings, which stay for the session.
+    let first_workspace = match &profile {
+        Some(profile) => profile
+            .credentials()
+            .ok()
+            .map(|credentials| (profile.name.clone(), credentials)),
+        None => Credentials::from_env()
+            .ok()
+            .map(|credentials| (ENV_WORKSPACE_NAME.to_string(), credentials)),
+    };
+    if let Some((name, credentials)) = first_workspace {
+        app.workspaces.push(WorkspaceEntry { name, credentials });
+    }
+    if let Some(profile) = &profile {
+        app.add_log_auto_scroll(LogEntry::info(
+            "profile",
+            format!(
+                "Using profile {} ({}){}",
+                profile.name,
+                profile.api_url,
+                if profile.settings.read_only {
+                    ", read-only"
+                } else {
+                    ""
                 }
-            } else {
-                app.add_log_auto_scroll(
-                    "No change set selected initially, skipping schema fetch."
-                        .to_string(),
-                    LOG_HEIGHT,
-                );
+            ),
+        ));
+    }
+    app.profile = profile;
+    let workspaces_file = workspaces_path();
+    match load_workspaces(&workspaces_file) {
+        Ok(entries) => app.workspaces.extend(entries),
+        Err(errors) => {
+            for error in errors {
+                app.add_log_auto_scroll(LogEntry::error(
+                    "workspace",
+                    format!("{}: {}", workspaces_file.display(), error),
+                ));
             }
+            app.add_log_auto_scroll(LogEntry::warn(
+                "workspace",
+                "Ignoring the workspaces file.",
+            ));
         }
-        Err(e) => {
-            // Log the error message for whoami failure into the app's log buffer.
-            let error_msg = format!("Error fetching initial data: {}", e);
-            app.add_log_auto_scroll(error_msg, LOG_HEIGHT);
-            // Optionally, still print to stderr during development if helpful
-            // eprintln!("Error fetching initial data: {}", e);
+    }
+    if let Some(first) = app.workspaces.first() {
+        match api_client::use_credentials(&first.credentials) {
+            Ok(()) => app.active_workspace = Some(0),
+            Err(e) => app.add_log_auto_scroll(LogEntry::error(
+                "workspace",
+                format!("Cannot use workspace {}: {}", first.name, e),
+            )),
         }
     }
 
+    // Intention: Perform initial data fetch (whoami, change sets, schemas and components).
+    load_workspace(&mut app, terminal).await;
+
     loop {
         // Intention: Draw the current state of the UI using app state.
         terminal.draw(|f| ui(f, &mut app))?; // Pass mutable app state to ui
 
         // Intention: Handle user input events asynchronously by polling and dispatching to the handler.
-        // Design Choice: Poll for events, then call the dedicated handler function if it's a key event.
-        if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                // Call the extracted handler function
-                // Pass the mutable app state and terminal reference
-                let should_quit =
-                    handle_key_event(key, &mut app, terminal).await?;
-                if should_quit {
-                    return Ok(()); // Exit the loop if the handler signals quit
+        // Design Choice: Poll for events, then call the dedicated handler for key and mouse events.
+        // Resize needs no handling, the next draw uses the new size. Events queued while a fetch
+        // was in flight come first; each event gets its own load token, so Esc only cancels the
+        // fetches of the event being handled.
+        let next_event = match app.pending_events.pop_front() {
+            Some(event) => Some(event),
+            None if event::poll(Duration::from_millis(100))? => {
+                Some(event::read()?)
+            }
+            None => None,
+        };
+        if let Some(event) = next_event {
+            app.load_token = CancelToken::new();
+            match event {
+                Event::Key(key) => {
+                    // Pass the mutable app state and terminal reference
+                    let should_quit =
+                        handle_key_event(key, &mut app, terminal).await?;
+                    if should_quit {
+                        return Ok(()); // Exit the loop if the handler signals quit
+                    }
                 }
+                Event::Mouse(mouse) => {
+                    handle_mouse_event(mouse, &mut app, terminal).await?
+                }
+                _ => {}
             }
-            // Handle other event types (e.g., Mouse, Resize) here if needed in the future
         }
+        // Advance a running apply monitor between input events
+        poll_apply_monitor(&mut app).await;
+
+        // Pick up changes made elsewhere once the refresh interval has passed
+        auto_refresh(&mut app, terminal).await?;
+
+        // Track the token's expiry, and ask for a new token once the server rejected it
+        app.update_token_status(Utc::now());
+        if api_client::awaiting_token() && app.input_mode != InputMode::Token {
+            app.open_token_prompt(true);
+        }
+
         // Placeholder for other async tasks or periodic refresh if needed later
         // tokio::time::sleep(Duration::from_millis(50)).await; // Small sleep to prevent busy-looping if no events
     }
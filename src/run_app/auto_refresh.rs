@@ -0,0 +1,157 @@
+// src/run_app/auto_refresh.rs
+
+// Intention: Run the background refresh from the main loop when it is due: the change set list,
+// then the selected change set's details and merge status.
+// Design Choice: The calls run through `cancellable` with a token of their own, so input keeps
+// being handled during a refresh and Esc gives up on it. The client's entries for these calls
+// are logged at debug level, so a refresh every few seconds does not flood the log panel; only
+// changes and failures are logged above it, as the same events `situation watch` prints (the
+// diffing is shared with `Watcher`). The refresh is skipped offline (Refresh reconnects) and
+// while the client waits for a new token.
+
+use std::{
+    io,
+    time::Instant,
+};
+
+use chrono::Utc;
+use ratatui::{
+    Terminal,
+    backend::Backend,
+};
+use situation::{
+    CancelToken,
+    LogEntry,
+    LogLevel,
+    WatchEvent,
+    api_client,
+    watch::{
+        diff_actions,
+        diff_change_sets,
+    },
+};
+
+use super::{
+    commands::load_selected_change_set,
+    loading::cancellable,
+};
+use crate::app::App;
+
+// Intention: Log the client's entries of a background call, lowering info to debug.
+fn add_quiet_logs(app: &mut App, logs: Vec<LogEntry>) {
+    for mut log in logs {
+        if log.level == LogLevel::Info {
+            log.level = LogLevel::Debug;
+        }
+        app.add_log_auto_scroll(log);
+    }
+}
+
+// Intention: Log what a refresh changed, one entry per event; a failed action is a warning.
+fn log_events(app: &mut App, events: Vec<WatchEvent>) {
+    for event in events {
+        let entry = match event {
+            WatchEvent::ActionFailed { .. } => LogEntry::warn,
+            _ => LogEntry::info,
+        };
+        app.add_log_auto_scroll(entry("refresh", event.to_string()));
+    }
+}
+
+// Intention: Refresh the change set list and the selected change set if a refresh is due.
+pub(super) async fn auto_refresh<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+) -> io::Result<()> {
+    let now = Instant::now();
+    if !app.auto_refresh.is_due(now)
+        || app.offline
+        || api_client::awaiting_token()
+    {
+        return Ok(());
+    }
+    app.auto_refresh.schedule(now);
+    let Some(ws_id) = app.whoami_data.as_ref().map(|d| d.workspace_id.clone())
+    else {
+        return Ok(());
+    };
+    app.load_token = CancelToken::new();
+    let token = app.load_token.clone();
+
+    let listed = api_client::list_change_sets(&ws_id, &token);
+    let listed = cancellable(app, terminal, None, listed).await;
+    match listed {
+        Ok((response, logs)) => {
+            add_quiet_logs(app, logs);
+            if let Some(old) = &app.change_sets {
+                let events = diff_change_sets(old, &response.change_sets);
+                log_events(app, events);
+            }
+            let previous = app.get_selected_changeset_summary().cloned();
+            if !app.apply_refreshed_change_sets(response.change_sets) {
+                if let Some(previous) = previous {
+                    app.add_log_auto_scroll(LogEntry::warn(
+                        "refresh",
+                        format!(
+                            "Change set {} is no longer listed.",
+                            previous.name
+                        ),
+                    ));
+                }
+                app.changed_action_ids.clear();
+                return load_selected_change_set(app, terminal).await;
+            }
+        }
+        Err(e) => {
+            app.log_api_error(
+                LogEntry::warn(
+                    "refresh",
+                    format!("Auto-refresh failed: {}", e),
+                ),
+                &*e,
+            );
+            return Ok(());
+        }
+    }
+
+    if let Some(cs_id) =
+        app.get_selected_changeset_summary().map(|cs| cs.id.clone())
+    {
+        // The status is what changes on its own, so it is read from the server
+        api_client::invalidate_cached_change_set(&ws_id, &cs_id);
+        let details = api_client::get_change_set(&ws_id, &cs_id, &token);
+        let details = cancellable(app, terminal, Some(&cs_id), details).await;
+        let status = api_client::get_merge_status(&ws_id, &cs_id, &token);
+        let status = cancellable(app, terminal, Some(&cs_id), status).await;
+        let still_selected = app
+            .get_selected_changeset_summary()
+            .is_some_and(|cs| cs.id == cs_id);
+        match (details, status) {
+            (Ok((details, details_logs)), Ok((status, status_logs))) => {
+                add_quiet_logs(app, details_logs);
+                add_quiet_logs(app, status_logs);
+                if still_selected {
+                    if let Some(old) = &app.selected_change_set_merge_status {
+                        let events =
+                            diff_actions(&cs_id, &old.actions, &status.actions);
+                        log_events(app, events);
+                    }
+                    app.apply_refreshed_status(details.change_set, status);
+                }
+            }
+            (Err(e), _) | (_, Err(e)) => {
+                app.log_api_error(
+                    LogEntry::warn(
+                        "refresh",
+                        format!("Auto-refresh of {} failed: {}", cs_id, e),
+                    ),
+                    &*e,
+                );
+                return Ok(());
+            }
+        }
+    }
+
+    app.save_snapshot(Utc::now());
+    Ok(())
+}
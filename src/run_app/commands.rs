@@ -0,0 +1,720 @@
+// src/run_app/commands.rs
+
+// Intention: Implement the change set commands that can be triggered from more than one place
+// (single-letter keys in the top bar and the command palette), and dispatch palette entries.
+// Design Choice: Each command works from the current selection in `App`, logs its own outcome,
+// and takes the terminal so it can redraw while a request is in flight, same as the key arms
+// it was extracted from.
+
+use std::{
+    io,
+    time::Instant,
+};
+
+use chrono::Utc;
+use ratatui::{
+    Terminal,
+    backend::Backend,
+};
+use situation::{
+    Action,
+    ApplyMonitor,
+    ApplyMonitorOptions,
+    KeyContext,
+    LogEntry,
+    api_client,
+    compare::{
+        find_change_set,
+        find_head,
+    },
+    compare_change_sets,
+    load_review,
+    scan_conflicts,
+};
+
+use super::{
+    fetch_helpers::{
+        fetch_component_details,
+        fetch_components,
+        fetch_details_and_status,
+        fetch_schemas,
+        load_workspace,
+    },
+    key_actions::open_workspace_dropdown,
+    loading::cancellable,
+};
+use crate::{
+    app::{
+        App,
+        AppFocus,
+        DropdownFocus,
+        InputMode,
+        PaletteCommand,
+        PaletteTarget,
+    },
+    refresh_change_sets::refresh_change_sets,
+    ui::ui,
+};
+
+// Intention: Return the workspace and selected change set IDs, if both are known.
+fn selected_ids(app: &App) -> (Option<String>, Option<String>) {
+    let workspace_id = app.whoami_data.as_ref().map(|d| d.workspace_id.clone());
+    let selected_cs_id =
+        app.get_selected_changeset_summary().map(|cs| cs.id.clone());
+    (workspace_id, selected_cs_id)
+}
+
+// Intention: Refuse a change when the active profile is read-only or the app is offline,
+// logging why.
+// Design Choice: The client refuses these calls anyway; checking first avoids asking for a
+// change set name or showing a progress message for a request that will not be sent.
+fn refuse_if_read_only(app: &mut App, what: &str) -> bool {
+    if app.refuse_if_offline(what) {
+        return true;
+    }
+    let read_only = api_client::client_settings().read_only;
+    if read_only {
+        app.add_log_auto_scroll(LogEntry::warn(
+            "profile",
+            format!("Cannot {}: the active profile is read-only.", what),
+        ));
+    }
+    read_only
+}
+
+// Intention: Clear everything shown for the selected change set.
+fn clear_change_set_view(app: &mut App) {
+    app.selected_change_set_details = None;
+    app.selected_change_set_merge_status = None;
+    app.selected_change_set_components = None;
+    app.schema_component_counts.clear();
+    app.selected_component_details = None;
+    app.schemas.clear();
+    app.schema_list_state.select(None);
+}
+
+// Intention: After a mutation, refresh the change set list and reload schemas for whatever
+// ends up selected. Details are cleared since the change set status has likely changed.
+async fn refresh_after_mutation<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    ws_id: &str,
+) {
+    refresh_change_sets(app).await; // Refresh list
+    let new_selected_cs_id =
+        app.get_selected_changeset_summary().map(|cs| cs.id.clone()); // Get ID and drop borrow
+    if let Some(cs_id) = new_selected_cs_id {
+        fetch_schemas(app, terminal, ws_id, &cs_id).await;
+    } else {
+        // Ensure schemas are cleared if no CS selected after refresh
+        app.schemas.clear();
+        app.schema_list_state.select(None);
+    }
+    app.selected_change_set_details = None;
+    app.selected_change_set_merge_status = None;
+    if app.offline {
+        app.show_snapshot_change_set(); // The backend went away during the mutation
+    }
+}
+
+// Intention: Load details, merge status, schemas and components for the selected change set.
+// Design Choice: Used when the selection changes (dropdown Enter, palette) and on refresh.
+// Offline, the change set is shown from the snapshot; online, what was loaded is saved to it.
+pub(super) async fn load_selected_change_set<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+) -> io::Result<()> {
+    app.changed_action_ids.clear(); // Highlights belong to the change set they were seen in
+    app.close_comparison(); // So does a comparison
+    app.close_review(); // And a review
+    if app.offline {
+        app.show_snapshot_change_set();
+        return Ok(());
+    }
+    match selected_ids(app) {
+        (Some(ws_id), Some(cs_id)) => {
+            app.current_action =
+                Some("Fetching details, schemas & components...".to_string());
+            terminal.draw(|f| ui(f, app))?; // Redraw immediately
+            fetch_details_and_status(app, terminal, &ws_id, &cs_id).await;
+            fetch_schemas(app, terminal, &ws_id, &cs_id).await;
+            fetch_components(app, terminal, &ws_id, &cs_id).await;
+            app.current_action = None;
+            if app.offline {
+                app.show_snapshot_change_set(); // The backend went away during the load
+            } else {
+                app.save_snapshot(Utc::now());
+            }
+        }
+        (None, Some(_)) => {
+            app.add_log_auto_scroll(
+                "Workspace ID missing unexpectedly.".to_string(),
+            );
+            clear_change_set_view(app);
+        }
+        _ => clear_change_set_view(app),
+    }
+    Ok(())
+}
+
+// Intention: Re-fetch the change set list and reload the selected change set.
+// Design Choice: Offline, the workspace is loaded from scratch instead; its whoami call tells
+// whether the backend is back, and the snapshot stays shown if not.
+pub(super) async fn refresh_all<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+) -> io::Result<()> {
+    app.current_action = Some("Refreshing change sets...".to_string());
+    terminal.draw(|f| ui(f, app))?;
+    api_client::clear_cache(); // An explicit refresh reads everything again
+    app.auto_refresh.schedule(Instant::now()); // No background refresh right after this one
+    if app.offline {
+        app.add_log_auto_scroll("Reconnecting to the backend...".to_string());
+        load_workspace(app, terminal).await;
+        if app.offline {
+            app.current_action = None;
+            return Ok(());
+        }
+    } else {
+        refresh_change_sets(app).await;
+    }
+    load_selected_change_set(app, terminal).await
+}
+
+// Intention: Switch the API client to another configured workspace and load it from scratch.
+// Design Choice: The credentials are swapped first, so nothing from the old workspace is shown
+// once they are in use; on failure the current workspace is kept.
+pub(super) async fn switch_workspace<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    index: usize,
+) -> io::Result<()> {
+    let Some(workspace) = app.workspaces.get(index).cloned() else {
+        return Ok(());
+    };
+    if let Err(e) = api_client::use_credentials(&workspace.credentials) {
+        app.add_log_auto_scroll(LogEntry::error(
+            "workspace",
+            format!("Cannot use workspace {}: {}", workspace.name, e),
+        ));
+        return Ok(());
+    }
+    app.active_workspace = Some(index);
+    app.reset_workspace_data();
+    app.add_log_auto_scroll(LogEntry::info(
+        "workspace",
+        format!(
+            "Switched to workspace {} ({})",
+            workspace.name, workspace.credentials.api_url
+        ),
+    ));
+    app.current_action =
+        Some(format!("Loading workspace {}...", workspace.name));
+    terminal.draw(|f| ui(f, app))?; // Redraw immediately
+    load_workspace(app, terminal).await;
+    app.current_action = None;
+    Ok(())
+}
+
+// Intention: Enter change set name input mode.
+pub(super) fn start_create_change_set(app: &mut App) {
+    if refuse_if_read_only(app, "create a change set") {
+        return;
+    }
+    if app.whoami_data.is_some() {
+        app.input_mode = InputMode::ChangeSetName;
+        app.current_focus = AppFocus::Input; // Set focus to input
+        app.input_buffer.clear();
+        app.current_action = None;
+    } else {
+        app.add_log_auto_scroll(
+            "Cannot create: No workspace available.".to_string(),
+        );
+    }
+}
+
+// Intention: Abandon the selected change set and refresh the list.
+pub(super) async fn abandon_selected_change_set<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+) -> io::Result<()> {
+    if refuse_if_read_only(app, "delete") {
+        return Ok(());
+    }
+    let (Some(ws_id), Some(cs_id)) = selected_ids(app) else {
+        app.add_log_auto_scroll(
+            "Cannot delete: No change set selected.".to_string(),
+        );
+        return Ok(());
+    };
+    app.current_action = Some(format!("Deleting {}...", cs_id));
+    terminal.draw(|f| ui(f, app))?;
+    let token = app.load_token.clone();
+    match api_client::abandon_change_set(&ws_id, &cs_id, &token).await {
+        Ok((resp, logs)) => {
+            logs.into_iter()
+                .for_each(|log| app.add_log_auto_scroll(log));
+            app.add_log_auto_scroll(format!(
+                "Abandoned changeset {} (Success: {})",
+                cs_id, resp.success
+            ));
+            // Clear state related to the deleted item
+            clear_change_set_view(app);
+        }
+        Err(e) => app.log_api_error(
+            LogEntry::error(
+                "app",
+                format!("Error abandoning changeset {}: {}", cs_id, e),
+            ),
+            &*e,
+        ),
+    }
+    app.current_action = None;
+    refresh_after_mutation(app, terminal, &ws_id).await;
+    Ok(())
+}
+
+// Intention: Force apply the selected change set and start tracking it with an apply monitor.
+pub(super) async fn force_apply_selected_change_set<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+) -> io::Result<()> {
+    if refuse_if_read_only(app, "apply") {
+        return Ok(());
+    }
+    let (Some(ws_id), Some(cs_id)) = selected_ids(app) else {
+        app.add_log_auto_scroll(
+            "Cannot apply: No change set selected.".to_string(),
+        );
+        return Ok(());
+    };
+    app.current_action = Some(format!("Applying {}...", cs_id));
+    terminal.draw(|f| ui(f, app))?;
+    let token = app.load_token.clone();
+    match api_client::force_apply(&ws_id, &cs_id, &token).await {
+        Ok((_, logs)) => {
+            logs.into_iter()
+                .for_each(|log| app.add_log_auto_scroll(log));
+            app.add_log_auto_scroll(format!(
+                "Apply initiated for changeset {}",
+                cs_id
+            ));
+            // Track the apply until it finishes, seeded with the actions queued before it
+            let queued_actions = app
+                .selected_change_set_merge_status
+                .as_ref()
+                .filter(|status| status.change_set.id == cs_id)
+                .map(|status| status.actions.clone())
+                .unwrap_or_default();
+            app.apply_monitor = Some(
+                ApplyMonitor::new(
+                    &ws_id,
+                    &cs_id,
+                    ApplyMonitorOptions::default(),
+                )
+                .with_actions(&queued_actions),
+            );
+            app.selected_component_details = None;
+        }
+        Err(e) => app.log_api_error(
+            LogEntry::error(
+                "app",
+                format!("Error applying changeset {}: {}", cs_id, e),
+            ),
+            &*e,
+        ),
+    }
+    app.current_action = None;
+    refresh_after_mutation(app, terminal, &ws_id).await;
+    Ok(())
+}
+
+// Intention: Show what applying the selected change set will change relative to HEAD, before
+// applying it.
+// Design Choice: This is what `f` does; the review's own `f` then applies (see
+// `apply_reviewed_change_set`). It loads HEAD's components, so it is refused offline. The open
+// change sets are scanned for conflicts with the same loads, so the review warns about edits
+// another change set would overwrite or lose.
+pub(super) async fn review_selected_change_set<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+) -> io::Result<()> {
+    if app.refuse_if_offline("review the change set") {
+        return Ok(());
+    }
+    let (Some(ws_id), Some(cs_id)) = selected_ids(app) else {
+        app.add_log_auto_scroll(
+            "Cannot apply: No change set selected.".to_string(),
+        );
+        return Ok(());
+    };
+    let change_sets = app.change_sets.clone().unwrap_or_default();
+    let (Some(head), Some(change_set)) = (
+        find_head(&change_sets).cloned(),
+        find_change_set(&change_sets, &cs_id).cloned(),
+    ) else {
+        app.add_log_auto_scroll(LogEntry::warn(
+            "review",
+            "Cannot review: HEAD is not listed.",
+        ));
+        return Ok(());
+    };
+    if head.id == change_set.id {
+        app.add_log_auto_scroll(LogEntry::warn(
+            "review",
+            "HEAD is selected; select the change set to apply.",
+        ));
+        return Ok(());
+    }
+    app.current_action = Some(format!("Reviewing {}...", change_set.name));
+    terminal.draw(|f| ui(f, app))?; // Redraw immediately
+    let token = app.load_token.clone();
+    let call = load_review(&ws_id, &change_sets, &change_set, &token);
+    match cancellable(app, terminal, Some(&cs_id), call).await {
+        Ok((review, report, logs)) => {
+            logs.into_iter()
+                .for_each(|log| app.add_log_auto_scroll(log));
+            app.set_conflicts(report);
+            let keys =
+                |action| app.keymap.describe(KeyContext::ContentArea, action);
+            let message = format!(
+                "Review of {}: {}. Press {} to apply, {} to cancel.",
+                change_set.name,
+                review.summary(),
+                keys(Action::ConfirmApply),
+                keys(Action::Back)
+            );
+            app.add_log_auto_scroll(LogEntry::info("review", message));
+            app.show_review(review);
+        }
+        Err(e) => app.log_api_error(
+            LogEntry::error(
+                "review",
+                format!("Error reviewing {}: {}", change_set.name, e),
+            ),
+            &*e,
+        ),
+    }
+    app.current_action = None;
+    Ok(())
+}
+
+// Intention: Scan the open change sets for components modified in more than one of them, for the
+// badges in the change set dropdown.
+// Design Choice: Scans the change sets as listed; refused offline since it loads the components
+// of every open change set.
+pub(super) async fn check_conflicts<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+) -> io::Result<()> {
+    if app.refuse_if_offline("check for conflicts") {
+        return Ok(());
+    }
+    let (Some(ws_id), Some(change_sets)) = (
+        app.whoami_data.as_ref().map(|d| d.workspace_id.clone()),
+        app.change_sets.clone(),
+    ) else {
+        app.add_log_auto_scroll(LogEntry::warn(
+            "conflicts",
+            "Cannot check for conflicts: No change sets loaded.",
+        ));
+        return Ok(());
+    };
+    app.current_action =
+        Some("Checking open change sets for conflicts...".to_string());
+    terminal.draw(|f| ui(f, app))?; // Redraw immediately
+    let token = app.load_token.clone();
+    let call = scan_conflicts(&ws_id, &change_sets, &token);
+    match cancellable(app, terminal, None, call).await {
+        Ok((report, logs)) => {
+            logs.into_iter()
+                .for_each(|log| app.add_log_auto_scroll(log));
+            app.set_conflicts(report);
+        }
+        Err(e) => app.log_api_error(
+            LogEntry::error(
+                "conflicts",
+                format!("Error checking for conflicts: {}", e),
+            ),
+            &*e,
+        ),
+    }
+    app.current_action = None;
+    Ok(())
+}
+
+// Intention: Apply the change set the review shown was made for.
+// Design Choice: The selection may have changed behind the review (auto-refresh moving it off
+// a change set that went away); the review is then dropped instead of applying something else.
+pub(super) async fn apply_reviewed_change_set<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+) -> io::Result<()> {
+    let reviewed = app.review.as_ref().map(|r| r.change_set.id.clone());
+    let (_, selected) = selected_ids(app);
+    app.close_review();
+    if reviewed.is_none() || reviewed != selected {
+        app.add_log_auto_scroll(LogEntry::warn(
+            "review",
+            "The reviewed change set is no longer selected; review it again.",
+        ));
+        return Ok(());
+    }
+    force_apply_selected_change_set(app, terminal).await
+}
+
+// Intention: Request approval for the selected change set, then reload it to show the new status.
+pub(super) async fn request_approval_for_selected_change_set<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+) -> io::Result<()> {
+    if refuse_if_read_only(app, "request approval") {
+        return Ok(());
+    }
+    let (Some(ws_id), Some(cs_id)) = selected_ids(app) else {
+        app.add_log_auto_scroll(
+            "Cannot request approval: No change set selected.".to_string(),
+        );
+        return Ok(());
+    };
+    app.current_action = Some(format!("Requesting approval for {}...", cs_id));
+    terminal.draw(|f| ui(f, app))?;
+    let token = app.load_token.clone();
+    match api_client::request_approval(&ws_id, &cs_id, &token).await {
+        Ok((_, logs)) => {
+            logs.into_iter()
+                .for_each(|log| app.add_log_auto_scroll(log));
+            app.add_log_auto_scroll(format!(
+                "Approval requested for changeset {}",
+                cs_id
+            ));
+        }
+        Err(e) => app.log_api_error(
+            LogEntry::error(
+                "app",
+                format!("Error requesting approval for {}: {}", cs_id, e),
+            ),
+            &*e,
+        ),
+    }
+    app.current_action = None;
+    refresh_change_sets(app).await;
+    fetch_details_and_status(app, terminal, &ws_id, &cs_id).await;
+    Ok(())
+}
+
+// Intention: Use the token typed into the token prompt and retry what the old token could not do.
+// Design Choice: The client resends the rejected request if it changed something; everything
+// shown is then reloaded, which repeats the rejected reads. The new token is kept for the active
+// workspace, so switching away and back does not bring the old token back.
+pub(super) async fn submit_token<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+) -> io::Result<()> {
+    let token = app.input_buffer.trim().to_string();
+    if token.is_empty() {
+        return Ok(()); // Keep the prompt open
+    }
+    app.close_token_prompt();
+    app.current_action = Some("Retrying with the new token...".to_string());
+    terminal.draw(|f| ui(f, app))?; // Redraw immediately
+    let cancel = app.load_token.clone();
+    match api_client::reauthenticate(&token, &cancel).await {
+        Ok(logs) => {
+            logs.into_iter()
+                .for_each(|log| app.add_log_auto_scroll(log));
+            app.add_log_auto_scroll(LogEntry::info(
+                "token",
+                "Using the new token.",
+            ));
+        }
+        Err(e) => app.log_api_error(
+            LogEntry::error(
+                "token",
+                format!("Error retrying with the new token: {}", e),
+            ),
+            &*e,
+        ),
+    }
+    if let (Some(credentials), Some(workspace)) = (
+        api_client::current_credentials(),
+        app.active_workspace
+            .and_then(|index| app.workspaces.get_mut(index)),
+    ) {
+        workspace.credentials = credentials;
+    }
+    if app.whoami_data.is_none() {
+        load_workspace(app, terminal).await; // The token was rejected at startup
+    } else {
+        refresh_all(app, terminal).await?;
+    }
+    app.current_action = None;
+    Ok(())
+}
+
+// Intention: Close the token prompt without a new token.
+// Design Choice: The client stops waiting and drops the rejected request, so the next request
+// is sent with the current token (and opens the prompt again if it is still rejected).
+pub(super) fn dismiss_token_prompt(app: &mut App) {
+    app.close_token_prompt();
+    if api_client::awaiting_token() {
+        api_client::dismiss_rejection();
+        app.add_log_auto_scroll(LogEntry::warn(
+            "token",
+            "Kept the current token; the rejected request was not retried.",
+        ));
+    }
+}
+
+// Intention: Open the component detail view for a component in the selected change set.
+pub(super) async fn open_component<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    component_id: &str,
+) -> io::Result<()> {
+    if app.refuse_if_offline("open a component") {
+        return Ok(()); // Component details are not in the snapshot
+    }
+    let (Some(ws_id), Some(cs_id)) = selected_ids(app) else {
+        app.add_log_auto_scroll(
+            "Cannot open component: No change set selected.".to_string(),
+        );
+        return Ok(());
+    };
+    app.current_action =
+        Some(format!("Fetching component {}...", component_id));
+    terminal.draw(|f| ui(f, app))?; // Redraw immediately
+    fetch_component_details(app, terminal, &ws_id, &cs_id, component_id).await;
+    app.current_action = None;
+    Ok(())
+}
+
+// Intention: Compare the selected change set (right) with another one (left): the one with this
+// ID, or HEAD.
+pub(super) async fn compare_selected_change_set<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    left_id: Option<&str>,
+) -> io::Result<()> {
+    if app.refuse_if_offline("compare change sets") {
+        return Ok(()); // Components of other change sets are not in the snapshot
+    }
+    let change_sets = app.change_sets.clone().unwrap_or_default();
+    let left = match left_id {
+        Some(id) => find_change_set(&change_sets, id),
+        None => find_head(&change_sets),
+    };
+    let (Some(ws_id), Some(right), Some(left)) = (
+        app.whoami_data.as_ref().map(|d| d.workspace_id.clone()),
+        app.get_selected_changeset_summary().cloned(),
+        left.cloned(),
+    ) else {
+        app.add_log_auto_scroll(LogEntry::warn(
+            "compare",
+            "Cannot compare: No change set selected, or HEAD is not listed.",
+        ));
+        return Ok(());
+    };
+    if left.id == right.id {
+        app.add_log_auto_scroll(LogEntry::warn(
+            "compare",
+            format!("{} is the change set to compare with.", right.name),
+        ));
+        return Ok(());
+    }
+    app.current_action =
+        Some(format!("Comparing {} with {}...", right.name, left.name));
+    terminal.draw(|f| ui(f, app))?; // Redraw immediately
+    let token = app.load_token.clone();
+    let call = compare_change_sets(&ws_id, &left, &right, &token);
+    match cancellable(app, terminal, Some(&right.id), call).await {
+        Ok((comparison, logs)) => {
+            logs.into_iter()
+                .for_each(|log| app.add_log_auto_scroll(log));
+            app.add_log_auto_scroll(LogEntry::info(
+                "compare",
+                format!(
+                    "{} compared with {}: {}",
+                    right.name,
+                    left.name,
+                    comparison.summary()
+                ),
+            ));
+            app.show_comparison(comparison);
+        }
+        Err(e) => app.log_api_error(
+            LogEntry::error(
+                "compare",
+                format!(
+                    "Error comparing {} with {}: {}",
+                    right.name, left.name, e
+                ),
+            ),
+            &*e,
+        ),
+    }
+    app.current_action = None;
+    Ok(())
+}
+
+// Intention: Run the entry selected in the command palette.
+// Design Choice: Navigation entries move focus to the pane that shows the selected item, so the
+// result is visible and the usual keys work on it straight away.
+pub(super) async fn run_palette_target<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    target: PaletteTarget,
+) -> io::Result<()> {
+    match target {
+        PaletteTarget::Command(command) => match command {
+            PaletteCommand::CreateChangeSet => start_create_change_set(app),
+            PaletteCommand::AbandonChangeSet => {
+                abandon_selected_change_set(app, terminal).await?
+            }
+            PaletteCommand::ApplyChangeSet => {
+                review_selected_change_set(app, terminal).await?
+            }
+            PaletteCommand::RequestApproval => {
+                request_approval_for_selected_change_set(app, terminal).await?
+            }
+            PaletteCommand::Refresh => refresh_all(app, terminal).await?,
+            PaletteCommand::SwitchWorkspace => {
+                app.dropdown_focus = DropdownFocus::Workspace;
+                open_workspace_dropdown(app);
+            }
+            PaletteCommand::ToggleAutoRefresh => {
+                app.toggle_auto_refresh(Instant::now())
+            }
+            PaletteCommand::ToggleInspector => app.toggle_inspector(),
+            PaletteCommand::CycleTheme => app.cycle_theme(),
+            PaletteCommand::EnterToken => app.open_token_prompt(false),
+            PaletteCommand::CompareWithHead => {
+                compare_selected_change_set(app, terminal, None).await?
+            }
+            PaletteCommand::CompareChangeSets => app.open_compare_palette(),
+            PaletteCommand::CheckConflicts => {
+                check_conflicts(app, terminal).await?
+            }
+        },
+        PaletteTarget::CompareWith(cs_id) => {
+            compare_selected_change_set(app, terminal, Some(&cs_id)).await?
+        }
+        PaletteTarget::ChangeSet(cs_id) => {
+            app.select_change_set_by_id(&cs_id);
+            app.current_focus = AppFocus::TopBar;
+            app.dropdown_focus = DropdownFocus::ChangeSet;
+            load_selected_change_set(app, terminal).await?;
+        }
+        PaletteTarget::Schema(index) => {
+            if index < app.schemas.len() {
+                app.select_schema_index(index);
+                app.current_focus = AppFocus::SchemaList;
+            }
+        }
+        PaletteTarget::Component(component_id) => {
+            app.current_focus = AppFocus::ContentArea;
+            open_component(app, terminal, &component_id).await?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,358 @@
+// src/run_app/fetch_helpers.rs
+
+// Intention: Fetch change set data into the app state for the key handler and palette commands.
+// Design Choice: Moved out of event_handler.rs so both the key handler and the command palette
+// can share them. Each helper logs its own progress and clears the related state on error.
+// Calls are given a clone of the load token and run through `cancellable`, so they can be
+// cancelled (see `loading`); a cancelled call clears its state like a failed one, without
+// logging an error.
+
+use chrono::Utc;
+use ratatui::{
+    Terminal,
+    backend::Backend,
+};
+use situation::{
+    LogEntry,
+    api_client,
+    api_models::ComponentViewV1,
+    count_components_by_schema,
+};
+
+use super::loading::cancellable;
+use crate::{
+    app::App,
+    refresh_change_sets::refresh_change_sets,
+};
+
+// Intention: Fetch change set details and merge status.
+// Design Choice: Encapsulate the dual fetch logic. Updates app state.
+pub(super) async fn fetch_details_and_status<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    ws_id: &str,
+    cs_id: &str,
+) {
+    let token = app.load_token.clone();
+    // Fetch details
+    let details = api_client::get_change_set(ws_id, cs_id, &token);
+    match cancellable(app, terminal, Some(cs_id), details).await {
+        Ok((get_response, logs)) => {
+            app.selected_change_set_details = Some(get_response.change_set);
+            logs.into_iter()
+                .for_each(|log| app.add_log_auto_scroll(log));
+            app.add_log_auto_scroll(format!("Details fetched for {}", cs_id));
+        }
+        Err(e) => {
+            app.selected_change_set_details = None; // Clear on error
+            app.log_api_error(
+                LogEntry::error(
+                    "app",
+                    format!("Error fetching details for {}: {}", cs_id, e),
+                ),
+                &*e,
+            );
+        }
+    }
+    // Fetch merge status
+    let status = api_client::get_merge_status(ws_id, cs_id, &token);
+    match cancellable(app, terminal, Some(cs_id), status).await {
+        Ok((status_response, logs)) => {
+            // Select the first action so the table is immediately navigable
+            app.action_table_state
+                .select((!status_response.actions.is_empty()).then_some(0));
+            app.selected_change_set_merge_status = Some(status_response);
+            logs.into_iter()
+                .for_each(|log| app.add_log_auto_scroll(log));
+            app.add_log_auto_scroll(format!(
+                "Merge status fetched for {}",
+                cs_id
+            ));
+        }
+        Err(e) => {
+            app.selected_change_set_merge_status = None; // Clear on error
+            app.log_api_error(
+                LogEntry::error(
+                    "app",
+                    format!("Error fetching merge status for {}: {}", cs_id, e),
+                ),
+                &*e,
+            );
+        }
+    }
+}
+
+// Intention: Fetch a single component's details for the component detail view.
+// Design Choice: Same shape as the other fetch helpers. Keeps the previous details on error
+// cleared so the content area falls back to the change set view.
+pub(super) async fn fetch_component_details<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    ws_id: &str,
+    cs_id: &str,
+    component_id: &str,
+) {
+    let token = app.load_token.clone();
+    let component =
+        api_client::get_component(ws_id, cs_id, component_id, &token);
+    match cancellable(app, terminal, Some(cs_id), component).await {
+        Ok((component_response, logs)) => {
+            logs.into_iter()
+                .for_each(|log| app.add_log_auto_scroll(log));
+            app.selected_component_details = Some(component_response);
+            app.add_log_auto_scroll(format!(
+                "Component details fetched for {}",
+                component_id
+            ));
+        }
+        Err(e) => {
+            app.selected_component_details = None; // Clear on error
+            app.log_api_error(
+                LogEntry::error(
+                    "app",
+                    format!("Error fetching component {}: {}", component_id, e),
+                ),
+                &*e,
+            );
+        }
+    }
+}
+
+// Intention: Fetch the list of components for the given workspace and change set.
+// Design Choice: Encapsulate component fetching logic. Updates app state.
+// The list endpoint only returns IDs, so each component is then resolved with `get_components`
+// (concurrently, up to the client's concurrency limit) to learn its name and schema, which feed
+// the per-schema counts in the schema tree. The logs of every lookup are kept, in list order.
+pub(super) async fn fetch_components<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    ws_id: &str,
+    cs_id: &str,
+) {
+    app.add_log_auto_scroll(format!(
+        "Fetching components for change set {}...",
+        cs_id
+    ));
+    let token = app.load_token.clone();
+    let list = api_client::list_components(ws_id, cs_id, &token);
+    let listed = cancellable(app, terminal, Some(cs_id), list).await;
+    match listed {
+        Ok((components_response, mut api_logs)) => {
+            // Add API client logs first
+            api_logs
+                .drain(..)
+                .for_each(|log| app.add_log_auto_scroll(log));
+
+            let num_components = components_response.components.len();
+            app.add_log_auto_scroll(format!(
+                "Received {} component IDs, resolving schemas...",
+                num_components
+            ));
+
+            let lookups = api_client::get_components(
+                ws_id,
+                cs_id,
+                &components_response.components,
+                &token,
+            );
+            let lookups =
+                match cancellable(app, terminal, Some(cs_id), lookups).await {
+                    Ok(lookups) => lookups,
+                    Err(e) => {
+                        app.selected_change_set_components = None;
+                        app.schema_component_counts.clear();
+                        app.log_api_error(
+                            LogEntry::error(
+                                "app",
+                                format!("Error resolving components: {}", e),
+                            ),
+                            &*e,
+                        );
+                        return;
+                    }
+                };
+
+            let mut components = Vec::with_capacity(num_components);
+            let mut failed = 0;
+            for (id, result) in lookups {
+                let (name, schema_id) = match result {
+                    Ok((details, logs)) => {
+                        logs.into_iter()
+                            .for_each(|log| app.add_log_auto_scroll(log));
+                        (
+                            details
+                                .component_str("name")
+                                .unwrap_or(&id)
+                                .to_string(),
+                            details
+                                .component_str("schemaId")
+                                .unwrap_or("unknown")
+                                .to_string(),
+                        )
+                    }
+                    Err(e) => {
+                        failed += 1;
+                        app.add_log_auto_scroll(LogEntry::warn(
+                            "app",
+                            format!(
+                                "Could not resolve component {}: {}",
+                                id, e
+                            ),
+                        ));
+                        (id.clone(), "unknown".to_string())
+                    }
+                };
+                components.push(ComponentViewV1 {
+                    id,
+                    schema_id,
+                    schema_variant_id: "unknown".to_string(),
+                    sockets: Vec::new(),
+                    domain_props: Vec::new(),
+                    resource_props: Vec::new(),
+                    name,
+                    resource_id: "unknown".to_string(),
+                    to_delete: false,
+                    can_be_upgraded: false,
+                    connections: Vec::new(),
+                    views: Vec::new(),
+                });
+            }
+
+            if failed > 0 {
+                app.add_log_auto_scroll(LogEntry::warn(
+                    "app",
+                    format!(
+                        "Could not resolve the schema of {} component(s).",
+                        failed
+                    ),
+                ));
+            }
+            app.schema_component_counts = count_components_by_schema(
+                components
+                    .iter()
+                    .map(|comp| comp.schema_id.as_str())
+                    .filter(|schema_id| *schema_id != "unknown"),
+            );
+            app.selected_change_set_components = Some(components);
+            app.add_log_auto_scroll(format!(
+                "Successfully processed {} component IDs.",
+                num_components
+            ));
+        }
+        Err(e) => {
+            // Log the detailed error
+            app.log_api_error(
+                format!("ERROR fetching components: {:?}", e), // Use debug format for full error
+                &*e,
+            );
+            // Ensure state is cleared on error
+            app.selected_change_set_components = None;
+            app.schema_component_counts.clear();
+            app.add_log_auto_scroll(
+                "Cleared component state due to fetch error.".to_string(),
+            );
+        }
+    }
+}
+
+// Intention: Fetch the list of schemas for the given workspace and change set.
+// Design Choice: Encapsulate schema fetching logic. Updates app state.
+pub(super) async fn fetch_schemas<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    ws_id: &str,
+    cs_id: &str,
+) {
+    app.add_log_auto_scroll(format!(
+        "Fetching schemas for change set {}...",
+        cs_id
+    ));
+    let token = app.load_token.clone();
+    let schemas = api_client::list_schemas(ws_id, cs_id, &token);
+    match cancellable(app, terminal, Some(cs_id), schemas).await {
+        Ok(schema_response) => {
+            // Removed 'mut'
+            // Store the full SchemaSummary vector
+            app.schemas = schema_response.schemas;
+            // Sort by category, then by schema name
+            app.schemas.sort_unstable_by(|a, b| {
+                a.category
+                    .cmp(&b.category)
+                    .then_with(|| a.schema_name.cmp(&b.schema_name))
+            });
+            // Select the first schema row of the tree, or clear the selection if empty
+            app.reset_schema_selection();
+            app.add_log_auto_scroll(
+                "Successfully fetched schemas.".to_string(),
+            );
+        }
+        Err(e) => {
+            app.schemas.clear(); // Clear schemas on error
+            app.schema_list_state.select(None); // Clear selection on error
+            app.log_api_error(
+                LogEntry::error(
+                    "app",
+                    format!("Error fetching schemas: {}", e),
+                ),
+                &*e,
+            );
+        }
+    }
+}
+
+// Intention: Load a workspace from scratch: whoami, change sets, then schemas and components of
+// the selected change set. Used at startup, after switching workspace and to reconnect.
+// Design Choice: Call whoami first, then list_change_sets if whoami succeeds. A whoami answer
+// means the backend is reachable, which ends the offline mode; what was loaded is then saved
+// to the snapshot. If the backend cannot be reached, the snapshot is shown instead.
+pub(super) async fn load_workspace<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+) {
+    app.add_log_auto_scroll("Fetching /whoami data...".to_string());
+    let token = app.load_token.clone();
+    match cancellable(app, terminal, None, api_client::whoami(&token)).await {
+        Ok((whoami_data, whoami_logs)) => {
+            let _workspace_id = whoami_data.workspace_id.clone(); // Prefix with _ as it's not directly used here
+            app.whoami_data = Some(whoami_data);
+            app.go_online();
+            // Add logs individually to ensure auto-scroll
+            for log in whoami_logs {
+                app.add_log_auto_scroll(log);
+            }
+            app.add_log_auto_scroll("/whoami call successful.".to_string());
+            // Initial fetch of change sets
+            refresh_change_sets(app).await; // This populates app.change_sets and might select one
+
+            // After fetching change sets, fetch schemas and components for the selected one
+            if let Some(selected_cs) = app.get_selected_changeset_summary() {
+                let cs_id = selected_cs.id.clone();
+                let workspace_id =
+                    app.whoami_data.as_ref().unwrap().workspace_id.clone(); // Safe unwrap due to check above
+                fetch_schemas(app, terminal, &workspace_id, &cs_id).await;
+                fetch_components(app, terminal, &workspace_id, &cs_id).await;
+            } else {
+                app.add_log_auto_scroll(
+                    "No change set selected initially, skipping schema fetch."
+                        .to_string(),
+                );
+            }
+            if !app.offline {
+                app.save_snapshot(Utc::now());
+            }
+        }
+        Err(e) => {
+            // Log the error message for whoami failure into the app's log buffer.
+            let error_msg = LogEntry::error(
+                "app",
+                format!("Error fetching initial data: {}", e),
+            );
+            app.log_api_error(error_msg, &*e);
+            // Optionally, still print to stderr during development if helpful
+            // eprintln!("Error fetching initial data: {}", e);
+        }
+    }
+    if app.offline {
+        app.show_snapshot_change_set(); // The loads that failed cleared their views
+    }
+}
@@ -0,0 +1,322 @@
+// src/run_app/key_actions.rs
+
+// Intention: Perform the action a key is bound to in Normal mode.
+// Design Choice: The event handler resolves the key to an `Action` through `App::keymap`
+// (focused context first, then global); this module holds what each action does, moved
+// unchanged from the per-focus key arms it replaces. Actions work from the app state rather
+// than the focus they were pressed in, so any action can be bound in any section of the
+// keybindings file.
+
+use std::io;
+
+use chrono::Utc;
+use ratatui::{
+    Terminal,
+    backend::Backend,
+};
+use situation::{
+    Action,
+    LogEntry,
+    Pane,
+    workspaces_path,
+};
+
+use super::{
+    commands::{
+        abandon_selected_change_set,
+        apply_reviewed_change_set,
+        load_selected_change_set,
+        open_component,
+        review_selected_change_set,
+        start_create_change_set,
+        switch_workspace,
+    },
+    fetch_helpers::fetch_components,
+};
+use crate::{
+    app::{
+        App,
+        AppFocus,
+        DropdownFocus,
+    },
+    ui::ui,
+};
+
+// Lines scrolled by one page in the request inspector's detail view
+const INSPECTOR_PAGE: u16 = 10;
+
+// Intention: Run one bound action. Returns Ok(true) if the app should quit.
+pub(super) async fn run_action<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    action: Action,
+) -> io::Result<bool> {
+    match action {
+        // --- Global ---
+        Action::Quit => return Ok(true),
+        Action::ShowHelp => app.open_help(),
+        Action::OpenPalette => app.open_palette(),
+        Action::ToggleInspector => app.toggle_inspector(),
+        Action::CycleFocus => {
+            // Tab cycles focus even when a dropdown is open, close it first
+            close_dropdown(app);
+            // Collapsed panes are skipped
+            loop {
+                app.current_focus = match app.current_focus {
+                    AppFocus::TopBar => AppFocus::SchemaList,
+                    AppFocus::SchemaList => AppFocus::ContentArea,
+                    AppFocus::ContentArea => AppFocus::LogPanel,
+                    AppFocus::LogPanel => AppFocus::TopBar,
+                    AppFocus::ChangeSetDropdown => AppFocus::SchemaList,
+                    AppFocus::WorkspaceDropdown => AppFocus::SchemaList,
+                    AppFocus::Input => AppFocus::TopBar,
+                };
+                if !app
+                    .focused_pane()
+                    .is_some_and(|pane| app.layout.is_collapsed(pane))
+                {
+                    break;
+                }
+            }
+        }
+        Action::FocusWorkspace => {
+            close_dropdown(app);
+            app.current_focus = AppFocus::TopBar;
+            app.dropdown_focus = DropdownFocus::Workspace;
+        }
+        Action::FocusChangeSet => {
+            close_dropdown(app);
+            app.current_focus = AppFocus::TopBar;
+            app.dropdown_focus = DropdownFocus::ChangeSet;
+        }
+        Action::FocusSchemas => {
+            close_dropdown(app);
+            app.reveal_pane(Pane::SchemaList);
+            app.current_focus = AppFocus::SchemaList;
+        }
+        Action::FocusLogs => {
+            close_dropdown(app);
+            app.reveal_pane(Pane::LogPanel);
+            app.current_focus = AppFocus::LogPanel;
+        }
+        Action::ScrollLogsUp => app.scroll_logs_up(),
+        Action::ScrollLogsDown => app.scroll_logs_down(),
+
+        // --- Pane layout ---
+        Action::GrowLogPanel => app.resize_log_panel(true),
+        Action::ShrinkLogPanel => app.resize_log_panel(false),
+        Action::WidenSchemaList => app.resize_schema_list(true),
+        Action::NarrowSchemaList => app.resize_schema_list(false),
+        Action::ToggleSchemaList => app.toggle_pane_collapsed(Pane::SchemaList),
+        Action::ToggleLogPanel => app.toggle_pane_collapsed(Pane::LogPanel),
+        Action::ZoomPane => app.toggle_zoom(),
+        Action::ResetLayout => app.reset_layout(),
+
+        // --- Top bar ---
+        Action::SwitchTrigger => {
+            app.dropdown_focus = match app.dropdown_focus {
+                DropdownFocus::Workspace => DropdownFocus::ChangeSet,
+                DropdownFocus::ChangeSet => DropdownFocus::Workspace,
+            };
+        }
+        Action::ActivateTrigger => match app.dropdown_focus {
+            DropdownFocus::Workspace => open_workspace_dropdown(app),
+            DropdownFocus::ChangeSet => open_change_set_dropdown(app),
+        },
+        Action::CreateChangeSet => start_create_change_set(app),
+        Action::AbandonChangeSet => {
+            abandon_selected_change_set(app, terminal).await?
+        }
+        Action::ForceApply => review_selected_change_set(app, terminal).await?,
+
+        // --- Workspace and change set dropdowns ---
+        Action::DropdownPrevious if app.workspace_dropdown_active => {
+            app.workspace_previous()
+        }
+        Action::DropdownNext if app.workspace_dropdown_active => {
+            app.workspace_next()
+        }
+        Action::DropdownConfirm if app.workspace_dropdown_active => {
+            close_dropdown(app);
+            app.current_focus = AppFocus::TopBar;
+            match app.workspace_list_state.selected() {
+                // Choosing the workspace in use just closes the dropdown
+                Some(index) if app.active_workspace != Some(index) => {
+                    switch_workspace(app, terminal, index).await?
+                }
+                _ => {}
+            }
+        }
+        Action::DropdownPrevious => app.change_set_previous(),
+        Action::DropdownNext => app.change_set_next(),
+        Action::DropdownConfirm => {
+            // Select item, close dropdown, return focus to the top bar
+            close_dropdown(app);
+            app.current_focus = AppFocus::TopBar;
+            // Fetch details and schemas for the newly selected item
+            load_selected_change_set(app, terminal).await?;
+        }
+        Action::DropdownClose => {
+            // Close dropdown without changing selection
+            close_dropdown(app);
+            app.current_focus = AppFocus::TopBar;
+        }
+
+        // --- Schema list ---
+        Action::SchemaPrevious => app.schema_previous(),
+        Action::SchemaNext => app.schema_next(),
+        Action::CollapseCategory => {
+            app.set_selected_category_collapsed(Some(false))
+        }
+        Action::ExpandCategory => {
+            app.set_selected_category_collapsed(Some(true))
+        }
+        Action::ToggleCategory => app.set_selected_category_collapsed(None),
+        Action::SearchSchemas => app.start_schema_search(),
+        Action::ToggleInstalledOnly => app.toggle_schema_installed_only(),
+        Action::OpenSchema if app.get_selected_schema().is_none() => {
+            // On a category header it toggles the category
+            app.set_selected_category_collapsed(None)
+        }
+        Action::OpenSchema if app.offline => {
+            // The snapshot's components are already shown
+            app.refuse_if_offline("fetch components");
+        }
+        Action::OpenSchema => {
+            // On a schema, fetch components for the selected change set
+            let workspace_id =
+                app.whoami_data.as_ref().map(|d| d.workspace_id.clone());
+            let selected_cs_id =
+                app.get_selected_changeset_summary().map(|cs| cs.id.clone());
+            if let (Some(ws_id), Some(cs_id)) = (workspace_id, selected_cs_id) {
+                app.current_action = Some("Fetching components...".to_string());
+                terminal.draw(|f| ui(f, app))?; // Redraw immediately
+                fetch_components(app, terminal, &ws_id, &cs_id).await;
+                app.current_action = None;
+            } else {
+                app.add_log_auto_scroll(
+                    "Cannot fetch components: No change set selected."
+                        .to_string(),
+                );
+            }
+        }
+
+        // --- Content area (actions table / component details / comparison / review) ---
+        Action::ActionPrevious if app.review.is_some() => {
+            app.scroll_review_up(1)
+        }
+        Action::ActionNext if app.review.is_some() => app.scroll_review_down(1),
+        Action::ActionPrevious if app.comparison.is_some() => {
+            app.scroll_comparison_up(1)
+        }
+        Action::ActionNext if app.comparison.is_some() => {
+            app.scroll_comparison_down(1)
+        }
+        Action::ActionPrevious => app.action_previous(),
+        Action::ActionNext => app.action_next(),
+        Action::OpenComponent => {
+            // Jump to the selected action's component
+            let component_id = app
+                .get_selected_action()
+                .and_then(|action| action.component.as_ref())
+                .map(|comp| comp.id.clone());
+            match component_id {
+                Some(comp_id) => {
+                    open_component(app, terminal, &comp_id).await?
+                }
+                None => app.add_log_auto_scroll(
+                    "Selected action has no component.".to_string(),
+                ),
+            }
+        }
+        Action::Back => {
+            // Return from component details, a comparison or a review to the
+            // change set view, or dismiss a finished apply summary
+            if app.review.is_some() {
+                app.close_review();
+                app.add_log_auto_scroll(LogEntry::info(
+                    "review",
+                    "Apply cancelled.",
+                ));
+            } else if app.comparison.is_some() {
+                app.close_comparison();
+            } else if app.selected_component_details.is_some() {
+                app.selected_component_details = None;
+            } else if app
+                .apply_monitor
+                .as_ref()
+                .is_some_and(|monitor| monitor.is_finished())
+            {
+                app.apply_monitor = None;
+            }
+        }
+        Action::ConfirmApply if app.review.is_some() => {
+            apply_reviewed_change_set(app, terminal).await?
+        }
+        Action::ConfirmApply => {
+            review_selected_change_set(app, terminal).await?
+        }
+        Action::ExportReview => app.export_review(Utc::now()),
+
+        // --- Request inspector ---
+        Action::InspectorPrevious => app.inspector_previous(),
+        Action::InspectorNext => app.inspector_next(),
+        Action::InspectorLatest => app.inspector_select_latest(),
+        Action::InspectorScrollUp => app.inspector_scroll_up(INSPECTOR_PAGE),
+        Action::InspectorScrollDown => {
+            app.inspector_scroll_down(INSPECTOR_PAGE)
+        }
+        Action::CloseInspector => {
+            if app.inspector_open {
+                app.toggle_inspector();
+            }
+        }
+
+        // --- Log panel ---
+        Action::CycleLogFilter => app.cycle_log_level_filter(),
+        Action::CycleLogVerbosity => app.cycle_log_verbosity(),
+        Action::SearchLogs => app.start_log_search(),
+        Action::ClearLogSearch => app.finish_log_search(false),
+    }
+    Ok(false)
+}
+
+// Intention: Open the workspace dropdown on the workspace in use.
+// Design Choice: Also opened from the command palette.
+pub(super) fn open_workspace_dropdown(app: &mut App) {
+    if app.workspaces.is_empty() {
+        app.add_log_auto_scroll(format!(
+            "No workspaces configured (see {}).",
+            workspaces_path().display()
+        ));
+    } else {
+        app.workspace_list_state
+            .select(Some(app.active_workspace.unwrap_or(0)));
+        app.workspace_dropdown_active = true;
+        app.current_focus = AppFocus::WorkspaceDropdown;
+    }
+}
+
+// Intention: Open the change set dropdown, if change sets exist.
+// Design Choice: Also used while a fetch is in flight (see `loading`).
+pub(super) fn open_change_set_dropdown(app: &mut App) {
+    if app.change_sets.as_ref().is_some_and(|cs| !cs.is_empty()) {
+        app.changeset_dropdown_active = true;
+        app.current_focus = AppFocus::ChangeSetDropdown;
+        // Ensure selection is valid if opening
+        if app.change_set_list_state.selected().is_none() {
+            app.change_set_list_state.select(Some(0));
+        }
+    } else {
+        app.add_log_auto_scroll("No change sets to select.".to_string());
+    }
+}
+
+// Intention: Close the open dropdown, if any, keeping the selection.
+pub(super) fn close_dropdown(app: &mut App) {
+    if app.changeset_dropdown_active || app.workspace_dropdown_active {
+        app.changeset_dropdown_active = false;
+        app.workspace_dropdown_active = false;
+        app.current_action = None;
+    }
+}
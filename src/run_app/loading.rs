@@ -0,0 +1,170 @@
+// src/run_app/loading.rs
+
+// Intention: Keep reading input while a fetch is in flight, so a slow backend can be given up on
+// with Esc, or by choosing another change set, instead of blocking the TUI until it answers.
+
+// Design Choices:
+// - Every input event gets a fresh `App::load_token` (see `run_app`), shared by all the fetches
+//   it starts. Esc cancels it: the fetch in flight fails with `Cancelled` and the remaining
+//   fetches of the same load return at once, leaving what they show empty.
+// - The change set dropdown stays usable during a load: opening it, moving through it and
+//   closing it are applied and drawn straight away. Confirming a change set other than the one
+//   loading cancels the load, and the confirm is handled again once the load returned, which
+//   loads the new selection.
+// - Every other event is queued in `App::pending_events` and handled after the load, in order,
+//   as if the load had been instant.
+
+use std::{
+    error::Error,
+    time::Duration,
+};
+
+use crossterm::event::{
+    self,
+    Event,
+    KeyCode,
+    KeyEvent,
+};
+use ratatui::{
+    Terminal,
+    backend::Backend,
+};
+use situation::{
+    Action,
+    KeyChord,
+    LogEntry,
+};
+
+use super::key_actions::{
+    close_dropdown,
+    open_change_set_dropdown,
+};
+use crate::{
+    app::{
+        App,
+        AppFocus,
+        DropdownFocus,
+        InputMode,
+    },
+    ui::ui,
+};
+
+// How often input is read while a fetch is in flight
+const INPUT_POLL: Duration = Duration::from_millis(50);
+
+// Intention: Run `call` (a client call given a clone of the app's load token) while reading
+// input, so it can be cancelled.
+// Design Choice: `change_set_id` is the change set the call loads for, if any; confirming
+// another one in the dropdown cancels it.
+pub(super) async fn cancellable<B: Backend, T>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    change_set_id: Option<&str>,
+    call: impl Future<Output = Result<T, Box<dyn Error + Send + Sync>>>,
+) -> Result<T, Box<dyn Error + Send + Sync>> {
+    tokio::pin!(call);
+    loop {
+        tokio::select! {
+            result = &mut call => return result,
+            () = tokio::time::sleep(INPUT_POLL) => {
+                read_input(app, terminal, change_set_id);
+            }
+        }
+    }
+}
+
+// Intention: Handle or queue the events that arrived since the last poll.
+fn read_input<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    change_set_id: Option<&str>,
+) {
+    while event::poll(Duration::ZERO).unwrap_or(false) {
+        let Ok(event) = event::read() else {
+            return;
+        };
+        let handled = match &event {
+            Event::Key(key) => handle_key(app, key, change_set_id),
+            _ => false,
+        };
+        if handled {
+            // A failed draw is retried by the next one
+            let _ = terminal.draw(|f| ui(f, app));
+        } else {
+            app.pending_events.push_back(event);
+        }
+    }
+}
+
+// Intention: Apply a key that is handled while loading. Returns false for a key to queue.
+fn handle_key(
+    app: &mut App,
+    key: &KeyEvent,
+    change_set_id: Option<&str>,
+) -> bool {
+    // Once the load is cancelled, the rest is queued behind what cancelled it
+    if app.input_mode != InputMode::Normal
+        || app.help_open
+        || app.load_token.is_cancelled()
+    {
+        return false;
+    }
+    let action = KeyChord::from_event(key)
+        .and_then(|chord| app.keymap.action_for(app.key_context(), &chord));
+    match action {
+        Some(Action::ActivateTrigger)
+            if app.current_focus == AppFocus::TopBar
+                && app.dropdown_focus == DropdownFocus::ChangeSet =>
+        {
+            open_change_set_dropdown(app);
+            true
+        }
+        Some(Action::DropdownPrevious) if app.changeset_dropdown_active => {
+            app.change_set_previous();
+            true
+        }
+        Some(Action::DropdownNext) if app.changeset_dropdown_active => {
+            app.change_set_next();
+            true
+        }
+        Some(Action::DropdownClose) if app.changeset_dropdown_active => {
+            close_dropdown(app);
+            app.current_focus = AppFocus::TopBar;
+            true
+        }
+        Some(Action::DropdownConfirm) if app.changeset_dropdown_active => {
+            let selected = app
+                .get_selected_changeset_summary()
+                .map(|cs| cs.id.as_str());
+            match change_set_id {
+                Some(loading) if selected != Some(loading) => {
+                    cancel_load(app, "another change set was selected");
+                    false // Handled again after the load, loading the new selection
+                }
+                Some(_) => {
+                    // Already loading it; the dropdown just closes
+                    close_dropdown(app);
+                    app.current_focus = AppFocus::TopBar;
+                    true
+                }
+                None => false, // Not loading a change set yet; handled after the load
+            }
+        }
+        _ if key.code == KeyCode::Esc => {
+            cancel_load(app, "Esc");
+            true
+        }
+        _ => false,
+    }
+}
+
+// Intention: Cancel the load in flight, once, saying why.
+fn cancel_load(app: &mut App, reason: &str) {
+    if !app.load_token.is_cancelled() {
+        app.load_token.cancel();
+        app.add_log_auto_scroll(LogEntry::info(
+            "app",
+            format!("Cancelled loading ({}).", reason),
+        ));
+    }
+}
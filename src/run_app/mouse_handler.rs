@@ -0,0 +1,205 @@
+// src/run_app/mouse_handler.rs
+
+// Intention: Handle mouse clicks and scroll-wheel events in Normal mode.
+// Design Choice: The position is resolved with `App::click_target` against the areas of the
+// last drawn frame; the resulting behaviour reuses the keyboard actions from `key_actions`, so a
+// click does exactly what the equivalent keys would. Mouse events are ignored while typing
+// into the input line, a search or the command palette. The help overlay, when open, takes
+// every mouse event.
+
+use std::io;
+
+use crossterm::event::{
+    MouseButton,
+    MouseEvent,
+    MouseEventKind,
+};
+use ratatui::{
+    Terminal,
+    backend::Backend,
+};
+use situation::Action;
+
+use super::key_actions::run_action;
+use crate::app::{
+    App,
+    AppFocus,
+    ClickTarget,
+    DropdownFocus,
+    InputMode,
+};
+
+// Lines (or rows) moved by one notch of the scroll wheel
+const WHEEL_STEP: usize = 3;
+
+// Intention: Process a single mouse event and update the app state accordingly.
+pub async fn handle_mouse_event<B: Backend>(
+    mouse: MouseEvent,
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+) -> io::Result<()> {
+    if app.help_open {
+        // The wheel scrolls the help overlay, a click closes it
+        match mouse.kind {
+            MouseEventKind::Down(_) => app.close_help(),
+            MouseEventKind::ScrollUp => app.help_scroll_up(WHEEL_STEP as u16),
+            MouseEventKind::ScrollDown => {
+                app.help_scroll_down(WHEEL_STEP as u16)
+            }
+            _ => {}
+        }
+        return Ok(());
+    }
+    if app.input_mode != InputMode::Normal {
+        return Ok(());
+    }
+    let target = app.click_target(mouse.column, mouse.row);
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            click(app, terminal, target).await?
+        }
+        MouseEventKind::ScrollUp => scroll(app, target, true),
+        MouseEventKind::ScrollDown => scroll(app, target, false),
+        _ => {}
+    }
+    Ok(())
+}
+
+// Intention: Focus the clicked pane and act on the clicked element.
+// Design Choice: A click outside an open dropdown closes it before the click is handled.
+async fn click<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    target: Option<ClickTarget>,
+) -> io::Result<()> {
+    if app.changeset_dropdown_active
+        && !matches!(
+            target,
+            Some(ClickTarget::Dropdown(_) | ClickTarget::ChangeSetTrigger)
+        )
+    {
+        run_action(app, terminal, Action::DropdownClose).await?;
+    }
+    if app.workspace_dropdown_active
+        && !matches!(
+            target,
+            Some(
+                ClickTarget::WorkspaceDropdown(_)
+                    | ClickTarget::WorkspaceTrigger
+            )
+        )
+    {
+        run_action(app, terminal, Action::DropdownClose).await?;
+    }
+    match target {
+        Some(ClickTarget::WorkspaceTrigger) => {
+            // Clicking the trigger toggles the dropdown
+            if app.workspace_dropdown_active {
+                run_action(app, terminal, Action::DropdownClose).await?;
+            } else {
+                app.current_focus = AppFocus::TopBar;
+                app.dropdown_focus = DropdownFocus::Workspace;
+                run_action(app, terminal, Action::ActivateTrigger).await?;
+            }
+        }
+        Some(ClickTarget::ChangeSetTrigger) => {
+            // Clicking the trigger toggles the dropdown
+            if app.changeset_dropdown_active {
+                run_action(app, terminal, Action::DropdownClose).await?;
+            } else {
+                app.current_focus = AppFocus::TopBar;
+                app.dropdown_focus = DropdownFocus::ChangeSet;
+                run_action(app, terminal, Action::ActivateTrigger).await?;
+            }
+        }
+        Some(ClickTarget::Dropdown(Some(index))) => {
+            // Select the clicked change set and load it, like Enter
+            let id = app
+                .change_sets
+                .as_ref()
+                .and_then(|cs| cs.get(index))
+                .map(|cs| cs.id.clone());
+            if let Some(id) = id {
+                if app.change_set_list_state.selected() != Some(index) {
+                    app.select_change_set_by_id(&id);
+                }
+                run_action(app, terminal, Action::DropdownConfirm).await?;
+            }
+        }
+        Some(ClickTarget::Dropdown(None)) => {}
+        Some(ClickTarget::WorkspaceDropdown(Some(index))) => {
+            // Switch to the clicked workspace, like Enter
+            app.workspace_list_state.select(Some(index));
+            run_action(app, terminal, Action::DropdownConfirm).await?;
+        }
+        Some(ClickTarget::WorkspaceDropdown(None)) => {}
+        Some(ClickTarget::SchemaList(row)) => {
+            app.current_focus = AppFocus::SchemaList;
+            if let Some(row) = row {
+                // A second click on a schema opens it; a click on a category toggles it
+                let reselected = app.schema_list_state.selected() == Some(row);
+                app.schema_list_state.select(Some(row));
+                if app.get_selected_schema().is_none() || reselected {
+                    run_action(app, terminal, Action::OpenSchema).await?;
+                }
+            }
+        }
+        Some(ClickTarget::Content) => app.current_focus = AppFocus::ContentArea,
+        Some(ClickTarget::LogPanel) => app.current_focus = AppFocus::LogPanel,
+        None => {}
+    }
+    Ok(())
+}
+
+// Intention: Scroll the pane under the pointer without moving focus.
+// Design Choice: Lists move their selection one row per notch, text views scroll `WHEEL_STEP`
+// lines; the content area scrolls the request inspector when it is open.
+fn scroll(app: &mut App, target: Option<ClickTarget>, up: bool) {
+    match target {
+        Some(ClickTarget::LogPanel) => {
+            for _ in 0..WHEEL_STEP {
+                if up {
+                    app.scroll_logs_up();
+                } else {
+                    app.scroll_logs_down();
+                }
+            }
+        }
+        Some(ClickTarget::Content) if app.inspector_open => {
+            if up {
+                app.inspector_scroll_up(WHEEL_STEP as u16);
+            } else {
+                app.inspector_scroll_down(WHEEL_STEP as u16);
+            }
+        }
+        Some(ClickTarget::Content) => {
+            if up {
+                app.action_previous();
+            } else {
+                app.action_next();
+            }
+        }
+        Some(ClickTarget::SchemaList(_)) => {
+            if up {
+                app.schema_previous();
+            } else {
+                app.schema_next();
+            }
+        }
+        Some(ClickTarget::Dropdown(_)) => {
+            if up {
+                app.change_set_previous();
+            } else {
+                app.change_set_next();
+            }
+        }
+        Some(ClickTarget::WorkspaceDropdown(_)) => {
+            if up {
+                app.workspace_previous();
+            } else {
+                app.workspace_next();
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,95 @@
+// src/run_app/poll_apply_monitor.rs
+
+// Intention: Advance the apply monitor started by a force apply, from the main loop.
+// Design Choice: Only polls when the monitor says a poll is due, so the main loop keeps
+// handling input between polls. When the monitor finishes, the summary is logged and the
+// change set list is refreshed, since the applied change set's status has changed.
+
+use situation::{
+    ApplyOutcome,
+    LogEntry,
+    api_client::{
+        self,
+        CancelToken,
+    },
+};
+
+use crate::{
+    app::App,
+    refresh_change_sets::refresh_change_sets,
+};
+
+// Intention: Run one poll of the active apply monitor if it is due.
+// Design Choice: The monitor stays in `app.apply_monitor` after finishing so the content
+// area can keep showing the summary until it is dismissed.
+pub async fn poll_apply_monitor(app: &mut App) {
+    let Some(monitor) = app.apply_monitor.as_mut() else {
+        return;
+    };
+    // Polls would fail unsent while the client waits for a new token, and count as errors;
+    // offline, they would only fail
+    if !monitor.is_due() || api_client::awaiting_token() || app.offline {
+        return;
+    }
+
+    let cs_id = monitor.change_set_id().to_string();
+    // Not tied to an input event, so nothing cancels it
+    let result = monitor.poll(&CancelToken::new()).await;
+    let poll_count = monitor.poll_count();
+    let finished = monitor.is_finished();
+    let summary = monitor.summary();
+
+    match result {
+        Ok(logs) => logs
+            .into_iter()
+            .for_each(|log| app.add_log_auto_scroll(log)),
+        Err(e) => app.log_api_error(
+            LogEntry::error(
+                "apply_monitor",
+                format!("Error polling apply status for {}: {}", cs_id, e),
+            ),
+            &*e,
+        ),
+    }
+
+    if !finished {
+        app.current_action = Some(format!(
+            "Applying {} ({} done, {} failed, {} pending; poll {})",
+            cs_id,
+            summary.succeeded.len(),
+            summary.failed.len(),
+            summary.pending.len(),
+            poll_count
+        ));
+        return;
+    }
+
+    app.current_action = None;
+    let outcome = match &summary.outcome {
+        Some(ApplyOutcome::Completed) => "completed".to_string(),
+        Some(ApplyOutcome::TimedOut) => "timed out".to_string(),
+        Some(ApplyOutcome::Errored(e)) => {
+            format!("stopped after errors ({})", e)
+        }
+        None => "stopped".to_string(),
+    };
+    app.add_log_auto_scroll(format!(
+        "Apply of {} {} in {}s: {} succeeded, {} failed, {} pending.",
+        cs_id,
+        outcome,
+        summary.elapsed.as_secs(),
+        summary.succeeded.len(),
+        summary.failed.len(),
+        summary.pending.len()
+    ));
+    for failed in &summary.failed {
+        app.add_log_auto_scroll(LogEntry::error(
+            "apply_monitor",
+            format!(
+                "Failed action: [{}] {} ({})",
+                failed.action.kind, failed.action.name, failed.action.id
+            ),
+        ));
+    }
+    refresh_change_sets(app).await;
+}
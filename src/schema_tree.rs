@@ -0,0 +1,117 @@
+// src/schema_tree.rs
+
+// Intention:
+// Builds the rows of the schema pane: schemas grouped under their category, filtered by an
+// incremental search and an installed-only toggle, with per-schema component counts.
+
+// Design Choices:
+// - Pure function over `SchemaSummary` slices so the TUI only has to render rows and map the
+//   selected row back to a schema. Lives in the library so it can be unit tested.
+// - Rows refer to schemas by index into the input slice instead of cloning them.
+// - While a search is active every category with a match is shown expanded, otherwise
+//   collapsed categories would hide the results being searched for.
+
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use crate::api_models::SchemaSummary;
+
+/// Filter and expansion state used to build the schema tree.
+#[derive(Debug, Clone, Copy)]
+pub struct SchemaTreeOptions<'a> {
+    /// Case-insensitive substring matched against schema names and categories. Empty = no filter.
+    pub query: &'a str,
+    /// Hide schemas that are not installed in the workspace.
+    pub installed_only: bool,
+    /// Categories whose schemas are hidden (ignored while `query` is non-empty).
+    pub collapsed: &'a HashSet<String>,
+}
+
+/// One visible row of the schema tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaTreeRow {
+    /// A category header.
+    Category {
+        name: String,
+        /// Number of schemas in the category that pass the filters.
+        schema_count: usize,
+        /// Total components in the current change set across those schemas.
+        component_count: usize,
+        /// Whether the category's schemas are hidden.
+        collapsed: bool,
+    },
+    /// A schema, by index into the slice passed to [`build_schema_tree`].
+    Schema {
+        index: usize,
+        /// Components in the current change set using this schema.
+        component_count: usize,
+    },
+}
+
+/// Counts components per schema ID.
+/// Intention: Turn the resolved schema ID of every component in a change set into the counts
+/// shown next to each schema. Components whose schema is unknown should be left out by the caller.
+pub fn count_components_by_schema<'a>(
+    schema_ids: impl IntoIterator<Item = &'a str>,
+) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for schema_id in schema_ids {
+        *counts.entry(schema_id.to_string()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Builds the visible rows of the schema tree.
+///
+/// Categories are ordered by name and schemas by name within each category, regardless of
+/// the order of `schemas`. Categories with no schema passing the filters are omitted.
+pub fn build_schema_tree(
+    schemas: &[SchemaSummary],
+    component_counts: &HashMap<String, usize>,
+    options: SchemaTreeOptions,
+) -> Vec<SchemaTreeRow> {
+    let query = options.query.trim().to_lowercase();
+    let searching = !query.is_empty();
+
+    let mut visible: Vec<usize> = (0..schemas.len())
+        .filter(|&i| {
+            let schema = &schemas[i];
+            (!options.installed_only || schema.installed)
+                && (!searching
+                    || schema.schema_name.to_lowercase().contains(&query)
+                    || schema.category.to_lowercase().contains(&query))
+        })
+        .collect();
+    visible.sort_by(|&a, &b| {
+        schemas[a]
+            .category
+            .cmp(&schemas[b].category)
+            .then_with(|| schemas[a].schema_name.cmp(&schemas[b].schema_name))
+    });
+
+    let count_for =
+        |i: usize| *component_counts.get(&schemas[i].schema_id).unwrap_or(&0);
+
+    let mut rows = Vec::new();
+    for group in
+        visible.chunk_by(|&a, &b| schemas[a].category == schemas[b].category)
+    {
+        let name = schemas[group[0]].category.clone();
+        let collapsed = !searching && options.collapsed.contains(&name);
+        rows.push(SchemaTreeRow::Category {
+            schema_count: group.len(),
+            component_count: group.iter().map(|&i| count_for(i)).sum(),
+            collapsed,
+            name,
+        });
+        if !collapsed {
+            rows.extend(group.iter().map(|&i| SchemaTreeRow::Schema {
+                index: i,
+                component_count: count_for(i),
+            }));
+        }
+    }
+    rows
+}
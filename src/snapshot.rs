@@ -0,0 +1,193 @@
+// src/snapshot.rs
+
+// Intention:
+// Keep the last state fetched from a workspace on disk, so the TUI can still show it when the
+// backend cannot be reached (e.g. the VPN dropped), in a read-only offline mode.
+
+// Design Choices:
+// - One JSON file per workspace (named after the workspace entry) in a cache directory: the
+//   whoami response, the change set list and, for each change set loaded while online, its
+//   details, merge status, schemas and components. Change sets that left the list are dropped,
+//   so the file does not grow without bound.
+// - Components are stored as the id, name and schema the TUI resolves for the list, not the
+//   full component views, which are fetched on demand.
+// - A file is written to a temporary name and renamed, so a crash never leaves a half snapshot.
+// - `saved_at` is the time of the last save; the TUI shows the snapshot's age from it.
+
+use std::{
+    collections::BTreeMap,
+    env,
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use chrono::{
+    DateTime,
+    Utc,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+    api_models::{
+        ChangeSet,
+        ChangeSetSummary,
+        ComponentViewV1,
+        MergeStatusV1Response,
+        SchemaSummary,
+        WhoamiResponse,
+    },
+    format_time_left,
+};
+
+/// Environment variable overriding the snapshot directory.
+pub const SNAPSHOT_DIR_ENV: &str = "SI_SNAPSHOT_DIR";
+
+/// A component as listed in the TUI: its id, name and schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotComponent {
+    pub id: String,
+    pub name: String,
+    pub schema_id: String,
+}
+
+impl SnapshotComponent {
+    /// The component as the TUI lists it; like the list the TUI builds from `list_components`,
+    /// only the id, name and schema are known.
+    pub fn to_view(&self) -> ComponentViewV1 {
+        ComponentViewV1 {
+            id: self.id.clone(),
+            schema_id: self.schema_id.clone(),
+            schema_variant_id: "unknown".to_string(),
+            sockets: Vec::new(),
+            domain_props: Vec::new(),
+            resource_props: Vec::new(),
+            name: self.name.clone(),
+            resource_id: "unknown".to_string(),
+            to_delete: false,
+            can_be_upgraded: false,
+            connections: Vec::new(),
+            views: Vec::new(),
+        }
+    }
+}
+
+/// What was loaded for one change set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChangeSetSnapshot {
+    pub details: Option<ChangeSet>,
+    pub merge_status: Option<MergeStatusV1Response>,
+    pub schemas: Vec<SchemaSummary>,
+    pub components: Option<Vec<SnapshotComponent>>,
+}
+
+/// The last known state of a workspace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceSnapshot {
+    pub saved_at: DateTime<Utc>,
+    pub whoami: WhoamiResponse,
+    pub change_sets: Vec<ChangeSetSummary>,
+    pub selected_change_set: Option<String>, // Id of the change set selected when saved
+    pub loaded: BTreeMap<String, ChangeSetSnapshot>, // By change set id
+}
+
+impl WorkspaceSnapshot {
+    pub fn new(
+        whoami: WhoamiResponse,
+        change_sets: Vec<ChangeSetSummary>,
+        saved_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            saved_at,
+            whoami,
+            change_sets,
+            selected_change_set: None,
+            loaded: BTreeMap::new(),
+        }
+    }
+
+    /// Replaces the change set list, forgetting what was loaded for change sets no longer in it.
+    pub fn set_change_sets(&mut self, change_sets: Vec<ChangeSetSummary>) {
+        self.loaded
+            .retain(|id, _| change_sets.iter().any(|cs| &cs.id == id));
+        if self
+            .selected_change_set
+            .as_ref()
+            .is_some_and(|id| !change_sets.iter().any(|cs| &cs.id == id))
+        {
+            self.selected_change_set = None;
+        }
+        self.change_sets = change_sets;
+    }
+
+    /// How long ago the snapshot was saved, in the units of `format_time_left` (`just now` under
+    /// a second).
+    pub fn age_label(&self, now: DateTime<Utc>) -> String {
+        if (now - self.saved_at).num_seconds() < 1 {
+            "just now".to_string()
+        } else {
+            format_time_left(now, self.saved_at)
+        }
+    }
+
+    /// Reads a snapshot file.
+    pub fn load(path: &Path) -> Result<WorkspaceSnapshot, String> {
+        let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        serde_json::from_str(&text)
+            .map_err(|e| format!("invalid snapshot: {}", e))
+    }
+
+    /// Writes the snapshot, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = serde_json::to_string(self).map_err(io::Error::other)?;
+        let temporary = path.with_extension("json.tmp");
+        fs::write(&temporary, text)?;
+        fs::rename(&temporary, path)
+    }
+
+    /// The snapshot directory.
+    /// Order: `SI_SNAPSHOT_DIR`, `$XDG_CACHE_HOME/situation/snapshots`,
+    /// `$HOME/.cache/situation/snapshots`, then `snapshots` in the working directory.
+    pub fn default_dir() -> PathBuf {
+        if let Ok(path) = env::var(SNAPSHOT_DIR_ENV)
+            && !path.trim().is_empty()
+        {
+            return PathBuf::from(path);
+        }
+        let cache_dir = env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| {
+                env::var_os("HOME")
+                    .map(|home| PathBuf::from(home).join(".cache"))
+            });
+        match cache_dir {
+            Some(dir) => dir.join("situation").join("snapshots"),
+            None => PathBuf::from("snapshots"),
+        }
+    }
+
+    /// The snapshot file of the workspace named `workspace` in `dir`. Characters other than
+    /// letters, digits, `-` and `_` are replaced, so any workspace name gives a plain file name.
+    pub fn path_in(dir: &Path, workspace: &str) -> PathBuf {
+        let name: String = workspace
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        dir.join(format!("{}.json", name))
+    }
+}
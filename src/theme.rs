@@ -0,0 +1,428 @@
+// src/theme.rs
+
+// Intention:
+// Defines the colours the TUI draws with, as a named set of roles (focused border, dimmed text,
+// hotkey letters, status colours, ...), with built-in dark, light and high-contrast themes,
+// user theme files and `NO_COLOR` support.
+
+// Design Choices:
+// - Render code asks the theme for a style by role (`theme.dim()`, `theme.border(focused)`)
+//   instead of naming colours, so a theme only has to choose colours, not know the layout.
+// - `dark` reproduces the original hard-coded colours. `light` avoids `DarkGray`/`Gray`, which
+//   are unreadable on many light terminals, using 256-colour greys instead.
+// - When `NO_COLOR` is set (to anything non-empty, per no-color.org) every role has no colour and
+//   the styles fall back to modifiers: reversed selection, bold focused border and errors,
+//   underlined hotkeys.
+// - Theme files are `role = colour` lines with an optional `base = <built-in>` (default `dark`)
+//   for the roles they do not set. Colours are anything ratatui's `Color::from_str` accepts:
+//   names (`light_blue`, `dark gray`), `#rrggbb` or a 0-255 palette index.
+//   ```text
+//   base = light
+//   border_focused = #005faf
+//   hotkey = magenta
+//   ```
+
+use std::{
+    env,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+    str::FromStr,
+};
+
+use ratatui::style::{
+    Color,
+    Modifier,
+    Style,
+};
+
+use crate::config_file::{
+    Entry,
+    config_dir,
+    line_errors,
+    parse_entries,
+};
+
+/// Environment variable selecting the theme: a built-in name, a user theme name or a file path.
+pub const THEME_ENV: &str = "SI_THEME";
+/// Environment variable disabling colour when set to a non-empty value.
+pub const NO_COLOR_ENV: &str = "NO_COLOR";
+/// Extension of user theme files in `Theme::themes_dir()`.
+pub const THEME_FILE_EXTENSION: &str = "theme";
+
+/// Names of the built-in themes.
+pub const BUILTIN_THEMES: [&str; 3] = ["dark", "light", "high-contrast"];
+
+/// The colour of every UI role.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    pub name: String,
+    /// Border of the focused pane.
+    pub border_focused: Color,
+    /// Border of the other panes.
+    pub border: Color,
+    /// Secondary text: hints, timestamps, unselected triggers.
+    pub dim: Color,
+    /// The highlighted letter of a focus hotkey (`W`orkspace, `L`ogs, ...).
+    pub hotkey: Color,
+    /// Names and values worth picking out: workspace, counts, current action, prompts.
+    pub accent: Color,
+    /// The selected change set, search queries, matched characters, input line.
+    pub highlight: Color,
+    /// Selected rows and the focused top bar trigger.
+    pub selection_fg: Color,
+    pub selection_bg: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+    /// Informational colour: debug log entries, installed schemas.
+    pub info: Color,
+    /// De-emphasized items: abandoned change sets, palette entry kinds.
+    pub muted: Color,
+    /// States that need attention but are not errors (actions on hold).
+    pub special: Color,
+    /// No colours at all: roles are shown with modifiers only (`NO_COLOR`).
+    pub monochrome: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::dark()
+    }
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            name: "dark".to_string(),
+            border_focused: Color::Cyan,
+            border: Color::DarkGray,
+            dim: Color::DarkGray,
+            hotkey: Color::Yellow,
+            accent: Color::Cyan,
+            highlight: Color::Yellow,
+            selection_fg: Color::White,
+            selection_bg: Color::DarkGray,
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
+            info: Color::Blue,
+            muted: Color::Gray,
+            special: Color::Magenta,
+            monochrome: false,
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            name: "light".to_string(),
+            border_focused: Color::Blue,
+            border: Color::Indexed(248),
+            dim: Color::Indexed(243),
+            hotkey: Color::Magenta,
+            accent: Color::Indexed(25),
+            highlight: Color::Indexed(130),
+            selection_fg: Color::Black,
+            selection_bg: Color::Indexed(153),
+            success: Color::Indexed(28),
+            warning: Color::Indexed(130),
+            error: Color::Indexed(160),
+            info: Color::Indexed(25),
+            muted: Color::Indexed(243),
+            special: Color::Indexed(91),
+            monochrome: false,
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "high-contrast".to_string(),
+            border_focused: Color::LightYellow,
+            border: Color::White,
+            dim: Color::White,
+            hotkey: Color::LightYellow,
+            accent: Color::LightCyan,
+            highlight: Color::LightYellow,
+            selection_fg: Color::Black,
+            selection_bg: Color::White,
+            success: Color::LightGreen,
+            warning: Color::LightYellow,
+            error: Color::LightRed,
+            info: Color::LightCyan,
+            muted: Color::White,
+            special: Color::LightMagenta,
+            monochrome: false,
+        }
+    }
+
+    /// No colours; roles are told apart by modifiers.
+    pub fn no_color() -> Self {
+        Self {
+            name: "no-color".to_string(),
+            border_focused: Color::Reset,
+            border: Color::Reset,
+            dim: Color::Reset,
+            hotkey: Color::Reset,
+            accent: Color::Reset,
+            highlight: Color::Reset,
+            selection_fg: Color::Reset,
+            selection_bg: Color::Reset,
+            success: Color::Reset,
+            warning: Color::Reset,
+            error: Color::Reset,
+            info: Color::Reset,
+            muted: Color::Reset,
+            special: Color::Reset,
+            monochrome: true,
+        }
+    }
+
+    pub fn builtin(name: &str) -> Option<Theme> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "dark" => Some(Theme::dark()),
+            "light" => Some(Theme::light()),
+            "high-contrast" | "high_contrast" | "highcontrast" => {
+                Some(Theme::high_contrast())
+            }
+            "no-color" | "no_color" => Some(Theme::no_color()),
+            _ => None,
+        }
+    }
+
+    /// Whether `NO_COLOR` asks for no colour.
+    pub fn no_color_requested() -> bool {
+        env::var_os(NO_COLOR_ENV).is_some_and(|value| !value.is_empty())
+    }
+
+    /// `$XDG_CONFIG_HOME/situation/themes`, else `~/.config/situation/themes`.
+    pub fn themes_dir() -> PathBuf {
+        config_dir()
+            .map_or_else(|| PathBuf::from("themes"), |dir| dir.join("themes"))
+    }
+
+    /// Built-in theme names followed by the user themes found in `themes_dir`, sorted.
+    pub fn available() -> Vec<String> {
+        let mut user: Vec<String> = fs::read_dir(Theme::themes_dir())
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .filter(|path| {
+                        path.extension()
+                            .is_some_and(|ext| ext == THEME_FILE_EXTENSION)
+                    })
+                    .filter_map(|path| {
+                        Some(path.file_stem()?.to_string_lossy().into_owned())
+                    })
+                    .filter(|name| Theme::builtin(name).is_none())
+                    .collect()
+            })
+            .unwrap_or_default();
+        user.sort();
+        BUILTIN_THEMES
+            .iter()
+            .map(|name| name.to_string())
+            .chain(user)
+            .collect()
+    }
+
+    /// The theme selected by `NO_COLOR` and `SI_THEME`, `dark` if neither is set. An unknown or
+    /// invalid theme is an error; callers fall back to the default.
+    pub fn from_env() -> Result<Theme, String> {
+        if Theme::no_color_requested() {
+            return Ok(Theme::no_color());
+        }
+        match env::var(THEME_ENV) {
+            Ok(name) if !name.trim().is_empty() => Theme::load(&name),
+            _ => Ok(Theme::dark()),
+        }
+    }
+
+    /// A built-in theme, a user theme by name (`<themes_dir>/<name>.theme`) or a theme file path.
+    pub fn load(name: &str) -> Result<Theme, String> {
+        let name = name.trim();
+        if let Some(theme) = Theme::builtin(name) {
+            return Ok(theme);
+        }
+        let path = if name.contains(std::path::MAIN_SEPARATOR)
+            || name.ends_with(&format!(".{}", THEME_FILE_EXTENSION))
+        {
+            PathBuf::from(name)
+        } else {
+            Theme::themes_dir()
+                .join(format!("{}.{}", name, THEME_FILE_EXTENSION))
+        };
+        Theme::load_file(&path)
+    }
+
+    /// Reads and parses a theme file, named after the file.
+    pub fn load_file(path: &Path) -> Result<Theme, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        Theme::parse(&name, &text).map_err(|errors| {
+            format!("{}: {}", path.display(), errors.join("; "))
+        })
+    }
+
+    /// Parses a theme file. Returns every problem found, in line order.
+    pub fn parse(name: &str, text: &str) -> Result<Theme, Vec<String>> {
+        let mut errors = Vec::new();
+        let entries = parse_entries(text, &mut errors);
+
+        // The base must be known before the roles are applied over it
+        let mut theme = Theme::dark();
+        for Entry { line, key, value } in &entries {
+            if *key == "base" {
+                match Theme::builtin(value) {
+                    Some(base) => theme = base,
+                    None => errors.push((
+                        *line,
+                        format!("unknown base theme '{}'", value),
+                    )),
+                }
+            }
+        }
+        theme.name = name.to_string();
+
+        for Entry { line, key, value } in entries {
+            if key == "base" {
+                continue;
+            }
+            let Some(role) = theme.role_mut(key) else {
+                errors.push((line, format!("unknown role '{}'", key)));
+                continue;
+            };
+            match Color::from_str(value) {
+                Ok(color) => *role = color,
+                Err(_) => {
+                    errors.push((line, format!("invalid colour '{}'", value)))
+                }
+            }
+        }
+
+        line_errors(theme, errors)
+    }
+
+    fn role_mut(&mut self, role: &str) -> Option<&mut Color> {
+        let color = match role {
+            "border_focused" => &mut self.border_focused,
+            "border" => &mut self.border,
+            "dim" => &mut self.dim,
+            "hotkey" => &mut self.hotkey,
+            "accent" => &mut self.accent,
+            "highlight" => &mut self.highlight,
+            "selection_fg" => &mut self.selection_fg,
+            "selection_bg" => &mut self.selection_bg,
+            "success" => &mut self.success,
+            "warning" => &mut self.warning,
+            "error" => &mut self.error,
+            "info" => &mut self.info,
+            "muted" => &mut self.muted,
+            "special" => &mut self.special,
+            _ => return None,
+        };
+        Some(color)
+    }
+
+    fn fg(&self, color: Color) -> Style {
+        if self.monochrome {
+            Style::default()
+        } else {
+            Style::default().fg(color)
+        }
+    }
+
+    /// Border of a pane, depending on whether it has focus.
+    pub fn border(&self, focused: bool) -> Style {
+        match (focused, self.monochrome) {
+            (true, true) => Style::default().add_modifier(Modifier::BOLD),
+            (true, false) => self.fg(self.border_focused),
+            (false, _) => self.fg(self.border),
+        }
+    }
+
+    pub fn dim(&self) -> Style {
+        self.fg(self.dim)
+    }
+
+    pub fn hotkey(&self) -> Style {
+        if self.monochrome {
+            Style::default().add_modifier(Modifier::UNDERLINED)
+        } else {
+            self.fg(self.hotkey)
+        }
+    }
+
+    pub fn accent(&self) -> Style {
+        self.fg(self.accent)
+    }
+
+    pub fn highlight(&self) -> Style {
+        self.fg(self.highlight)
+    }
+
+    /// A selected row or the focused trigger.
+    pub fn selection(&self) -> Style {
+        if self.monochrome {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().fg(self.selection_fg).bg(self.selection_bg)
+        }
+    }
+
+    /// An unselected item inside a focused pane (the other top bar trigger).
+    pub fn selection_inactive(&self) -> Style {
+        if self.monochrome {
+            Style::default().add_modifier(Modifier::UNDERLINED)
+        } else {
+            Style::default().fg(self.muted).bg(self.selection_bg)
+        }
+    }
+
+    pub fn success(&self) -> Style {
+        self.fg(self.success)
+    }
+
+    pub fn warning(&self) -> Style {
+        self.fg(self.warning)
+    }
+
+    pub fn error(&self) -> Style {
+        if self.monochrome {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            self.fg(self.error)
+        }
+    }
+
+    pub fn info(&self) -> Style {
+        self.fg(self.info)
+    }
+
+    /// A banner that must not be missed (the production profile): the selection text colour
+    /// on the error colour.
+    pub fn alert(&self) -> Style {
+        if self.monochrome {
+            Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)
+        } else {
+            Style::default()
+                .fg(self.selection_fg)
+                .bg(self.error)
+                .add_modifier(Modifier::BOLD)
+        }
+    }
+
+    pub fn muted(&self) -> Style {
+        self.fg(self.muted)
+    }
+
+    pub fn special(&self) -> Style {
+        self.fg(self.special)
+    }
+}
@@ -0,0 +1,249 @@
+// src/token_store.rs
+
+// Intention:
+// An encrypted local store of named JWTs, usable on every OS, so tokens do not have to sit in
+// plain text in `.env` or the profiles file. Profiles refer to an entry with `token_store = name`.
+
+// Design Choices:
+// - One file holds every token, as a JSON object of name to token, sealed with
+//   ChaCha20-Poly1305 under a key derived from a passphrase with PBKDF2-HMAC-SHA256. A fresh salt
+//   and nonce are drawn every time the file is written.
+// - The file is text, one `key value` pair per line with hex values, so it can be inspected
+//   (but not read) without tools:
+//   ```text
+//   situation-token-store v1
+//   salt <hex>
+//   nonce <hex>
+//   data <hex>
+//   ```
+// - The passphrase comes from `SI_TOKEN_STORE_PASSPHRASE`; the TUI cannot prompt before it
+//   starts. A wrong passphrase and a tampered file give the same error.
+// - The file is written to a temporary file in the same directory, with mode 0600 on Unix, and
+//   renamed over the store, so an interrupted write never loses the tokens already stored.
+// - Entries are managed with `situation token set|remove|list`.
+
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt,
+    fs,
+    io::{
+        self,
+        Write,
+    },
+    num::NonZeroU32,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use ring::{
+    aead::{
+        Aad,
+        CHACHA20_POLY1305,
+        LessSafeKey,
+        NONCE_LEN,
+        Nonce,
+        UnboundKey,
+    },
+    pbkdf2,
+    rand::{
+        SecureRandom,
+        SystemRandom,
+    },
+};
+
+use crate::config_file::config_path;
+
+/// Environment variable overriding the token store path.
+pub const TOKEN_STORE_FILE_ENV: &str = "SI_TOKEN_STORE";
+
+/// Environment variable holding the token store passphrase.
+pub const TOKEN_STORE_PASSPHRASE_ENV: &str = "SI_TOKEN_STORE_PASSPHRASE";
+
+const HEADER: &str = "situation-token-store v1";
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+const PBKDF2_ITERATIONS: u32 = 100_000;
+
+/// Named tokens, decrypted in memory.
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct TokenStore {
+    tokens: BTreeMap<String, String>,
+}
+
+// The tokens are left out so a store can be logged safely
+impl fmt::Debug for TokenStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenStore")
+            .field("names", &self.tokens.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+fn derive_key(passphrase: &str, salt: &[u8]) -> Result<LessSafeKey, String> {
+    let mut key = [0u8; KEY_LEN];
+    let iterations = NonZeroU32::new(PBKDF2_ITERATIONS).expect("non-zero");
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        passphrase.as_bytes(),
+        &mut key,
+    );
+    let key = UnboundKey::new(&CHACHA20_POLY1305, &key)
+        .map_err(|_| "cannot create the encryption key".to_string())?;
+    Ok(LessSafeKey::new(key))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+impl TokenStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.tokens.get(name).map(String::as_str)
+    }
+
+    pub fn set(&mut self, name: &str, token: &str) {
+        self.tokens.insert(name.to_string(), token.to_string());
+    }
+
+    /// Removes an entry; returns whether it existed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.tokens.remove(name).is_some()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.tokens.keys().map(String::as_str)
+    }
+
+    /// Encrypts the store into the text written to the store file.
+    pub fn seal(&self, passphrase: &str) -> Result<String, String> {
+        let rng = SystemRandom::new();
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        rng.fill(&mut salt)
+            .and_then(|_| rng.fill(&mut nonce))
+            .map_err(|_| "cannot generate random bytes".to_string())?;
+
+        let mut data = serde_json::to_vec(&self.tokens)
+            .map_err(|e| format!("cannot serialize the tokens: {}", e))?;
+        derive_key(passphrase, &salt)?
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(HEADER),
+                &mut data,
+            )
+            .map_err(|_| "cannot encrypt the tokens".to_string())?;
+
+        Ok(format!(
+            "{}\nsalt {}\nnonce {}\ndata {}\n",
+            HEADER,
+            to_hex(&salt),
+            to_hex(&nonce),
+            to_hex(&data)
+        ))
+    }
+
+    /// Decrypts the text of a store file.
+    pub fn unseal(text: &str, passphrase: &str) -> Result<Self, String> {
+        let mut lines = text.lines();
+        if lines.next().map(str::trim) != Some(HEADER) {
+            return Err("not a token store file".to_string());
+        }
+        let mut field = |name: &str| -> Result<Vec<u8>, String> {
+            lines
+                .next()
+                .and_then(|line| line.trim().strip_prefix(name))
+                .and_then(|value| from_hex(value.trim()))
+                .ok_or_else(|| format!("invalid or missing `{}` line", name))
+        };
+        let salt = field("salt")?;
+        let nonce = field("nonce")?;
+        let mut data = field("data")?;
+
+        let nonce = Nonce::try_assume_unique_for_key(&nonce)
+            .map_err(|_| "invalid `nonce` line".to_string())?;
+        let plain = derive_key(passphrase, &salt)?
+            .open_in_place(nonce, Aad::from(HEADER), &mut data)
+            .map_err(|_| {
+                "wrong passphrase or corrupted token store".to_string()
+            })?;
+        let tokens = serde_json::from_slice(plain)
+            .map_err(|e| format!("invalid token store contents: {}", e))?;
+        Ok(Self { tokens })
+    }
+
+    /// Reads the store at `path`; a missing file is an empty store.
+    pub fn load(path: &Path, passphrase: &str) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::unseal(&text, passphrase),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(e) => Err(format!("cannot read {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Writes the store to `path`, readable by the current user only (on Unix).
+    pub fn save(&self, path: &Path, passphrase: &str) -> Result<(), String> {
+        let text = self.seal(passphrase)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| {
+                format!("cannot create {}: {}", dir.display(), e)
+            })?;
+        }
+        // Written next to the store then renamed over it, so a crash or a full disk never
+        // leaves a truncated store
+        let mut temporary = path.as_os_str().to_owned();
+        temporary.push(".tmp");
+        let temporary = PathBuf::from(temporary);
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        options
+            .open(&temporary)
+            .and_then(|mut file| {
+                // `mode` only applies when the file is created; a temporary file left behind by
+                // an earlier crash is restricted too
+                #[cfg(unix)]
+                file.set_permissions(<fs::Permissions as std::os::unix::fs::PermissionsExt>::from_mode(0o600))?;
+                file.write_all(text.as_bytes())?;
+                file.sync_all()
+            })
+            .and_then(|()| fs::rename(&temporary, path))
+            .map_err(|e| {
+                fs::remove_file(&temporary).ok();
+                format!("cannot write {}: {}", path.display(), e)
+            })
+    }
+}
+
+/// `SI_TOKEN_STORE`, else `$XDG_CONFIG_HOME/situation/tokens.store`, else
+/// `~/.config/situation/tokens.store`.
+pub fn token_store_path() -> PathBuf {
+    config_path(TOKEN_STORE_FILE_ENV, "tokens.store")
+}
+
+/// The passphrase in `SI_TOKEN_STORE_PASSPHRASE`.
+pub fn token_store_passphrase() -> Result<String, String> {
+    match env::var(TOKEN_STORE_PASSPHRASE_ENV) {
+        Ok(passphrase) if !passphrase.is_empty() => Ok(passphrase),
+        _ => Err(format!("{} is not set", TOKEN_STORE_PASSPHRASE_ENV)),
+    }
+}
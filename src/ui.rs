@@ -5,13 +5,24 @@
 // It declares submodules for specific rendering tasks and calls them from the main `ui` function.
 
 // Declare submodules for rendering components
-mod get_trigger_style; // Although not directly called by `ui`, it's part of the module
+mod get_action_state_style;
+mod render_action_table;
+mod render_apply_progress;
+mod render_apply_review;
+mod render_change_set_comparison;
 mod render_changeset_dropdown;
+mod render_command_palette;
+mod render_component_details;
 mod render_content_area;
+mod render_help;
 mod render_input_line;
 mod render_log_panel;
+mod render_offline_banner;
+mod render_profile_banner;
+mod render_request_inspector;
 mod render_schema_list; // Declare the new module
 mod render_top_bar;
+mod render_workspace_dropdown;
 
 use ratatui::{
     Frame,
@@ -25,79 +36,116 @@ use ratatui::{
 };
 // Import helper functions from submodules
 use render_changeset_dropdown::render_changeset_dropdown;
+use render_command_palette::render_command_palette;
 use render_content_area::render_content_area;
+use render_help::render_help;
 use render_input_line::render_input_line;
 use render_log_panel::render_log_panel;
+use render_offline_banner::render_offline_banner;
+use render_profile_banner::render_profile_banner;
+use render_request_inspector::render_request_inspector;
 use render_schema_list::render_schema_list; // Import the new function
 use render_top_bar::render_top_bar;
+use render_workspace_dropdown::render_workspace_dropdown;
 
 use crate::app::{
     App,
     InputMode,
+    ScreenAreas,
 }; // Use App, Enums from local app module
 
-// --- Constants for UI Layout ---
-const LOG_PANEL_HEIGHT: u16 = 10;
-const SCHEMA_LIST_WIDTH: u16 = 30; // Width for the new schema list pane
-
 // Intention: Main UI rendering function. Sets up the layout and calls helper functions for each section.
-// Design Choice: Split rendering logic into focused helper functions. Added horizontal split for schema list.
+// Design Choice: Split rendering logic into focused helper functions. The schema list, details
+// pane and log panel are placed by `app.layout` (`PaneLayout::split`), which handles resized,
+// collapsed and zoomed panes; a pane with an empty area is not drawn.
 // Changed `app` parameter to `&mut App` to allow state modification by stateful widgets.
 pub fn ui(f: &mut Frame, app: &mut App) {
     // Changed to &mut App
-    // Define main vertical layout: Top Bar, Middle Area, Logs, optional Input Line.
-    let (log_constraint, input_constraint) =
-        if app.input_mode == InputMode::ChangeSetName {
-            (Constraint::Length(LOG_PANEL_HEIGHT), Constraint::Length(1)) // Log height, Input line height
-        } else {
-            (Constraint::Length(LOG_PANEL_HEIGHT), Constraint::Length(0)) // Log height, No input line
-        };
+    // Define main vertical layout: offline or production banner, Top Bar, Panes, optional Input
+    // Line.
+    let banner_constraint = if app.offline
+        || app
+            .profile
+            .as_ref()
+            .is_some_and(|profile| profile.production)
+    {
+        Constraint::Length(1) // Banner height
+    } else {
+        Constraint::Length(0) // No banner
+    };
+    let input_constraint = if matches!(
+        app.input_mode,
+        InputMode::ChangeSetName | InputMode::Token
+    ) {
+        Constraint::Length(1) // Input line height
+    } else {
+        Constraint::Length(0) // No input line
+    };
 
     // Vertical layout for the whole screen
     let vertical_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
+            banner_constraint, // Offline or production banner (conditional)
             Constraint::Length(1), // Top bar
-            Constraint::Min(0),    // Middle area (will be split horizontally)
-            log_constraint,        // Log panel
-            input_constraint,      // Input line (conditional)
+            Constraint::Min(0), // Panes (split by the pane layout)
+            input_constraint,  // Input line (conditional)
         ])
         .split(f.size());
 
-    let top_bar_area = vertical_chunks[0];
-    let middle_area = vertical_chunks[1]; // This area will contain schema list + content
-    let log_area = vertical_chunks[2];
-    let input_area = if vertical_chunks.len() > 3 {
+    let banner_area = vertical_chunks[0];
+    let top_bar_area = vertical_chunks[1];
+    let panes_area = vertical_chunks[2];
+    let input_area = if vertical_chunks[3].height > 0 {
         Some(vertical_chunks[3])
     } else {
         None
     };
 
-    // Horizontal layout for the middle area
-    let horizontal_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Length(SCHEMA_LIST_WIDTH), // Left pane for schema list
-            Constraint::Min(0), // Right pane for main content
-        ])
-        .split(middle_area); // Split the middle_area defined above
-
-    let schema_list_area = horizontal_chunks[0];
-    let content_area = horizontal_chunks[1]; // This is the new content area
+    // A zoomed layout shows the focused pane, so the zoom follows focus changes
+    if app.layout.zoomed.is_some()
+        && let Some(pane) = app.focused_pane()
+    {
+        app.layout.zoomed = Some(pane);
+    }
+    let panes = app.layout.split(panes_area);
 
     // --- Render UI Components ---
 
-    // Render Top Bar (returns area for dropdown)
-    let cs_trigger_area = render_top_bar(f, app, top_bar_area);
+    // Render the Offline or Production Banner (conditional); being offline matters more
+    if !banner_area.is_empty() {
+        if app.offline {
+            render_offline_banner(f, app, banner_area);
+        } else {
+            render_profile_banner(f, app, banner_area);
+        }
+    }
+
+    // Render Top Bar (returns the trigger areas, which position the dropdowns)
+    let (ws_trigger_area, cs_trigger_area) =
+        render_top_bar(f, app, top_bar_area);
 
     // Render Schema List
-    render_schema_list(f, app, schema_list_area); // Call the new function
+    if !panes.schema_list.is_empty() {
+        render_schema_list(f, app, panes.schema_list);
+    }
 
-    // Render Main Content Area (now on the right)
-    render_content_area(f, &*app, content_area);
+    // Render Main Content Area (now on the right), or the request inspector in its place
+    if panes.content.is_empty() {
+        // Hidden by a zoomed pane
+    } else if app.inspector_open {
+        render_request_inspector(f, app, panes.content);
+    } else {
+        render_content_area(f, app, panes.content);
+    }
 
-    // Render Log Panel
-    render_log_panel(f, app, log_area);
+    // Render Log Panel, and keep its scroll limits in step with its text height
+    if !panes.log_panel.is_empty() {
+        app.set_log_view_height(
+            panes.log_panel.height.saturating_sub(2) as usize
+        );
+        render_log_panel(f, app, panes.log_panel);
+    }
 
     // Render Input Line (conditional)
     if let Some(input_area_rect) = input_area {
@@ -105,7 +153,29 @@ pub fn ui(f: &mut Frame, app: &mut App) {
     }
 
     // Render Change Set Dropdown (overlay)
-    render_changeset_dropdown(f, app, cs_trigger_area); // Pass mutable app
+    let dropdown_area = render_changeset_dropdown(f, app, cs_trigger_area); // Pass mutable app
+
+    // Render Workspace Dropdown (overlay)
+    let workspace_dropdown_area =
+        render_workspace_dropdown(f, app, ws_trigger_area);
+
+    // Remember where everything was drawn, for mouse hit-testing
+    app.screen_areas = ScreenAreas {
+        panes: panes_area,
+        workspace_trigger: ws_trigger_area,
+        change_set_trigger: cs_trigger_area,
+        schema_list: panes.schema_list,
+        content: panes.content,
+        log_panel: panes.log_panel,
+        dropdown: dropdown_area,
+        workspace_dropdown: workspace_dropdown_area,
+    };
+
+    // Render Command Palette (overlay, drawn above the dropdowns)
+    render_command_palette(f, app);
+
+    // Render Help (overlay, drawn last since it can open above the palette)
+    render_help(f, app);
 }
 
 // Helper functions and tests previously here have been moved to their respective modules
@@ -0,0 +1,21 @@
+// src/ui/get_action_state_style.rs
+
+// Intention: Get the display style for a merge status action based on its state.
+// Design Choice: Centralizes the state -> colour mapping so the actions table and any
+// future action views colour states consistently. Unknown states use the default style.
+
+use ratatui::style::Style;
+use situation::Theme;
+
+// Intention: Map an action state string from the API to a theme style.
+// Design Choice: Case-insensitive match on the states the Luminork API reports.
+pub(super) fn get_action_state_style(theme: &Theme, state: &str) -> Style {
+    match state.to_ascii_lowercase().as_str() {
+        "queued" => theme.accent(),
+        "dispatched" | "running" => theme.warning(),
+        "failed" => theme.error(),
+        "onhold" => theme.special(),
+        "success" | "completed" => theme.success(),
+        _ => Style::default(),
+    }
+}
@@ -0,0 +1,119 @@
+// src/ui/render_action_table.rs
+
+// Intention: Render the merge status actions of the selected change set as a table.
+// Design Choice: Rows follow `actions_grouped_by_kind()` so they line up with
+// `App::action_table_state`. The kind is only printed on the first row of each group,
+// which visually groups the table without adding unselectable header rows.
+
+use ratatui::{
+    Frame,
+    layout::{
+        Constraint,
+        Rect,
+    },
+    prelude::*, // Import common traits and types
+    style::{
+        Modifier,
+        Style,
+    },
+    widgets::{
+        Block,
+        Borders,
+        Row,
+        Table,
+    },
+};
+use situation::{
+    Action,
+    KeyContext,
+};
+
+use super::get_action_state_style::get_action_state_style;
+use crate::app::{
+    App,
+    AppFocus,
+}; // Use App from local app module
+
+// Intention: Render the actions table for the selected change set's merge status.
+// Design Choice: Highlights the selected row only while the content area has focus. Actions
+// changed by the last auto-refresh are marked with `*` before their name.
+pub(super) fn render_action_table(f: &mut Frame, app: &mut App, area: Rect) {
+    let Some(merge_status) = &app.selected_change_set_merge_status else {
+        return;
+    };
+    let actions = merge_status.actions_grouped_by_kind();
+    let theme = &app.theme;
+
+    let mut previous_kind: Option<&str> = None;
+    let rows: Vec<Row> = actions
+        .iter()
+        .map(|action| {
+            // Only show the kind on the first row of each group
+            let kind_cell = if previous_kind == Some(action.kind.as_str()) {
+                String::new()
+            } else {
+                action.kind.clone()
+            };
+            previous_kind = Some(action.kind.as_str());
+
+            let component_cell = action
+                .component
+                .as_ref()
+                .map_or_else(|| "-".to_string(), |comp| comp.name.clone());
+
+            Row::new(vec![
+                Line::from(Span::styled(
+                    kind_cell,
+                    Style::default().add_modifier(Modifier::BOLD),
+                )),
+                Line::from(Span::styled(
+                    action.state.clone(),
+                    get_action_state_style(theme, &action.state),
+                )),
+                if app.changed_action_ids.contains(&action.id) {
+                    Line::from(vec![
+                        Span::styled("* ", theme.highlight()),
+                        Span::raw(action.name.clone()),
+                    ])
+                } else {
+                    Line::from(action.name.clone())
+                },
+                Line::from(Span::styled(component_cell, theme.accent())),
+            ])
+        })
+        .collect();
+
+    let header = Row::new(vec!["Kind", "State", "Action", "Component"])
+        .style(Style::default().add_modifier(Modifier::UNDERLINED));
+
+    let highlight_style = if app.current_focus == AppFocus::ContentArea {
+        theme.selection().add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),     // Kind
+            Constraint::Length(12),     // State
+            Constraint::Percentage(40), // Action name
+            Constraint::Min(10),        // Component name
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .title(format!(
+                "Actions ({}) - {}: Open Component",
+                actions.len(),
+                app.keymap
+                    .describe(KeyContext::ContentArea, Action::OpenComponent)
+            ))
+            .borders(Borders::BOTTOM),
+    )
+    .highlight_style(highlight_style)
+    .highlight_symbol("> ");
+
+    f.render_stateful_widget(table, area, &mut app.action_table_state);
+}
@@ -0,0 +1,125 @@
+// src/ui/render_apply_progress.rs
+
+// Intention: Render the progress of a running force apply, and its summary once finished.
+// Design Choice: Reads everything from the `ApplyMonitor` kept in `App`, so the view shows
+// the same data the monitor uses to decide completion. Pending actions are coloured by
+// their last reported state, like the actions table.
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    prelude::*, // Import common traits and types
+    style::{
+        Modifier,
+        Style,
+    },
+    widgets::{
+        Paragraph,
+        Wrap,
+    },
+};
+use situation::{
+    Action,
+    ApplyMonitor,
+    ApplyOutcome,
+    KeyContext,
+    Keymap,
+    Theme,
+    apply_monitor::ActionOutcome,
+};
+
+use super::get_action_state_style::get_action_state_style;
+
+// Intention: Render the apply progress view inside the content area.
+// Design Choice: Header with overall status, one line per tracked action, then a summary
+// line once the monitor has finished, with the dismiss key from the keymap.
+pub(super) fn render_apply_progress(
+    f: &mut Frame,
+    monitor: &ApplyMonitor,
+    keymap: &Keymap,
+    theme: &Theme,
+    area: Rect,
+) {
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let summary = monitor.summary();
+
+    let mut lines: Vec<Line> = vec![
+        Line::from(vec![
+            Span::styled("Applying Change Set:", bold),
+            Span::raw(format!(" {}", monitor.change_set_id())),
+        ]),
+        Line::from(vec![
+            Span::styled("Status:", bold),
+            Span::raw(format!(
+                " {} (elapsed {}s, poll {})",
+                monitor.change_set_status().unwrap_or("waiting..."),
+                monitor.elapsed().as_secs(),
+                monitor.poll_count()
+            )),
+        ]),
+        Line::from(""), // Spacer
+        Line::from(Span::styled("Actions:", bold)),
+    ];
+
+    if monitor.actions().is_empty() {
+        lines.push(Line::from("  No actions queued."));
+    }
+    for tracked in monitor.actions() {
+        let (marker, label, style) = match &tracked.outcome {
+            ActionOutcome::Succeeded => {
+                ("✓", "Succeeded".to_string(), theme.success())
+            }
+            ActionOutcome::Failed => ("✗", "Failed".to_string(), theme.error()),
+            ActionOutcome::Pending(state) => {
+                ("…", state.clone(), get_action_state_style(theme, state))
+            }
+        };
+        let component = tracked
+            .action
+            .component
+            .as_ref()
+            .map_or_else(String::new, |comp| format!(" - {}", comp.name));
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} {:<10}", marker, label), style),
+            Span::raw(format!(
+                " [{}] {}{}",
+                tracked.action.kind, tracked.action.name, component
+            )),
+        ]));
+    }
+
+    if let Some(outcome) = &summary.outcome {
+        let (text, style) = match outcome {
+            ApplyOutcome::Completed if summary.is_success() => {
+                ("Apply completed successfully.".to_string(), theme.success())
+            }
+            ApplyOutcome::Completed => {
+                ("Apply completed with failures.".to_string(), theme.error())
+            }
+            ApplyOutcome::TimedOut => {
+                ("Stopped waiting: timed out.".to_string(), theme.warning())
+            }
+            ApplyOutcome::Errored(e) => (
+                format!("Stopped waiting after errors: {}", e),
+                theme.error(),
+            ),
+        };
+        lines.push(Line::from("")); // Spacer
+        lines.push(Line::from(Span::styled(
+            text,
+            style.add_modifier(Modifier::BOLD),
+        )));
+        lines.push(Line::from(format!(
+            "Summary: {} succeeded, {} failed, {} pending.",
+            summary.succeeded.len(),
+            summary.failed.len(),
+            summary.pending.len()
+        )));
+        lines.push(Line::from(format!(
+            "{}: Dismiss",
+            keymap.describe(KeyContext::ContentArea, Action::Back)
+        )));
+    }
+
+    f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), area);
+}
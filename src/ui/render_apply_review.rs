@@ -0,0 +1,178 @@
+// src/ui/render_apply_review.rs
+
+// Intention: Render the review shown before a force apply: what the change set creates, modifies
+// and deletes relative to HEAD, and the actions that will run.
+// Design Choice: Same table as the comparison view (HEAD on the left, the change set on the
+// right), split into sections with a heading row each, and the keys that confirm, export or
+// cancel on the summary line, since applying is the one thing this screen is for. Components
+// other open change sets also modify come first, with a warning line, so they are seen before
+// applying.
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    prelude::*, // Import common traits and types
+    style::{
+        Modifier,
+        Style,
+    },
+    widgets::{
+        Cell,
+        Paragraph,
+        Row,
+    },
+};
+use situation::{
+    Action,
+    ApplyReview,
+    ComponentDiff,
+    KeyContext,
+    Keymap,
+    Theme,
+    conflicts::ComponentOverlap,
+};
+
+use super::{
+    get_action_state_style::get_action_state_style,
+    render_change_set_comparison::{
+        component_rows,
+        render_scrolled_table,
+    },
+};
+
+// Intention: A section heading row, e.g. `Created (2)`.
+fn heading(title: &str, count: usize) -> Row<'static> {
+    Row::new(vec![Cell::from(Span::styled(
+        format!("{} ({})", title, count),
+        Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+    ))])
+}
+
+fn section(
+    title: &str,
+    diffs: &[ComponentDiff],
+    theme: &Theme,
+) -> Vec<Row<'static>> {
+    let mut rows = vec![heading(title, diffs.len())];
+    rows.extend(diffs.iter().flat_map(|diff| component_rows(diff, theme)));
+    rows
+}
+
+// Intention: The rows of a component shared with other open change sets: a warning header
+// naming them, then per conflicting prop the other change sets' values (left) and this one's
+// (right).
+fn conflict_rows(
+    overlap: &ComponentOverlap,
+    change_set_id: &str,
+    theme: &Theme,
+) -> Vec<Row<'static>> {
+    let mut rows = vec![Row::new(vec![Cell::from(Span::styled(
+        format!(
+            "⚠ {} ({}) also in {}",
+            overlap.name,
+            overlap.id,
+            overlap.others(change_set_id).join(", ")
+        ),
+        theme.warning().add_modifier(Modifier::BOLD),
+    ))])];
+    rows.extend(overlap.props.iter().map(|prop| {
+        let others: Vec<String> = prop
+            .values
+            .iter()
+            .filter(|v| v.change_set.id != change_set_id)
+            .map(|v| {
+                format!(
+                    "{}: {}",
+                    v.change_set.name,
+                    prop.value_label(&v.change_set.id)
+                )
+            })
+            .collect();
+        Row::new(vec![
+            Cell::from(format!("  {}", prop.path)),
+            Cell::from(Span::styled(others.join(", "), theme.warning())),
+            Cell::from(prop.value_label(change_set_id)),
+        ])
+    }));
+    rows
+}
+
+// Intention: Render the review inside the content area; returns the scroll actually used. The
+// apply, export and cancel keys come from the keymap.
+pub(super) fn render_apply_review(
+    f: &mut Frame,
+    review: &ApplyReview,
+    scroll: u16,
+    keymap: &Keymap,
+    theme: &Theme,
+    area: Rect,
+) -> u16 {
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let keys = |action| keymap.describe(KeyContext::ContentArea, action);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+    let mut summary = vec![
+        Line::from(vec![
+            Span::styled("Review before applying:", bold),
+            Span::raw(format!(
+                " {} vs {}: {}",
+                review.change_set.name,
+                review.head.name,
+                review.summary()
+            )),
+        ]),
+        Line::from(vec![
+            Span::styled(keys(Action::ConfirmApply), theme.hotkey()),
+            Span::raw(": Apply  "),
+            Span::styled(keys(Action::ExportReview), theme.hotkey()),
+            Span::raw(": Export Markdown  "),
+            Span::styled(keys(Action::Back), theme.hotkey()),
+            Span::raw(": Cancel"),
+        ]),
+    ];
+    if !review.conflicts.is_empty() {
+        summary.push(Line::from(Span::styled(
+            format!(
+                "⚠ {} component(s) also modified in other open change sets",
+                review.conflicts.len()
+            ),
+            theme.warning().add_modifier(Modifier::BOLD),
+        )));
+    }
+    f.render_widget(Paragraph::new(summary), chunks[0]);
+
+    let mut rows = Vec::new();
+    if !review.conflicts.is_empty() {
+        rows.push(heading("Conflicts", review.conflicts.len()));
+        rows.extend(review.conflicts.iter().flat_map(|overlap| {
+            conflict_rows(overlap, &review.change_set.id, theme)
+        }));
+    }
+    rows.extend(section("Created", &review.created, theme));
+    rows.extend(section("Modified", &review.modified, theme));
+    rows.extend(section("Deleted", &review.deleted, theme));
+    rows.push(heading("Queued actions", review.actions.len()));
+    rows.extend(review.actions.iter().map(|action| {
+        Row::new(vec![
+            Cell::from(format!("  {} {}", action.kind, action.name)),
+            Cell::from(
+                action
+                    .component
+                    .as_ref()
+                    .map_or_else(|| "-".to_string(), |c| c.name.clone()),
+            ),
+            Cell::from(Span::styled(
+                action.state.clone(),
+                get_action_state_style(theme, &action.state),
+            )),
+        ])
+    }));
+    let header = Row::new(vec![
+        Cell::from("Prop / connection"),
+        Cell::from(review.head.name.clone()),
+        Cell::from(review.change_set.name.clone()),
+    ]);
+    render_scrolled_table(f, header, rows, scroll, chunks[1])
+}
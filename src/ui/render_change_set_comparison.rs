@@ -0,0 +1,125 @@
+// src/ui/render_change_set_comparison.rs
+
+// Intention: Render a change set comparison side by side: what differs, its value in the other
+// change set (left) and in the selected one (right).
+// Design Choice: One table for the whole comparison, a header row per component (marked and
+// coloured as added, removed or modified) followed by its rows from `ComponentDiff::rows`, so
+// the columns line up across components. The view scrolls by row.
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    prelude::*, // Import common traits and types
+    style::{
+        Modifier,
+        Style,
+    },
+    widgets::{
+        Cell,
+        Paragraph,
+        Row,
+        Table,
+    },
+};
+use situation::{
+    Action,
+    ChangeSetComparison,
+    ComponentDiff,
+    ComponentDiffKind,
+    KeyContext,
+    Keymap,
+    Theme,
+};
+
+// Intention: The rows of one component: a header row (marked and coloured by kind), then what
+// differs. Shared with the pre-apply review.
+pub(super) fn component_rows(
+    diff: &ComponentDiff,
+    theme: &Theme,
+) -> Vec<Row<'static>> {
+    let style = match diff.kind {
+        ComponentDiffKind::Added => theme.success(),
+        ComponentDiffKind::Removed => theme.error(),
+        ComponentDiffKind::Modified => theme.warning(),
+    };
+    let mut rows = vec![Row::new(vec![Cell::from(Span::styled(
+        format!("{} {} ({})", diff.kind.symbol(), diff.name(), diff.id()),
+        style.add_modifier(Modifier::BOLD),
+    ))])];
+    rows.extend(diff.rows().into_iter().map(|row| {
+        Row::new(vec![
+            Cell::from(format!("  {}", row.label)),
+            Cell::from(Span::styled(row.left, theme.muted())),
+            Cell::from(row.right),
+        ])
+    }));
+    rows
+}
+
+// Intention: Draw rows under a header, skipping `scroll` rows; returns the scroll used, clamped
+// so the last row stays visible. Shared with the pre-apply review.
+pub(super) fn render_scrolled_table(
+    f: &mut Frame,
+    header: Row<'static>,
+    rows: Vec<Row<'static>>,
+    scroll: u16,
+    area: Rect,
+) -> u16 {
+    let visible = area.height.saturating_sub(1) as usize; // Below the header
+    let max_scroll = rows.len().saturating_sub(visible.max(1));
+    let scroll = (scroll as usize).min(max_scroll);
+    let table = Table::new(
+        rows.into_iter().skip(scroll),
+        [
+            Constraint::Percentage(40),
+            Constraint::Percentage(30),
+            Constraint::Percentage(30),
+        ],
+    )
+    .header(header.style(Style::default().add_modifier(Modifier::BOLD)));
+    f.render_widget(table, area);
+    scroll as u16
+}
+
+// Intention: Render the comparison inside the content area; returns the scroll actually used,
+// clamped to the rows there are. The back hint comes from the keymap.
+pub(super) fn render_change_set_comparison(
+    f: &mut Frame,
+    comparison: &ChangeSetComparison,
+    scroll: u16,
+    keymap: &Keymap,
+    theme: &Theme,
+    area: Rect,
+) -> u16 {
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .split(area);
+    let summary = Line::from(vec![
+        Span::styled("Comparison:", bold),
+        Span::raw(format!(
+            " {} -> {}: {}  ({}: Back)",
+            comparison.left.name,
+            comparison.right.name,
+            comparison.summary(),
+            keymap.describe(KeyContext::ContentArea, Action::Back)
+        )),
+    ]);
+    f.render_widget(Paragraph::new(summary), chunks[0]);
+
+    let mut rows: Vec<Row> = comparison
+        .components
+        .iter()
+        .flat_map(|diff| component_rows(diff, theme))
+        .collect();
+    if rows.is_empty() {
+        rows.push(Row::new(vec![Cell::from("  No differences.")]));
+    }
+    let header = Row::new(vec![
+        Cell::from("Prop / connection"),
+        Cell::from(comparison.left.name.clone()),
+        Cell::from(comparison.right.name.clone()),
+    ]);
+    render_scrolled_table(f, header, rows, scroll, chunks[1])
+}
@@ -9,7 +9,6 @@ use ratatui::{
     layout::Rect,
     prelude::*, // Import common traits and types
     style::{
-        Color,
         Modifier,
         Style,
     },
@@ -32,11 +31,14 @@ const DROPDOWN_MAX_ITEMS: usize = 10;
 // Intention: Render the Change Set dropdown list overlay if active.
 // Design Choice: Encapsulates the logic for calculating dropdown position, creating list items,
 // and rendering the stateful List widget. Requires the Change Set trigger area for positioning.
+// Change sets changed by the last auto-refresh are marked with `*`, and those sharing components
+// with other open change sets (last conflict scan) get a `⚠ N` badge.
+// Returns the area of the open dropdown for mouse hit-testing.
 pub(super) fn render_changeset_dropdown(
     f: &mut Frame,
-    app: &App,
+    app: &mut App,
     cs_trigger_area: Rect,
-) {
+) -> Option<Rect> {
     if app.changeset_dropdown_active {
         // Use constants for dropdown dimensions
         let list_height = app
@@ -65,21 +67,35 @@ pub(super) fn render_changeset_dropdown(
                         .iter()
                         .map(|cs| {
                             let status_style = match cs.status.as_str() {
-                                "Completed" => {
-                                    Style::default().fg(Color::Green)
-                                }
-                                "Failed" => Style::default().fg(Color::Red),
-                                "InProgress" => {
-                                    Style::default().fg(Color::Yellow)
-                                }
-                                "Abandoned" => Style::default().fg(Color::Gray),
+                                "Completed" => app.theme.success(),
+                                "Failed" => app.theme.error(),
+                                "InProgress" => app.theme.warning(),
+                                "Abandoned" => app.theme.muted(),
                                 _ => Style::default(),
                             };
-                            ListItem::new(format!(
+                            // Changed by the last auto-refresh
+                            let marker = if app
+                                .changed_change_set_ids
+                                .contains(&cs.id)
+                            {
+                                Span::styled("* ", app.theme.highlight())
+                            } else {
+                                Span::raw("  ")
+                            };
+                            let mut spans = vec![marker];
+                            if let Some(count) = app.conflict_count(&cs.id) {
+                                spans.push(Span::styled(
+                                    format!("⚠ {} ", count),
+                                    app.theme
+                                        .warning()
+                                        .add_modifier(Modifier::BOLD),
+                                ));
+                            }
+                            spans.push(Span::raw(format!(
                                 "{} ({}) - {}",
                                 cs.name, cs.status, cs.id
-                            ))
-                            .style(status_style)
+                            )));
+                            ListItem::new(Line::from(spans)).style(status_style)
                         })
                         .collect()
                 }
@@ -93,17 +109,20 @@ pub(super) fn render_changeset_dropdown(
                     .title("Select Change Set (Enter/Esc)")
                     .borders(Borders::ALL),
             )
-            .highlight_style(
-                Style::default()
-                    .bg(Color::LightBlue)
-                    .add_modifier(Modifier::BOLD),
-            )
+            .highlight_style(app.theme.selection().add_modifier(Modifier::BOLD))
             .highlight_symbol("> ")
             .highlight_spacing(HighlightSpacing::Always);
 
         // Render the dropdown list
         f.render_widget(Clear, list_area); // Clear the area first
-        let mut list_state = app.change_set_list_state.clone(); // Clone state for rendering
-        f.render_stateful_widget(dropdown_list, list_area, &mut list_state);
+        // Render with the app's state so the scroll offset is kept for mouse clicks
+        f.render_stateful_widget(
+            dropdown_list,
+            list_area,
+            &mut app.change_set_list_state,
+        );
+        Some(list_area)
+    } else {
+        None
     }
 }
@@ -0,0 +1,141 @@
+// src/ui/render_command_palette.rs
+
+// Intention: Render the Ctrl-P command palette overlay when it is open.
+// Design Choice: Centered box with the query on the first line and the ranked entries below.
+// Matched characters are highlighted so it is clear why an entry is listed. Entries are
+// rebuilt from app state on each draw (see `App::palette_entries`).
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    prelude::*, // Import common traits and types
+    style::{
+        Modifier,
+        Style,
+    },
+    widgets::{
+        Block,
+        Borders,
+        Clear,
+        HighlightSpacing,
+        List,
+        ListItem,
+        Paragraph,
+    },
+};
+use situation::Theme;
+
+use crate::app::{
+    App,
+    InputMode,
+    PaletteEntry,
+};
+
+// --- Constants for UI Layout ---
+const PALETTE_WIDTH: u16 = 70;
+const PALETTE_MAX_ITEMS: u16 = 12;
+const KIND_COLUMN_WIDTH: usize = 12;
+
+// Intention: Build the spans for one entry, highlighting the matched characters of the label.
+fn entry_line(entry: &PaletteEntry, theme: &Theme) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        format!(
+            "{:<width$}",
+            entry.target.kind_label(),
+            width = KIND_COLUMN_WIDTH
+        ),
+        theme.muted(),
+    )];
+    let highlight = theme.highlight().add_modifier(Modifier::BOLD);
+    for (i, c) in entry.label.chars().enumerate() {
+        let style = if entry.positions.contains(&i) {
+            highlight
+        } else {
+            Style::default()
+        };
+        spans.push(Span::styled(c.to_string(), style));
+    }
+    if !entry.detail.is_empty() {
+        spans.push(Span::styled(format!("  {}", entry.detail), theme.muted()));
+    }
+    Line::from(spans)
+}
+
+// Intention: Render the palette overlay on top of everything else.
+pub(super) fn render_command_palette(f: &mut Frame, app: &App) {
+    if app.input_mode != InputMode::CommandPalette {
+        return;
+    }
+
+    let entries = app.palette_entries();
+    let theme = &app.theme;
+    let screen = f.size();
+    let width = PALETTE_WIDTH.min(screen.width);
+    let list_rows = (entries.len() as u16).clamp(1, PALETTE_MAX_ITEMS);
+    let height = (list_rows + 4).min(screen.height); // query line + separator + borders
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + (screen.height - height) / 4,
+        width,
+        height,
+    };
+
+    let title = match &app.palette_compare_to {
+        Some(_) => format!(
+            "Compare With ({}) - Enter: Compare, Esc: Close",
+            entries.len()
+        ),
+        None => format!(
+            "Command Palette ({}) - Enter: Run, Esc: Close",
+            entries.len()
+        ),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(theme.border(true));
+    let inner = block.inner(area);
+    f.render_widget(Clear, area); // Clear the area first
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Query line
+            Constraint::Length(1), // Separator
+            Constraint::Min(0),    // Entries
+        ])
+        .split(inner);
+
+    let query = Paragraph::new(Line::from(vec![
+        Span::styled("> ", theme.accent()),
+        Span::raw(app.palette_query.clone()),
+    ]));
+    f.render_widget(query, chunks[0]);
+    f.set_cursor(
+        chunks[0].x + 2 + app.palette_query.chars().count() as u16,
+        chunks[0].y,
+    );
+    f.render_widget(
+        Paragraph::new("─".repeat(chunks[1].width as usize)).style(theme.dim()),
+        chunks[1],
+    );
+
+    let items: Vec<ListItem> = if entries.is_empty() {
+        vec![ListItem::new("No matches.")]
+    } else {
+        entries
+            .iter()
+            .map(|e| ListItem::new(entry_line(e, theme)))
+            .collect()
+    };
+    let list = List::new(items)
+        .highlight_style(theme.selection().add_modifier(Modifier::BOLD))
+        .highlight_symbol("> ")
+        .highlight_spacing(HighlightSpacing::Always);
+    let mut list_state = app.palette_list_state.clone(); // Clone state for rendering
+    if entries.is_empty() {
+        list_state.select(None);
+    }
+    f.render_stateful_widget(list, chunks[2], &mut list_state);
+}
@@ -0,0 +1,93 @@
+// src/ui/render_component_details.rs
+
+// Intention: Render the detail view of a single component opened from the actions table.
+// Design Choice: Shows the identifying fields first, then the domain as pretty-printed JSON,
+// since the domain is an arbitrary object whose shape depends on the schema.
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    prelude::*, // Import common traits and types
+    style::{
+        Modifier,
+        Style,
+    },
+    widgets::{
+        Paragraph,
+        Wrap,
+    },
+};
+use situation::{
+    Action,
+    KeyContext,
+    Keymap,
+    api_models::GetComponentV1Response,
+};
+
+// Intention: Render the component detail view inside the content area.
+// Design Choice: Reads optional fields from the untyped `component` value so missing
+// fields render as "-" instead of failing. The back hint comes from the keymap.
+pub(super) fn render_component_details(
+    f: &mut Frame,
+    details: &GetComponentV1Response,
+    keymap: &Keymap,
+    area: Rect,
+) {
+    let field = |name: &str| {
+        details
+            .component
+            .get(name)
+            .and_then(|value| value.as_str())
+            .unwrap_or("-")
+            .to_string()
+    };
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+
+    let mut lines: Vec<Line> = vec![
+        Line::from(vec![
+            Span::styled("Component:", bold),
+            Span::raw(format!(" {} ({})", field("name"), field("id"))),
+        ]),
+        Line::from(vec![
+            Span::styled("Schema:", bold),
+            Span::raw(format!(" {}", field("schemaId"))),
+        ]),
+        Line::from(vec![
+            Span::styled("To Delete:", bold),
+            Span::raw(format!(
+                " {}",
+                details
+                    .component
+                    .get("toDelete")
+                    .and_then(|value| value.as_bool())
+                    .unwrap_or(false)
+            )),
+        ]),
+        Line::from(""), // Spacer
+        Line::from(Span::styled("Domain:", bold)),
+    ];
+
+    let domain_text = serde_json::to_string_pretty(&details.domain)
+        .unwrap_or_else(|_| details.domain.to_string());
+    lines.extend(
+        domain_text
+            .lines()
+            .map(|line| Line::from(format!("  {}", line))),
+    );
+
+    if !details.management_functions.is_empty() {
+        lines.push(Line::from("")); // Spacer
+        lines.push(Line::from(Span::styled("Management Functions:", bold)));
+        for function in &details.management_functions {
+            lines.push(Line::from(format!("  - {}", function.name)));
+        }
+    }
+
+    lines.push(Line::from("")); // Spacer
+    lines.push(Line::from(format!(
+        "{}: Back to change set",
+        keymap.describe(KeyContext::ContentArea, Action::Back)
+    )));
+
+    f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), area);
+}
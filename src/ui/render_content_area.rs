@@ -21,10 +21,20 @@ use ratatui::{
         Wrap,
     },
 };
+use situation::Keymap;
 
+use super::{
+    render_action_table::render_action_table,
+    render_apply_progress::render_apply_progress,
+    render_apply_review::render_apply_review,
+    render_change_set_comparison::render_change_set_comparison,
+    render_component_details::render_component_details,
+};
 use crate::app::{
     App,
     AppFocus,
+    InputMode,
+    text_entry_keys,
 }; // Use App from local app module
 
 // Intention: Render the main content area based on application state.
@@ -32,25 +42,92 @@ use crate::app::{
 // 1. If components are loaded and non-empty: Show ONLY components.
 // 2. If components are loaded but empty OR components are loading/error: Show details/status/component status.
 // 3. If no change set details are selected: Show keybindings.
-pub(super) fn render_content_area(f: &mut Frame, app: &App, area: Rect) {
-    // Changed app to immutable reference since we don't need to modify it in this function
-    // We need a fixed height for the log panel to pass here, assuming 10 like in event_handler.
-    const LOG_HEIGHT: usize = 10;
+// A force apply being monitored, the review before one, a change set comparison, or a
+// component opened from the actions table, takes over the whole area, and a non-empty
+// merge status is shown as an actions table above the rest of the content.
+pub(super) fn render_content_area(f: &mut Frame, app: &mut App, area: Rect) {
+    // Mutable because the actions table is a stateful widget
 
     // Determine border style based on focus
-    let border_style = if app.current_focus == AppFocus::ContentArea {
-        Style::default().fg(Color::Cyan) // Highlight color when focused
-    } else {
-        Style::default().fg(Color::DarkGray) // Default color when not focused
-    };
+    let border_style =
+        app.theme.border(app.current_focus == AppFocus::ContentArea);
 
     let details_block = Block::default()
         .title("Details")
         .borders(Borders::ALL)
         .border_style(border_style); // Apply conditional border style
-    let inner_details_area = details_block.inner(area);
+    let mut inner_details_area = details_block.inner(area);
     f.render_widget(details_block, area); // Render the block border/title first
 
+    // Running (or just finished) apply: show its progress
+    if let Some(monitor) = &app.apply_monitor {
+        render_apply_progress(
+            f,
+            monitor,
+            &app.keymap,
+            &app.theme,
+            inner_details_area,
+        );
+        return;
+    }
+
+    // Review before a force apply: show only the review
+    if let Some(review) = &app.review {
+        let scroll = render_apply_review(
+            f,
+            review,
+            app.review_scroll,
+            &app.keymap,
+            &app.theme,
+            inner_details_area,
+        );
+        app.review_scroll = scroll;
+        return;
+    }
+
+    // Change set comparison: show only the comparison
+    if let Some(comparison) = &app.comparison {
+        let scroll = render_change_set_comparison(
+            f,
+            comparison,
+            app.comparison_scroll,
+            &app.keymap,
+            &app.theme,
+            inner_details_area,
+        );
+        app.comparison_scroll = scroll;
+        return;
+    }
+
+    // Component opened from the actions table: show only its details
+    if let Some(component_details) = &app.selected_component_details {
+        render_component_details(
+            f,
+            component_details,
+            &app.keymap,
+            inner_details_area,
+        );
+        return;
+    }
+
+    // Actions table above the remaining content when there is anything queued
+    let action_count = app
+        .selected_change_set_merge_status
+        .as_ref()
+        .map_or(0, |status| status.actions.len());
+    if action_count > 0 {
+        // Header + rows + bottom border, capped at half the area
+        let table_height = (action_count as u16 + 2)
+            .min(inner_details_area.height / 2)
+            .max(3);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(table_height), Constraint::Min(0)])
+            .split(inner_details_area);
+        render_action_table(f, app, chunks[0]);
+        inner_details_area = chunks[1];
+    }
+
     // Debug: Log the state of components
     let debug_lines = match &app.selected_change_set_components {
         Some(components) => {
@@ -59,7 +136,7 @@ pub(super) fn render_content_area(f: &mut Frame, app: &App, area: Rect) {
         None => "DEBUG: No components loaded".to_string(),
     };
     f.render_widget(
-        Paragraph::new(debug_lines).style(Style::default().fg(Color::Red)),
+        Paragraph::new(debug_lines).style(app.theme.error()),
         Rect::new(area.x, area.y, area.width, 1),
     );
 
@@ -71,7 +148,7 @@ pub(super) fn render_content_area(f: &mut Frame, app: &App, area: Rect) {
             // Debug: Add component IDs and schema IDs
             lines.push(Line::from(Span::styled(
                 "DEBUG: Component IDs and Schema IDs:",
-                Style::default().fg(Color::Red),
+                app.theme.error(),
             )));
             for component in components.iter().take(3) {
                 // Show first 3 for brevity
@@ -80,39 +157,29 @@ pub(super) fn render_content_area(f: &mut Frame, app: &App, area: Rect) {
                         "  - {} (schema_id: {})",
                         component.name, component.schema_id
                     ),
-                    Style::default().fg(Color::Red),
+                    app.theme.error(),
                 )));
             }
             if components.len() > 3 {
                 lines.push(Line::from(Span::styled(
                     format!("  ... and {} more", components.len() - 3),
-                    Style::default().fg(Color::Red),
+                    app.theme.error(),
                 )));
             }
 
             // Debug: Show selected schema info
-            if let Some(selected_idx) = app.schema_list_state.selected() {
-                if !app.schemas.is_empty() {
-                    let selected_schema = &app.schemas[selected_idx];
-                    lines.push(Line::from(Span::styled(
-                        format!(
-                            "DEBUG: Selected schema: {} (id: {})",
-                            selected_schema.schema_name,
-                            selected_schema.schema_id
-                        ),
-                        Style::default().fg(Color::Red),
-                    )));
-                } else {
-                    lines.push(Line::from(Span::styled(
-                        "DEBUG: No schemas available",
-                        Style::default().fg(Color::Red),
-                    )));
-                }
-            } else {
-                lines.push(Line::from(Span::styled(
+            match app.get_selected_schema() {
+                Some(selected_schema) => lines.push(Line::from(Span::styled(
+                    format!(
+                        "DEBUG: Selected schema: {} (id: {})",
+                        selected_schema.schema_name, selected_schema.schema_id
+                    ),
+                    app.theme.error(),
+                ))),
+                None => lines.push(Line::from(Span::styled(
                     "DEBUG: No schema selected",
-                    Style::default().fg(Color::Red),
-                )));
+                    app.theme.error(),
+                ))),
             }
 
             // Display all components without filtering
@@ -126,19 +193,18 @@ pub(super) fn render_content_area(f: &mut Frame, app: &App, area: Rect) {
                 lines.push(Line::from("  No components in this change set."));
             } else {
                 for component in components.iter() {
-                    // Look up the schema name for this component ID
-                    // The component ID is the same as the schema ID
+                    // Look up the schema name from the component's resolved schema ID
                     let schema_name = app
                         .schemas
                         .iter()
-                        .find(|schema| schema.schema_id == component.id)
+                        .find(|schema| schema.schema_id == component.schema_id)
                         .map(|schema| schema.schema_name.clone())
                         .unwrap_or_else(|| "Unknown Schema".to_string());
 
                     // Display the component with its schema name
                     lines.push(Line::from(format!(
                         "  - {} ({})",
-                        component.id, schema_name
+                        component.name, schema_name
                     )));
                     // TODO: Render as rectangles later if needed
                 }
@@ -182,25 +248,11 @@ pub(super) fn render_content_area(f: &mut Frame, app: &App, area: Rect) {
                     if merge_status.actions.is_empty() {
                         lines.push(Line::from("  No actions required."));
                     } else {
-                        for action in &merge_status.actions {
-                            let component_info =
-                                action.component.as_ref().map_or_else(
-                                    || "".to_string(),
-                                    |comp| {
-                                        format!(
-                                            " - {} ({})",
-                                            comp.name, comp.id
-                                        )
-                                    },
-                                );
-                            lines.push(Line::from(format!(
-                                "  [{}] {} {} {}",
-                                action.kind,
-                                action.state,
-                                action.name,
-                                component_info
-                            )));
-                        }
+                        // Individual actions are listed in the actions table above
+                        lines.push(Line::from(format!(
+                            "  {} action(s) queued, see table above.",
+                            merge_status.actions.len()
+                        )));
                     }
                 } else {
                     lines.push(Line::from(
@@ -231,7 +283,7 @@ pub(super) fn render_content_area(f: &mut Frame, app: &App, area: Rect) {
                 Paragraph::new(lines).wrap(Wrap { trim: true })
             } else {
                 // Fallback: No change set details selected -> Render Keybindings
-                render_keybindings()
+                render_keybindings(&app.keymap)
             }
         }
     };
@@ -240,49 +292,25 @@ pub(super) fn render_content_area(f: &mut Frame, app: &App, area: Rect) {
 }
 
 // Helper function to generate keybindings paragraph (extracted for clarity)
-fn render_keybindings<'a>() -> Paragraph<'a> {
-    let keybindings = vec![
-        Line::from("--- Keybindings ---".bold()),
-        Line::from(""),
-        Line::from("Global:".underlined()),
-        Line::from("  q          : Quit"),
-        Line::from(
-            "  Tab        : Cycle Focus (Top Bar -> Schemas -> Details -> Logs)",
-        ),
-        Line::from("  Alt+W      : Focus Workspace Trigger"),
-        Line::from("  Alt+C      : Focus Change Set Trigger"),
-        Line::from("  Alt+S      : Focus Schema List"),
-        Line::from("  Alt+L      : Focus Log Panel"),
-        Line::from(""),
-        Line::from("Top Bar:".underlined()),
-        Line::from(
-            "  Enter/Space: Activate Focused Trigger (Open Dropdown / Fetch Details)",
-        ),
-        Line::from("  c          : Create Change Set (Enter Input Mode)"),
-        Line::from("  d          : Delete Selected Change Set"),
-        Line::from("  f          : Force Apply Selected Change Set"),
-        Line::from("  k          : Scroll Logs Up (Any Focus)"),
-        Line::from("  j          : Scroll Logs Down (Any Focus)"),
-        Line::from(""),
-        Line::from("Top Bar (Change Set Dropdown Active):".underlined()),
-        Line::from("  Up Arrow   : Select Previous Item"),
-        Line::from("  Down Arrow : Select Next Item"),
-        Line::from("  Enter      : Confirm Selection & Close Dropdown"),
-        Line::from("  Esc / Tab  : Close Dropdown"),
-        Line::from(""),
-        Line::from("Schema List:".underlined()),
-        Line::from("  Up Arrow   : Select Previous Schema"),
-        Line::from("  Down Arrow : Select Next Schema"),
-        Line::from(""),
-        Line::from("Log Panel:".underlined()),
-        Line::from("  Up/k       : Scroll Logs Up"),
-        Line::from("  Down/j     : Scroll Logs Down"),
-        Line::from(""),
-        Line::from("Input Mode (Create Change Set):".underlined()),
-        Line::from("  Enter      : Submit Name & Create"),
-        Line::from("  Esc        : Cancel Input"),
-        Line::from("  Backspace  : Delete Character"),
-        Line::from("  (any char) : Append Character"),
-    ];
+// Design Choice: Generated from the keymap so the help always matches the active bindings;
+// text entry is not remappable and is listed as-is.
+fn render_keybindings<'a>(keymap: &Keymap) -> Paragraph<'a> {
+    let mut keybindings =
+        vec![Line::from("--- Keybindings ---".bold()), Line::from("")];
+    for (context, entries) in keymap.help() {
+        keybindings
+            .push(Line::from(format!("{}:", context.title()).underlined()));
+        for (keys, description) in entries {
+            keybindings
+                .push(Line::from(format!("  {:<11}: {}", keys, description)));
+        }
+        keybindings.push(Line::from(""));
+    }
+    keybindings
+        .push(Line::from("Input Mode (Create Change Set):".underlined()));
+    for (keys, description) in text_entry_keys(&InputMode::ChangeSetName) {
+        keybindings
+            .push(Line::from(format!("  {:<11}: {}", keys, description)));
+    }
     Paragraph::new(keybindings).wrap(Wrap { trim: true })
 }
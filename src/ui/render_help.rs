@@ -0,0 +1,76 @@
+// src/ui/render_help.rs
+
+// Intention: Render the `?` help overlay listing the keys valid for the focused pane and mode.
+// Design Choice: Centered box drawn above everything else, with one underlined heading per
+// section (see `App::help_sections`) and the keys in a fixed-width column. The scroll is
+// clamped here, where the box height is known, so scrolling stops at the last line.
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    prelude::*, // Import common traits and types
+    widgets::{
+        Block,
+        Borders,
+        Clear,
+        Paragraph,
+    },
+};
+
+use crate::app::App;
+
+// --- Constants for UI Layout ---
+const HELP_WIDTH: u16 = 64;
+const KEYS_COLUMN_WIDTH: usize = 16;
+
+// Intention: Render the help overlay on top of everything else.
+pub(super) fn render_help(f: &mut Frame, app: &mut App) {
+    if !app.help_open {
+        return;
+    }
+
+    let theme = &app.theme;
+    let mut lines: Vec<Line> = Vec::new();
+    for (title, entries) in app.help_sections() {
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(
+            format!("{}:", title),
+            theme.accent().add_modifier(Modifier::UNDERLINED),
+        )));
+        for (keys, description) in entries {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<width$}", keys, width = KEYS_COLUMN_WIDTH),
+                    theme.hotkey(),
+                ),
+                Span::raw(description),
+            ]));
+        }
+    }
+
+    let screen = f.size();
+    let width = HELP_WIDTH.min(screen.width);
+    let height = (lines.len() as u16 + 2).min(screen.height);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    };
+
+    let block = Block::default()
+        .title("Help - Up/Down: Scroll, Esc/?: Close")
+        .borders(Borders::ALL)
+        .border_style(theme.border(true));
+    let max_scroll =
+        (lines.len() as u16).saturating_sub(block.inner(area).height);
+    app.help_scroll = app.help_scroll.min(max_scroll);
+
+    let help = Paragraph::new(lines)
+        .block(block)
+        .scroll((app.help_scroll, 0));
+    f.render_widget(Clear, area); // Clear the area first
+    f.render_widget(help, area);
+}
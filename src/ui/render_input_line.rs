@@ -1,16 +1,12 @@
 // src/ui/render_input_line.rs
 
-// Intention: Render the input line when in ChangeSetName mode.
+// Intention: Render the input line when in ChangeSetName or Token mode.
 // Design Choice: Encapsulates the conditional rendering of the input prompt and buffer. Extracted from ui.rs.
 
 use ratatui::{
     Frame,
     layout::Rect,
     prelude::*, // Import common traits and types
-    style::{
-        Color,
-        Style,
-    },
     widgets::Paragraph,
 };
 
@@ -19,10 +15,18 @@ use crate::app::{
     InputMode,
 }; // Use App, Enums from local app module
 
-// Intention: Render the input line when in ChangeSetName mode.
-// Design Choice: Encapsulates the conditional rendering of the input prompt and buffer.
+// Intention: Render the input line when in ChangeSetName or Token mode.
+// Design Choice: Encapsulates the conditional rendering of the input prompt and buffer. A token
+// is never shown, only how many characters were pasted so far.
 pub(super) fn render_input_line(f: &mut Frame, app: &App, area: Rect) {
-    if app.input_mode == InputMode::ChangeSetName {
+    if app.input_mode == InputMode::Token {
+        let input_paragraph = Paragraph::new(format!(
+            "Paste a new token (Esc: Keep current, Enter: Use & retry): [{} characters]_",
+            app.input_buffer.chars().count()
+        ))
+        .style(app.theme.warning());
+        f.render_widget(input_paragraph, area);
+    } else if app.input_mode == InputMode::ChangeSetName {
         let input_prompt_text =
             "Enter Change Set Name (Esc: Cancel, Enter: Create):";
         let input_paragraph = Paragraph::new(format!(
@@ -31,7 +35,7 @@ pub(super) fn render_input_line(f: &mut Frame, app: &App, area: Rect) {
             app.input_buffer,
             "_" // Simple cursor indicator
         ))
-        .style(Style::default().fg(Color::Yellow));
+        .style(app.theme.highlight());
         f.render_widget(input_paragraph, area);
     }
 }
@@ -2,6 +2,8 @@
 
 // Intention: Render the log panel at the bottom.
 // Design Choice: Encapsulates the log block (with dynamic title) and the scrollable log paragraph. Extracted from ui.rs.
+// Each entry shows its local time, colored level, source and request ID before the message. The
+// title shows the level filter, the verbosity and the `/` search query.
 
 use ratatui::{
     Frame,
@@ -14,31 +16,98 @@ use ratatui::{
         Wrap,
     },
 };
+use situation::{
+    Action,
+    KeyContext,
+    LogEntry,
+    LogLevel,
+    Theme,
+};
 
 use crate::app::{
     App,
     AppFocus,
+    InputMode,
 }; // Use App from local app module
 
+fn level_style(theme: &Theme, level: LogLevel) -> Style {
+    match level {
+        LogLevel::Trace => theme.dim(),
+        LogLevel::Debug => theme.info(),
+        LogLevel::Info => theme.success(),
+        LogLevel::Warn => theme.warning(),
+        LogLevel::Error => theme.error().add_modifier(Modifier::BOLD),
+    }
+}
+
+// Intention: One line per entry: `HH:MM:SS LEVEL source (request) message`.
+fn log_line<'a>(entry: &'a LogEntry, theme: &Theme) -> Line<'a> {
+    let dim = theme.dim();
+    let mut spans = vec![
+        Span::styled(
+            entry
+                .timestamp
+                .with_timezone(&chrono::Local)
+                .format("%H:%M:%S ")
+                .to_string(),
+            dim,
+        ),
+        Span::styled(
+            format!("{:<5} ", entry.level.as_str()),
+            level_style(theme, entry.level),
+        ),
+        Span::styled(format!("{} ", entry.source), theme.accent()),
+    ];
+    if let Some(id) = &entry.request_id {
+        spans.push(Span::styled(format!("({}) ", id), dim));
+    }
+    spans.push(Span::raw(entry.message.as_str()));
+    Line::from(spans)
+}
+
 // Intention: Render the log panel at the bottom. Highlights border on focus.
 // Design Choice: Encapsulates the log block (with dynamic title using Spans) and the scrollable log paragraph.
 pub(super) fn render_log_panel(f: &mut Frame, app: &App, area: Rect) {
     // Determine border style based on focus
-    let border_style = if app.current_focus == AppFocus::LogPanel {
-        Style::default().fg(Color::Cyan) // Highlight color when focused
-    } else {
-        Style::default().fg(Color::DarkGray) // Default color when not focused
-    };
+    let theme = &app.theme;
+    let border_style = theme.border(app.current_focus == AppFocus::LogPanel);
+
+    // Key hints come from the keymap so remapped keys are shown
+    let keys = |action| app.keymap.describe(KeyContext::LogPanel, action);
+    let hints = format!(
+        "ogs ({}/{}: Scroll, {}: Filter, {}: Verbosity, {}: Search)",
+        app.keymap
+            .describe(KeyContext::Global, Action::ScrollLogsDown),
+        app.keymap
+            .describe(KeyContext::Global, Action::ScrollLogsUp),
+        keys(Action::CycleLogFilter),
+        keys(Action::CycleLogVerbosity),
+        keys(Action::SearchLogs),
+    );
 
     // Construct the title with highlighted 'L' and optional action
     let mut title_spans = vec![
-        Span::styled("L", Style::default().fg(Color::Yellow)), // Highlighted 'L'
-        Span::raw("ogs (j/k: Scroll)"), // Rest of base title
+        Span::styled("L", theme.hotkey()), // Highlighted 'L'
+        Span::raw(hints),                  // Rest of base title
+        Span::styled(
+            format!(
+                " [>={} keep>={}]",
+                app.log_level_filter, app.log_verbosity
+            ),
+            theme.dim(),
+        ),
     ];
+    let searching = app.input_mode == InputMode::LogSearch;
+    if searching || !app.log_query.is_empty() {
+        let cursor = if searching { "_" } else { "" };
+        title_spans.push(Span::styled(
+            format!(" /{}{}", app.log_query, cursor),
+            theme.highlight(),
+        ));
+    }
     if let Some(action) = &app.current_action {
         title_spans.push(Span::raw(" - ["));
-        title_spans
-            .push(Span::styled(action, Style::default().fg(Color::Cyan))); // Style the action
+        title_spans.push(Span::styled(action, theme.accent())); // Style the action
         title_spans.push(Span::raw("]"));
     }
     let log_title_line = Line::from(title_spans).alignment(Alignment::Left); // Align title left
@@ -52,9 +121,9 @@ pub(super) fn render_log_panel(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(log_block, area); // Render the block (border + title)
 
     let log_lines: Vec<Line> = app
-        .logs
-        .iter()
-        .map(|log| Line::from(log.as_str()))
+        .visible_logs()
+        .into_iter()
+        .map(|entry| log_line(entry, theme))
         .collect();
     let log_paragraph = Paragraph::new(log_lines)
         .wrap(Wrap { trim: false })
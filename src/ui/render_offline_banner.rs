@@ -0,0 +1,41 @@
+// src/ui/render_offline_banner.rs
+
+// Intention: Render the banner shown above the top bar while the app is offline.
+// Design Choice: A full-width line in the warning colour, reversed, saying how old the snapshot
+// is, so stale data is never taken for live data. It takes the place of the production banner.
+
+use chrono::Utc;
+use ratatui::{
+    Frame,
+    layout::{
+        Alignment,
+        Rect,
+    },
+    style::Modifier,
+    widgets::Paragraph,
+};
+
+use crate::app::App;
+
+// Intention: Render the offline banner with the age of the snapshot shown.
+pub(super) fn render_offline_banner(f: &mut Frame, app: &App, area: Rect) {
+    let age = app
+        .snapshot
+        .as_ref()
+        .map(|snapshot| {
+            format!(" saved {} ago", snapshot.age_label(Utc::now()))
+        })
+        .unwrap_or_default();
+    let text = format!(
+        "OFFLINE - snapshot{} - read-only - Refresh to reconnect",
+        age
+    );
+    let banner = Paragraph::new(text)
+        .style(
+            app.theme
+                .warning()
+                .add_modifier(Modifier::REVERSED | Modifier::BOLD),
+        )
+        .alignment(Alignment::Center);
+    f.render_widget(banner, area);
+}
@@ -0,0 +1,36 @@
+// src/ui/render_profile_banner.rs
+
+// Intention: Render the banner shown above the top bar while a production profile is active.
+// Design Choice: A full-width line in the theme's alert style (white on red by default), so the
+// production backend cannot be mistaken for another one. Only drawn when `ui` reserves a line.
+
+use ratatui::{
+    Frame,
+    layout::{
+        Alignment,
+        Rect,
+    },
+    widgets::Paragraph,
+};
+
+use crate::app::App;
+
+// Intention: Render the production banner for the active profile.
+pub(super) fn render_profile_banner(f: &mut Frame, app: &App, area: Rect) {
+    let Some(profile) = &app.profile else {
+        return;
+    };
+    let read_only = if profile.settings.read_only {
+        " (read-only)"
+    } else {
+        ""
+    };
+    let text = format!(
+        "PRODUCTION - profile {} - {}{}",
+        profile.name, profile.api_url, read_only
+    );
+    let banner = Paragraph::new(text)
+        .style(app.theme.alert())
+        .alignment(Alignment::Center);
+    f.render_widget(banner, area);
+}
@@ -0,0 +1,220 @@
+// src/ui/render_request_inspector.rs
+
+// Intention: Render the HTTP request inspector in place of the content area.
+// Design Choice: A table of every request made in the session (time, method, status, latency,
+// size, URL path) above a scrollable detail view of the selected request with its pretty-printed
+// request and response bodies. Bodies were redacted when recorded.
+
+use ratatui::{
+    Frame,
+    layout::{
+        Constraint,
+        Direction,
+        Layout,
+        Rect,
+    },
+    prelude::*, // Import common traits and types
+    widgets::{
+        Block,
+        Borders,
+        Paragraph,
+        Row,
+        Table,
+        Wrap,
+    },
+};
+use situation::{
+    Action,
+    HttpExchange,
+    HttpHistory,
+    KeyContext,
+    Theme,
+    format_size,
+    pretty_body,
+};
+
+use crate::app::{
+    App,
+    AppFocus,
+}; // Use App from local app module
+
+// Intention: Style a status by class: 2xx success, 3xx/4xx warning, 5xx and no response error.
+fn status_style(theme: &Theme, status: Option<u16>) -> Style {
+    match status {
+        Some(200..=299) => theme.success(),
+        Some(300..=499) => theme.warning(),
+        _ => theme.error().add_modifier(Modifier::BOLD),
+    }
+}
+
+fn status_text(exchange: &HttpExchange) -> String {
+    exchange
+        .status
+        .map_or_else(|| "ERR".to_string(), |status| status.to_string())
+}
+
+fn size_text(exchange: &HttpExchange) -> String {
+    exchange
+        .response_size
+        .map_or_else(|| "-".to_string(), format_size)
+}
+
+// Intention: Drop the scheme and host, which are the same for every request.
+fn url_path(url: &str) -> &str {
+    url.split_once("://")
+        .and_then(|(_, rest)| rest.find('/').map(|i| &rest[i..]))
+        .unwrap_or(url)
+}
+
+// Intention: Lines describing one request: summary, then request and response bodies.
+fn detail_lines(exchange: &HttpExchange, theme: &Theme) -> Vec<Line<'static>> {
+    let heading = theme.highlight().add_modifier(Modifier::BOLD);
+    let dim = theme.dim();
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(format!("{} ", exchange.method), heading),
+            Span::raw(exchange.url.clone()),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                status_text(exchange),
+                status_style(theme, exchange.status),
+            ),
+            Span::styled(
+                format!(
+                    "  {} ms  {}  {}  {}",
+                    exchange.latency.as_millis(),
+                    size_text(exchange),
+                    exchange.request_id,
+                    exchange.source
+                ),
+                dim,
+            ),
+        ]),
+    ];
+    for (title, body) in [
+        ("Request body", &exchange.request_body),
+        ("Response body", &exchange.response_body),
+    ] {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(title, heading)));
+        match body.as_deref() {
+            Some(body) if !body.is_empty() => lines.extend(
+                pretty_body(body)
+                    .lines()
+                    .map(|line| Line::from(line.to_string())),
+            ),
+            _ => lines.push(Line::from(Span::styled("(none)", dim))),
+        }
+    }
+    lines
+}
+
+// Intention: Render the request table and the selected request's details.
+pub(super) fn render_request_inspector(
+    f: &mut Frame,
+    app: &mut App,
+    area: Rect,
+) {
+    let exchanges = HttpHistory::global().snapshot();
+
+    let theme = &app.theme;
+    let border_style = theme.border(app.current_focus == AppFocus::ContentArea);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(40), Constraint::Min(0)])
+        .split(area);
+
+    let rows: Vec<Row> = exchanges
+        .iter()
+        .map(|exchange| {
+            Row::new(vec![
+                Line::from(
+                    exchange
+                        .started_at
+                        .with_timezone(&chrono::Local)
+                        .format("%H:%M:%S")
+                        .to_string(),
+                ),
+                Line::from(exchange.method.clone()),
+                Line::from(Span::styled(
+                    status_text(exchange),
+                    status_style(theme, exchange.status),
+                )),
+                Line::from(format!("{} ms", exchange.latency.as_millis()))
+                    .alignment(Alignment::Right),
+                Line::from(size_text(exchange)).alignment(Alignment::Right),
+                Line::from(url_path(&exchange.url).to_string()),
+            ])
+        })
+        .collect();
+
+    let header =
+        Row::new(vec!["Time", "Method", "Status", "Latency", "Size", "URL"])
+            .style(Style::default().add_modifier(Modifier::UNDERLINED));
+
+    let title = Line::from(vec![
+        Span::raw(format!("Requests ({}) ", exchanges.len())),
+        Span::styled(
+            {
+                let keys =
+                    |action| app.keymap.describe(KeyContext::Inspector, action);
+                format!(
+                    "{}/{}: Select, {}/{}: Scroll, {}: Latest, {}/{}: Close",
+                    keys(Action::InspectorPrevious),
+                    keys(Action::InspectorNext),
+                    keys(Action::InspectorScrollUp),
+                    keys(Action::InspectorScrollDown),
+                    keys(Action::InspectorLatest),
+                    keys(Action::CloseInspector),
+                    app.keymap
+                        .describe(KeyContext::Global, Action::ToggleInspector),
+                )
+            },
+            theme.dim(),
+        ),
+    ]);
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(8),
+            Constraint::Length(6),
+            Constraint::Length(6),
+            Constraint::Length(8),
+            Constraint::Length(9),
+            Constraint::Min(10),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(border_style),
+    )
+    .highlight_style(theme.selection().add_modifier(Modifier::BOLD));
+    f.render_stateful_widget(table, chunks[0], &mut app.inspector_table_state);
+
+    let detail_block = Block::default()
+        .title("Request")
+        .borders(Borders::ALL)
+        .border_style(border_style);
+    let lines = match app
+        .inspector_table_state
+        .selected()
+        .and_then(|i| exchanges.get(i))
+    {
+        Some(exchange) => detail_lines(exchange, &app.theme),
+        None => vec![Line::from(Span::styled(
+            "No requests yet.",
+            app.theme.dim(),
+        ))],
+    };
+    let detail = Paragraph::new(lines)
+        .block(detail_block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.inspector_detail_scroll, 0));
+    f.render_widget(detail, chunks[1]);
+}
@@ -1,80 +1,145 @@
 // src/ui/render_schema_list.rs
 
 // Intention: Defines the function to render the schema list widget.
-// Design Choice: Creates a List widget populated with schema names from the App state.
-// Highlights the selected item based on `app.schema_list_state`.
-// Applies a border and title, highlighting the border if the list has focus.
+// Design Choice: Renders the rows of `App::schema_tree_rows`: category headers (collapsible) with
+// their schemas indented below, and the number of components in the current change set using
+// each schema right-aligned. Highlights the selected row based on `app.schema_list_state`.
+// Applies a border and title, highlighting the border if the list has focus. The title shows the
+// installed/all filter and the `/` search query.
 
 use ratatui::{
     prelude::*,
     widgets::{
         Block,
         Borders,
+        HighlightSpacing,
         List,
         ListItem,
         ListState,
     },
 };
-use situation::api_models::SchemaSummary;
+use situation::{
+    SchemaTreeRow,
+    api_models::SchemaSummary,
+};
 
 use crate::app::{
     App,
     AppFocus,
+    InputMode,
 }; // Import App and AppFocus // Correct import path
 
-pub fn render_schema_list(f: &mut Frame, app: &mut App, area: Rect) {
-    // Intention: Create ListItems grouped by category with conditional styling.
-    // Design Choice: Iterate sorted schemas, add category headers, indent items, style based on 'installed'.
-    let mut list_items = Vec::new();
-    let mut current_category: Option<String> = None; // Explicit type annotation
-
-    for schema in &app.schemas {
-        // Explicitly check if the category has changed using pattern matching
-        let category_changed = match current_category {
-            Some(ref current_cat_string) => {
-                current_cat_string != &schema.category
-            } // Compare String with String
-            None => true, // Always true for the first category
-        };
+// Intention: Build one line with `label` on the left and `count` right-aligned to `width`.
+// Design Choice: Counts of zero are left blank so the schemas that are in use stand out.
+fn row_line(
+    label: Span<'static>,
+    count: usize,
+    width: usize,
+    count_style: Style,
+) -> Line<'static> {
+    let count_text = if count > 0 {
+        count.to_string()
+    } else {
+        String::new()
+    };
+    let label_width = label.content.chars().count();
+    let padding = width.saturating_sub(label_width + count_text.len()).max(1);
+    Line::from(vec![
+        label,
+        Span::raw(" ".repeat(padding)),
+        Span::styled(count_text, count_style),
+    ])
+}
 
-        if category_changed {
-            // Add category header (bold)
-            list_items.push(ListItem::new(Line::from(Span::styled(
-                schema.category.clone(),
-                Style::default().add_modifier(Modifier::BOLD),
-            ))));
-            current_category = Some(schema.category.clone()); // Update current category
-        }
+pub fn render_schema_list(f: &mut Frame, app: &mut App, area: Rect) {
+    // Intention: Create ListItems for the visible tree rows with conditional styling.
+    // Design Choice: Category headers are bold with an expand marker; schemas are indented and
+    // styled based on 'installed'.
+    let rows = app.schema_tree_rows();
+    // Inner width minus the highlight symbol, so counts line up on the right edge
+    let width = area.width.saturating_sub(2 + 2) as usize;
+    let theme = &app.theme;
 
-        // Determine style based on installed status (now a boolean)
-        let item_style = if schema.installed {
-            Style::default().fg(Color::Blue) // Blue if installed
-        } else {
-            Style::default() // Default color otherwise
-        };
+    let list_items: Vec<ListItem> = rows
+        .iter()
+        .map(|row| match row {
+            SchemaTreeRow::Category {
+                name,
+                schema_count,
+                component_count,
+                collapsed,
+            } => {
+                let marker = if *collapsed { "▸" } else { "▾" };
+                ListItem::new(row_line(
+                    Span::styled(
+                        format!("{} {} ({})", marker, name, schema_count),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    *component_count,
+                    width,
+                    theme.accent(),
+                ))
+            }
+            SchemaTreeRow::Schema {
+                index,
+                component_count,
+            } => {
+                let schema: &SchemaSummary = &app.schemas[*index];
+                // Determine style based on installed status (now a boolean)
+                let item_style = if schema.installed {
+                    theme.info() // Info colour if installed
+                } else {
+                    Style::default() // Default color otherwise
+                };
+                ListItem::new(row_line(
+                    Span::styled(
+                        format!("  {}", schema.schema_name),
+                        item_style,
+                    ), // Indent schema name
+                    *component_count,
+                    width,
+                    theme.accent(),
+                ))
+            }
+        })
+        .collect();
 
-        // Add schema name (indented) with conditional style
-        list_items.push(ListItem::new(Line::from(Span::styled(
-            format!("  {}", schema.schema_name), // Indent schema name
-            item_style,
-        ))));
-    }
+    let list_items = if list_items.is_empty() && !app.schemas.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "No matching schemas.",
+            theme.dim(),
+        ))]
+    } else {
+        list_items
+    };
 
     // Intention: Determine border style based on focus.
     // Design Choice: Use different colors to indicate focus.
-    let border_style = if app.current_focus == AppFocus::SchemaList {
-        Style::default().fg(Color::Cyan) // Highlight color when focused
-    } else {
-        Style::default().fg(Color::DarkGray) // Default color when not focused
-    };
+    let border_style = theme.border(app.current_focus == AppFocus::SchemaList);
 
     // Intention: Create the List widget with items, border, title, and highlight style.
     // Design Choice: Use standard List widget configuration. Apply conditional border style.
-    // Construct the title with highlighted 'S'
-    let title_spans = vec![
-        Span::styled("S", Style::default().fg(Color::Yellow)), // Highlighted 'S'
-        Span::raw("chemas"), // Rest of the title
+    // Construct the title with highlighted 'S', the active filter and the search query
+    let mut title_spans = vec![
+        Span::styled("S", theme.hotkey()), // Highlighted 'S'
+        Span::raw("chemas"),               // Rest of the title
+        Span::styled(
+            if app.schema_installed_only {
+                " [installed]"
+            } else {
+                " [all]"
+            },
+            theme.dim(),
+        ),
     ];
+    let searching = app.input_mode == InputMode::SchemaSearch;
+    if searching || !app.schema_query.is_empty() {
+        let cursor = if searching { "_" } else { "" };
+        title_spans.push(Span::styled(
+            format!(" /{}{}", app.schema_query, cursor),
+            theme.highlight(),
+        ));
+    }
     let title_line = Line::from(title_spans).alignment(Alignment::Left); // Align title left
 
     // Use the generated list_items (headers + schemas)
@@ -85,14 +150,20 @@ pub fn render_schema_list(f: &mut Frame, app: &mut App, area: Rect) {
                 .title(title_line) // Use the constructed Line as title
                 .border_style(border_style),
         )
-        .highlight_style(
-            Style::default()
-                .bg(Color::DarkGray) // Background for selected item
-                .add_modifier(Modifier::BOLD),
-        )
-        .highlight_symbol("> "); // Symbol prefix for selected item
+        .highlight_style(theme.selection().add_modifier(Modifier::BOLD)) // Selected item
+        .highlight_symbol("> ") // Symbol prefix for selected item
+        .highlight_spacing(HighlightSpacing::Always); // Keep counts aligned on every row
 
     // Intention: Render the list widget with its state.
     // Design Choice: Pass the mutable list state to the render_stateful_widget function.
-    f.render_stateful_widget(schemas_list, area, &mut app.schema_list_state);
+    // The placeholder row is not selectable.
+    if rows.is_empty() {
+        f.render_stateful_widget(schemas_list, area, &mut ListState::default());
+    } else {
+        f.render_stateful_widget(
+            schemas_list,
+            area,
+            &mut app.schema_list_state,
+        );
+    }
 }
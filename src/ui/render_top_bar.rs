@@ -2,8 +2,10 @@
 
 // Intention: Render the top bar containing Workspace trigger, Change Set trigger, and Email.
 // Design Choice: Encapsulates the horizontal layout and widget rendering for the top bar. Extracted from ui.rs.
-// Returns the Rect of the Change Set trigger area for dropdown positioning.
+// Returns the Rects of the Workspace and Change Set trigger areas, for mouse hit-testing and
+// dropdown positioning.
 
+use chrono::Utc;
 use ratatui::{
     Frame,
     layout::{
@@ -14,29 +16,31 @@ use ratatui::{
         Rect,
     },
     prelude::*, // Import common traits and types
-    style::{
-        Color,
-        Style,
-    },
+    style::Style,
     widgets::{
         Block,
         Paragraph,
     },
 };
 
-// Import the helper function from its new module
-use super::get_trigger_style::get_trigger_style; // Keep this if still used for inner focus
 use crate::app::{
     App,
     AppFocus, // Need AppFocus to check overall focus
     DropdownFocus,
     InputMode,
+    TokenUrgency,
 }; // Use App, Enums from local app module
 
 // Intention: Render the top bar containing Workspace trigger, Change Set trigger, and Email.
 // Design Choice: Encapsulates the horizontal layout and widget rendering for the top bar.
-// Returns the Rect of the Change Set trigger area for dropdown positioning.
-pub(super) fn render_top_bar(f: &mut Frame, app: &App, area: Rect) -> Rect {
+// The active profile, if any, and the time left before the token expires are shown before the
+// email. Returns the Rects of the Workspace and Change Set trigger areas, for mouse hit-testing and
+// dropdown positioning.
+pub(super) fn render_top_bar(
+    f: &mut Frame,
+    app: &App,
+    area: Rect,
+) -> (Rect, Rect) {
     let top_bar_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
@@ -50,11 +54,20 @@ pub(super) fn render_top_bar(f: &mut Frame, app: &App, area: Rect) -> Rect {
     let cs_trigger_area = top_bar_chunks[1];
     let email_area = top_bar_chunks[2];
 
-    // Workspace Trigger
-    let ws_name = app
+    // Workspace Trigger: the configured name, then the SI workspace ID once loaded
+    let ws_id = app
         .whoami_data
         .as_ref()
         .map_or("Loading...", |d| &d.workspace_id);
+    let ws_name = match app.active_workspace_name() {
+        Some(name) => format!("{} ({})", name, ws_id),
+        None => ws_id.to_string(),
+    };
+    let ws_indicator = if app.workspace_dropdown_active {
+        "▼"
+    } else {
+        "▶"
+    };
     // Use helper function to get style
     let ws_is_focused = app.dropdown_focus == DropdownFocus::Workspace; // Inner focus check
     let top_bar_has_focus = app.current_focus == AppFocus::TopBar; // Overall focus check
@@ -63,26 +76,25 @@ pub(super) fn render_top_bar(f: &mut Frame, app: &App, area: Rect) -> Rect {
     let ws_style = if top_bar_has_focus {
         if ws_is_focused {
             // Focused pane, focused element
-            Style::default()
-                .bg(Color::DarkGray)
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD)
+            app.theme.selection().add_modifier(Modifier::BOLD)
         } else {
             // Focused pane, unfocused element
-            Style::default().bg(Color::DarkGray).fg(Color::Gray)
+            app.theme.selection_inactive()
         }
     } else {
         // Unfocused pane
-        Style::default().fg(Color::DarkGray) // Keep it simple when pane is not focused
+        app.theme.dim() // Keep it simple when pane is not focused
     };
 
     // Construct the title with highlighted 'W'
     let ws_title_spans = vec![
-        Span::raw(" "), // Leading space
-        Span::styled("W", Style::default().fg(Color::Yellow)), // Highlighted 'W'
-        Span::raw("orkspace: "), // Rest of the label
-        Span::styled(ws_name, Style::default().fg(Color::Cyan)), // Workspace name
-        Span::raw(" "), // Trailing space
+        Span::raw(" "),                            // Leading space
+        Span::styled("W", app.theme.hotkey()),     // Highlighted 'W'
+        Span::raw("orkspace: "),                   // Rest of the label
+        Span::styled(ws_name, app.theme.accent()), // Workspace name
+        Span::raw(" "),                            // Space before indicator
+        Span::raw(ws_indicator),                   // Dropdown indicator
+        Span::raw(" "),                            // Trailing space
     ];
     let ws_line = Line::from(ws_title_spans);
     let ws_trigger = Paragraph::new(ws_line)
@@ -109,29 +121,26 @@ pub(super) fn render_top_bar(f: &mut Frame, app: &App, area: Rect) -> Rect {
     let cs_style = if top_bar_has_focus {
         if cs_is_focused {
             // Focused pane, focused element
-            Style::default()
-                .bg(Color::DarkGray)
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD)
+            app.theme.selection().add_modifier(Modifier::BOLD)
         } else {
             // Focused pane, unfocused element
-            Style::default().bg(Color::DarkGray).fg(Color::Gray)
+            app.theme.selection_inactive()
         }
     } else {
         // Unfocused pane
-        Style::default().fg(Color::DarkGray) // Keep it simple when pane is not focused
+        app.theme.dim() // Keep it simple when pane is not focused
     };
 
     // Construct the title with highlighted 'C'
     let cs_title_spans = vec![
-        Span::raw(" "), // Leading space
-        Span::styled("C", Style::default().fg(Color::Yellow)), // Highlighted 'C'
-        Span::raw("hange Set: "), // Rest of the label
-        Span::styled(selected_cs_name, Style::default().fg(Color::Yellow)), // Selected CS name (keep yellow?)
-        Span::raw(selected_cs_status), // Status
-        Span::raw(" "),                // Space before indicator
-        Span::raw(cs_indicator),       // Dropdown indicator
-        Span::raw(" "),                // Trailing space
+        Span::raw(" "),                        // Leading space
+        Span::styled("C", app.theme.hotkey()), // Highlighted 'C'
+        Span::raw("hange Set: "),              // Rest of the label
+        Span::styled(selected_cs_name, app.theme.highlight()), // Selected CS name
+        Span::raw(selected_cs_status),                         // Status
+        Span::raw(" "),          // Space before indicator
+        Span::raw(cs_indicator), // Dropdown indicator
+        Span::raw(" "),          // Trailing space
     ];
     let cs_line = Line::from(cs_title_spans);
     let cs_trigger = Paragraph::new(cs_line)
@@ -139,14 +148,41 @@ pub(super) fn render_top_bar(f: &mut Frame, app: &App, area: Rect) -> Rect {
         .block(Block::default());
     f.render_widget(cs_trigger, cs_trigger_area);
 
-    // Email
-    let email_text = app
-        .whoami_data
-        .as_ref()
-        .map_or("".to_string(), |d| d.user_email.clone());
+    // Profile (when one is selected) and Email
+    let mut email_spans = Vec::new();
+    if let Some(profile) = &app.profile {
+        let profile_style = if profile.production {
+            app.theme.error().add_modifier(Modifier::BOLD)
+        } else {
+            app.theme.accent()
+        };
+        email_spans.push(Span::raw("Profile: "));
+        email_spans.push(Span::styled(profile.name.clone(), profile_style));
+        if profile.settings.read_only {
+            email_spans.push(Span::styled(" [read-only]", app.theme.warning()));
+        }
+        email_spans.push(Span::raw(" | "));
+    }
+    if let Some((left, urgency)) = app.token_time_left(Utc::now()) {
+        let token_style = match urgency {
+            TokenUrgency::Valid => app.theme.muted(),
+            TokenUrgency::ExpiresSoon => app.theme.warning(),
+            TokenUrgency::Expired => {
+                app.theme.error().add_modifier(Modifier::BOLD)
+            }
+        };
+        email_spans.push(Span::raw("Token: "));
+        email_spans.push(Span::styled(left, token_style));
+        email_spans.push(Span::raw(" | "));
+    }
+    email_spans.push(Span::raw(
+        app.whoami_data
+            .as_ref()
+            .map_or("".to_string(), |d| d.user_email.clone()),
+    ));
     let email_paragraph =
-        Paragraph::new(email_text).alignment(Alignment::Right);
+        Paragraph::new(Line::from(email_spans)).alignment(Alignment::Right);
     f.render_widget(email_paragraph, email_area);
 
-    cs_trigger_area // Return this area for dropdown positioning
+    (ws_trigger_area, cs_trigger_area) // Returned for hit-testing and dropdown positioning
 }
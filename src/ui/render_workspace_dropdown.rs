@@ -0,0 +1,86 @@
+// src/ui/render_workspace_dropdown.rs
+
+// Intention: Render the Workspace dropdown list overlay if active.
+// Design Choice: Same placement and widgets as the change set dropdown, positioned below the
+// Workspace trigger. Each item shows the workspace name and API URL; the workspace in use is
+// marked. The JWT is never shown.
+
+use ratatui::{
+    Frame,
+    layout::Rect,
+    prelude::*, // Import common traits and types
+    style::Modifier,
+    widgets::{
+        Block,
+        Borders,
+        Clear,
+        HighlightSpacing,
+        List,
+        ListItem,
+    },
+};
+
+use crate::app::App; // Use App from local app module
+
+// --- Constants for UI Layout ---
+const DROPDOWN_LIST_WIDTH: u16 = 60;
+const DROPDOWN_MAX_ITEMS: usize = 10;
+
+// Intention: Render the Workspace dropdown list overlay if active.
+// Design Choice: Returns the area of the open dropdown for mouse hit-testing.
+pub(super) fn render_workspace_dropdown(
+    f: &mut Frame,
+    app: &mut App,
+    ws_trigger_area: Rect,
+) -> Option<Rect> {
+    if !app.workspace_dropdown_active {
+        return None;
+    }
+    let list_height =
+        app.workspaces.len().clamp(1, DROPDOWN_MAX_ITEMS) as u16 + 2; // +2 for borders
+
+    // Calculate position below the trigger
+    let list_area = Rect {
+        x: ws_trigger_area.x,
+        y: ws_trigger_area.y + 1,
+        width: DROPDOWN_LIST_WIDTH.min(f.size().width - ws_trigger_area.x), // Clamp width
+        height: list_height.min(f.size().height - (ws_trigger_area.y + 1)), // Clamp height
+    };
+
+    let items: Vec<ListItem> = app
+        .workspaces
+        .iter()
+        .enumerate()
+        .map(|(index, workspace)| {
+            let label = format!(
+                "{} - {}",
+                workspace.name, workspace.credentials.api_url
+            );
+            if app.active_workspace == Some(index) {
+                ListItem::new(format!("{} (active)", label))
+                    .style(app.theme.accent())
+            } else {
+                ListItem::new(label)
+            }
+        })
+        .collect();
+
+    let dropdown_list = List::new(items)
+        .block(
+            Block::default()
+                .title("Select Workspace (Enter/Esc)")
+                .borders(Borders::ALL),
+        )
+        .highlight_style(app.theme.selection().add_modifier(Modifier::BOLD))
+        .highlight_symbol("> ")
+        .highlight_spacing(HighlightSpacing::Always);
+
+    f.render_widget(Clear, list_area); // Clear the area first
+    // Render with the app's state so the scroll offset is kept for mouse clicks
+    f.render_stateful_widget(
+        dropdown_list,
+        list_area,
+        &mut app.workspace_list_state,
+    );
+    Some(list_area)
+}
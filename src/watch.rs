@@ -0,0 +1,257 @@
+// src/watch.rs
+
+// Intention:
+// Poll a workspace, or one change set, and yield typed events for what changed between polls:
+// change sets created, changing status or abandoned, components added, removed or modified,
+// and actions queued, changing state, failed or completed. Shared by the CLI `watch` command,
+// the TUI's auto-refresh (which reuses the diffing) and anything else that wants to react to
+// changes, e.g. bots.
+
+// Design Choices:
+// - Events are computed by diffing successive observations (`diff`): `list_change_sets`, then
+//   for every open change set (not applied or abandoned) its `get_merge_status` and, if
+//   components are watched, `list_components` plus `get_components` (at most
+//   `ClientSettings::concurrency` lookups in flight). The first poll only records a baseline.
+// - Like `ApplyMonitor`, `Watcher` is step-driven (`is_due`, `poll`), so the TUI can poll it
+//   between input events; `next` waits and yields one event at a time, like a stream, for
+//   callers that just consume events.
+// - Every poll reads from the server: its reads run inside `api_client::read_fresh`, which
+//   bypasses the client cache without dropping what other callers (e.g. the TUI) have cached.
+// - A failed poll returns the error and keeps the previous observation, so the next poll
+//   reports everything that changed since the last successful one.
+
+mod diff;
+mod event;
+
+use std::{
+    collections::{
+        BTreeMap,
+        VecDeque,
+    },
+    error::Error,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+pub use diff::{
+    CLOSED_CHANGE_SET_STATUSES,
+    ChangeSetObservation,
+    Observation,
+    ObservedComponent,
+    diff_actions,
+    diff_change_sets,
+    diff_components,
+    diff_observations,
+    is_open_status,
+};
+pub use event::WatchEvent;
+
+use crate::{
+    api_client::{
+        self,
+        CancelToken,
+    },
+    logging::LogEntry,
+};
+
+type WatchError = Box<dyn Error + Send + Sync>;
+
+/// What to watch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchScope {
+    /// Every change set of the workspace.
+    Workspace,
+    /// One change set, by ID.
+    ChangeSet(String),
+}
+
+/// Tuning knobs for `Watcher`.
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// Time between polls.
+    pub interval: Duration,
+    /// Resolve every component on every poll to report component events. Costs one request
+    /// per component and open change set.
+    pub components: bool,
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(10),
+            components: true,
+        }
+    }
+}
+
+/// Polls a workspace or change set and reports what changed.
+#[derive(Debug, Clone)]
+pub struct Watcher {
+    workspace_id: String,
+    scope: WatchScope,
+    options: WatchOptions,
+    last: Option<Observation>,
+    pending: VecDeque<WatchEvent>,
+    next_poll_at: Instant,
+}
+
+impl Watcher {
+    /// A watcher whose first poll is due at once.
+    pub fn new(
+        workspace_id: &str,
+        scope: WatchScope,
+        options: WatchOptions,
+    ) -> Self {
+        Self {
+            workspace_id: workspace_id.to_string(),
+            scope,
+            options,
+            last: None,
+            pending: VecDeque::new(),
+            next_poll_at: Instant::now(),
+        }
+    }
+
+    pub fn scope(&self) -> &WatchScope {
+        &self.scope
+    }
+
+    /// The last successful observation, if any.
+    pub fn observation(&self) -> Option<&Observation> {
+        self.last.as_ref()
+    }
+
+    pub fn is_due(&self) -> bool {
+        Instant::now() >= self.next_poll_at
+    }
+
+    /// Time left until the next poll is due (zero if due).
+    pub fn time_until_next_poll(&self) -> Duration {
+        self.next_poll_at.saturating_duration_since(Instant::now())
+    }
+
+    /// Observes the workspace once and returns the events since the previous successful poll
+    /// (none for the first), with the API logs of this poll.
+    pub async fn poll(
+        &mut self,
+        cancel: &CancelToken,
+    ) -> Result<(Vec<WatchEvent>, Vec<LogEntry>), WatchError> {
+        self.next_poll_at = Instant::now() + self.options.interval;
+        let (observation, logs) = observe(
+            &self.workspace_id,
+            &self.scope,
+            self.options.components,
+            cancel,
+        )
+        .await?;
+        let events = match &self.last {
+            Some(last) => diff_observations(last, &observation),
+            None => Vec::new(),
+        };
+        self.last = Some(observation);
+        Ok((events, logs))
+    }
+
+    /// The next event, polling every interval until there is one. Logs are dropped; use
+    /// `poll` to keep them.
+    pub async fn next(
+        &mut self,
+        cancel: &CancelToken,
+    ) -> Result<WatchEvent, WatchError> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(event);
+            }
+            tokio::time::sleep(self.time_until_next_poll()).await;
+            let (events, _logs) = self.poll(cancel).await?;
+            self.pending.extend(events);
+        }
+    }
+}
+
+/// Observes the change sets in `scope` once, reading from the server.
+pub async fn observe(
+    workspace_id: &str,
+    scope: &WatchScope,
+    components: bool,
+    cancel: &CancelToken,
+) -> Result<(Observation, Vec<LogEntry>), WatchError> {
+    let (listed, mut logs) =
+        api_client::list_change_sets(workspace_id, cancel).await?;
+    let change_sets: Vec<_> = match scope {
+        WatchScope::Workspace => listed.change_sets,
+        WatchScope::ChangeSet(id) => listed
+            .change_sets
+            .into_iter()
+            .filter(|cs| &cs.id == id)
+            .collect(),
+    };
+    let mut details = BTreeMap::new();
+    for cs in change_sets.iter().filter(|cs| is_open_status(&cs.status)) {
+        let (status, status_logs) = api_client::read_fresh(
+            api_client::get_merge_status(workspace_id, &cs.id, cancel),
+        )
+        .await?;
+        logs.extend(status_logs);
+        let components = if components {
+            let (observed, component_logs) = api_client::read_fresh(
+                observe_components(workspace_id, &cs.id, cancel),
+            )
+            .await?;
+            logs.extend(component_logs);
+            Some(observed)
+        } else {
+            None
+        };
+        details.insert(
+            cs.id.clone(),
+            ChangeSetObservation {
+                components,
+                actions: status.actions,
+            },
+        );
+    }
+    Ok((
+        Observation {
+            change_sets,
+            details,
+        },
+        logs,
+    ))
+}
+
+/// Lists the components of a change set and resolves each one (concurrently, up to
+/// `ClientSettings::concurrency` at a time), by ID.
+pub async fn observe_components(
+    workspace_id: &str,
+    change_set_id: &str,
+    cancel: &CancelToken,
+) -> Result<(BTreeMap<String, ObservedComponent>, Vec<LogEntry>), WatchError> {
+    let (listed, mut logs) =
+        api_client::list_components(workspace_id, change_set_id, cancel)
+            .await?;
+    let lookups = api_client::get_components(
+        workspace_id,
+        change_set_id,
+        &listed.components,
+        cancel,
+    )
+    .await?;
+    let mut components = BTreeMap::new();
+    for (id, result) in lookups {
+        // A component that cannot be read would look removed, so the poll fails instead
+        let (response, component_logs) = result?;
+        logs.extend(component_logs);
+        components.insert(
+            id.clone(),
+            ObservedComponent {
+                name: response.component_str("name").unwrap_or(&id).to_string(),
+                component: response.component,
+                domain: response.domain,
+            },
+        );
+    }
+    Ok((components, logs))
+}
@@ -0,0 +1,194 @@
+// src/watch/diff.rs
+
+// Intention: What one poll of a `Watcher` saw, and the events between two such observations.
+// Design Choice: Diffing is pure and public, so it can be tested without a backend and reused
+// by callers that fetch the data themselves (the TUI's auto-refresh). Rows are matched by ID.
+// Components and actions are only compared for change sets observed in both polls: a change
+// set seen for the first time sets a baseline, since everything in it would otherwise be
+// reported as added.
+
+use std::collections::BTreeMap;
+
+use super::WatchEvent;
+use crate::api_models::{
+    ChangeSetSummary,
+    MergeStatusV1ResponseAction,
+};
+
+/// Change set statuses after which a change set no longer changes.
+pub const CLOSED_CHANGE_SET_STATUSES: [&str; 2] = ["Applied", "Abandoned"];
+
+/// Whether a change set with this status can still change (not applied or abandoned).
+pub fn is_open_status(status: &str) -> bool {
+    !CLOSED_CHANGE_SET_STATUSES.contains(&status)
+}
+
+/// A component as observed: its name and everything `get_component` returned that describes
+/// it (the component data and domain properties).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObservedComponent {
+    pub name: String,
+    pub component: serde_json::Value,
+    pub domain: serde_json::Value,
+}
+
+/// What was observed in one change set.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChangeSetObservation {
+    /// By component ID; `None` when components are not watched.
+    pub components: Option<BTreeMap<String, ObservedComponent>>,
+    pub actions: Vec<MergeStatusV1ResponseAction>,
+}
+
+/// What one poll observed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Observation {
+    /// The watched change sets as listed.
+    pub change_sets: Vec<ChangeSetSummary>,
+    /// Details of the open ones, by change set ID.
+    pub details: BTreeMap<String, ChangeSetObservation>,
+}
+
+/// Events for change sets created, whose status changed, or that were abandoned.
+pub fn diff_change_sets(
+    old: &[ChangeSetSummary],
+    new: &[ChangeSetSummary],
+) -> Vec<WatchEvent> {
+    let mut events = Vec::new();
+    for cs in new {
+        match old.iter().find(|previous| previous.id == cs.id) {
+            None => events.push(WatchEvent::ChangeSetCreated {
+                change_set: cs.clone(),
+            }),
+            Some(previous) if previous.status == cs.status => {}
+            Some(_) if cs.status == "Abandoned" => {
+                events.push(WatchEvent::ChangeSetAbandoned {
+                    change_set: cs.clone(),
+                })
+            }
+            Some(previous) => events.push(WatchEvent::ChangeSetStatusChanged {
+                change_set: cs.clone(),
+                previous_status: previous.status.clone(),
+            }),
+        }
+    }
+    // Abandoned change sets may leave the list; applied ones are not abandoned
+    events.extend(
+        old.iter()
+            .filter(|previous| {
+                is_open_status(&previous.status)
+                    && !new.iter().any(|cs| cs.id == previous.id)
+            })
+            .map(|previous| WatchEvent::ChangeSetAbandoned {
+                change_set: ChangeSetSummary {
+                    status: "Abandoned".to_string(),
+                    ..previous.clone()
+                },
+            }),
+    );
+    events
+}
+
+/// Events for the components added, removed or modified in a change set.
+pub fn diff_components(
+    change_set_id: &str,
+    old: &BTreeMap<String, ObservedComponent>,
+    new: &BTreeMap<String, ObservedComponent>,
+) -> Vec<WatchEvent> {
+    let fields = |id: &String, component: &ObservedComponent| {
+        (
+            change_set_id.to_string(),
+            id.clone(),
+            component.name.clone(),
+        )
+    };
+    let mut events = Vec::new();
+    for (id, component) in new {
+        let (change_set_id, component_id, name) = fields(id, component);
+        match old.get(id) {
+            None => events.push(WatchEvent::ComponentAdded {
+                change_set_id,
+                component_id,
+                name,
+            }),
+            Some(previous) if previous != component => {
+                events.push(WatchEvent::ComponentModified {
+                    change_set_id,
+                    component_id,
+                    name,
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    for (id, component) in old {
+        if !new.contains_key(id) {
+            let (change_set_id, component_id, name) = fields(id, component);
+            events.push(WatchEvent::ComponentRemoved {
+                change_set_id,
+                component_id,
+                name,
+            });
+        }
+    }
+    events
+}
+
+/// Events for the actions queued in, changing state in, or gone from, a change set's merge
+/// status.
+pub fn diff_actions(
+    change_set_id: &str,
+    old: &[MergeStatusV1ResponseAction],
+    new: &[MergeStatusV1ResponseAction],
+) -> Vec<WatchEvent> {
+    let changed = new.iter().filter_map(|action| {
+        let change_set_id = change_set_id.to_string();
+        match old.iter().find(|previous| previous.id == action.id) {
+            None => Some(WatchEvent::ActionAdded {
+                change_set_id,
+                action: action.clone(),
+            }),
+            Some(previous) if previous.state == action.state => None,
+            Some(_) if action.state.eq_ignore_ascii_case("Failed") => {
+                Some(WatchEvent::ActionFailed {
+                    change_set_id,
+                    action: action.clone(),
+                })
+            }
+            Some(previous) => Some(WatchEvent::ActionStateChanged {
+                change_set_id,
+                action: action.clone(),
+                previous_state: previous.state.clone(),
+            }),
+        }
+    });
+    let completed = old
+        .iter()
+        .filter(|previous| !new.iter().any(|action| action.id == previous.id))
+        .map(|action| WatchEvent::ActionCompleted {
+            change_set_id: change_set_id.to_string(),
+            action: action.clone(),
+        });
+    changed.chain(completed).collect()
+}
+
+/// Every event between two observations: change sets first, then the components and actions
+/// of each change set observed in both.
+pub fn diff_observations(
+    old: &Observation,
+    new: &Observation,
+) -> Vec<WatchEvent> {
+    let mut events = diff_change_sets(&old.change_sets, &new.change_sets);
+    for (id, details) in &new.details {
+        let Some(previous) = old.details.get(id) else {
+            continue; // First observation of this change set
+        };
+        if let (Some(old_components), Some(new_components)) =
+            (&previous.components, &details.components)
+        {
+            events.extend(diff_components(id, old_components, new_components));
+        }
+        events.extend(diff_actions(id, &previous.actions, &details.actions));
+    }
+    events
+}
@@ -0,0 +1,181 @@
+// src/watch/event.rs
+
+// Intention: The typed events a `Watcher` yields.
+// Design Choice: Each event carries what a consumer needs to report it without another request
+// (the change set summary, the component name, the action), and serializes as one JSON object
+// tagged with `event`, so bots can consume the CLI's `--json` output line by line.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::api_models::{
+    ChangeSetSummary,
+    MergeStatusV1ResponseAction,
+};
+
+/// Something that changed in the watched workspace between two polls.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum WatchEvent {
+    ChangeSetCreated {
+        change_set: ChangeSetSummary,
+    },
+    ChangeSetStatusChanged {
+        change_set: ChangeSetSummary, // With the new status
+        previous_status: String,
+    },
+    /// Its status became `Abandoned`, or it left the list without being applied.
+    ChangeSetAbandoned {
+        change_set: ChangeSetSummary,
+    },
+    ComponentAdded {
+        change_set_id: String,
+        component_id: String,
+        name: String,
+    },
+    ComponentRemoved {
+        change_set_id: String,
+        component_id: String,
+        name: String,
+    },
+    /// Its name, data or domain properties differ.
+    ComponentModified {
+        change_set_id: String,
+        component_id: String,
+        name: String,
+    },
+    ActionAdded {
+        change_set_id: String,
+        action: MergeStatusV1ResponseAction,
+    },
+    /// It is still listed, in another state than `Failed`.
+    ActionStateChanged {
+        change_set_id: String,
+        action: MergeStatusV1ResponseAction, // With the new state
+        previous_state: String,
+    },
+    /// Its state became `Failed`.
+    ActionFailed {
+        change_set_id: String,
+        action: MergeStatusV1ResponseAction,
+    },
+    /// It left the merge status, which happens once it ran (see `ApplyMonitor`).
+    ActionCompleted {
+        change_set_id: String,
+        action: MergeStatusV1ResponseAction,
+    },
+}
+
+impl WatchEvent {
+    /// The change set the event happened in.
+    pub fn change_set_id(&self) -> &str {
+        match self {
+            WatchEvent::ChangeSetCreated { change_set }
+            | WatchEvent::ChangeSetStatusChanged { change_set, .. }
+            | WatchEvent::ChangeSetAbandoned { change_set } => &change_set.id,
+            WatchEvent::ComponentAdded { change_set_id, .. }
+            | WatchEvent::ComponentRemoved { change_set_id, .. }
+            | WatchEvent::ComponentModified { change_set_id, .. }
+            | WatchEvent::ActionAdded { change_set_id, .. }
+            | WatchEvent::ActionStateChanged { change_set_id, .. }
+            | WatchEvent::ActionFailed { change_set_id, .. }
+            | WatchEvent::ActionCompleted { change_set_id, .. } => {
+                change_set_id
+            }
+        }
+    }
+}
+
+impl fmt::Display for WatchEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WatchEvent::ChangeSetCreated { change_set } => write!(
+                f,
+                "Change set {} ({}) created, status {}",
+                change_set.name, change_set.id, change_set.status
+            ),
+            WatchEvent::ChangeSetStatusChanged {
+                change_set,
+                previous_status,
+            } => write!(
+                f,
+                "Change set {} ({}): {} -> {}",
+                change_set.name,
+                change_set.id,
+                previous_status,
+                change_set.status
+            ),
+            WatchEvent::ChangeSetAbandoned { change_set } => write!(
+                f,
+                "Change set {} ({}) abandoned",
+                change_set.name, change_set.id
+            ),
+            WatchEvent::ComponentAdded {
+                change_set_id,
+                component_id,
+                name,
+            } => write!(
+                f,
+                "[{}] Component {} ({}) added",
+                change_set_id, name, component_id
+            ),
+            WatchEvent::ComponentRemoved {
+                change_set_id,
+                component_id,
+                name,
+            } => write!(
+                f,
+                "[{}] Component {} ({}) removed",
+                change_set_id, name, component_id
+            ),
+            WatchEvent::ComponentModified {
+                change_set_id,
+                component_id,
+                name,
+            } => write!(
+                f,
+                "[{}] Component {} ({}) modified",
+                change_set_id, name, component_id
+            ),
+            WatchEvent::ActionAdded {
+                change_set_id,
+                action,
+            } => write!(
+                f,
+                "[{}] Action {} {} ({}) added",
+                change_set_id, action.kind, action.name, action.id
+            ),
+            WatchEvent::ActionStateChanged {
+                change_set_id,
+                action,
+                previous_state,
+            } => write!(
+                f,
+                "[{}] Action {} {} ({}): {} -> {}",
+                change_set_id,
+                action.kind,
+                action.name,
+                action.id,
+                previous_state,
+                action.state
+            ),
+            WatchEvent::ActionFailed {
+                change_set_id,
+                action,
+            } => write!(
+                f,
+                "[{}] Action {} {} ({}) failed",
+                change_set_id, action.kind, action.name, action.id
+            ),
+            WatchEvent::ActionCompleted {
+                change_set_id,
+                action,
+            } => write!(
+                f,
+                "[{}] Action {} {} ({}) completed",
+                change_set_id, action.kind, action.name, action.id
+            ),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+// src/workspaces.rs
+
+// Intention:
+// The workspaces the TUI can switch between, each with its own API URL and JWT, read from a
+// workspaces file next to the other config files.
+
+// Design Choices:
+// - The file is INI-like (see `config_file`): a `[name]` section per workspace with
+//   `api = <url>` and one token key, as in the profiles file (`token`, `token_env`, `token_file`,
+//   `token_command`, `token_store`; see `TokenSource`). Problems are reported by line (or by
+//   section, for missing keys).
+// - The token is read when the file is, and the source kept on the entry's credentials when it
+//   can change, so a file, command or store token is read again after a `401`.
+//   ```text
+//   [staging]
+//   api = https://staging.example.com/api
+//   token_env = STAGING_JWT
+//   ```
+// - The `.env` credentials (`SI_API`, `JWT_TOKEN`) are not part of the file; the TUI lists them
+//   first, as the `.env` workspace, when they are set.
+// - The JWT decides which SI workspace is used; the name here is only a label.
+
+use std::{
+    env,
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use crate::{
+    api_client::{
+        Credentials,
+        TokenSource,
+    },
+    config_file::{
+        Entry,
+        Section,
+        config_path,
+        line_errors,
+        parse_sections,
+    },
+};
+
+/// Environment variable overriding the workspaces file path.
+pub const WORKSPACES_FILE_ENV: &str = "SI_WORKSPACES_FILE";
+
+/// Name of the workspace using the `.env` credentials.
+pub const ENV_WORKSPACE_NAME: &str = ".env";
+
+/// A workspace the TUI can switch to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceEntry {
+    pub name: String,
+    pub credentials: Credentials,
+}
+
+/// Parses a workspaces file, reading each token. `token_env` variables are looked up with
+/// `lookup_env`.
+pub fn parse_workspaces(
+    text: &str,
+    lookup_env: impl Fn(&str) -> Option<String>,
+) -> Result<Vec<WorkspaceEntry>, Vec<String>> {
+    let mut errors: Vec<(usize, String)> = Vec::new();
+    let sections = parse_sections(text, "workspace", &mut errors);
+    let entries = sections
+        .iter()
+        .filter_map(|section| {
+            parse_workspace(section, &lookup_env, &mut errors)
+        })
+        .collect();
+    line_errors(entries, errors)
+}
+
+// The workspace of one section, or `None` after reporting why there is none.
+fn parse_workspace(
+    section: &Section,
+    lookup_env: impl Fn(&str) -> Option<String>,
+    errors: &mut Vec<(usize, String)>,
+) -> Option<WorkspaceEntry> {
+    if section.name == ENV_WORKSPACE_NAME {
+        errors.push((section.line, format!("invalid name '{}'", section.name)));
+    }
+    let (mut api, mut token) = (None, None);
+    for &Entry { line, key, value } in &section.entries {
+        if let Some(source) = TokenSource::from_key(key, value) {
+            if token.is_some() {
+                errors.push((
+                    line,
+                    format!(
+                        "`{}`: workspace '{}' already has a token source",
+                        key, section.name
+                    ),
+                ));
+            } else {
+                token = Some((line, source));
+            }
+            continue;
+        }
+        match key {
+            "api" => api = Some(value.to_string()),
+            _ => errors.push((line, format!("unknown key '{}'", key))),
+        }
+    }
+    let missing =
+        |key: &str| format!("workspace '{}' has no `{}`", section.name, key);
+    if api.is_none() {
+        errors.push((section.line, missing("api")));
+    }
+    let Some((line, source)) = token else {
+        errors.push((section.line, missing("token")));
+        return None;
+    };
+    let token = match source.read_with(lookup_env) {
+        Ok(token) => token,
+        Err(message) => {
+            errors.push((line, message));
+            return None;
+        }
+    };
+    Some(WorkspaceEntry {
+        name: section.name.to_string(),
+        credentials: Credentials {
+            api_url: api?,
+            token,
+            source: source.can_change().then_some(source),
+        },
+    })
+}
+
+/// Loads the workspaces file at `path`; a missing file means no extra workspaces.
+pub fn load_workspaces(
+    path: &Path,
+) -> Result<Vec<WorkspaceEntry>, Vec<String>> {
+    match fs::read_to_string(path) {
+        Ok(text) => parse_workspaces(&text, |name| env::var(name).ok()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(vec![e.to_string()]),
+    }
+}
+
+/// `SI_WORKSPACES_FILE`, else `$XDG_CONFIG_HOME/situation/workspaces.conf`, else
+/// `~/.config/situation/workspaces.conf`.
+pub fn workspaces_path() -> PathBuf {
+    config_path(WORKSPACES_FILE_ENV, "workspaces.conf")
+}
@@ -18,6 +18,7 @@ mod test_force_apply_endpoint;
 mod test_get_change_set_endpoint;
 mod test_get_merge_status_endpoint;
 mod test_list_change_sets_endpoint;
+mod test_request_approval_endpoint;
 
 // Note: The original file contained imports (std::env, chrono::Utc, dotenvy, situation::*, tokio::time::sleep)
 // and a #[cfg(test)] mod tests { ... } block. These are no longer needed here as the actual
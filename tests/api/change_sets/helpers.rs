@@ -5,7 +5,10 @@
 use std::env;
 
 use dotenvy::dotenv;
-use situation::api_client; // Use the library crate name 'situation'
+use situation::api_client::{
+    self,
+    CancelToken,
+}; // Use the library crate name 'situation'
 
 // Helper function to get workspace_id (could be moved to a shared test utils module later)
 // For now, assumes it's set directly in .env or fetched via whoami if needed
@@ -17,7 +20,7 @@ pub(super) async fn get_workspace_id() -> Result<String, String> {
         Ok(id) => Ok(id), // Return Ok if found
         Err(_) => {
             // If not in env, try fetching from whoami
-            match api_client::whoami().await {
+            match api_client::whoami(&CancelToken::new()).await {
                 // Remove incorrect type annotation from pattern
                 Ok((whoami_data, _logs)) => Ok(whoami_data.workspace_id),
                 Err(e) => Err(format!(
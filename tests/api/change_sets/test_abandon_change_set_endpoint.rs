@@ -5,7 +5,11 @@
 use chrono::Utc;
 use dotenvy::dotenv;
 use situation::{
-    api_client,
+    LogEntry,
+    api_client::{
+        self,
+        CancelToken,
+    },
     api_models,
 };
 use tokio::time::sleep;
@@ -33,8 +37,12 @@ async fn test_abandon_change_set_endpoint() {
     let create_request_body = api_models::CreateChangeSetV1Request {
         change_set_name: change_set_name.clone(),
     };
-    let create_result =
-        api_client::create_change_set(&workspace_id, create_request_body).await;
+    let create_result = api_client::create_change_set(
+        &workspace_id,
+        create_request_body,
+        &CancelToken::new(),
+    )
+    .await;
     assert!(
         create_result.is_ok(),
         "Failed to create change set for delete test: {:?}",
@@ -53,8 +61,12 @@ async fn test_abandon_change_set_endpoint() {
 
     // 2. Abandon the created change set
     // Use the renamed api_client::abandon_change_set function
-    let abandon_result =
-        api_client::abandon_change_set(&workspace_id, &change_set_id).await;
+    let abandon_result = api_client::abandon_change_set(
+        &workspace_id,
+        &change_set_id,
+        &CancelToken::new(),
+    )
+    .await;
 
     assert!(
         abandon_result.is_ok(),
@@ -66,7 +78,7 @@ async fn test_abandon_change_set_endpoint() {
     let (abandon_response, _logs): (
         // Renamed variable
         api_models::DeleteChangeSetV1Response, // Model name is correct
-        Vec<String>,
+        Vec<LogEntry>,
     ) = abandon_result.unwrap();
 
     // Check the structure based on DeleteChangeSetV1Response
@@ -77,7 +89,7 @@ async fn test_abandon_change_set_endpoint() {
 
     // Optional: Verify deletion by trying to GET the change set again (expecting an error)
     // sleep(std::time::Duration::from_millis(100)).await; // Delay before checking
-    // let get_result_after_delete = api_client::get_change_set(&workspace_id, &change_set_id).await;
+    // let get_result_after_delete = api_client::get_change_set(&workspace_id, &change_set_id, &CancelToken::new()).await;
     // assert!(get_result_after_delete.is_err(), "Getting the change set after deletion should fail.");
     // Note: The exact error type/status code for getting a deleted change set isn't specified,
     // so checking for `is_err()` is a basic verification.
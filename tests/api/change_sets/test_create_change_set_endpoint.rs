@@ -5,7 +5,11 @@
 use chrono::Utc;
 use dotenvy::dotenv;
 use situation::{
-    api_client,
+    LogEntry,
+    api_client::{
+        self,
+        CancelToken,
+    },
     api_models,
 };
 use tokio::time::sleep;
@@ -35,8 +39,12 @@ async fn test_create_change_set_endpoint() {
         change_set_name: change_set_name.clone(), // Use clone as we need the original later potentially
     };
 
-    let result =
-        api_client::create_change_set(&workspace_id, request_body).await; // Pass the request body struct
+    let result = api_client::create_change_set(
+        &workspace_id,
+        request_body,
+        &CancelToken::new(),
+    )
+    .await; // Pass the request body struct
 
     assert!(
         result.is_ok(),
@@ -47,7 +55,7 @@ async fn test_create_change_set_endpoint() {
     // Add explicit type annotation to the destructuring let binding
     let (create_response, _logs): (
         api_models::CreateChangeSetV1Response,
-        Vec<String>,
+        Vec<LogEntry>,
     ) = result.unwrap();
 
     // Check the structure based on CreateChangeSetV1Response using the ChangeSet struct
@@ -67,7 +75,7 @@ async fn test_create_change_set_endpoint() {
     // Increased delay before abandon to potentially avoid DispatchGone error
     sleep(std::time::Duration::from_millis(500)).await;
     let abandon_result = // Use abandon_change_set
-        api_client::abandon_change_set(&workspace_id, &change_set_id).await;
+        api_client::abandon_change_set(&workspace_id, &change_set_id, &CancelToken::new()).await;
     assert!(
         abandon_result.is_ok(),
         "Failed to abandon change set after create test: {:?}", // Updated message
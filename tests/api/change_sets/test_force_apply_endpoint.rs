@@ -5,7 +5,11 @@
 use chrono::Utc;
 use dotenvy::dotenv;
 use situation::{
-    api_client,
+    LogEntry,
+    api_client::{
+        self,
+        CancelToken,
+    },
     api_models,
 };
 use tokio::time::sleep;
@@ -33,8 +37,12 @@ async fn test_force_apply_endpoint() {
     let create_request_body = api_models::CreateChangeSetV1Request {
         change_set_name: change_set_name.clone(),
     };
-    let create_result =
-        api_client::create_change_set(&workspace_id, create_request_body).await;
+    let create_result = api_client::create_change_set(
+        &workspace_id,
+        create_request_body,
+        &CancelToken::new(),
+    )
+    .await;
     assert!(
         create_result.is_ok(),
         "Failed to create change set for force apply test: {:?}",
@@ -53,8 +61,12 @@ async fn test_force_apply_endpoint() {
 
     // 2. Force apply the created change set
     // Use the renamed api_client::force_apply function
-    let apply_result = api_client::force_apply(&workspace_id, &change_set_id) // Use renamed function
-        .await;
+    let apply_result = api_client::force_apply(
+        &workspace_id,
+        &change_set_id,
+        &CancelToken::new(),
+    ) // Use renamed function
+    .await;
 
     assert!(
         apply_result.is_ok(),
@@ -64,7 +76,7 @@ async fn test_force_apply_endpoint() {
 
     // The success response has no body, so checking for Ok is the main assertion.
     // We get back logs, but no specific response data model.
-    let (_response_body_ignored, _logs): ((), Vec<String>) =
+    let (_response_body_ignored, _logs): ((), Vec<LogEntry>) =
         apply_result.unwrap();
 
     // Add delay before cleanup
@@ -74,7 +86,7 @@ async fn test_force_apply_endpoint() {
     // Note: Force applying might merge/abandon the change set automatically.
     // If abandonment fails, it might be expected. We'll log the result but not fail the test.
     let abandon_result = // Use abandon_change_set
-        api_client::abandon_change_set(&workspace_id, &change_set_id).await;
+        api_client::abandon_change_set(&workspace_id, &change_set_id, &CancelToken::new()).await;
     if abandon_result.is_err() {
         println!(
             "Note: Failed to abandon change set after force apply (might be expected): {:?}", // Updated message
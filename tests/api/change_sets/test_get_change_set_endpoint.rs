@@ -5,7 +5,11 @@
 use chrono::Utc;
 use dotenvy::dotenv;
 use situation::{
-    api_client,
+    LogEntry,
+    api_client::{
+        self,
+        CancelToken,
+    },
     api_models,
 };
 use tokio::time::sleep;
@@ -33,8 +37,12 @@ async fn test_get_change_set_endpoint() {
     let create_request_body = api_models::CreateChangeSetV1Request {
         change_set_name: change_set_name.clone(),
     };
-    let create_result =
-        api_client::create_change_set(&workspace_id, create_request_body).await;
+    let create_result = api_client::create_change_set(
+        &workspace_id,
+        create_request_body,
+        &CancelToken::new(),
+    )
+    .await;
     assert!(
         create_result.is_ok(),
         "Failed to create change set for get test: {:?}",
@@ -53,8 +61,12 @@ async fn test_get_change_set_endpoint() {
 
     // 2. Get the created change set
     // Assume api_client::get_change_set exists
-    let get_result =
-        api_client::get_change_set(&workspace_id, &change_set_id).await;
+    let get_result = api_client::get_change_set(
+        &workspace_id,
+        &change_set_id,
+        &CancelToken::new(),
+    )
+    .await;
 
     assert!(
         get_result.is_ok(),
@@ -65,7 +77,7 @@ async fn test_get_change_set_endpoint() {
     // Add explicit type annotation
     let (get_response, _logs): (
         api_models::GetChangeSetV1Response,
-        Vec<String>,
+        Vec<LogEntry>,
     ) = get_result.unwrap();
 
     // Check the structure based on GetChangeSetV1Response using the ChangeSet struct
@@ -84,7 +96,7 @@ async fn test_get_change_set_endpoint() {
     // Clean up: Abandon the created change set
     sleep(std::time::Duration::from_millis(100)).await; // Small delay before abandon
     let abandon_result = // Use abandon_change_set
-        api_client::abandon_change_set(&workspace_id, &change_set_id).await;
+        api_client::abandon_change_set(&workspace_id, &change_set_id, &CancelToken::new()).await;
     assert!(
         abandon_result.is_ok(),
         "Failed to abandon change set after get test: {:?}", // Updated message
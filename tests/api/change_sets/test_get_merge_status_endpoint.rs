@@ -5,7 +5,11 @@
 use chrono::Utc;
 use dotenvy::dotenv;
 use situation::{
-    api_client,
+    LogEntry,
+    api_client::{
+        self,
+        CancelToken,
+    },
     api_models,
 };
 use tokio::time::sleep;
@@ -33,8 +37,12 @@ async fn test_get_merge_status_endpoint() {
     let create_request_body = api_models::CreateChangeSetV1Request {
         change_set_name: change_set_name.clone(),
     };
-    let create_result =
-        api_client::create_change_set(&workspace_id, create_request_body).await;
+    let create_result = api_client::create_change_set(
+        &workspace_id,
+        create_request_body,
+        &CancelToken::new(),
+    )
+    .await;
     assert!(
         create_result.is_ok(),
         "Failed to create change set for merge status test: {:?}",
@@ -53,8 +61,12 @@ async fn test_get_merge_status_endpoint() {
 
     // 2. Get the merge status for the created change set
     // Assume api_client::get_merge_status exists
-    let merge_status_result =
-        api_client::get_merge_status(&workspace_id, &change_set_id).await;
+    let merge_status_result = api_client::get_merge_status(
+        &workspace_id,
+        &change_set_id,
+        &CancelToken::new(),
+    )
+    .await;
 
     assert!(
         merge_status_result.is_ok(),
@@ -65,7 +77,7 @@ async fn test_get_merge_status_endpoint() {
     // Add explicit type annotation
     let (merge_status_response, _logs): (
         api_models::MergeStatusV1Response, // Assuming this model exists
-        Vec<String>,
+        Vec<LogEntry>,
     ) = merge_status_result.unwrap();
 
     // Check the structure based on MergeStatusV1Response using the ChangeSet struct
@@ -91,7 +103,7 @@ async fn test_get_merge_status_endpoint() {
 
     // Clean up: Abandon the change set
     let abandon_result = // Use abandon_change_set
-        api_client::abandon_change_set(&workspace_id, &change_set_id).await;
+        api_client::abandon_change_set(&workspace_id, &change_set_id, &CancelToken::new()).await;
     assert!(
         abandon_result.is_ok(),
         "Failed to abandon change set after merge status test: {:?}", // Updated message
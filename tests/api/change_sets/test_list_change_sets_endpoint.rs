@@ -4,7 +4,11 @@
 
 use dotenvy::dotenv;
 use situation::{
-    api_client,
+    LogEntry,
+    api_client::{
+        self,
+        CancelToken,
+    },
     api_models,
 };
 
@@ -26,7 +30,8 @@ async fn test_list_change_sets_endpoint() {
         .await
         .expect("Failed to get workspace_id for test");
 
-    let result = api_client::list_change_sets(&workspace_id).await;
+    let result =
+        api_client::list_change_sets(&workspace_id, &CancelToken::new()).await;
     assert!(
         result.is_ok(),
         "API call should return Ok. Error: {:?}",
@@ -36,7 +41,7 @@ async fn test_list_change_sets_endpoint() {
     // Add explicit type annotation to the destructuring let binding
     let (list_response, _logs): (
         api_models::ListChangeSetV1Response,
-        Vec<String>,
+        Vec<LogEntry>,
     ) = result.unwrap();
     // Check the structure based on ListChangeSetV1Response
     assert!(
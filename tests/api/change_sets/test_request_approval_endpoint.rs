@@ -0,0 +1,80 @@
+// tests/api/change_sets/test_request_approval_endpoint.rs
+
+// Intention: Test the request approval endpoint.
+
+use chrono::Utc;
+use dotenvy::dotenv;
+use situation::{
+    api_client::{
+        self,
+        CancelToken,
+    },
+    api_models,
+};
+use tokio::time::sleep;
+
+// Import helper function from the same directory
+use super::helpers::get_workspace_id;
+
+/// Test Case: Verify the `POST /v1/w/{workspace_id}/change-sets/{change_set_id}/request_approval` endpoint call.
+/// Intention: Ensure the application can request approval for a change set.
+/// Design: Creates a new change set, requests approval for it and asserts the call returns Ok,
+///         then abandons the change set. Requires a running SI instance and valid .env configuration.
+#[tokio::test]
+async fn test_request_approval_endpoint() {
+    dotenv().ok(); // Load .env file
+    let workspace_id = get_workspace_id()
+        .await
+        .expect("Failed to get workspace_id for test");
+    let change_set_name =
+        format!("test-request-approval-{}", Utc::now().timestamp_millis());
+
+    // 1. Create a change set to get an ID
+    let create_request_body = api_models::CreateChangeSetV1Request {
+        change_set_name: change_set_name.clone(),
+    };
+    let create_result = api_client::create_change_set(
+        &workspace_id,
+        create_request_body,
+        &CancelToken::new(),
+    )
+    .await;
+    assert!(
+        create_result.is_ok(),
+        "Failed to create change set for request approval test: {:?}",
+        create_result.err()
+    );
+    let (create_response, _logs) = create_result.unwrap();
+    let change_set_id = create_response.change_set.id.clone();
+
+    // Add a small delay
+    sleep(std::time::Duration::from_millis(200)).await;
+
+    // 2. Request approval for the created change set
+    let approval_result = api_client::request_approval(
+        &workspace_id,
+        &change_set_id,
+        &CancelToken::new(),
+    )
+    .await;
+
+    // Clean up before asserting so a failure doesn't leak the change set
+    let abandon_result = api_client::abandon_change_set(
+        &workspace_id,
+        &change_set_id,
+        &CancelToken::new(),
+    )
+    .await;
+    if abandon_result.is_err() {
+        println!(
+            "Note: Failed to abandon change set after request approval: {:?}",
+            abandon_result.err()
+        );
+    }
+
+    assert!(
+        approval_result.is_ok(),
+        "API call to request approval should return Ok. Error: {:?}",
+        approval_result.err()
+    );
+}
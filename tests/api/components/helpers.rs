@@ -7,7 +7,10 @@ use std::env;
 use chrono::Utc;
 use dotenvy::dotenv;
 use situation::{
-    api_client,
+    api_client::{
+        self,
+        CancelToken,
+    },
     api_models,
 }; // Use the library crate name 'situation'
 
@@ -16,7 +19,7 @@ pub(super) async fn get_workspace_id() -> Result<String, String> {
     dotenv().ok(); // Load .env file
     match env::var("WORKSPACE_ID") {
         Ok(id) => Ok(id),
-        Err(_) => match api_client::whoami().await {
+        Err(_) => match api_client::whoami(&CancelToken::new()).await {
             Ok((whoami_data, _logs)) => Ok(whoami_data.workspace_id),
             Err(e) => Err(format!(
                 "WORKSPACE_ID not in .env and failed to get from whoami: {}",
@@ -33,7 +36,13 @@ pub(super) async fn create_temp_change_set(
     let change_set_name =
         format!("test-component-cs-{}", Utc::now().timestamp_millis());
     let request_body = api_models::CreateChangeSetV1Request { change_set_name };
-    match api_client::create_change_set(workspace_id, request_body).await {
+    match api_client::create_change_set(
+        workspace_id,
+        request_body,
+        &CancelToken::new(),
+    )
+    .await
+    {
         Ok((response, _logs)) => Ok(response.change_set.id),
         Err(e) => Err(format!("Failed to create temp change set: {}", e)),
     }
@@ -44,7 +53,13 @@ pub(super) async fn abandon_temp_change_set(
     workspace_id: &str,
     change_set_id: &str,
 ) -> Result<(), String> {
-    match api_client::abandon_change_set(workspace_id, change_set_id).await {
+    match api_client::abandon_change_set(
+        workspace_id,
+        change_set_id,
+        &CancelToken::new(),
+    )
+    .await
+    {
         Ok(_) => Ok(()),
         Err(e) => Err(format!("Failed to abandon temp change set: {}", e)),
     }
@@ -6,7 +6,10 @@ use chrono::Utc;
 use dotenvy::dotenv;
 use serde_json::json;
 use situation::{
-    api_client,
+    api_client::{
+        self,
+        CancelToken,
+    },
     api_models,
 };
 use tokio::time::sleep;
@@ -52,6 +55,7 @@ async fn test_component_crud_endpoints() {
         &workspace_id,
         &change_set_id,
         create_request,
+        &CancelToken::new(),
     )
     .await;
     assert!(
@@ -68,9 +72,13 @@ async fn test_component_crud_endpoints() {
     sleep(std::time::Duration::from_millis(200)).await; // Delay
 
     // 3. Get Component
-    let get_result =
-        api_client::get_component(&workspace_id, &change_set_id, &component_id)
-            .await;
+    let get_result = api_client::get_component(
+        &workspace_id,
+        &change_set_id,
+        &component_id,
+        &CancelToken::new(),
+    )
+    .await;
     assert!(
         get_result.is_ok(),
         "API call to get component should return Ok. Error: {:?}",
@@ -95,6 +103,7 @@ async fn test_component_crud_endpoints() {
         &change_set_id,
         &component_id,
         update_request,
+        &CancelToken::new(),
     )
     .await;
     assert!(
@@ -106,9 +115,13 @@ async fn test_component_crud_endpoints() {
     sleep(std::time::Duration::from_millis(200)).await; // Delay
 
     // 5. Get Component Again (Verify Update)
-    let get_after_update_result =
-        api_client::get_component(&workspace_id, &change_set_id, &component_id)
-            .await;
+    let get_after_update_result = api_client::get_component(
+        &workspace_id,
+        &change_set_id,
+        &component_id,
+        &CancelToken::new(),
+    )
+    .await;
     assert!(
         get_after_update_result.is_ok(),
         "API call to get component after update should return Ok. Error: {:?}",
@@ -132,6 +145,7 @@ async fn test_component_crud_endpoints() {
         &workspace_id,
         &change_set_id,
         &component_id,
+        &CancelToken::new(),
     )
     .await;
     assert!(
@@ -18,6 +18,7 @@ use std::error::Error;
 
 use situation::api_client::{
     // Changed crate name
+    CancelToken,
     abandon_change_set, // Add other necessary imports like create_component if needed
     create_change_set,
     list_components,
@@ -40,7 +41,8 @@ async fn setup_test_change_set(
         // Create the request struct
         change_set_name: name.to_string(),
     };
-    let (response, _) = create_change_set(&workspace_id, request).await?; // Pass struct
+    let (response, _) =
+        create_change_set(&workspace_id, request, &CancelToken::new()).await?; // Pass struct
     Ok(response.change_set.id)
 }
 
@@ -51,7 +53,8 @@ async fn cleanup_test_change_set(
     // Changed return type
     let workspace_id = std::env::var("TEST_WORKSPACE_ID")
         .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?; // Map error explicitly
-    abandon_change_set(&workspace_id, change_set_id).await?; // Result type now matches
+    abandon_change_set(&workspace_id, change_set_id, &CancelToken::new())
+        .await?; // Result type now matches
     Ok(())
 }
 
@@ -70,7 +73,9 @@ async fn test_list_components_success()
     //       and using `create_component`.
 
     // Action: Call the list_components function
-    let result = list_components(&workspace_id, &change_set_id).await;
+    let result =
+        list_components(&workspace_id, &change_set_id, &CancelToken::new())
+            .await;
 
     // Assertions
     assert!(result.is_ok(), "list_components failed: {:?}", result.err());
@@ -78,14 +83,15 @@ async fn test_list_components_success()
 
     // Check logs (optional)
     assert!(
-        logs.iter().any(|log| log.contains(&format!(
+        logs.iter().any(|log| log.message.contains(&format!(
             "GET /v1/w/{}/change-sets/{}/components",
             workspace_id, change_set_id
         ))),
         "API call log not found"
     );
     assert!(
-        logs.iter().any(|log| log.contains("Status: 200 OK")),
+        logs.iter()
+            .any(|log| log.message.contains("Status: 200 OK")),
         "Success status log not found"
     );
 
@@ -10,18 +10,21 @@
 // - Asserts that the call succeeds and the response contains expected data.
 // - Requires a valid `.env` file with `SI_API` and `JWT_TOKEN` for the test to pass.
 
-use situation::whoami; // Import the function from the library crate
+use situation::{
+    api_client::CancelToken,
+    whoami, // Import the function from the library crate
+};
 
 /// Test Case: Verify the `/whoami` endpoint call.
 /// Intention: Ensure the application can correctly call the `/whoami` endpoint
 ///            using the library function and handle a successful response.
-/// Design: Calls `situation::whoami().await` and asserts that the result is Ok,
+/// Design: Calls `situation::whoami(&CancelToken::new()).await` and asserts that the result is Ok,
 ///         the WhoamiResponse contains non-empty user/workspace info, and logs are returned.
 #[tokio::test]
 // #[ignore = "Requires valid .env configuration and running API"] // Keep comment for context
 async fn test_whoami_endpoint_success() {
     // Ensure .env is loaded (dotenvy is called within get_api_config)
-    let result = whoami().await;
+    let result = whoami(&CancelToken::new()).await;
 
     assert!(
         result.is_ok(),
@@ -59,16 +62,18 @@ async fn test_whoami_endpoint_success() {
         // Check that logs were generated
         assert!(!logs.is_empty(), "Logs should have been generated");
         assert!(
-            logs.iter().any(|log| log.contains("Calling API: GET")),
+            logs.iter()
+                .any(|log| log.message.contains("Calling API: GET")),
             "Logs should contain API call info"
         );
         assert!(
             logs.iter()
-                .any(|log| log.contains("API Response Status: 200 OK")),
+                .any(|log| log.message.contains("API Response Status: 200 OK")),
             "Logs should contain success status"
         );
         assert!(
-            logs.iter().any(|log| log.contains("API Success Body:")),
+            logs.iter()
+                .any(|log| log.message.contains("API Success Body:")),
             "Logs should contain success body"
         );
     }
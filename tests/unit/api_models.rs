@@ -16,6 +16,7 @@ mod test_deserialize_list_change_set_response;
 mod test_deserialize_list_change_set_response_empty;
 mod test_deserialize_token_details;
 mod test_deserialize_whoami_response;
+mod test_merge_status_actions_grouped_by_kind;
 
 // Note: The original file contained imports (situation::*) and the test functions.
 // These are no longer needed here as the actual test code and necessary imports
@@ -0,0 +1,35 @@
+// tests/unit/api_models/test_merge_status_actions_grouped_by_kind.rs
+
+// Intention: Test grouping of merge status actions by kind for the actions table.
+
+use situation::MergeStatusV1Response; // Use the library crate namespace
+
+#[test]
+fn test_merge_status_actions_grouped_by_kind() {
+    let json = r#"{
+        "changeSet": { "id": "cs_1", "name": "Change Set 1", "status": "Open" },
+        "actions": [
+            { "id": "a1", "state": "Queued", "kind": "Refresh", "name": "refresh" },
+            { "id": "a2", "state": "Queued", "kind": "Manual", "name": "manual" },
+            { "id": "a3", "state": "Failed", "kind": "Delete", "name": "delete" },
+            { "id": "a4", "state": "Queued", "kind": "Create", "name": "create 1",
+              "component": { "id": "c1", "name": "Component 1" } },
+            { "id": "a5", "state": "OnHold", "kind": "Create", "name": "create 2",
+              "component": null },
+            { "id": "a6", "state": "Queued", "kind": "Custom", "name": "custom" },
+            { "id": "a7", "state": "Queued", "kind": "Manual", "name": "manual 2" }
+        ]
+    }"#;
+    let merge_status: MergeStatusV1Response = serde_json::from_str(json)
+        .expect("Failed to deserialize MergeStatusV1Response");
+
+    let ordered_ids: Vec<&str> = merge_status
+        .actions_grouped_by_kind()
+        .iter()
+        .map(|action| action.id.as_str())
+        .collect();
+
+    // Create first (API order kept within a kind), then Delete, Refresh, then unknown kinds,
+    // one group per kind
+    assert_eq!(ordered_ids, vec!["a4", "a5", "a3", "a1", "a6", "a2", "a7"]);
+}
@@ -0,0 +1,16 @@
+// tests/unit/apply_monitor.rs
+
+// Intention:
+// Declares unit test modules for the apply monitor bookkeeping (`ApplyMonitor::record_observation`).
+// Each submodule corresponds to a file containing a single test function or helpers.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+// - Only the pure bookkeeping is tested here; polling needs a running backend.
+
+// Declare helper module
+mod helpers;
+
+// Declare test function modules
+mod test_apply_monitor_completes_when_actions_finish;
+mod test_apply_monitor_reports_failed_actions;
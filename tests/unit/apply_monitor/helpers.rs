@@ -0,0 +1,16 @@
+// tests/unit/apply_monitor/helpers.rs
+
+// Intention: Contains helper functions for apply monitor unit tests.
+
+use situation::api_models::MergeStatusV1ResponseAction;
+
+// Helper function to create a merge status action with the given ID and state
+pub(super) fn action(id: &str, state: &str) -> MergeStatusV1ResponseAction {
+    MergeStatusV1ResponseAction {
+        id: id.to_string(),
+        state: state.to_string(),
+        kind: "Create".to_string(),
+        name: format!("create {}", id),
+        component: None,
+    }
+}
@@ -0,0 +1,41 @@
+// tests/unit/apply_monitor/test_apply_monitor_completes_when_actions_finish.rs
+
+// Intention: Test that actions disappearing from the merge status count as succeeded,
+// and that the monitor only completes once the change set is applied and nothing runs.
+
+use situation::{
+    ApplyMonitor,
+    ApplyMonitorOptions,
+    ApplyOutcome,
+};
+
+use super::helpers::action;
+
+#[test]
+fn test_apply_monitor_completes_when_actions_finish() {
+    let mut monitor =
+        ApplyMonitor::new("ws_1", "cs_1", ApplyMonitorOptions::default())
+            .with_actions(&[action("a1", "Queued"), action("a2", "Queued")]);
+
+    // Applied, but one action is still running: not finished yet
+    let changed =
+        monitor.record_observation("Applied", &[action("a2", "Running")]);
+    assert!(changed);
+    assert!(!monitor.is_finished());
+    let summary = monitor.summary();
+    assert_eq!(summary.succeeded.len(), 1);
+    assert_eq!(summary.pending.len(), 1);
+    assert_eq!(summary.outcome, None);
+
+    // Same observation again: nothing changed
+    assert!(!monitor.record_observation("Applied", &[action("a2", "Running")]));
+
+    // Last action gone: completed successfully
+    assert!(monitor.record_observation("Applied", &[]));
+    assert!(monitor.is_finished());
+    let summary = monitor.summary();
+    assert_eq!(summary.outcome, Some(ApplyOutcome::Completed));
+    assert_eq!(summary.succeeded.len(), 2);
+    assert!(summary.failed.is_empty());
+    assert!(summary.is_success());
+}
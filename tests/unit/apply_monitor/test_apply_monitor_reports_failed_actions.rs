@@ -0,0 +1,32 @@
+// tests/unit/apply_monitor/test_apply_monitor_reports_failed_actions.rs
+
+// Intention: Test that failed and on-hold actions settle the apply, and that failures
+// make the summary unsuccessful.
+
+use situation::{
+    ApplyMonitor,
+    ApplyMonitorOptions,
+    ApplyOutcome,
+};
+
+use super::helpers::action;
+
+#[test]
+fn test_apply_monitor_reports_failed_actions() {
+    let mut monitor =
+        ApplyMonitor::new("ws_1", "cs_1", ApplyMonitorOptions::default());
+
+    // Actions first seen during polling are tracked too
+    monitor.record_observation(
+        "Applied",
+        &[action("a1", "Failed"), action("a2", "OnHold")],
+    );
+
+    assert!(monitor.is_finished());
+    let summary = monitor.summary();
+    assert_eq!(summary.outcome, Some(ApplyOutcome::Completed));
+    assert_eq!(summary.failed.len(), 1);
+    assert_eq!(summary.failed[0].action.id, "a1");
+    assert_eq!(summary.pending.len(), 1);
+    assert!(!summary.is_success());
+}
@@ -0,0 +1,13 @@
+// tests/unit/auto_refresh.rs
+
+// Intention:
+// Declares unit test modules for the background refresh (`AutoRefresh`, `changed_change_sets`,
+// `changed_actions`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_auto_refresh_schedule;
+mod test_changed_rows;
@@ -0,0 +1,51 @@
+// tests/unit/auto_refresh/test_auto_refresh_schedule.rs
+
+// Intention: Test that the refresh interval is parsed, and that refreshes become due one
+// interval after the last one, never when disabled, and not while paused.
+
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use situation::AutoRefresh;
+
+#[test]
+fn test_auto_refresh_schedule() {
+    assert_eq!(
+        AutoRefresh::parse_interval(" 15 "),
+        Ok(Some(Duration::from_secs(15)))
+    );
+    assert_eq!(AutoRefresh::parse_interval("0"), Ok(None));
+    assert_eq!(AutoRefresh::parse_interval("OFF"), Ok(None));
+    assert!(AutoRefresh::parse_interval("soon").is_err());
+    assert!(AutoRefresh::parse_interval("-5").is_err());
+
+    let start = Instant::now();
+    let secs = Duration::from_secs;
+    let mut refresh = AutoRefresh::new(Some(secs(30)), start);
+    assert!(refresh.is_active());
+    assert!(!refresh.is_due(start + secs(29)));
+    assert!(refresh.is_due(start + secs(30)));
+
+    // The next refresh is one interval after the last one
+    refresh.schedule(start + secs(40));
+    assert!(!refresh.is_due(start + secs(69)));
+    assert!(refresh.is_due(start + secs(70)));
+
+    // Paused, nothing is due; resuming starts a new interval
+    assert!(!refresh.toggle_paused(start + secs(70)));
+    assert!(!refresh.is_active());
+    assert!(!refresh.is_due(start + secs(500)));
+    assert!(refresh.toggle_paused(start + secs(500)));
+    assert!(!refresh.is_due(start + secs(529)));
+    assert!(refresh.is_due(start + secs(530)));
+
+    // Disabled, nothing is ever due
+    for interval in [None, Some(Duration::ZERO)] {
+        let refresh = AutoRefresh::new(interval, start);
+        assert_eq!(refresh.interval(), None);
+        assert!(!refresh.is_active());
+        assert!(!refresh.is_due(start + secs(3_600)));
+    }
+}
@@ -0,0 +1,50 @@
+// tests/unit/auto_refresh/test_changed_rows.rs
+
+// Intention: Test that new rows and rows whose status (or name) changed are reported by ID, and
+// unchanged or removed rows are not.
+
+use std::collections::HashSet;
+
+use situation::{
+    changed_actions,
+    changed_change_sets,
+};
+
+use crate::unit::helpers::{
+    action,
+    change_set,
+};
+
+fn ids(ids: &[&str]) -> HashSet<String> {
+    ids.iter().map(|id| id.to_string()).collect()
+}
+
+#[test]
+fn test_changed_rows() {
+    let old = [
+        change_set("cs1", "one", "Open"),
+        change_set("cs2", "two", "Open"),
+        change_set("cs3", "three", "Open"),
+    ];
+    let new = [
+        change_set("cs1", "one", "Open"), // Unchanged
+        change_set("cs2", "two", "NeedsApproval"), // Status changed
+        change_set("cs4", "four", "Open"), // New (cs3 removed)
+        change_set("cs5", "five", "Open"), // New
+    ];
+    assert_eq!(changed_change_sets(&old, &new), ids(&["cs2", "cs4", "cs5"]));
+    assert_eq!(
+        changed_change_sets(&old, &[change_set("cs1", "renamed", "Open")]),
+        ids(&["cs1"])
+    );
+    assert!(changed_change_sets(&old, &old).is_empty());
+
+    let old = [action("a1", "Queued"), action("a2", "Queued")];
+    let new = [
+        action("a1", "Queued"),
+        action("a2", "Running"),
+        action("a3", "Queued"),
+    ];
+    assert_eq!(changed_actions(&old, &new), ids(&["a2", "a3"]));
+    assert!(changed_actions(&old, &old[..1]).is_empty());
+}
@@ -0,0 +1,12 @@
+// tests/unit/cache.rs
+
+// Intention:
+// Declares unit test modules for the client response cache (`ResponseCache`, `CacheTtls`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_response_cache;
+mod test_response_cache_generations;
@@ -0,0 +1,133 @@
+// tests/unit/cache/test_response_cache.rs
+
+// Intention: Test that cached responses expire with their TTL, that each invalidation drops only
+// the entries it affects, and that a read started before an invalidation is not stored.
+
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use situation::{
+    CacheKey,
+    CacheTtls,
+    CachedResource,
+    ResponseCache,
+};
+
+#[test]
+fn test_response_cache() {
+    let start = Instant::now();
+    let ttl = Duration::from_secs(30);
+    let key = |cs: &str, resource| CacheKey::new("ws", cs, resource);
+    let mut cache = ResponseCache::new();
+
+    // Served until the TTL has passed, with its age
+    let generation = cache.generation();
+    assert!(cache.insert(
+        key("cs1", CachedResource::Components),
+        vec!["c1".to_string()],
+        start,
+        generation,
+    ));
+    let later = start + Duration::from_secs(10);
+    assert_eq!(
+        cache.get::<Vec<String>>(
+            &key("cs1", CachedResource::Components),
+            ttl,
+            later
+        ),
+        Some((vec!["c1".to_string()], Duration::from_secs(10)))
+    );
+    assert_eq!(
+        cache.get::<Vec<String>>(
+            &key("cs1", CachedResource::Components),
+            ttl,
+            start + ttl
+        ),
+        None
+    );
+    assert_eq!(
+        cache.get::<Vec<String>>(
+            &key("cs1", CachedResource::Components),
+            Duration::ZERO,
+            start
+        ),
+        None
+    );
+    // Asking for the wrong type is a miss, not a panic
+    assert_eq!(
+        cache.get::<String>(
+            &key("cs1", CachedResource::Components),
+            ttl,
+            later
+        ),
+        None
+    );
+
+    // Component changes keep the schemas and the other components
+    for resource in [
+        CachedResource::Schemas,
+        CachedResource::MergeStatus,
+        CachedResource::Component("c1".to_string()),
+        CachedResource::Component("c2".to_string()),
+    ] {
+        let generation = cache.generation();
+        assert!(cache.insert(key("cs1", resource), 1u8, start, generation));
+    }
+    let generation = cache.generation();
+    assert!(cache.insert(
+        key("cs2", CachedResource::Components),
+        2u8,
+        start,
+        generation
+    ));
+    assert_eq!(cache.len(), 6);
+    cache.invalidate_components("ws", "cs1", Some("c1"));
+    assert_eq!(cache.len(), 3);
+    assert!(
+        cache
+            .get::<u8>(&key("cs1", CachedResource::Schemas), ttl, later)
+            .is_some()
+    );
+    assert!(
+        cache
+            .get::<u8>(
+                &key("cs1", CachedResource::Component("c2".to_string())),
+                ttl,
+                later
+            )
+            .is_some()
+    );
+    assert!(
+        cache
+            .get::<u8>(&key("cs2", CachedResource::Components), ttl, later)
+            .is_some()
+    );
+
+    // A read that started before an invalidation is not stored
+    let stale = cache.generation();
+    cache.invalidate_change_set("ws", "cs1");
+    assert_eq!(cache.len(), 1);
+    assert!(!cache.insert(
+        key("cs1", CachedResource::Schemas),
+        3u8,
+        later,
+        stale
+    ));
+    assert_eq!(cache.len(), 1);
+
+    cache.invalidate_workspace("other");
+    assert_eq!(cache.len(), 1);
+    cache.invalidate_workspace("ws");
+    assert!(cache.is_empty());
+
+    // Default TTLs, and none at all
+    let defaults = CacheTtls::default();
+    assert_eq!(defaults, CacheTtls::DEFAULT);
+    assert!(
+        defaults.ttl(&CachedResource::Schemas)
+            > defaults.ttl(&CachedResource::MergeStatus)
+    );
+    assert!(CacheTtls::NONE.ttl(&CachedResource::Components).is_zero());
+}
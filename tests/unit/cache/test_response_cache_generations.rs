@@ -0,0 +1,53 @@
+// tests/unit/cache/test_response_cache_generations.rs
+
+// Intention: Test that an invalidation only keeps reads of what it covers from being stored: a
+// read of another change set started before it is still stored, while invalidating the
+// workspace or clearing the cache covers every change set.
+
+use std::time::Instant;
+
+use situation::{
+    CacheKey,
+    CachedResource,
+    ResponseCache,
+};
+
+#[test]
+fn test_response_cache_generations() {
+    let now = Instant::now();
+    let key =
+        |ws: &str, cs: &str| CacheKey::new(ws, cs, CachedResource::MergeStatus);
+    let mut cache = ResponseCache::new();
+
+    // Invalidating a change set only blocks reads of that change set
+    let started = cache.generation();
+    cache.invalidate_change_set("ws", "cs1");
+    assert!(!cache.insert(key("ws", "cs1"), 1u8, now, started));
+    assert!(cache.insert(key("ws", "cs2"), 2u8, now, started));
+    assert!(cache.insert(key("other", "cs1"), 3u8, now, started));
+    // A read started after the invalidation is stored
+    let after = cache.generation();
+    assert!(cache.insert(key("ws", "cs1"), 4u8, now, after));
+
+    // So does dropping the components of a change set
+    let started = cache.generation();
+    cache.invalidate_components("ws", "cs2", None);
+    assert!(!cache.insert(key("ws", "cs2"), 5u8, now, started));
+    assert!(cache.insert(key("ws", "cs3"), 6u8, now, started));
+
+    // Invalidating the workspace blocks every change set of it, and only of it
+    let started = cache.generation();
+    cache.invalidate_workspace("ws");
+    assert!(!cache.insert(key("ws", "cs1"), 7u8, now, started));
+    assert!(!cache.insert(key("ws", "cs9"), 8u8, now, started));
+    assert!(cache.insert(key("other", "cs2"), 9u8, now, started));
+
+    // Clearing blocks everything started before it
+    let started = cache.generation();
+    cache.clear();
+    assert!(!cache.insert(key("other", "cs1"), 10u8, now, started));
+    assert!(!cache.insert(key("ws", "cs1"), 11u8, now, started));
+    assert!(cache.is_empty());
+    let after = cache.generation();
+    assert!(cache.insert(key("ws", "cs1"), 12u8, now, after));
+}
@@ -0,0 +1,11 @@
+// tests/unit/cancel.rs
+
+// Intention:
+// Declares unit test modules for cancelling client calls (`CancelToken`, `Cancelled`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_cancel_token;
@@ -0,0 +1,56 @@
+// tests/unit/cancel/test_cancel_token.rs
+
+// Intention: Test that a call run with a token completes normally until the token (or a clone)
+// is cancelled, and then fails with `Cancelled` without waiting for the call.
+
+use std::{
+    error::Error,
+    time::Duration,
+};
+
+use situation::{
+    CancelToken,
+    is_cancelled,
+};
+
+#[tokio::test]
+async fn test_cancel_token() {
+    let token = CancelToken::new();
+    assert!(!token.is_cancelled());
+
+    // A call that completes is returned as is, success or failure
+    let done = token
+        .run(async { Ok::<_, Box<dyn Error + Send + Sync>>(42) })
+        .await;
+    assert_eq!(done.unwrap(), 42);
+    let failed = token
+        .run(async { Err::<(), Box<dyn Error + Send + Sync>>("down".into()) })
+        .await
+        .unwrap_err();
+    assert_eq!(failed.to_string(), "down");
+    assert!(!is_cancelled(&*failed));
+
+    // Cancelling a clone stops a call in flight
+    let clone = token.clone();
+    let canceller = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        clone.cancel();
+    });
+    let hung = token
+        .run(async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok::<_, Box<dyn Error + Send + Sync>>(())
+        })
+        .await
+        .unwrap_err();
+    canceller.await.unwrap();
+    assert!(token.is_cancelled());
+    assert!(is_cancelled(&*hung));
+    assert_eq!(hung.to_string(), "Cancelled");
+
+    // Calls run with a cancelled token are not run at all
+    let skipped = token
+        .run(async { Ok::<_, Box<dyn Error + Send + Sync>>(1) })
+        .await;
+    assert!(is_cancelled(&*skipped.unwrap_err()));
+}
@@ -0,0 +1,14 @@
+// tests/unit/compare.rs
+
+// Intention:
+// Declares unit test modules for change set comparison (`compare_components`, `flatten_domain`,
+// `connection_label`, `find_change_set`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_compare_components;
+mod test_flatten_domain;
+mod test_secret_props_redacted;
@@ -0,0 +1,133 @@
+// tests/unit/compare/test_compare_components.rs
+
+// Intention: Test that components are matched by ID, then by name, and reported as added,
+// removed or modified with the props, connections and deletion flag that differ, and that
+// unchanged components and change set lookups by ID or name behave.
+
+use serde_json::{
+    Value,
+    json,
+};
+use situation::{
+    ComponentDiffKind,
+    compare::{
+        ABSENT,
+        compare_components,
+        find_change_set,
+        find_head,
+    },
+};
+
+use crate::unit::helpers::{
+    change_set,
+    component,
+    components,
+};
+
+fn incoming(from: &str) -> Value {
+    json!({ "incoming": {
+        "fromComponentId": "x",
+        "fromComponentName": from,
+        "from": "out",
+        "to": "in",
+    }})
+}
+
+#[test]
+fn test_compare_components() {
+    let db = |from: &str| {
+        let mut db = component("db", json!({ "size": 1 }), false);
+        db.component["connections"] = json!([incoming(from)]);
+        db
+    };
+    let left = components(vec![
+        (
+            "c1",
+            component("web", json!({ "size": 1, "region": "eu" }), false),
+        ),
+        ("c2", db("vpc")),
+        ("c3", component("cache", json!({}), false)),
+        ("c4", component("same", json!({ "size": 1 }), false)),
+        ("c5", component("queue", json!({ "size": 1 }), false)),
+    ]);
+    let marked = component("same", json!({ "size": 1 }), true);
+    let right = components(vec![
+        // Modified prop, one removed
+        ("c1", component("web", json!({ "size": 2 }), false)),
+        // Connection changed
+        ("c2", db("net")),
+        // Unchanged: not reported
+        ("c3", component("cache", json!({}), false)),
+        // Marked for deletion
+        ("c4", marked),
+        // Another ID, same name: matched by name, unchanged
+        ("c9", component("queue", json!({ "size": 1 }), false)),
+        // Added
+        ("c6", component("api", json!({ "port": 80 }), false)),
+    ]);
+
+    let diffs = compare_components(&left, &right);
+    let summary: Vec<(&str, ComponentDiffKind)> =
+        diffs.iter().map(|d| (d.name(), d.kind)).collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("api", ComponentDiffKind::Added),
+            ("db", ComponentDiffKind::Modified),
+            ("same", ComponentDiffKind::Modified),
+            ("web", ComponentDiffKind::Modified),
+        ]
+    );
+
+    let rows = |index: usize| -> Vec<(String, String, String)> {
+        diffs[index]
+            .rows()
+            .into_iter()
+            .map(|row| (row.label, row.left, row.right))
+            .collect()
+    };
+    let row = |label: &str, left: &str, right: &str| {
+        (label.to_string(), left.to_string(), right.to_string())
+    };
+    assert_eq!(rows(0), vec![row("/domain/port", ABSENT, "80")]);
+    assert_eq!(
+        rows(1),
+        vec![
+            row("connection", "vpc.out -> in", ABSENT),
+            row("connection", ABSENT, "net.out -> in"),
+        ]
+    );
+    assert_eq!(rows(2), vec![row("to delete", "false", "true")]);
+    assert!(diffs[2].marked_for_deletion());
+    assert_eq!(
+        rows(3),
+        vec![
+            row("/domain/region", "eu", ABSENT),
+            row("/domain/size", "1", "2"),
+        ]
+    );
+
+    // Swapping the sides turns the added component into a removed one
+    let reversed = compare_components(&right, &left);
+    assert_eq!(reversed[0].kind, ComponentDiffKind::Removed);
+    assert_eq!(reversed[0].id(), "c6");
+    assert!(compare_components(&left, &left).is_empty());
+
+    let change_sets = [
+        change_set("cs0", "HEAD", "Open"),
+        change_set("cs1", "feature", "Open"),
+    ];
+    assert_eq!(
+        find_head(&change_sets).map(|cs| cs.id.as_str()),
+        Some("cs0")
+    );
+    assert_eq!(
+        find_change_set(&change_sets, "feature").map(|cs| cs.id.as_str()),
+        Some("cs1")
+    );
+    assert_eq!(
+        find_change_set(&change_sets, "cs1").map(|cs| cs.name.as_str()),
+        Some("feature")
+    );
+    assert!(find_change_set(&change_sets, "missing").is_none());
+}
@@ -0,0 +1,60 @@
+// tests/unit/compare/test_flatten_domain.rs
+
+// Intention: Test that a domain flattens to its leaves by prop path, with array items by index
+// and empty containers kept as leaves, and how values and connections are labelled.
+
+use serde_json::json;
+use situation::compare::{
+    connection_label,
+    flatten_domain,
+    value_label,
+};
+
+#[test]
+fn test_flatten_domain() {
+    let domain = json!({
+        "region": "us-east-1",
+        "tags": { "env": "prod", "team": null },
+        "ports": [80, 443],
+        "extra": {},
+    });
+    let leaves: Vec<(String, String)> = flatten_domain(&domain)
+        .into_iter()
+        .map(|(path, value)| (path, value_label(&value)))
+        .collect();
+    let expected = [
+        ("/domain/extra", "{}"),
+        ("/domain/ports/0", "80"),
+        ("/domain/ports/1", "443"),
+        ("/domain/region", "us-east-1"),
+        ("/domain/tags/env", "prod"),
+        ("/domain/tags/team", "null"),
+    ];
+    assert_eq!(
+        leaves,
+        expected
+            .iter()
+            .map(|(path, value)| (path.to_string(), value.to_string()))
+            .collect::<Vec<_>>()
+    );
+    assert!(flatten_domain(&json!(null)).is_empty());
+
+    let incoming = json!({ "incoming": {
+        "fromComponentId": "c1",
+        "fromComponentName": "vpc",
+        "from": "VPC ID",
+        "to": "VPC ID",
+    }});
+    assert_eq!(connection_label(&incoming), "vpc.VPC ID -> VPC ID");
+    let outgoing = json!({ "outgoing": {
+        "toComponentId": "c2",
+        "toComponentName": "subnet",
+        "from": "VPC ID",
+    }});
+    assert_eq!(connection_label(&outgoing), "VPC ID -> subnet");
+    let managed = json!({ "managedBy": {
+        "componentId": "c3",
+        "componentName": "stack",
+    }});
+    assert_eq!(connection_label(&managed), "managed by stack");
+}
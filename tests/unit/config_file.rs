@@ -0,0 +1,13 @@
+// tests/unit/config_file.rs
+
+// Intention:
+// Declares unit test modules for the parts shared by the config files (`parse_entries`,
+// `split_sections`, `parse_sections`, `line_errors`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_parse_entries;
+mod test_parse_sections;
@@ -0,0 +1,44 @@
+// tests/unit/config_file/test_parse_entries.rs
+
+// Intention: Test that `parse_entries` reads a file without sections and reports any header,
+// and that `split_sections` keeps the entries before the first header apart and repeated names.
+
+use situation::config_file::{
+    Entry,
+    parse_entries,
+    split_sections,
+};
+
+#[test]
+fn test_parse_entries() {
+    let text = "# layout\nlog_height = 8\n[global]\nschema_width\n";
+    let mut errors = Vec::new();
+    let entries = parse_entries(text, &mut errors);
+    assert_eq!(
+        entries,
+        vec![Entry {
+            line: 2,
+            key: "log_height",
+            value: "8",
+        }]
+    );
+    assert_eq!(
+        errors,
+        vec![
+            (4, "expected `key = value`".to_string()),
+            (3, "expected `key = value`".to_string()),
+        ]
+    );
+
+    let text = "quit = q\n[list]\nup = k\n[list]\ndown = j\n";
+    let mut errors = Vec::new();
+    let (leading, sections) = split_sections(text, &mut errors);
+    assert!(errors.is_empty());
+    assert_eq!(leading.len(), 1);
+    assert_eq!(leading[0].key, "quit");
+    let names: Vec<(&str, &str)> = sections
+        .iter()
+        .map(|s| (s.name, s.entries[0].key))
+        .collect();
+    assert_eq!(names, vec![("list", "up"), ("list", "down")]);
+}
@@ -0,0 +1,47 @@
+// tests/unit/config_file/test_parse_sections.rs
+
+// Intention: Test that `parse_sections` splits a file into its sections and entries, skipping
+// blank and comment lines, and that every malformed line is reported by `line_errors` in order.
+
+use situation::config_file::{
+    Entry,
+    line_errors,
+    parse_sections,
+};
+
+#[test]
+fn test_parse_sections() {
+    let text =
+        "# comment\n\n[dev]\napi = http://localhost\n\n[prod]\ntoken=abc=\n";
+    let mut errors = Vec::new();
+    let sections = parse_sections(text, "profile", &mut errors);
+    assert!(errors.is_empty());
+    let names: Vec<(&str, usize)> =
+        sections.iter().map(|s| (s.name, s.line)).collect();
+    assert_eq!(names, vec![("dev", 3), ("prod", 6)]);
+    assert_eq!(
+        sections[0].entries,
+        vec![Entry {
+            line: 4,
+            key: "api",
+            value: "http://localhost",
+        }]
+    );
+    // Only the first `=` separates the key from the value
+    assert_eq!(sections[1].entries[0].value, "abc=");
+    assert_eq!(line_errors(sections.len(), errors), Ok(2));
+
+    let text = "api = x\n[dev]\n[ ]\noops\n[dev]\n";
+    let mut errors = Vec::new();
+    let sections = parse_sections(text, "workspace", &mut errors);
+    assert_eq!(sections.len(), 3);
+    assert_eq!(
+        line_errors((), errors).unwrap_err(),
+        vec![
+            "line 1: expected a `[name]` section",
+            "line 3: empty workspace name",
+            "line 4: expected `key = value`",
+            "line 5: duplicate workspace 'dev'",
+        ]
+    );
+}
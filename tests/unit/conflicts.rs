@@ -0,0 +1,14 @@
+// tests/unit/conflicts.rs
+
+// Intention:
+// Declares unit test modules for conflict detection across open change sets (`find_conflicts`,
+// `ConflictReport`) and its part in the pre-apply review.
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_find_conflicts;
+mod test_open_change_sets;
+mod test_review_with_conflicts;
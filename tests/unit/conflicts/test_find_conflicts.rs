@@ -0,0 +1,127 @@
+// tests/unit/conflicts/test_find_conflicts.rs
+
+// Intention: Test that components modified in more than one change set are reported, with the
+// props set to different values (and deletions), and that the same edit or a component only one
+// change set touches is not a conflict.
+
+use serde_json::json;
+use situation::{
+    ChangeSetComparison,
+    compare::compare_components,
+    conflicts::{
+        DELETION_PATH,
+        find_conflicts,
+    },
+};
+
+use crate::unit::helpers::{
+    change_set,
+    component,
+    components,
+};
+
+#[test]
+fn test_find_conflicts() {
+    let head = components(vec![
+        (
+            "c1",
+            component("web", json!({ "size": 1, "region": "eu" }), false),
+        ),
+        ("c2", component("db", json!({ "size": 1 }), false)),
+        ("c3", component("cache", json!({ "size": 1 }), false)),
+        ("c4", component("queue", json!({ "size": 1 }), false)),
+    ]);
+    let alice = components(vec![
+        // Conflicts with bob on size, same region
+        (
+            "c1",
+            component("web", json!({ "size": 2, "region": "us" }), false),
+        ),
+        // Deleted here, modified by bob
+        ("c2", component("db", json!({ "size": 1 }), true)),
+        // Same edit as bob
+        ("c3", component("cache", json!({ "size": 5 }), false)),
+        // Only alice
+        ("c4", component("queue", json!({ "size": 9 }), false)),
+        // Added: never overlaps
+        ("c9", component("api", json!({}), false)),
+    ]);
+    let bob = components(vec![
+        (
+            "c1",
+            component("web", json!({ "size": 3, "region": "us" }), false),
+        ),
+        ("c2", component("db", json!({ "size": 2 }), false)),
+        ("c3", component("cache", json!({ "size": 5 }), false)),
+        ("c4", component("queue", json!({ "size": 1 }), false)),
+        ("c8", component("api", json!({}), false)),
+    ]);
+    let carol = components(vec![
+        ("c1", component("web", json!({ "size": 1 }), false)), // Removes region
+        ("c2", component("db", json!({ "size": 1 }), false)),
+        ("c3", component("cache", json!({ "size": 1 }), false)),
+        ("c4", component("queue", json!({ "size": 1 }), false)),
+    ]);
+    let head_cs = change_set("head", "HEAD", "Open");
+    let comparisons: Vec<ChangeSetComparison> =
+        [("a", &alice), ("b", &bob), ("c", &carol)]
+            .into_iter()
+            .map(|(id, components)| ChangeSetComparison {
+                left: head_cs.clone(),
+                right: change_set(id, &format!("{}-name", id), "Open"),
+                components: compare_components(&head, components),
+            })
+            .collect();
+
+    let report = find_conflicts(&comparisons);
+    assert_eq!(report.change_sets.len(), 3);
+    let overlaps: Vec<(&str, Vec<&str>)> = report
+        .components
+        .iter()
+        .map(|o| {
+            (
+                o.name.as_str(),
+                o.change_sets.iter().map(|cs| cs.id.as_str()).collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        overlaps,
+        vec![
+            ("cache", vec!["a", "b"]),
+            ("db", vec!["a", "b"]),
+            ("web", vec!["a", "b", "c"]),
+        ]
+    );
+
+    // The same edit in both change sets
+    assert!(!report.components[0].has_conflicting_values());
+
+    // Deleted in one, modified in the other
+    let db = &report.components[1];
+    let paths: Vec<&str> = db.props.iter().map(|p| p.path.as_str()).collect();
+    assert_eq!(paths, vec![DELETION_PATH]);
+    assert_eq!(db.props[0].value_label("a"), "true");
+    assert_eq!(db.props[0].value_label("b"), "false");
+
+    let web = &report.components[2];
+    let paths: Vec<&str> = web.props.iter().map(|p| p.path.as_str()).collect();
+    assert_eq!(paths, vec!["/domain/region", "/domain/size"]);
+    // Carol removes the region the others set to "us"
+    assert_eq!(web.props[0].value_label("a"), "us");
+    assert_eq!(web.props[0].value_label("c"), "-");
+    // Carol did not change size: only alice and bob conflict on it
+    assert_eq!(web.props[1].values.len(), 2);
+    assert_eq!(web.props[1].value_label("b"), "3");
+    assert_eq!(web.others("a"), vec!["b-name", "c-name"]);
+
+    assert_eq!(report.count_for("a"), 3);
+    assert_eq!(report.count_for("c"), 1);
+    assert_eq!(report.count_for("head"), 0);
+    assert_eq!(
+        report.summary(),
+        "3 component(s) modified in more than one of 3 open change set(s), 2 with \
+         conflicting values"
+    );
+    assert!(find_conflicts(&comparisons[..1]).is_empty());
+}
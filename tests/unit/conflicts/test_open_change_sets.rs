@@ -0,0 +1,34 @@
+// tests/unit/conflicts/test_open_change_sets.rs
+
+// Intention: Test that a conflict scan compares only the change sets still open, leaving out
+// HEAD and the applied or abandoned ones, in list order.
+
+use situation::{
+    api_models::ChangeSetSummary,
+    conflicts::open_change_sets,
+};
+
+#[test]
+fn test_open_change_sets() {
+    let listed: Vec<ChangeSetSummary> = [
+        ("head", "HEAD", "Open"),
+        ("cs1", "feature", "Open"),
+        ("cs2", "old", "Applied"),
+        ("cs3", "dropped", "Abandoned"),
+        ("cs4", "waiting", "NeedsApproval"),
+    ]
+    .into_iter()
+    .map(|(id, name, status)| ChangeSetSummary {
+        id: id.to_string(),
+        name: name.to_string(),
+        status: status.to_string(),
+    })
+    .collect();
+
+    let open: Vec<String> = open_change_sets(&listed)
+        .into_iter()
+        .map(|cs| cs.id)
+        .collect();
+    assert_eq!(open, vec!["cs1".to_string(), "cs4".to_string()]);
+    assert!(open_change_sets(&[]).is_empty());
+}
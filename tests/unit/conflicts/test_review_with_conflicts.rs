@@ -0,0 +1,98 @@
+// tests/unit/conflicts/test_review_with_conflicts.rs
+
+// Intention: Test that a review keeps only the overlaps its change set is part of, and that its
+// Markdown lists them, with the conflicting values, before the components it changes.
+
+use serde_json::json;
+use situation::{
+    ApplyReview,
+    ChangeSetComparison,
+    ConflictReport,
+    api_models::ChangeSetSummary,
+    conflicts::{
+        ComponentOverlap,
+        ConflictingValue,
+        PropConflict,
+    },
+};
+
+use crate::unit::helpers::change_set;
+
+#[test]
+fn test_review_with_conflicts() {
+    let (mine, theirs, other) = (
+        change_set("cs1", "mine", "Open"),
+        change_set("cs2", "theirs", "Open"),
+        change_set("cs3", "other", "Open"),
+    );
+    let overlap =
+        |id: &str, name: &str, sets: Vec<&ChangeSetSummary>| ComponentOverlap {
+            id: id.to_string(),
+            name: name.to_string(),
+            change_sets: sets.into_iter().cloned().collect(),
+            props: Vec::new(),
+        };
+    let mut web = overlap("c1", "web", vec![&mine, &theirs]);
+    web.props.push(PropConflict {
+        path: "/domain/size".to_string(),
+        values: vec![
+            ConflictingValue {
+                change_set: mine.clone(),
+                value: Some(json!(2)),
+            },
+            ConflictingValue {
+                change_set: theirs.clone(),
+                value: Some(json!(3)),
+            },
+        ],
+    });
+    let report = ConflictReport {
+        change_sets: vec![mine.clone(), theirs.clone(), other.clone()],
+        components: vec![
+            overlap("c2", "cache", vec![&mine, &other]),
+            overlap("c3", "queue", vec![&theirs, &other]),
+            web,
+        ],
+    };
+
+    let review = ApplyReview::new(
+        ChangeSetComparison {
+            left: change_set("cs0", "HEAD", "Open"),
+            right: mine,
+            components: Vec::new(),
+        },
+        Vec::new(),
+    )
+    .with_conflicts(&report);
+    let names: Vec<&str> =
+        review.conflicts.iter().map(|o| o.name.as_str()).collect();
+    assert_eq!(names, vec!["cache", "web"]);
+
+    let at = chrono::DateTime::UNIX_EPOCH;
+    let expected_conflicts = "\
+## Conflicts with other open change sets (2)
+
+### cache (`c2`)
+
+Also modified in: other.
+
+No conflicting values.
+
+### web (`c1`)
+
+Also modified in: theirs.
+
+| Prop | Change set | Value |
+|---|---|---|
+| `/domain/size` | mine | 2 |
+| `/domain/size` | theirs | 3 |
+
+## Created (0)
+";
+    let markdown = review.to_markdown(at);
+    assert!(
+        markdown.contains(expected_conflicts),
+        "unexpected Markdown:\n{}",
+        markdown
+    );
+}
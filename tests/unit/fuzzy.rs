@@ -0,0 +1,12 @@
+// tests/unit/fuzzy.rs
+
+// Intention:
+// Declares unit test modules for the command palette fuzzy matcher (`fuzzy_match`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_fuzzy_match_prefers_word_starts;
+mod test_fuzzy_match_requires_ordered_subsequence;
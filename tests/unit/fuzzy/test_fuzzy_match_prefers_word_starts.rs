@@ -0,0 +1,23 @@
+// tests/unit/fuzzy/test_fuzzy_match_prefers_word_starts.rs
+
+// Intention: Test that matches on word starts and consecutive runs outrank scattered ones.
+
+use situation::fuzzy_match;
+
+#[test]
+fn test_fuzzy_match_prefers_word_starts() {
+    let score = |candidate: &str| {
+        fuzzy_match("fa", candidate)
+            .map(|m| m.score)
+            .expect("candidate should match")
+    };
+
+    // Word starts beat a mid-word hit
+    assert!(score("Force Apply") > score("Refresh all"));
+    // A consecutive prefix beats word starts further in
+    assert!(score("fast") > score("Force Apply"));
+
+    // The best alignment is chosen, not the first one found
+    let matched = fuzzy_match("ap", "map Apply").expect("should match");
+    assert_eq!(matched.positions, vec![4, 5]);
+}
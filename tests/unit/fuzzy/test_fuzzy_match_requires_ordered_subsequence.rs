@@ -0,0 +1,21 @@
+// tests/unit/fuzzy/test_fuzzy_match_requires_ordered_subsequence.rs
+
+// Intention: Test that a query only matches when all of its characters appear in order,
+// ignoring case and whitespace, and that matched positions are reported.
+
+use situation::fuzzy_match;
+
+#[test]
+fn test_fuzzy_match_requires_ordered_subsequence() {
+    let matched = fuzzy_match("ABN cs", "Abandon change set")
+        .expect("query is a subsequence");
+    assert_eq!(matched.positions, vec![0, 1, 3, 8, 15]);
+
+    assert!(fuzzy_match("sba", "Abandon change set").is_none());
+    assert!(fuzzy_match("applyx", "Apply").is_none());
+
+    // An empty query matches everything
+    let empty = fuzzy_match("  ", "Refresh").expect("empty query matches");
+    assert_eq!(empty.score, 0);
+    assert!(empty.positions.is_empty());
+}
@@ -0,0 +1,65 @@
+// tests/unit/helpers.rs
+
+// Intention: Shared builders for the change set, component and action fixtures used across the
+// unit test modules.
+
+use std::collections::BTreeMap;
+
+use serde_json::{
+    Value,
+    json,
+};
+use situation::{
+    api_models::{
+        ChangeSetSummary,
+        MergeStatusV1ResponseAction,
+    },
+    watch::ObservedComponent,
+};
+
+/// Builds a `ChangeSetSummary` with the given fields.
+pub fn change_set(id: &str, name: &str, status: &str) -> ChangeSetSummary {
+    ChangeSetSummary {
+        id: id.to_string(),
+        name: name.to_string(),
+        status: status.to_string(),
+    }
+}
+
+/// Builds a `Create` action in the given state, named after its ID.
+pub fn action(id: &str, state: &str) -> MergeStatusV1ResponseAction {
+    MergeStatusV1ResponseAction {
+        id: id.to_string(),
+        state: state.to_string(),
+        kind: "Create".to_string(),
+        name: format!("create {}", id),
+        component: None,
+    }
+}
+
+/// Builds an `ObservedComponent` without connections, with the given domain and deletion flag.
+pub fn component(
+    name: &str,
+    domain: Value,
+    to_delete: bool,
+) -> ObservedComponent {
+    ObservedComponent {
+        name: name.to_string(),
+        component: json!({
+            "name": name,
+            "connections": [],
+            "toDelete": to_delete,
+        }),
+        domain,
+    }
+}
+
+/// Keys components by their ID, as the loaders return them.
+pub fn components(
+    entries: Vec<(&str, ObservedComponent)>,
+) -> BTreeMap<String, ObservedComponent> {
+    entries
+        .into_iter()
+        .map(|(id, component)| (id.to_string(), component))
+        .collect()
+}
@@ -0,0 +1,12 @@
+// tests/unit/http_history.rs
+
+// Intention:
+// Declares unit test modules for the session HTTP history behind the request inspector.
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_http_history_drops_oldest_beyond_capacity;
+mod test_pretty_body_and_format_size;
@@ -0,0 +1,52 @@
+// tests/unit/http_history/test_http_history_drops_oldest_beyond_capacity.rs
+
+// Intention: Test that the history keeps exchanges oldest first and drops the oldest once its
+// capacity is exceeded.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use situation::{
+    HttpExchange,
+    HttpHistory,
+};
+
+fn exchange(request_id: &str, status: Option<u16>) -> HttpExchange {
+    HttpExchange {
+        request_id: request_id.to_string(),
+        source: "api::whoami".to_string(),
+        started_at: Utc::now(),
+        method: "GET".to_string(),
+        url: "https://api.test/whoami".to_string(),
+        status,
+        latency: Duration::from_millis(12),
+        response_size: Some(2),
+        request_body: None,
+        response_body: Some("{}".to_string()),
+    }
+}
+
+#[test]
+fn test_http_history_drops_oldest_beyond_capacity() {
+    let history = HttpHistory::new(2);
+    assert!(history.is_empty());
+
+    history.record(exchange("req-0001", Some(200)));
+    history.record(exchange("req-0002", Some(404)));
+    history.record(exchange("req-0003", None));
+
+    let ids: Vec<String> = history
+        .snapshot()
+        .into_iter()
+        .map(|exchange| exchange.request_id)
+        .collect();
+    assert_eq!(ids, vec!["req-0002", "req-0003"]);
+
+    let snapshot = history.snapshot();
+    assert!(!snapshot[0].is_success());
+    assert!(!snapshot[1].is_success());
+    assert!(exchange("req-0004", Some(204)).is_success());
+
+    history.clear();
+    assert_eq!(history.len(), 0);
+}
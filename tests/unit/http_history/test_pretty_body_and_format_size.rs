@@ -0,0 +1,20 @@
+// tests/unit/http_history/test_pretty_body_and_format_size.rs
+
+// Intention: Test the display helpers of the request inspector: JSON bodies are pretty-printed
+// (other text is kept as is) and sizes are shown in B/KB/MB.
+
+use situation::{
+    format_size,
+    pretty_body,
+};
+
+#[test]
+fn test_pretty_body_and_format_size() {
+    assert_eq!(pretty_body(r#"{"a":[1]}"#), "{\n  \"a\": [\n    1\n  ]\n}");
+    assert_eq!(pretty_body("not json"), "not json");
+    assert_eq!(pretty_body(""), "");
+
+    assert_eq!(format_size(512), "512 B");
+    assert_eq!(format_size(1536), "1.5 KB");
+    assert_eq!(format_size(2 * 1024 * 1024), "2.0 MB");
+}
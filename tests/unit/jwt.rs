@@ -0,0 +1,12 @@
+// tests/unit/jwt.rs
+
+// Intention:
+// Declares unit test modules for reading JWT claims (`decode_jwt_claims`, `format_time_left`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_decode_jwt_claims;
+mod test_format_time_left;
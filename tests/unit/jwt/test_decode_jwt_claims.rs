@@ -0,0 +1,50 @@
+// tests/unit/jwt/test_decode_jwt_claims.rs
+
+// Intention: Test that the `exp` and `iat` claims are read from a JWT payload, that both are
+// optional, and that values which are not JWTs are rejected.
+
+use chrono::DateTime;
+use situation::{
+    JwtClaims,
+    decode_jwt_claims,
+};
+
+#[test]
+fn test_decode_jwt_claims() {
+    // {"sub":"user","iat":1700000000,"exp":1700003600}
+    let token = "eyJhbGciOiJIUzI1NiJ9.\
+                 eyJzdWIiOiJ1c2VyIiwiaWF0IjoxNzAwMDAwMDAwLCJleHAiOjE3MDAwMDM2MDB9.\
+                 c2lnbmF0dXJl";
+    let claims = decode_jwt_claims(token).expect("valid JWT");
+    assert_eq!(
+        claims,
+        JwtClaims {
+            exp: Some(1_700_003_600),
+            iat: Some(1_700_000_000),
+        }
+    );
+    assert_eq!(
+        claims.expires_at(),
+        DateTime::from_timestamp(1_700_003_600, 0)
+    );
+    assert_eq!(
+        claims.issued_at(),
+        DateTime::from_timestamp(1_700_000_000, 0)
+    );
+
+    // {"sub":"user"}: no expiry
+    let claims =
+        decode_jwt_claims("eyJhbGciOiJIUzI1NiJ9.eyJzdWIiOiJ1c2VyIn0.sig")
+            .expect("valid JWT");
+    assert_eq!(claims, JwtClaims::default());
+    assert_eq!(claims.expires_at(), None);
+
+    assert!(decode_jwt_claims("not-a-jwt").is_err());
+    assert!(decode_jwt_claims("a.b.c.d").is_err());
+    assert!(decode_jwt_claims("eyJhbGciOiJIUzI1NiJ9.!!!.sig").is_err());
+    // {"exp":"soon"}: claims of the wrong type
+    assert!(
+        decode_jwt_claims("eyJhbGciOiJIUzI1NiJ9.eyJleHAiOiJzb29uIn0.sig")
+            .is_err()
+    );
+}
@@ -0,0 +1,23 @@
+// tests/unit/jwt/test_format_time_left.rs
+
+// Intention: Test the short labels shown in the top bar for the time left before expiry.
+
+use chrono::{
+    DateTime,
+    TimeDelta,
+};
+use situation::format_time_left;
+
+#[test]
+fn test_format_time_left() {
+    let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let left =
+        |secs: i64| format_time_left(now + TimeDelta::seconds(secs), now);
+
+    assert_eq!(left(-30), "expired");
+    assert_eq!(left(0), "expired");
+    assert_eq!(left(45), "45s");
+    assert_eq!(left(12 * 60 + 59), "12m");
+    assert_eq!(left(3 * 3_600 + 5 * 60), "3h 05m");
+    assert_eq!(left(2 * 86_400 + 4 * 3_600 + 59), "2d 4h");
+}
@@ -0,0 +1,14 @@
+// tests/unit/keymap.rs
+
+// Intention:
+// Declares unit test modules for the configurable keymap (`Keymap`, `KeyChord`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_key_chord_parse_and_display;
+mod test_keymap_file_overrides_defaults;
+mod test_keymap_help_for_context;
+mod test_keymap_reports_conflicts;
@@ -0,0 +1,49 @@
+// tests/unit/keymap/test_key_chord_parse_and_display.rs
+
+// Intention: Test parsing key names as written in the keybindings file, matching them against
+// terminal key events, and displaying them back in canonical form.
+
+use crossterm::event::{
+    KeyCode,
+    KeyEvent,
+    KeyModifiers,
+};
+use situation::keymap::{
+    Key,
+    KeyChord,
+};
+
+#[test]
+fn test_key_chord_parse_and_display() {
+    let ctrl_p = KeyChord::parse("Ctrl-P").unwrap();
+    assert_eq!(ctrl_p, KeyChord::new(Key::Char('p'), true, false));
+    assert_eq!(KeyChord::parse("ctrl+p").unwrap(), ctrl_p);
+    assert_eq!(ctrl_p.to_string(), "Ctrl-p");
+
+    // Plain letters keep their case: Shift is carried by the character
+    assert_ne!(KeyChord::parse("K").unwrap(), KeyChord::parse("k").unwrap());
+    assert_eq!(KeyChord::parse("space").unwrap().key, Key::Char(' '));
+    assert_eq!(KeyChord::parse("pgdn").unwrap().to_string(), "PageDown");
+    assert_eq!(KeyChord::parse("F12").unwrap().key, Key::F(12));
+    assert_eq!(KeyChord::parse("-").unwrap().key, Key::Char('-'));
+    assert_eq!(
+        KeyChord::parse("Alt--").unwrap(),
+        KeyChord::new(Key::Char('-'), false, true)
+    );
+    assert!(KeyChord::parse("Hyper-x").is_err());
+    assert!(KeyChord::parse("F99").is_err());
+
+    // Every canonical form parses back to the same chord
+    for text in ["Alt-w", "Space", "Comma", "Esc", "End", "/", "J"] {
+        let chord = KeyChord::parse(text).unwrap();
+        assert_eq!(KeyChord::parse(&chord.to_string()).unwrap(), chord);
+    }
+
+    let event = KeyEvent::new(KeyCode::Char('K'), KeyModifiers::SHIFT);
+    assert_eq!(
+        KeyChord::from_event(&event),
+        Some(KeyChord::parse("K").unwrap())
+    );
+    let event = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL);
+    assert_eq!(KeyChord::from_event(&event), Some(ctrl_p));
+}
@@ -0,0 +1,80 @@
+// tests/unit/keymap/test_keymap_file_overrides_defaults.rs
+
+// Intention: Test that a keybindings file replaces the keys of the actions it lists, leaves the
+// others at their defaults, falls back to global bindings, reports bad lines, and that the
+// generated help reflects the file.
+
+use situation::keymap::{
+    Action,
+    KeyChord,
+    KeyContext,
+    Keymap,
+    KeymapError,
+};
+
+fn chord(text: &str) -> KeyChord {
+    KeyChord::parse(text).unwrap()
+}
+
+#[test]
+fn test_keymap_file_overrides_defaults() {
+    let defaults = Keymap::default();
+    assert!(defaults.conflicts().is_empty());
+    assert_eq!(
+        defaults.action_for(KeyContext::TopBar, &chord("d")),
+        Some(Action::AbandonChangeSet)
+    );
+    // Context bindings first, then global ones
+    assert_eq!(
+        defaults.action_for(KeyContext::SchemaList, &chord("q")),
+        Some(Action::Quit)
+    );
+    assert_eq!(
+        defaults.action_for(KeyContext::LogPanel, &chord("f")),
+        Some(Action::CycleLogFilter)
+    );
+
+    let text = "\
+# my keys
+[global]
+quit = q, Ctrl-c
+
+[top_bar]
+abandon_change_set = D
+";
+    let keymap = Keymap::parse(text).unwrap();
+    assert_eq!(
+        keymap.action_for(KeyContext::Inspector, &chord("Ctrl-c")),
+        Some(Action::Quit)
+    );
+    assert_eq!(
+        keymap.action_for(KeyContext::TopBar, &chord("D")),
+        Some(Action::AbandonChangeSet)
+    );
+    assert_eq!(keymap.action_for(KeyContext::TopBar, &chord("d")), None);
+    assert_eq!(
+        keymap.describe(KeyContext::Global, Action::Quit),
+        "q/Ctrl-c"
+    );
+    assert_eq!(
+        keymap.action_for(KeyContext::TopBar, &chord("f")),
+        Some(Action::ForceApply)
+    );
+
+    let help = keymap.help();
+    let (context, top_bar) = &help[1];
+    assert_eq!(*context, KeyContext::TopBar);
+    assert!(top_bar.contains(&("D".to_string(), "Delete Selected Change Set")));
+
+    let errors =
+        Keymap::parse("[nowhere]\n[global]\nfly = x\nquit\nquit = Hyper-q\n")
+            .unwrap_err();
+    let lines: Vec<usize> = errors
+        .iter()
+        .map(|e| match e {
+            KeymapError::Parse { line, .. } => *line,
+            other => panic!("unexpected {:?}", other),
+        })
+        .collect();
+    assert_eq!(lines, vec![1, 3, 4, 5]);
+}
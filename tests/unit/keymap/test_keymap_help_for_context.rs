@@ -0,0 +1,43 @@
+// tests/unit/keymap/test_keymap_help_for_context.rs
+
+// Intention: Test that the help for one context lists that context's bindings first, then the
+// global ones, and nothing from other contexts; remapped keys show up in it.
+
+use situation::keymap::{
+    Action,
+    KeyContext,
+    Keymap,
+};
+
+#[test]
+fn test_keymap_help_for_context() {
+    let keymap = Keymap::default();
+
+    let sections = keymap.help_for(KeyContext::LogPanel);
+    let contexts: Vec<KeyContext> = sections.iter().map(|(c, _)| *c).collect();
+    assert_eq!(contexts, vec![KeyContext::LogPanel, KeyContext::Global]);
+    assert!(
+        sections[0]
+            .1
+            .contains(&("/".to_string(), Action::SearchLogs.description()))
+    );
+    assert!(
+        sections[1]
+            .1
+            .contains(&("?/F1".to_string(), Action::ShowHelp.description()))
+    );
+
+    // The global context only lists itself
+    let sections = keymap.help_for(KeyContext::Global);
+    assert_eq!(sections.len(), 1);
+    assert_eq!(sections[0].0, KeyContext::Global);
+
+    let keymap = Keymap::parse("[schema_list]\nsearch_schemas = s\n").unwrap();
+    let sections = keymap.help_for(KeyContext::SchemaList);
+    assert_eq!(sections[0].0, KeyContext::SchemaList);
+    assert!(
+        sections[0]
+            .1
+            .contains(&("s".to_string(), Action::SearchSchemas.description()))
+    );
+}
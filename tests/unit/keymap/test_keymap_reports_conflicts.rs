@@ -0,0 +1,50 @@
+// tests/unit/keymap/test_keymap_reports_conflicts.rs
+
+// Intention: Test conflict detection: one key bound to two actions in the same context, and a
+// context key hiding a global binding for a different action. Rebinding the same action in a
+// context is not a conflict.
+
+use situation::keymap::{
+    Action,
+    KeyContext,
+    Keymap,
+    KeymapError,
+};
+
+#[test]
+fn test_keymap_reports_conflicts() {
+    let errors = Keymap::parse("[top_bar]\nforce_apply = d\n").unwrap_err();
+    assert_eq!(errors.len(), 1);
+    match &errors[0] {
+        KeymapError::Conflict {
+            context,
+            first,
+            second,
+            shadows_global,
+            ..
+        } => {
+            assert_eq!(*context, KeyContext::TopBar);
+            assert_eq!(
+                (*first, *second),
+                (Action::AbandonChangeSet, Action::ForceApply)
+            );
+            assert!(!shadows_global);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert_eq!(
+        errors[0].to_string(),
+        "[top_bar] 'd' is bound to both abandon_change_set and force_apply"
+    );
+
+    // `q` in the schema list would hide the global quit
+    let errors =
+        Keymap::parse("[schema_list]\nsearch_schemas = q\n").unwrap_err();
+    assert_eq!(
+        errors[0].to_string(),
+        "[schema_list] 'q' is bound to search_schemas and hides global quit"
+    );
+
+    // The same action in a context and globally is fine
+    assert!(Keymap::parse("[log_panel]\nscroll_logs_up = k, Up\n").is_ok());
+}
@@ -0,0 +1,12 @@
+// tests/unit/layout.rs
+
+// Intention:
+// Declares unit test modules for the pane layout (`PaneLayout`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_pane_layout_file_round_trip;
+mod test_pane_layout_split_resize_and_zoom;
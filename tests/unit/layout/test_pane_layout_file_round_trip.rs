@@ -0,0 +1,56 @@
+// tests/unit/layout/test_pane_layout_file_round_trip.rs
+
+// Intention: Test that a layout written to the layout file loads back unchanged (without the
+// zoom), that a missing file gives the default layout, and that bad lines are reported.
+
+use std::{
+    env,
+    fs,
+};
+
+use situation::{
+    Pane,
+    PaneLayout,
+};
+
+#[test]
+fn test_pane_layout_file_round_trip() {
+    let dir = env::temp_dir()
+        .join(format!("situation-layout-test-{}", std::process::id()));
+    let path = dir.join("nested").join("layout.conf");
+    let _ = fs::remove_dir_all(&dir);
+
+    assert_eq!(PaneLayout::load(&path).unwrap(), PaneLayout::default());
+
+    let mut layout = PaneLayout {
+        log_height: 14,
+        schema_width: 42,
+        log_collapsed: false,
+        schema_collapsed: true,
+        zoomed: None,
+    };
+    layout.save(&path).unwrap();
+    layout.toggle_zoom(Pane::Content);
+    layout.save(&path).unwrap();
+    let loaded = PaneLayout::load(&path).unwrap();
+    assert_eq!(loaded.log_height, 14);
+    assert_eq!(loaded.schema_width, 42);
+    assert!(loaded.schema_collapsed);
+    assert_eq!(loaded.zoomed, None);
+
+    let errors = PaneLayout::parse(
+        "# comment\nlog_height = tall\nzoom = content\nschema_collapsed = yes\nwidth\n",
+    )
+    .unwrap_err();
+    assert_eq!(
+        errors,
+        vec![
+            "line 2: invalid height 'tall'",
+            "line 3: unknown key 'zoom'",
+            "line 4: expected true or false, got 'yes'",
+            "line 5: expected `key = value`",
+        ]
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,57 @@
+// tests/unit/layout/test_pane_layout_split_resize_and_zoom.rs
+
+// Intention: Test that the pane layout splits the screen with the stored sizes, clamps them to
+// small screens, hides collapsed panes, gives a zoomed pane the whole area, and bounds resizing.
+
+use ratatui::layout::Rect;
+use situation::{
+    Pane,
+    PaneLayout,
+};
+
+#[test]
+fn test_pane_layout_split_resize_and_zoom() {
+    let area = Rect::new(0, 1, 100, 40);
+    let mut layout = PaneLayout::default();
+
+    let panes = layout.split(area);
+    assert_eq!(panes.log_panel, Rect::new(0, 31, 100, 10));
+    assert_eq!(panes.schema_list, Rect::new(0, 1, 30, 30));
+    assert_eq!(panes.content, Rect::new(30, 1, 70, 30));
+
+    // Sizes too large for the screen leave room for the details pane
+    layout.log_height = 100;
+    layout.schema_width = 100;
+    let panes = layout.split(area);
+    assert_eq!(panes.log_panel.height, 35);
+    assert_eq!(panes.schema_list.width, 80);
+    assert_eq!(panes.content, Rect::new(80, 1, 20, 5));
+
+    // Resizing starts from the size on screen, not the stored one
+    layout.resize_log(-1, area);
+    assert_eq!(layout.log_height, 34);
+    layout.resize_schema(2, area);
+    assert_eq!(layout.schema_width, 80);
+    layout.log_height = 3;
+    layout.resize_log(-1, area);
+    assert_eq!(layout.log_height, 3);
+
+    // Collapsed panes get no area; resizing one shows it again
+    layout.toggle_collapsed(Pane::SchemaList);
+    layout.toggle_collapsed(Pane::LogPanel);
+    let panes = layout.split(area);
+    assert!(panes.schema_list.is_empty());
+    assert!(panes.log_panel.is_empty());
+    assert_eq!(panes.content, area);
+    layout.resize_log(1, area);
+    assert!(!layout.is_collapsed(Pane::LogPanel));
+    assert_eq!(layout.log_height, 4);
+
+    // A zoomed pane fills the area until zoomed again
+    layout.toggle_zoom(Pane::LogPanel);
+    let panes = layout.split(area);
+    assert_eq!(panes.log_panel, area);
+    assert!(panes.content.is_empty());
+    layout.toggle_zoom(Pane::LogPanel);
+    assert_eq!(layout.zoomed, None);
+}
@@ -0,0 +1,12 @@
+// tests/unit/logging.rs
+
+// Intention:
+// Declares unit test modules for structured log entries (`LogLevel`, `LogEntry`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_log_entry_matches_and_formats;
+mod test_log_level_parse_and_order;
@@ -0,0 +1,42 @@
+// tests/unit/logging/test_log_entry_matches_and_formats.rs
+
+// Intention: Test level and text filtering of log entries, and the single-line format written
+// to the log file.
+
+use chrono::{
+    TimeZone,
+    Utc,
+};
+use situation::{
+    LogEntry,
+    LogLevel,
+};
+
+#[test]
+fn test_log_entry_matches_and_formats() {
+    let mut entry = LogEntry::warn("api::whoami", "Slow response\nfrom server")
+        .with_request_id("req-0007");
+    entry.timestamp = Utc.with_ymd_and_hms(2024, 5, 1, 12, 30, 0).unwrap();
+
+    // Level filter
+    assert!(entry.matches(LogLevel::Info, ""));
+    assert!(entry.matches(LogLevel::Warn, ""));
+    assert!(!entry.matches(LogLevel::Error, ""));
+
+    // Text search over message, source and request ID, ignoring case
+    assert!(entry.matches(LogLevel::Trace, "SLOW"));
+    assert!(entry.matches(LogLevel::Trace, "whoami"));
+    assert!(entry.matches(LogLevel::Trace, "req-0007"));
+    assert!(!entry.matches(LogLevel::Trace, "timeout"));
+
+    assert_eq!(
+        entry.to_line(),
+        "2024-05-01T12:30:00.000Z WARN  [api::whoami] (req-0007) Slow response\\nfrom server"
+    );
+
+    // Plain strings become application info entries
+    let plain = LogEntry::from("Change set list refreshed.");
+    assert_eq!(plain.level, LogLevel::Info);
+    assert_eq!(plain.source, "app");
+    assert_eq!(plain.request_id, None);
+}
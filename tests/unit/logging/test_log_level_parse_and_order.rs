@@ -0,0 +1,20 @@
+// tests/unit/logging/test_log_level_parse_and_order.rs
+
+// Intention: Test that level names parse case-insensitively, that levels are ordered from most
+// to least verbose, and that cycling wraps around.
+
+use situation::LogLevel;
+
+#[test]
+fn test_log_level_parse_and_order() {
+    assert_eq!(LogLevel::parse("TRACE"), Some(LogLevel::Trace));
+    assert_eq!(LogLevel::parse(" debug "), Some(LogLevel::Debug));
+    assert_eq!(LogLevel::parse("Warning"), Some(LogLevel::Warn));
+    assert_eq!(LogLevel::parse("loud"), None);
+
+    assert!(LogLevel::Trace < LogLevel::Debug);
+    assert!(LogLevel::Warn < LogLevel::Error);
+
+    assert_eq!(LogLevel::Info.next(), LogLevel::Warn);
+    assert_eq!(LogLevel::Error.next(), LogLevel::Trace);
+}
@@ -4,8 +4,57 @@
 // This file declares the modules containing unit tests.
 // It allows the Rust test runner to discover tests within the `tests/unit/` subdirectory.
 
+// Declare the module of fixtures shared by the unit tests.
+mod helpers;
+
 // Declare the module containing api_models unit tests.
 pub mod api_models;
+// Declare the module containing apply monitor unit tests.
+pub mod apply_monitor;
+// Declare the module containing background refresh unit tests.
+pub mod auto_refresh;
+// Declare the module containing client response cache unit tests.
+pub mod cache;
+// Declare the module containing client call cancellation unit tests.
+pub mod cancel;
+// Declare the module containing change set comparison unit tests.
+pub mod compare;
+// Declare the module containing conflict detection unit tests.
+pub mod conflicts;
+// Declare the module containing shared config file unit tests.
+pub mod config_file;
+// Declare the module containing fuzzy matcher unit tests.
+pub mod fuzzy;
+// Declare the module containing HTTP history unit tests.
+pub mod http_history;
+// Declare the module containing JWT claims unit tests.
+pub mod jwt;
+// Declare the module containing keymap unit tests.
+pub mod keymap;
+// Declare the module containing pane layout unit tests.
+pub mod layout;
+// Declare the module containing structured logging unit tests.
+pub mod logging;
+// Declare the module containing configuration profile unit tests.
+pub mod profiles;
+// Declare the module containing secret redaction unit tests.
+pub mod redact;
+// Declare the module containing client retry policy unit tests.
+pub mod retry;
+// Declare the module containing pre-apply review unit tests.
+pub mod review;
+// Declare the module containing schema tree unit tests.
+pub mod schema_tree;
+// Declare the module containing offline workspace snapshot unit tests.
+pub mod snapshot;
+// Declare the module containing colour theme unit tests.
+pub mod theme;
+// Declare the module containing encrypted token store unit tests.
+pub mod token_store;
+// Declare the module containing watch stream unit tests.
+pub mod watch;
+// Declare the module containing workspaces file unit tests.
+pub mod workspaces;
 // Note: ui_rendering tests moved into src/ui.rs as inline module #[cfg(test)]
 
 // Add declarations for other unit test modules here as they are created.
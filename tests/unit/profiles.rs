@@ -0,0 +1,14 @@
+// tests/unit/profiles.rs
+
+// Intention:
+// Declares unit test modules for configuration profiles (`parse_profiles`, `TokenSource`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_parse_profiles;
+mod test_token_source_file_and_command;
+mod test_token_source_read;
+mod test_token_source_read_async;
@@ -0,0 +1,105 @@
+// tests/unit/profiles/test_parse_profiles.rs
+
+// Intention: Test that each profile section yields its URL, token source, timeouts and flags
+// (with defaults for the omitted keys), and that mistakes are reported by line.
+
+use std::time::Duration;
+
+use situation::{
+    ClientSettings,
+    TokenSource,
+    parse_profiles,
+};
+
+#[test]
+fn test_parse_profiles() {
+    let text = "\
+# Profiles
+[dev]
+api = http://localhost:5380/api
+token = dev-token
+
+[prod]
+api = https://prod.example.com/api
+token_env = PROD_JWT
+connect_timeout = 5
+timeout = 0
+retries = 5
+concurrency = 2
+retry_mutations = on
+read_only = true
+production = yes
+";
+    let profiles = parse_profiles(text).unwrap();
+    assert_eq!(profiles.len(), 2);
+
+    let dev = &profiles[0];
+    assert_eq!(dev.name, "dev");
+    assert_eq!(dev.api_url, "http://localhost:5380/api");
+    assert_eq!(dev.token, TokenSource::Value("dev-token".to_string()));
+    assert_eq!(dev.settings, ClientSettings::default());
+    assert!(!dev.production);
+
+    let prod = &profiles[1];
+    assert_eq!(prod.token, TokenSource::Env("PROD_JWT".to_string()));
+    assert_eq!(prod.settings.connect_timeout, Some(Duration::from_secs(5)));
+    assert_eq!(prod.settings.request_timeout, None); // 0 means no timeout
+    assert_eq!(prod.settings.retry.max_retries, 5);
+    assert_eq!(prod.settings.concurrency, 2);
+    assert!(prod.settings.retry.retry_mutations);
+    assert!(prod.settings.read_only);
+    assert!(prod.production);
+
+    // A literal token never appears in debug output
+    assert!(!format!("{:?}", dev).contains("dev-token"));
+
+    // Tokens that can rotate: a file, a command's output or a token store entry
+    let rotating = parse_profiles(
+        "[file]\napi = a\ntoken_file = /run/secrets/jwt\n\
+         [command]\napi = a\ntoken_command = pass show si/jwt\n\
+         [store]\napi = a\ntoken_store = staging\n",
+    )
+    .unwrap();
+    let tokens: Vec<_> = rotating.iter().map(|p| p.token.clone()).collect();
+    assert_eq!(
+        tokens,
+        vec![
+            TokenSource::File("/run/secrets/jwt".into()),
+            TokenSource::Command("pass show si/jwt".to_string()),
+            TokenSource::Store("staging".to_string()),
+        ]
+    );
+
+    let errors = parse_profiles(
+        "\
+timeout = 5
+[prod]
+api = https://prod.example.com/api
+timeout = soon
+read_only = maybe
+colour = red
+retries = -1
+concurrency = 0
+[prod]
+token = t
+token_file = /run/secrets/jwt
+",
+    )
+    .unwrap_err();
+    assert_eq!(
+        errors,
+        vec![
+            "line 1: expected a `[name]` section".to_string(),
+            "line 2: profile 'prod' has no `token`".to_string(),
+            "line 4: expected whole seconds, got 'soon'".to_string(),
+            "line 5: expected true or false, got 'maybe'".to_string(),
+            "line 6: unknown key 'colour'".to_string(),
+            "line 7: expected a whole number, got '-1'".to_string(),
+            "line 8: expected a positive number, got '0'".to_string(),
+            "line 9: duplicate profile 'prod'".to_string(),
+            "line 9: profile 'prod' has no `api`".to_string(),
+            "line 11: `token_file`: profile 'prod' already has a token source"
+                .to_string(),
+        ]
+    );
+}
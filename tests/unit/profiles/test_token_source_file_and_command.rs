@@ -0,0 +1,56 @@
+// tests/unit/profiles/test_token_source_file_and_command.rs
+
+// Intention: Test that file and command token sources yield the first line of their output,
+// and report empty output and failing commands.
+
+use std::fs;
+
+use situation::TokenSource;
+
+#[test]
+fn test_token_source_file_and_command() {
+    let dir = std::env::temp_dir()
+        .join(format!("situation-token-source-{}", std::process::id()));
+    fs::create_dir_all(&dir).expect("create temp dir");
+    let path = dir.join("jwt");
+    let no_env = |_: &str| None;
+
+    fs::write(&path, "  file-token  \nsecond line\n").expect("write token");
+    let file = TokenSource::File(path.clone());
+    assert!(file.can_change());
+    assert_eq!(file.read_with(no_env), Ok("file-token".to_string()));
+
+    // A rotated token is picked up on the next read
+    fs::write(&path, "rotated-token\n").expect("write token");
+    assert_eq!(file.read_with(no_env), Ok("rotated-token".to_string()));
+
+    fs::write(&path, "\n").expect("write token");
+    assert_eq!(
+        file.read_with(no_env),
+        Err(format!("token file {} is empty", path.display()))
+    );
+    assert!(
+        TokenSource::File(dir.join("missing"))
+            .read_with(no_env)
+            .is_err()
+    );
+
+    if cfg!(unix) {
+        let command = TokenSource::Command(
+            "printf 'command-token\\nlogin: me\\n'".to_string(),
+        );
+        assert_eq!(command.read_with(no_env), Ok("command-token".to_string()));
+        assert_eq!(
+            TokenSource::Command("true".to_string()).read_with(no_env),
+            Err("token command `true` printed nothing".to_string())
+        );
+        let failing = TokenSource::Command("echo nope >&2; exit 3".to_string())
+            .read_with(no_env)
+            .expect_err("failing command");
+        assert!(failing.contains("nope"), "{}", failing);
+    }
+
+    assert!(!TokenSource::Value("t".to_string()).can_change());
+    assert!(!TokenSource::Env("JWT".to_string()).can_change());
+    fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,33 @@
+// tests/unit/profiles/test_token_source_read.rs
+
+// Intention: Test that a token source yields the trimmed token, and names the missing
+// variable when the token cannot be read.
+
+use situation::TokenSource;
+
+#[test]
+fn test_token_source_read() {
+    let lookup = |name: &str| match name {
+        "SET_JWT" => Some(" env-token\n".to_string()),
+        "BLANK_JWT" => Some("  ".to_string()),
+        _ => None,
+    };
+
+    assert_eq!(
+        TokenSource::Value(" literal ".to_string()).read_with(lookup),
+        Ok("literal".to_string())
+    );
+    assert_eq!(
+        TokenSource::Env("SET_JWT".to_string()).read_with(lookup),
+        Ok("env-token".to_string())
+    );
+    assert_eq!(
+        TokenSource::Env("BLANK_JWT".to_string()).read_with(lookup),
+        Err("environment variable BLANK_JWT is not set".to_string())
+    );
+    assert_eq!(
+        TokenSource::Env("MISSING_JWT".to_string()).read_with(lookup),
+        Err("environment variable MISSING_JWT is not set".to_string())
+    );
+    assert!(TokenSource::Value(String::new()).read_with(lookup).is_err());
+}
@@ -0,0 +1,61 @@
+// tests/unit/profiles/test_token_source_read_async.rs
+
+// Intention: Test that the async read (used after a 401) yields the same token as the blocking
+// one, and gives up on a command that does not finish in time.
+
+use std::{
+    fs,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use situation::TokenSource;
+
+#[tokio::test]
+async fn test_token_source_read_async() {
+    let dir = std::env::temp_dir()
+        .join(format!("situation-token-async-{}", std::process::id()));
+    fs::create_dir_all(&dir).expect("create temp dir");
+    let path = dir.join("jwt");
+    fs::write(&path, "file-token\nsecond line\n").expect("write token");
+    let timeout = Duration::from_secs(5);
+
+    assert_eq!(
+        TokenSource::File(path).read_async(timeout).await,
+        Ok("file-token".to_string())
+    );
+    assert_eq!(
+        TokenSource::Value("t".to_string())
+            .read_async(timeout)
+            .await,
+        Ok("t".to_string())
+    );
+
+    if cfg!(unix) {
+        let command = TokenSource::Command(
+            "printf 'command-token\\nlogin: me\\n'".to_string(),
+        );
+        assert_eq!(
+            command.read_async(timeout).await,
+            Ok("command-token".to_string())
+        );
+        assert_eq!(
+            TokenSource::Command("true".to_string())
+                .read_async(timeout)
+                .await,
+            Err("token command `true` printed nothing".to_string())
+        );
+
+        // A command waiting on a prompt is abandoned (and killed)
+        let started = Instant::now();
+        let stuck = TokenSource::Command("sleep 30".to_string())
+            .read_async(Duration::from_millis(200))
+            .await
+            .expect_err("timed out");
+        assert!(stuck.contains("took longer than"), "{}", stuck);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+    fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,12 @@
+// tests/unit/redact.rs
+
+// Intention:
+// Declares unit test modules for secret redaction in API logs (`Redactor`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_redact_json_masks_keys_and_props;
+mod test_redact_text_masks_pairs_and_tokens;
@@ -0,0 +1,12 @@
+// tests/unit/retry.rs
+
+// Intention:
+// Declares unit test modules for the client retry policy (`RetryPolicy`, `parse_retry_after`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_parse_retry_after;
+mod test_retry_policy;
@@ -0,0 +1,31 @@
+// tests/unit/retry/test_parse_retry_after.rs
+
+// Intention: Test that `Retry-After` is read as seconds or as an HTTP date relative to now.
+
+use std::time::Duration;
+
+use chrono::DateTime;
+use situation::parse_retry_after;
+
+#[test]
+fn test_parse_retry_after() {
+    // Wed, 21 Oct 2015 07:28:00 GMT
+    let now = DateTime::from_timestamp(1_445_412_480, 0).unwrap();
+
+    assert_eq!(
+        parse_retry_after("120", now),
+        Some(Duration::from_secs(120))
+    );
+    assert_eq!(parse_retry_after(" 0 ", now), Some(Duration::ZERO));
+    assert_eq!(
+        parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+        Some(Duration::from_secs(30))
+    );
+    // A date in the past means retry now
+    assert_eq!(
+        parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(parse_retry_after("soon", now), None);
+    assert_eq!(parse_retry_after("-5", now), None);
+}
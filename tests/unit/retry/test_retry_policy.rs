@@ -0,0 +1,50 @@
+// tests/unit/retry/test_retry_policy.rs
+
+// Intention: Test which requests and responses the default policy retries, and that the delay
+// doubles up to the cap with the jitter choosing between half and all of it.
+
+use std::time::Duration;
+
+use reqwest::{
+    Method,
+    StatusCode,
+};
+use situation::RetryPolicy;
+
+#[test]
+fn test_retry_policy() {
+    let policy = RetryPolicy::default();
+    assert_eq!(policy, RetryPolicy::DEFAULT);
+
+    // Reads only, unless mutations are opted in
+    assert!(policy.allows(&Method::GET));
+    assert!(!policy.allows(&Method::POST));
+    assert!(!policy.allows(&Method::DELETE));
+    let opted_in = RetryPolicy {
+        retry_mutations: true,
+        ..RetryPolicy::DEFAULT
+    };
+    assert!(opted_in.allows(&Method::POST));
+    assert!(!RetryPolicy::NEVER.allows(&Method::GET));
+
+    for status in [429, 502, 503, 504] {
+        let status = StatusCode::from_u16(status).unwrap();
+        assert!(RetryPolicy::retries_status(status), "{}", status);
+    }
+    for status in [200, 400, 401, 404, 500] {
+        let status = StatusCode::from_u16(status).unwrap();
+        assert!(!RetryPolicy::retries_status(status), "{}", status);
+    }
+
+    let policy = RetryPolicy {
+        base_delay: Duration::from_millis(100),
+        max_delay: Duration::from_millis(700),
+        ..RetryPolicy::DEFAULT
+    };
+    assert_eq!(policy.delay(0, 1.0), Duration::from_millis(100));
+    assert_eq!(policy.delay(0, 0.0), Duration::from_millis(50));
+    assert_eq!(policy.delay(1, 1.0), Duration::from_millis(200));
+    assert_eq!(policy.delay(2, 0.5), Duration::from_millis(300));
+    assert_eq!(policy.delay(3, 1.0), Duration::from_millis(700)); // Capped
+    assert_eq!(policy.delay(40, 1.0), Duration::from_millis(700));
+}
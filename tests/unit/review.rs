@@ -0,0 +1,12 @@
+// tests/unit/review.rs
+
+// Intention:
+// Declares unit test modules for the pre-apply review (`ApplyReview`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_apply_review_markdown;
+mod test_apply_review_redacts_secrets;
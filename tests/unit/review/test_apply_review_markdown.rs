@@ -0,0 +1,122 @@
+// tests/unit/review/test_apply_review_markdown.rs
+
+// Intention: Test that a comparison with HEAD is sorted into created, modified and deleted
+// components, and that the review exports as the expected Markdown and file name.
+
+use std::{
+    collections::BTreeMap,
+    path::Path,
+};
+
+use chrono::{
+    TimeZone,
+    Utc,
+};
+use serde_json::json;
+use situation::{
+    ApplyReview,
+    ChangeSetComparison,
+    api_models::{
+        MergeStatusV1ResponseAction,
+        MergeStatusV1ResponseActionComponent,
+    },
+    compare::compare_components,
+    watch::ObservedComponent,
+};
+
+use crate::unit::helpers::{
+    change_set,
+    component,
+};
+
+#[test]
+fn test_apply_review_markdown() {
+    let head: BTreeMap<String, ObservedComponent> = [
+        ("c1", component("web", json!({ "size": 1 }), false)),
+        ("c2", component("old", json!({}), false)),
+        ("c3", component("db", json!({}), false)),
+        ("c4", component("same", json!({}), false)),
+    ]
+    .into_iter()
+    .map(|(id, c)| (id.to_string(), c))
+    .collect();
+    let change_set_components: BTreeMap<String, ObservedComponent> = [
+        ("c1", component("web", json!({ "size": 2 }), false)),
+        ("c3", component("db", json!({}), true)),
+        ("c4", component("same", json!({}), false)),
+        ("c5", component("api|v2", json!({ "port": 80 }), false)),
+    ]
+    .into_iter()
+    .map(|(id, c)| (id.to_string(), c))
+    .collect();
+    let comparison = ChangeSetComparison {
+        left: change_set("cs0", "HEAD", "Open"),
+        right: change_set("cs1", "my change/1", "Open"),
+        components: compare_components(&head, &change_set_components),
+    };
+    let actions = vec![MergeStatusV1ResponseAction {
+        id: "a1".to_string(),
+        state: "Queued".to_string(),
+        kind: "Create".to_string(),
+        name: "create".to_string(),
+        component: Some(MergeStatusV1ResponseActionComponent {
+            id: "c5".to_string(),
+            name: "api|v2".to_string(),
+        }),
+    }];
+
+    let review = ApplyReview::new(comparison, actions);
+    let names = |diffs: &[situation::ComponentDiff]| -> Vec<String> {
+        diffs.iter().map(|d| d.name().to_string()).collect()
+    };
+    assert_eq!(names(&review.created), vec!["api|v2"]);
+    assert_eq!(names(&review.modified), vec!["web"]);
+    // Gone from the change set, or marked for deletion in it
+    assert_eq!(names(&review.deleted), vec!["db", "old"]);
+    assert!(!review.is_empty());
+    assert_eq!(
+        review.summary(),
+        "1 created, 1 modified, 2 deleted, 1 queued action(s)"
+    );
+
+    let at = Utc.with_ymd_and_hms(2026, 10, 18, 9, 30, 5).unwrap();
+    let expected = "\
+# Apply review: my change/1 (`cs1`)
+
+Compared with HEAD (`cs0`) at 2026-10-18 09:30:05 UTC.
+
+1 created, 1 modified, 2 deleted, 1 queued action(s).
+
+## Created (1)
+
+### api|v2 (`c5`)
+
+| Prop / connection | HEAD | my change/1 |
+|---|---|---|
+| `/domain/port` | - | 80 |
+
+## Modified (1)
+
+### web (`c1`)
+
+| Prop / connection | HEAD | my change/1 |
+|---|---|---|
+| `/domain/size` | 1 | 2 |
+
+## Deleted (2)
+
+- db (`c3`)
+- old (`c2`)
+
+## Queued actions (1)
+
+| Kind | Action | Component | State |
+|---|---|---|---|
+| Create | create | api\\|v2 | Queued |
+";
+    assert_eq!(review.to_markdown(at), expected);
+    assert_eq!(
+        review.export_path_in(Path::new("/tmp/reviews"), at),
+        Path::new("/tmp/reviews/review-my_change_1-20261018-093005.md")
+    );
+}
@@ -0,0 +1,78 @@
+// tests/unit/review/test_apply_review_redacts_secrets.rs
+
+// Intention: Test that a review masks secret prop values (e.g. a `password`) in its conflicting
+// values and its Markdown, while the other values it reports are shown as is.
+
+use serde_json::json;
+use situation::{
+    ApplyReview,
+    ChangeSetComparison,
+    compare::compare_components,
+    conflicts::find_conflicts,
+    redact::REDACTED,
+};
+
+use crate::unit::helpers::{
+    change_set,
+    component,
+    components,
+};
+
+#[test]
+fn test_apply_review_redacts_secrets() {
+    let db = |password: &str, size: u32| {
+        components(vec![(
+            "c1",
+            component(
+                "db",
+                json!({ "password": password, "size": size }),
+                false,
+            ),
+        )])
+    };
+    let head = db("hunter2", 1);
+    let head_cs = change_set("cs0", "HEAD", "Open");
+    let comparisons: Vec<ChangeSetComparison> = [
+        ("cs1", "mine", db("s3cret", 2)),
+        ("cs2", "theirs", db("letmein", 3)),
+    ]
+    .into_iter()
+    .map(|(id, name, components)| ChangeSetComparison {
+        left: head_cs.clone(),
+        right: change_set(id, name, "Open"),
+        components: compare_components(&head, &components),
+    })
+    .collect();
+    let report = find_conflicts(&comparisons);
+    let review = ApplyReview::new(comparisons[0].clone(), Vec::new())
+        .with_conflicts(&report);
+
+    // Both change sets still conflict on the password, shown masked
+    let props = &review.conflicts[0].props;
+    let paths: Vec<&str> = props.iter().map(|p| p.path.as_str()).collect();
+    assert_eq!(paths, vec!["/domain/password", "/domain/size"]);
+    assert_eq!(props[0].value_label("cs1"), REDACTED);
+    assert_eq!(props[0].value_label("cs2"), REDACTED);
+    assert_eq!(props[1].value_label("cs2"), "3");
+
+    let markdown = review.to_markdown(chrono::DateTime::UNIX_EPOCH);
+    for secret in ["hunter2", "s3cret", "letmein"] {
+        assert!(
+            !markdown.contains(secret),
+            "unexpected Markdown:\n{}",
+            markdown
+        );
+    }
+    for line in [
+        "| `/domain/password` | theirs | [REDACTED] |",
+        "| `/domain/size` | theirs | 3 |",
+        "| `/domain/password` | [REDACTED] | [REDACTED] |",
+        "| `/domain/size` | 1 | 2 |",
+    ] {
+        assert!(
+            markdown.contains(line),
+            "unexpected Markdown:\n{}",
+            markdown
+        );
+    }
+}
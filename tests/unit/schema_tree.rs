@@ -0,0 +1,15 @@
+// tests/unit/schema_tree.rs
+
+// Intention:
+// Declares unit test modules for the schema pane tree builder (`build_schema_tree`).
+// Each submodule corresponds to a file containing a single test function or helpers.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare helper module
+mod helpers;
+
+// Declare test function modules
+mod test_build_schema_tree_filters_and_searches;
+mod test_build_schema_tree_groups_by_category;
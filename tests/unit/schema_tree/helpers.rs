@@ -0,0 +1,30 @@
+// tests/unit/schema_tree/helpers.rs
+
+// Intention: Shared builders for the schema tree tests.
+
+use situation::api_models::SchemaSummary;
+
+/// Builds a `SchemaSummary` with the given fields.
+pub fn schema(
+    id: &str,
+    name: &str,
+    category: &str,
+    installed: bool,
+) -> SchemaSummary {
+    SchemaSummary {
+        schema_id: id.to_string(),
+        schema_name: name.to_string(),
+        category: category.to_string(),
+        installed,
+    }
+}
+
+/// A small catalogue spanning two categories, deliberately out of order.
+pub fn catalogue() -> Vec<SchemaSummary> {
+    vec![
+        schema("s_vpc", "AWS::EC2::VPC", "AWS::EC2", true),
+        schema("s_region", "Region", "AWS", true),
+        schema("s_subnet", "AWS::EC2::Subnet", "AWS::EC2", false),
+        schema("s_cred", "AWS Credential", "AWS", false),
+    ]
+}
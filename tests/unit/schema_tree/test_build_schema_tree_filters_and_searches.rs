@@ -0,0 +1,85 @@
+// tests/unit/schema_tree/test_build_schema_tree_filters_and_searches.rs
+
+// Intention: Test the installed-only filter and the incremental search, including that a
+// search shows matches inside collapsed categories.
+
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use situation::{
+    SchemaTreeOptions,
+    SchemaTreeRow,
+    build_schema_tree,
+};
+
+use super::helpers::catalogue;
+
+#[test]
+fn test_build_schema_tree_filters_and_searches() {
+    let schemas = catalogue();
+    let counts = HashMap::new();
+    let collapsed: HashSet<String> =
+        ["AWS::EC2".to_string()].into_iter().collect();
+
+    let schema_indices = |rows: &[SchemaTreeRow]| -> Vec<usize> {
+        rows.iter()
+            .filter_map(|row| match row {
+                SchemaTreeRow::Schema { index, .. } => Some(*index),
+                SchemaTreeRow::Category { .. } => None,
+            })
+            .collect()
+    };
+
+    // Installed only: uninstalled schemas and the collapsed category's schemas are hidden
+    let rows = build_schema_tree(
+        &schemas,
+        &counts,
+        SchemaTreeOptions {
+            query: "",
+            installed_only: true,
+            collapsed: &collapsed,
+        },
+    );
+    assert_eq!(schema_indices(&rows), vec![1]);
+    assert_eq!(rows.len(), 3); // Two headers + Region
+
+    // Search is case-insensitive and expands collapsed categories with matches
+    let rows = build_schema_tree(
+        &schemas,
+        &counts,
+        SchemaTreeOptions {
+            query: "subNET",
+            installed_only: false,
+            collapsed: &collapsed,
+        },
+    );
+    assert_eq!(
+        rows,
+        vec![
+            SchemaTreeRow::Category {
+                name: "AWS::EC2".to_string(),
+                schema_count: 1,
+                component_count: 0,
+                collapsed: false,
+            },
+            SchemaTreeRow::Schema {
+                index: 2,
+                component_count: 0
+            },
+        ]
+    );
+
+    // Nothing matches: no rows at all
+    let rows = build_schema_tree(
+        &schemas,
+        &counts,
+        SchemaTreeOptions {
+            query: "gcp",
+            installed_only: false,
+            collapsed: &collapsed,
+        },
+    );
+    assert!(rows.is_empty());
+}
@@ -0,0 +1,87 @@
+// tests/unit/schema_tree/test_build_schema_tree_groups_by_category.rs
+
+// Intention: Test that schemas are grouped and sorted under category headers, that collapsed
+// categories hide their schemas, and that component counts are attached to rows.
+
+use std::collections::HashSet;
+
+use situation::{
+    SchemaTreeOptions,
+    SchemaTreeRow,
+    build_schema_tree,
+    count_components_by_schema,
+};
+
+use super::helpers::catalogue;
+
+#[test]
+fn test_build_schema_tree_groups_by_category() {
+    let schemas = catalogue();
+    let counts = count_components_by_schema(["s_vpc", "s_vpc", "s_region"]);
+    let mut collapsed = HashSet::new();
+
+    let rows = build_schema_tree(
+        &schemas,
+        &counts,
+        SchemaTreeOptions {
+            query: "",
+            installed_only: false,
+            collapsed: &collapsed,
+        },
+    );
+    assert_eq!(
+        rows,
+        vec![
+            SchemaTreeRow::Category {
+                name: "AWS".to_string(),
+                schema_count: 2,
+                component_count: 1,
+                collapsed: false,
+            },
+            SchemaTreeRow::Schema {
+                index: 3,
+                component_count: 0
+            }, // AWS Credential
+            SchemaTreeRow::Schema {
+                index: 1,
+                component_count: 1
+            }, // Region
+            SchemaTreeRow::Category {
+                name: "AWS::EC2".to_string(),
+                schema_count: 2,
+                component_count: 2,
+                collapsed: false,
+            },
+            SchemaTreeRow::Schema {
+                index: 2,
+                component_count: 0
+            }, // Subnet
+            SchemaTreeRow::Schema {
+                index: 0,
+                component_count: 2
+            }, // VPC
+        ]
+    );
+
+    // Collapsing a category keeps its header (with totals) but hides its schemas
+    collapsed.insert("AWS".to_string());
+    let rows = build_schema_tree(
+        &schemas,
+        &counts,
+        SchemaTreeOptions {
+            query: "",
+            installed_only: false,
+            collapsed: &collapsed,
+        },
+    );
+    assert_eq!(rows.len(), 4);
+    assert_eq!(
+        rows[0],
+        SchemaTreeRow::Category {
+            name: "AWS".to_string(),
+            schema_count: 2,
+            component_count: 1,
+            collapsed: true,
+        }
+    );
+}
@@ -0,0 +1,11 @@
+// tests/unit/snapshot.rs
+
+// Intention:
+// Declares unit test modules for the offline workspace snapshots (`WorkspaceSnapshot`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_workspace_snapshot;
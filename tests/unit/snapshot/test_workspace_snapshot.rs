@@ -0,0 +1,104 @@
+// tests/unit/snapshot/test_workspace_snapshot.rs
+
+// Intention: Test that a workspace snapshot survives a save and load, forgets change sets that
+// left the list, names its file safely, and reports its age.
+
+use std::{
+    env,
+    fs,
+    path::Path,
+};
+
+use chrono::{
+    Duration,
+    TimeZone,
+    Utc,
+};
+use situation::{
+    ChangeSetSnapshot,
+    SnapshotComponent,
+    WorkspaceSnapshot,
+    api_models::{
+        TokenDetails,
+        WhoamiResponse,
+    },
+};
+
+use crate::unit::helpers::change_set;
+
+#[test]
+fn test_workspace_snapshot() {
+    let saved_at = Utc.with_ymd_and_hms(2025, 4, 21, 12, 0, 0).unwrap();
+    let whoami = WhoamiResponse {
+        user_id: "user".to_string(),
+        user_email: "user@example.com".to_string(),
+        workspace_id: "ws".to_string(),
+        token: TokenDetails {
+            iat: 0,
+            sub: "user".to_string(),
+            user_pk: "user".to_string(),
+            workspace_pk: "ws".to_string(),
+        },
+    };
+    let mut snapshot = WorkspaceSnapshot::new(
+        whoami,
+        vec![
+            change_set("cs1", "one", "Open"),
+            change_set("cs2", "two", "Open"),
+        ],
+        saved_at,
+    );
+    snapshot.selected_change_set = Some("cs2".to_string());
+    snapshot.loaded.insert(
+        "cs1".to_string(),
+        ChangeSetSnapshot {
+            components: Some(vec![SnapshotComponent {
+                id: "c1".to_string(),
+                name: "web".to_string(),
+                schema_id: "schema".to_string(),
+            }]),
+            ..ChangeSetSnapshot::default()
+        },
+    );
+    snapshot
+        .loaded
+        .insert("cs2".to_string(), ChangeSetSnapshot::default());
+
+    // Round trip through a file in a directory that does not exist yet
+    let dir = env::temp_dir()
+        .join(format!("situation-snapshot-test-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let path = WorkspaceSnapshot::path_in(&dir.join("nested"), "prod/eu 1");
+    assert_eq!(path.file_name().unwrap(), "prod_eu_1.json");
+    assert!(WorkspaceSnapshot::load(&path).is_err());
+    snapshot.save(&path).unwrap();
+    let loaded = WorkspaceSnapshot::load(&path).unwrap();
+    assert_eq!(loaded.saved_at, saved_at);
+    assert_eq!(loaded.whoami.workspace_id, "ws");
+    assert_eq!(loaded.change_sets.len(), 2);
+    assert_eq!(loaded.selected_change_set.as_deref(), Some("cs2"));
+    let components = loaded.loaded["cs1"].components.as_ref().unwrap();
+    assert_eq!(components[0].name, "web");
+    assert_eq!(components[0].to_view().schema_id, "schema");
+    assert!(!Path::new(&path.with_extension("json.tmp")).exists());
+    fs::write(&path, "{ not json").unwrap();
+    assert!(WorkspaceSnapshot::load(&path).is_err());
+    let _ = fs::remove_dir_all(&dir);
+
+    // Change sets that left the list are forgotten, with the selection if it was one
+    snapshot.set_change_sets(vec![
+        change_set("cs1", "one", "Open"),
+        change_set("cs3", "three", "Open"),
+    ]);
+    assert_eq!(snapshot.loaded.keys().collect::<Vec<_>>(), ["cs1"]);
+    assert_eq!(snapshot.selected_change_set, None);
+    assert_eq!(snapshot.change_sets[1].id, "cs3");
+
+    // Age
+    assert_eq!(snapshot.age_label(saved_at), "just now");
+    assert_eq!(snapshot.age_label(saved_at + Duration::seconds(45)), "45s");
+    assert_eq!(
+        snapshot.age_label(saved_at + Duration::minutes(90)),
+        "1h 30m"
+    );
+}
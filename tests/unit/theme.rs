@@ -0,0 +1,12 @@
+// tests/unit/theme.rs
+
+// Intention:
+// Declares unit test modules for colour themes (`Theme`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_no_color_theme_uses_modifiers;
+mod test_theme_file_overrides_base;
@@ -0,0 +1,61 @@
+// tests/unit/theme/test_no_color_theme_uses_modifiers.rs
+
+// Intention: Test that the `NO_COLOR` theme sets no colours at all and still tells the focused
+// border, selection, hotkeys, errors and the production banner apart with modifiers.
+
+use ratatui::style::{
+    Modifier,
+    Style,
+};
+use situation::Theme;
+
+#[test]
+fn test_no_color_theme_uses_modifiers() {
+    let theme = Theme::no_color();
+    let styles = [
+        theme.border(true),
+        theme.border(false),
+        theme.dim(),
+        theme.hotkey(),
+        theme.accent(),
+        theme.highlight(),
+        theme.selection(),
+        theme.selection_inactive(),
+        theme.success(),
+        theme.warning(),
+        theme.error(),
+        theme.info(),
+        theme.muted(),
+        theme.special(),
+        theme.alert(),
+    ];
+    for style in styles {
+        assert_eq!(style.fg, None);
+        assert_eq!(style.bg, None);
+    }
+
+    assert_eq!(
+        theme.border(true),
+        Style::default().add_modifier(Modifier::BOLD)
+    );
+    assert_eq!(theme.border(false), Style::default());
+    assert_eq!(
+        theme.selection(),
+        Style::default().add_modifier(Modifier::REVERSED)
+    );
+    assert_eq!(
+        theme.hotkey(),
+        Style::default().add_modifier(Modifier::UNDERLINED)
+    );
+    assert_eq!(theme.error(), Style::default().add_modifier(Modifier::BOLD));
+    assert_eq!(
+        theme.alert(),
+        Style::default().add_modifier(Modifier::REVERSED | Modifier::BOLD)
+    );
+
+    // Coloured themes use colours for the same roles
+    let dark = Theme::dark();
+    assert!(dark.selection().bg.is_some());
+    assert!(dark.error().fg.is_some());
+    assert_eq!(dark.alert().bg, dark.error().fg);
+}
@@ -0,0 +1,59 @@
+// tests/unit/theme/test_theme_file_overrides_base.rs
+
+// Intention: Test that a theme file starts from its `base` built-in, sets the roles it lists
+// from names, hex and palette indices, and reports unknown roles, colours and bases by line.
+
+use ratatui::style::{
+    Color,
+    Style,
+};
+use situation::Theme;
+
+#[test]
+fn test_theme_file_overrides_base() {
+    let text = "\
+# readable on white
+base = light
+border_focused = #005faf
+hotkey = dark gray
+dim = 240
+";
+    let theme = Theme::parse("mine", text).unwrap();
+    assert_eq!(theme.name, "mine");
+    assert_eq!(theme.border_focused, Color::Rgb(0x00, 0x5f, 0xaf));
+    assert_eq!(theme.hotkey, Color::DarkGray);
+    assert_eq!(theme.dim, Color::Indexed(240));
+    // Roles not listed come from the base
+    assert_eq!(theme.selection_bg, Theme::light().selection_bg);
+    assert_eq!(
+        theme.border(true),
+        Style::default().fg(theme.border_focused)
+    );
+    assert_eq!(theme.border(false), Style::default().fg(theme.border));
+
+    // Without a base the file applies over `dark`
+    let theme = Theme::parse("plain", "error = magenta").unwrap();
+    assert_eq!(theme.error, Color::Magenta);
+    assert_eq!(theme.success, Theme::dark().success);
+
+    let errors = Theme::parse(
+        "bad",
+        "base = sepia\nfocus = red\nerror = reddish\nwarning\n",
+    )
+    .unwrap_err();
+    assert_eq!(
+        errors,
+        vec![
+            "line 1: unknown base theme 'sepia'",
+            "line 2: unknown role 'focus'",
+            "line 3: invalid colour 'reddish'",
+            "line 4: expected `key = value`",
+        ]
+    );
+
+    assert_eq!(
+        Theme::builtin("High-Contrast").unwrap().name,
+        "high-contrast"
+    );
+    assert!(Theme::builtin("sepia").is_none());
+}
@@ -0,0 +1,12 @@
+// tests/unit/token_store.rs
+
+// Intention:
+// Declares unit test modules for the encrypted token store (`TokenStore`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_token_store_save_permissions;
+mod test_token_store_seal_unseal;
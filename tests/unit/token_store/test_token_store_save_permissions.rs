@@ -0,0 +1,42 @@
+// tests/unit/token_store/test_token_store_save_permissions.rs
+
+// Intention: Test that saving the store leaves it readable by its owner only, also when the
+// file already existed with wider permissions, and replaces it without leaving the temporary
+// file it is written to (even one left behind by an earlier save).
+
+use std::fs;
+
+use situation::TokenStore;
+
+#[test]
+fn test_token_store_save_permissions() {
+    let dir = std::env::temp_dir().join(format!(
+        "situation-token-store-perms-{}",
+        std::process::id()
+    ));
+    fs::create_dir_all(&dir).expect("create temp dir");
+    let path = dir.join("tokens.store");
+    let temporary = dir.join("tokens.store.tmp");
+    fs::write(&path, "old").expect("write existing store");
+    fs::write(&temporary, "stale").expect("write stale temporary file");
+
+    let mut store = TokenStore::new();
+    store.set("prod", "secret-prod-jwt");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644))
+            .expect("widen permissions");
+        fs::set_permissions(&temporary, fs::Permissions::from_mode(0o644))
+            .expect("widen permissions");
+        store.save(&path, "pass").expect("save");
+        let mode = fs::metadata(&path).expect("metadata").permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+    #[cfg(not(unix))]
+    store.save(&path, "pass").expect("save");
+
+    assert!(!temporary.exists());
+    assert_eq!(TokenStore::load(&path, "pass").expect("load"), store);
+    fs::remove_dir_all(&dir).ok();
+}
@@ -0,0 +1,42 @@
+// tests/unit/token_store/test_token_store_seal_unseal.rs
+
+// Intention: Test that a sealed store opens with its passphrase only, and that the sealed text
+// does not contain the tokens.
+
+use situation::TokenStore;
+
+#[test]
+fn test_token_store_seal_unseal() {
+    let mut store = TokenStore::new();
+    store.set("prod", "secret-prod-jwt");
+    store.set("dev", "secret-dev-jwt");
+    assert!(store.remove("dev"));
+    assert!(!store.remove("dev"));
+    assert_eq!(store.names().collect::<Vec<_>>(), vec!["prod"]);
+
+    let sealed = store.seal("correct horse").expect("seal");
+    assert!(sealed.starts_with("situation-token-store v1\n"));
+    assert!(!sealed.contains("secret-prod-jwt"));
+    assert!(!format!("{:?}", store).contains("secret-prod-jwt"));
+
+    let opened = TokenStore::unseal(&sealed, "correct horse").expect("unseal");
+    assert_eq!(opened, store);
+    assert_eq!(opened.get("prod"), Some("secret-prod-jwt"));
+
+    assert_eq!(
+        TokenStore::unseal(&sealed, "wrong"),
+        Err("wrong passphrase or corrupted token store".to_string())
+    );
+    let mut tampered = sealed.trim_end().to_string();
+    let last = if tampered.ends_with('0') { '1' } else { '0' };
+    tampered.pop();
+    tampered.push(last);
+    assert_eq!(
+        TokenStore::unseal(&tampered, "correct horse"),
+        Err("wrong passphrase or corrupted token store".to_string())
+    );
+    assert!(TokenStore::unseal("not a store", "correct horse").is_err());
+
+    // Each seal draws a fresh salt and nonce
+    assert_ne!(store.seal("correct horse").expect("seal"), sealed);
+}
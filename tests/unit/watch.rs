@@ -0,0 +1,13 @@
+// tests/unit/watch.rs
+
+// Intention:
+// Declares unit test modules for the watch stream (`diff_observations`, `WatchEvent`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_diff_actions_state_changes;
+mod test_diff_observations;
+mod test_watch_event_output;
@@ -0,0 +1,51 @@
+// tests/unit/watch/test_diff_actions_state_changes.rs
+
+// Intention: Test that an action still listed in the merge status reports its state changes,
+// with a failure reported as `ActionFailed`, and that an unchanged state reports nothing.
+
+use situation::{
+    WatchEvent,
+    watch::diff_actions,
+};
+
+use crate::unit::helpers::action;
+
+#[test]
+fn test_diff_actions_state_changes() {
+    let queued = [action("a1", "Queued"), action("a2", "Queued")];
+    let running = [action("a1", "Running"), action("a2", "Queued")];
+    let failed = [action("a1", "Failed"), action("a2", "Queued")];
+
+    let events = diff_actions("cs1", &queued, &running);
+    assert_eq!(
+        events,
+        vec![WatchEvent::ActionStateChanged {
+            change_set_id: "cs1".to_string(),
+            action: action("a1", "Running"),
+            previous_state: "Queued".to_string(),
+        }]
+    );
+    assert_eq!(
+        events[0].to_string(),
+        "[cs1] Action Create create a1 (a1): Queued -> Running"
+    );
+
+    let events = diff_actions("cs1", &running, &failed);
+    assert_eq!(
+        events,
+        vec![WatchEvent::ActionFailed {
+            change_set_id: "cs1".to_string(),
+            action: action("a1", "Failed"),
+        }]
+    );
+    assert_eq!(
+        events[0].to_string(),
+        "[cs1] Action Create create a1 (a1) failed"
+    );
+    assert_eq!(
+        serde_json::to_value(&events[0]).unwrap()["event"],
+        "action_failed"
+    );
+
+    assert!(diff_actions("cs1", &failed, &failed).is_empty());
+}
@@ -0,0 +1,145 @@
+// tests/unit/watch/test_diff_observations.rs
+
+// Intention: Test the events between two observations: change sets created, changing status and
+// abandoned (by status or by leaving the list), components added, removed and modified, actions
+// queued and completed, and no events for a change set observed for the first time.
+
+use std::collections::BTreeMap;
+
+use serde_json::json;
+use situation::{
+    WatchEvent,
+    watch::{
+        ChangeSetObservation,
+        Observation,
+        ObservedComponent,
+        diff_observations,
+    },
+};
+
+use crate::unit::helpers::{
+    action,
+    change_set,
+    component,
+};
+
+fn details(
+    components: &[(&str, ObservedComponent)],
+    actions: &[&str],
+) -> ChangeSetObservation {
+    ChangeSetObservation {
+        components: Some(
+            components
+                .iter()
+                .map(|(id, c)| (id.to_string(), c.clone()))
+                .collect(),
+        ),
+        actions: actions.iter().map(|id| action(id, "Queued")).collect(),
+    }
+}
+
+#[test]
+fn test_diff_observations() {
+    let old = Observation {
+        change_sets: vec![
+            change_set("cs1", "one", "Open"),
+            change_set("cs2", "two", "Open"),
+            change_set("cs3", "three", "Open"),
+            change_set("cs4", "four", "Open"),
+            change_set("cs5", "five", "Applied"),
+        ],
+        details: BTreeMap::from([(
+            "cs1".to_string(),
+            details(
+                &[
+                    ("c1", component("web", json!({ "size": 1 }), false)),
+                    ("c2", component("db", json!({ "size": 1 }), false)),
+                ],
+                &["a1", "a2"],
+            ),
+        )]),
+    };
+    let new = Observation {
+        change_sets: vec![
+            change_set("cs1", "one", "Open"), // Unchanged
+            change_set("cs2", "two", "NeedsApproval"), // Status changed
+            change_set("cs3", "three", "Abandoned"), // Abandoned by status
+            change_set("cs6", "six", "Open"), // Created (cs4 vanished, cs5 applied)
+        ],
+        details: BTreeMap::from([
+            (
+                "cs1".to_string(),
+                details(
+                    &[
+                        ("c1", component("web", json!({ "size": 2 }), false)),
+                        ("c3", component("cache", json!({ "size": 1 }), false)),
+                    ],
+                    &["a2", "a3"],
+                ),
+            ),
+            // Observed for the first time: a baseline, no events
+            (
+                "cs6".to_string(),
+                details(
+                    &[("c9", component("new", json!({ "size": 1 }), false))],
+                    &["a9"],
+                ),
+            ),
+        ]),
+    };
+
+    let events = diff_observations(&old, &new);
+    let component_event = |id: &str, name: &str| {
+        ("cs1".to_string(), id.to_string(), name.to_string())
+    };
+    let mut expected = vec![
+        WatchEvent::ChangeSetStatusChanged {
+            change_set: change_set("cs2", "two", "NeedsApproval"),
+            previous_status: "Open".to_string(),
+        },
+        WatchEvent::ChangeSetAbandoned {
+            change_set: change_set("cs3", "three", "Abandoned"),
+        },
+        WatchEvent::ChangeSetCreated {
+            change_set: change_set("cs6", "six", "Open"),
+        },
+        WatchEvent::ChangeSetAbandoned {
+            change_set: change_set("cs4", "four", "Abandoned"),
+        },
+    ];
+    let (change_set_id, component_id, name) = component_event("c1", "web");
+    expected.push(WatchEvent::ComponentModified {
+        change_set_id,
+        component_id,
+        name,
+    });
+    let (change_set_id, component_id, name) = component_event("c3", "cache");
+    expected.push(WatchEvent::ComponentAdded {
+        change_set_id,
+        component_id,
+        name,
+    });
+    let (change_set_id, component_id, name) = component_event("c2", "db");
+    expected.push(WatchEvent::ComponentRemoved {
+        change_set_id,
+        component_id,
+        name,
+    });
+    expected.push(WatchEvent::ActionAdded {
+        change_set_id: "cs1".to_string(),
+        action: action("a3", "Queued"),
+    });
+    expected.push(WatchEvent::ActionCompleted {
+        change_set_id: "cs1".to_string(),
+        action: action("a1", "Queued"),
+    });
+    assert_eq!(events, expected);
+
+    assert!(diff_observations(&new, &new).is_empty());
+    // Without component data, only change sets and actions are compared
+    let mut without_components = new.clone();
+    for observed in without_components.details.values_mut() {
+        observed.components = None;
+    }
+    assert!(diff_observations(&new, &without_components).is_empty());
+}
@@ -0,0 +1,73 @@
+// tests/unit/watch/test_watch_event_output.rs
+
+// Intention: Test how events are printed by `situation watch`: one line of text, or one JSON
+// object tagged with `event` for `--json`.
+
+use serde_json::json;
+use situation::{
+    WatchEvent,
+    api_models::{
+        ChangeSetSummary,
+        MergeStatusV1ResponseAction,
+    },
+};
+
+#[test]
+fn test_watch_event_output() {
+    let status_changed = WatchEvent::ChangeSetStatusChanged {
+        change_set: ChangeSetSummary {
+            id: "cs1".to_string(),
+            name: "feature".to_string(),
+            status: "Approved".to_string(),
+        },
+        previous_status: "NeedsApproval".to_string(),
+    };
+    assert_eq!(
+        status_changed.to_string(),
+        "Change set feature (cs1): NeedsApproval -> Approved"
+    );
+    assert_eq!(status_changed.change_set_id(), "cs1");
+    assert_eq!(
+        serde_json::to_value(&status_changed).unwrap(),
+        json!({
+            "event": "change_set_status_changed",
+            "change_set": { "id": "cs1", "name": "feature", "status": "Approved" },
+            "previous_status": "NeedsApproval",
+        })
+    );
+
+    let removed = WatchEvent::ComponentRemoved {
+        change_set_id: "cs1".to_string(),
+        component_id: "c1".to_string(),
+        name: "web".to_string(),
+    };
+    assert_eq!(removed.to_string(), "[cs1] Component web (c1) removed");
+    assert_eq!(
+        serde_json::to_value(&removed).unwrap(),
+        json!({
+            "event": "component_removed",
+            "change_set_id": "cs1",
+            "component_id": "c1",
+            "name": "web",
+        })
+    );
+
+    let completed = WatchEvent::ActionCompleted {
+        change_set_id: "cs1".to_string(),
+        action: MergeStatusV1ResponseAction {
+            id: "a1".to_string(),
+            state: "Running".to_string(),
+            kind: "Create".to_string(),
+            name: "create web".to_string(),
+            component: None,
+        },
+    };
+    assert_eq!(
+        completed.to_string(),
+        "[cs1] Action Create create web (a1) completed"
+    );
+    assert_eq!(
+        serde_json::to_value(&completed).unwrap()["event"],
+        "action_completed"
+    );
+}
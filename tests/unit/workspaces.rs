@@ -0,0 +1,11 @@
+// tests/unit/workspaces.rs
+
+// Intention:
+// Declares unit test modules for the workspaces file (`parse_workspaces`).
+// Each submodule corresponds to a file containing a single test function.
+
+// Design Choices:
+// - Follows the one-function-per-file rule for tests.
+
+// Declare test function modules
+mod test_parse_workspaces;
@@ -0,0 +1,111 @@
+// tests/unit/workspaces/test_parse_workspaces.rs
+
+// Intention: Test that the workspaces file yields one entry per section, in file order, with
+// the token read from its source (`token_env` through the lookup), the source kept when it can
+// change, and that mistakes are reported by line.
+
+use std::fs;
+
+use situation::{
+    TokenSource,
+    parse_workspaces,
+};
+
+#[test]
+fn test_parse_workspaces() {
+    let lookup = |name: &str| {
+        (name == "STAGING_JWT").then(|| " staging-token ".to_string())
+    };
+    let dir = std::env::temp_dir()
+        .join(format!("situation-workspaces-{}", std::process::id()));
+    fs::create_dir_all(&dir).expect("create temp dir");
+    let token_file = dir.join("dev-jwt");
+    fs::write(&token_file, "dev-token\n").expect("write token");
+    let text = format!(
+        "\
+# Workspaces
+[prod]
+api = https://prod.example.com/api
+token = prod-token
+
+[staging]
+api = https://staging.example.com/api
+token_env = STAGING_JWT
+
+[dev]
+api = http://localhost:5380/api
+token_file = {}
+",
+        token_file.display()
+    );
+    let entries = parse_workspaces(&text, lookup).unwrap();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].name, "prod");
+    assert_eq!(
+        entries[0].credentials.api_url,
+        "https://prod.example.com/api"
+    );
+    assert_eq!(entries[0].credentials.token, "prod-token");
+    assert_eq!(entries[1].name, "staging");
+    assert_eq!(entries[1].credentials.token, "staging-token");
+    assert_eq!(entries[2].credentials.token, "dev-token");
+
+    // Only a source that can change is kept, to read the token again after a 401
+    assert_eq!(entries[0].credentials.source, None);
+    assert_eq!(entries[1].credentials.source, None);
+    assert_eq!(
+        entries[2].credentials.source,
+        Some(TokenSource::File(token_file.clone()))
+    );
+
+    // The JWT never appears in debug output
+    assert!(!format!("{:?}", entries[0]).contains("prod-token"));
+
+    assert_eq!(parse_workspaces("", lookup).unwrap(), Vec::new());
+
+    let errors = parse_workspaces(
+        &format!(
+            "\
+api = https://orphan.example.com
+[prod]
+api = https://prod.example.com/api
+token_env = MISSING_JWT
+colour = blue
+[prod]
+token = t
+[.env]
+api = a
+token = t
+[dev]
+api = a
+token = t
+token_command = echo t
+[missing]
+api = a
+token_file = {}
+",
+            dir.join("missing").display()
+        ),
+        lookup,
+    )
+    .unwrap_err();
+    assert_eq!(
+        errors,
+        vec![
+            "line 1: expected a `[name]` section".to_string(),
+            "line 4: environment variable MISSING_JWT is not set".to_string(),
+            "line 5: unknown key 'colour'".to_string(),
+            "line 6: duplicate workspace 'prod'".to_string(),
+            "line 6: workspace 'prod' has no `api`".to_string(),
+            "line 8: invalid name '.env'".to_string(),
+            "line 14: `token_command`: workspace 'dev' already has a token \
+             source"
+                .to_string(),
+            format!(
+                "line 17: cannot read token file {}: {}",
+                dir.join("missing").display(),
+                "No such file or directory (os error 2)"
+            ),
+        ]
+    );
+}